@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use common_utils::{
     crypto::{Encryptable, OptionalEncryptableName},
     pii,
@@ -83,6 +85,9 @@ pub struct MerchantAccountCreate {
     ///(900) for 15 mins
     #[schema(example = 900)]
     pub intent_fulfillment_time: Option<u32>,
+
+    /// Automatic capture and void of payments authorized with manual capture
+    pub capture_schedule: Option<CaptureScheduleConfig>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema)]
@@ -151,6 +156,9 @@ pub struct MerchantAccountUpdate {
     ///Will be used to expire client secret after certain amount of time to be supplied in seconds
     ///(900) for 15 mins
     pub intent_fulfillment_time: Option<u32>,
+
+    /// Automatic capture and void of payments authorized with manual capture
+    pub capture_schedule: Option<CaptureScheduleConfig>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -221,6 +229,10 @@ pub struct MerchantAccountResponse {
     ///Will be used to expire client secret after certain amount of time to be supplied in seconds
     ///(900) for 15 mins
     pub intent_fulfillment_time: Option<i64>,
+
+    /// Automatic capture and void of payments authorized with manual capture
+    #[schema(value_type = Option<CaptureScheduleConfig>)]
+    pub capture_schedule: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -330,6 +342,43 @@ pub struct PrimaryBusinessDetails {
     pub business: String,
 }
 
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CaptureSchedule {
+    /// Number of hours after authorization at which a payment that requires capture is captured
+    /// automatically
+    #[schema(example = 24)]
+    pub auto_capture_after_hours: Option<u32>,
+
+    /// Number of days after authorization at which a payment that has not been captured is
+    /// voided automatically
+    #[schema(example = 7)]
+    pub auto_void_after_days: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CaptureScheduleConfig {
+    /// Schedule applied to payment methods without a schedule of their own
+    pub default_schedule: Option<CaptureSchedule>,
+
+    /// Schedules for specific payment methods
+    #[serde(default)]
+    pub payment_method_schedules: HashMap<api_enums::PaymentMethod, CaptureSchedule>,
+}
+
+impl CaptureScheduleConfig {
+    /// Returns the schedule applicable to payments made using the specified payment method
+    pub fn get_schedule(
+        &self,
+        payment_method: Option<api_enums::PaymentMethod>,
+    ) -> Option<&CaptureSchedule> {
+        payment_method
+            .and_then(|payment_method| self.payment_method_schedules.get(&payment_method))
+            .or(self.default_schedule.as_ref())
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct WebhookDetails {
@@ -747,3 +796,57 @@ pub struct MerchantConnectorDetails {
     #[schema(value_type = Option<Object>,max_length = 255,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<pii::SecretSerdeValue>,
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_capture_schedule_is_resolved_by_payment_method() {
+        let card_schedule = CaptureSchedule {
+            auto_capture_after_hours: Some(24),
+            auto_void_after_days: None,
+        };
+        let default_schedule = CaptureSchedule {
+            auto_capture_after_hours: None,
+            auto_void_after_days: Some(7),
+        };
+        let config = CaptureScheduleConfig {
+            default_schedule: Some(default_schedule.clone()),
+            payment_method_schedules: HashMap::from([(
+                api_enums::PaymentMethod::Card,
+                card_schedule.clone(),
+            )]),
+        };
+
+        assert_eq!(
+            config.get_schedule(Some(api_enums::PaymentMethod::Card)),
+            Some(&card_schedule)
+        );
+        assert_eq!(
+            config.get_schedule(Some(api_enums::PaymentMethod::Wallet)),
+            Some(&default_schedule)
+        );
+        assert_eq!(config.get_schedule(None), Some(&default_schedule));
+        assert_eq!(CaptureScheduleConfig::default().get_schedule(None), None);
+    }
+
+    #[test]
+    fn test_capture_schedule_config_deserialization() {
+        let config: CaptureScheduleConfig = serde_json::from_str(
+            r#"{"payment_method_schedules": {"card": {"auto_capture_after_hours": 12}}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.default_schedule, None);
+        assert_eq!(
+            config.get_schedule(Some(api_enums::PaymentMethod::Card)),
+            Some(&CaptureSchedule {
+                auto_capture_after_hours: Some(12),
+                auto_void_after_days: None,
+            })
+        );
+    }
+}
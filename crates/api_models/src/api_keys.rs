@@ -69,7 +69,7 @@ pub struct CreateApiKeyResponse {
 }
 
 /// The response body for retrieving an API Key.
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Clone, Debug, Serialize, ToSchema)]
 pub struct RetrieveApiKeyResponse {
     /// The identifier for the API Key.
     #[schema(max_length = 64, example = "5hEEqkgJUyuxgSKGArHA4mWSnX")]
@@ -102,12 +102,11 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
-    /*
+
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub last_used: Option<PrimitiveDateTime>,
-    */
 }
 
 /// The request body for updating an API Key.
@@ -157,8 +156,32 @@ pub struct ListApiKeyConstraints {
     pub skip: Option<i64>,
 }
 
+/// The constraints that are applicable when reporting API Keys that have not been used recently.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UnusedApiKeyConstraints {
+    /// The number of days for which an API Key must not have been used to be included in the
+    /// report. Defaults to the value configured in the application.
+    pub unused_for_days: Option<i64>,
+}
+
+/// The response body for the report of API Keys that have not been used recently.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UnusedApiKeysResponse {
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// The number of days for which the reported API Keys have not been used.
+    #[schema(example = 90)]
+    pub unused_for_days: i64,
+
+    /// The API Keys that have not been used within the specified period.
+    pub api_keys: Vec<RetrieveApiKeyResponse>,
+}
+
 /// The expiration date and time for an API Key.
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 #[serde(untagged)]
 pub enum ApiKeyExpiration {
     /// The API Key does not expire.
@@ -91,6 +91,18 @@ pub struct CustomerResponse {
     /// object.
     #[schema(value_type = Option<Object>,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<pii::SecretSerdeValue>,
+    /// The identifier of the payment method used by default for off-session payments of the
+    /// customer
+    #[schema(example = "pm_lmqwg3ngi3wkpzzd9tpy")]
+    pub default_payment_method_id: Option<String>,
+    /// The identifier of the saved address used by default for billing the customer, for payments
+    /// of the customer without a billing address
+    #[schema(example = "add_ld5fuv2sw3hvmg4smwc3")]
+    pub default_billing_address_id: Option<String>,
+    /// The identifier of the saved address used by default for shipping to the customer, for
+    /// payments of the customer without a shipping address
+    #[schema(example = "add_ld5fuv2sw3hvmg4smwc3")]
+    pub default_shipping_address_id: Option<String>,
 }
 
 #[derive(Default, Debug, Deserialize, Serialize)]
@@ -98,6 +110,85 @@ pub struct CustomerId {
     pub customer_id: String,
 }
 
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerDefaultPaymentMethodResponse {
+    /// The identifier for the customer object
+    #[schema(example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+    /// The identifier of the payment method used by default for off-session payments of the
+    /// customer
+    #[schema(example = "pm_lmqwg3ngi3wkpzzd9tpy")]
+    pub default_payment_method_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerListConstraints {
+    /// Exact match on the customer's email address. Customers created before searching by email
+    /// was supported are not matched until their email address is updated.
+    #[schema(value_type = Option<String>, example = "JonTest@test.com")]
+    pub email: Option<pii::Email>,
+
+    /// Exact match on the customer's phone number. Customers created before searching by phone
+    /// number was supported are not matched until their phone number is updated.
+    #[schema(value_type = Option<String>, example = "9999999999")]
+    pub phone: Option<Secret<String>>,
+
+    /// A cursor for use in pagination, fetch the next list after some object
+    #[schema(example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub starting_after: Option<String>,
+
+    /// A cursor for use in pagination, fetch the previous list before some object
+    #[schema(example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub ending_before: Option<String>,
+
+    /// limit on the number of objects to return
+    #[schema(default = 10)]
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+
+    /// The time at which customer is created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub created: Option<time::PrimitiveDateTime>,
+
+    /// Time less than the customer created time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "custom_serde::iso8601::option", rename = "created.lt")]
+    pub created_lt: Option<time::PrimitiveDateTime>,
+
+    /// Time greater than the customer created time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "custom_serde::iso8601::option", rename = "created.gt")]
+    pub created_gt: Option<time::PrimitiveDateTime>,
+
+    /// Time less than or equals to the customer created time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        with = "custom_serde::iso8601::option",
+        rename = "created.lte"
+    )]
+    pub created_lte: Option<time::PrimitiveDateTime>,
+
+    /// Time greater than or equals to the customer created time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        with = "custom_serde::iso8601::option",
+        rename = "created.gte"
+    )]
+    pub created_gte: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct CustomerListResponse {
+    /// The number of customers included in the list
+    pub size: usize,
+    /// The list of customer response objects
+    pub data: Vec<CustomerResponse>,
+}
+
 #[derive(Default, Debug, Deserialize, Serialize, ToSchema)]
 pub struct CustomerDeleteResponse {
     /// The identifier for the customer object
@@ -114,10 +205,148 @@ pub struct CustomerDeleteResponse {
     pub payment_methods_deleted: bool,
 }
 
+/// The details of an address saved in the address book of a customer
+#[derive(Debug, Default, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerAddressRequest {
+    /// The address details
+    pub address: Option<crate::payments::AddressDetails>,
+    /// The phone number associated with the address
+    pub phone: Option<crate::payments::PhoneDetails>,
+    /// Whether the address is to be used by default for billing the customer. Passing `false`
+    /// for the current default billing address unsets it.
+    #[schema(example = true)]
+    pub default_billing: Option<bool>,
+    /// Whether the address is to be used by default for shipping to the customer. Passing
+    /// `false` for the current default shipping address unsets it.
+    #[schema(example = true)]
+    pub default_shipping: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerAddressResponse {
+    /// The identifier for the address, which can be passed as the `billing_address_id` or
+    /// `shipping_address_id` of a payment
+    #[schema(example = "add_ld5fuv2sw3hvmg4smwc3")]
+    pub address_id: String,
+    /// The identifier for the customer object
+    #[schema(example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+    /// The address details
+    pub address: Option<crate::payments::AddressDetails>,
+    /// The phone number associated with the address
+    pub phone: Option<crate::payments::PhoneDetails>,
+    /// Whether the address is used by default for billing the customer
+    #[schema(example = true)]
+    pub default_billing: bool,
+    /// Whether the address is used by default for shipping to the customer
+    #[schema(example = false)]
+    pub default_shipping: bool,
+    /// The time at which the address was saved
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    /// The time at which the address was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerAddressListResponse {
+    /// The number of addresses included in the list
+    pub size: usize,
+    /// The list of addresses saved for the customer
+    pub data: Vec<CustomerAddressResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerAddressDeleteResponse {
+    /// The identifier for the address
+    #[schema(example = "add_ld5fuv2sw3hvmg4smwc3")]
+    pub address_id: String,
+    /// The identifier for the customer object
+    #[schema(example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+    /// Whether the address was deleted or not
+    #[schema(example = true)]
+    pub deleted: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerDataExportConstraints {
+    /// The number of payments to export, most recent first, along with their refunds and disputes
+    #[schema(default = 100)]
+    #[serde(default = "default_export_limit")]
+    pub limit: i64,
+
+    /// The number of most recent payments to skip, to export the older payments of the customer
+    #[schema(default = 0)]
+    #[serde(default)]
+    pub offset: i64,
+}
+
+/// An archive of all the data held for a customer, with the personal details decrypted. Saved
+/// cards are exported with their masked details only.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CustomerDataExport {
+    /// The details of the customer
+    pub customer: CustomerResponse,
+    /// The addresses saved for the customer
+    pub addresses: Vec<crate::payments::Address>,
+    /// The payment methods saved for the customer
+    pub payment_methods: Vec<crate::payment_methods::PaymentMethodResponse>,
+    /// The payments made by the customer
+    pub payments: Vec<crate::payments::PaymentsResponse>,
+    /// The refunds of the payments made by the customer
+    pub refunds: Vec<crate::refunds::RefundResponse>,
+    /// The mandates given by the customer
+    pub mandates: Vec<crate::mandates::MandateResponse>,
+    /// The disputes raised against the payments made by the customer
+    pub disputes: Vec<crate::disputes::DisputeResponse>,
+    /// Whether the customer has older payments than the ones exported
+    pub has_more_payments: bool,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerErasureResponse {
+    /// The identifier for the erasure request
+    #[schema(example = "erasure_4xjH2n0qqbV9mTgsbd4h")]
+    pub erasure_id: String,
+    /// The identifier for the customer object
+    #[schema(example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+    /// The status of the erasure
+    #[schema(value_type = CustomerErasureStatus, example = "pending")]
+    pub status: crate::enums::CustomerErasureStatus,
+    /// The number of records erased for each kind of data, once the erasure is completed
+    #[schema(value_type = Object, example = json!({"payments": 2, "refunds": 1}))]
+    pub erased_records: serde_json::Value,
+    /// The reason for the erasure to fail, if it did
+    pub error_message: Option<String>,
+    /// The time at which the erasure was requested
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    /// The time at which the erasure was completed
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2023-01-18T11:05:09.922Z")]
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
 pub fn generate_customer_id() -> String {
     common_utils::generate_id(consts::ID_LENGTH, "cus")
 }
 
+fn default_limit() -> i64 {
+    10
+}
+
+fn default_export_limit() -> i64 {
+    100
+}
+
 fn unknown_merchant() -> String {
     String::from("merchant_unknown")
 }
@@ -272,6 +272,8 @@ pub enum EventType {
     PaymentSucceeded,
     PaymentFailed,
     PaymentProcessing,
+    PaymentExpired,
+    PaymentCancelled,
     ActionRequired,
     RefundSucceeded,
     RefundFailed,
@@ -282,6 +284,9 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    DisputeEvidenceDue,
+    ApiKeyExpiring,
+    PaymentMethodUpdated,
 }
 
 #[derive(
@@ -311,6 +316,7 @@ pub enum IntentStatus {
     #[default]
     RequiresConfirmation,
     RequiresCapture,
+    Expired,
 }
 
 #[derive(
@@ -920,6 +926,35 @@ pub enum DisputeStatus {
     DisputeLost,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ProcessTrackerStatus {
+    /// Picked up by the producer and added to a batch
+    Processing,
+    /// Newly added, or rescheduled, and waiting to be picked up
+    New,
+    /// Sent for a retry
+    Pending,
+    /// Picked up by a consumer
+    ProcessStarted,
+    /// Finished by a consumer, or cancelled
+    Finish,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use utoipa::ToSchema;
+
+/// The health of a single dependency of the application, or of the application as a whole
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Healthy,
+    Unhealthy,
+}
+
+/// The dependencies checked as part of the readiness check
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthCheckComponent {
+    DatabaseMaster,
+    DatabaseReplica,
+    Redis,
+    Locker,
+    ProcessTracker,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ComponentHealth {
+    /// Whether the component could be reached
+    pub status: HealthStatus,
+
+    /// Time taken for a round trip to the component, in milliseconds
+    #[schema(example = 3)]
+    pub latency_ms: u64,
+
+    /// The reason for the component being unhealthy, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct RouterHealthCheckResponse {
+    /// Unhealthy if any of the components are unhealthy
+    pub status: HealthStatus,
+
+    /// Health of each of the components checked
+    pub components: HashMap<HealthCheckComponent, ComponentHealth>,
+}
@@ -10,9 +10,13 @@ pub mod ephemeral_key;
 #[cfg(feature = "errors")]
 pub mod errors;
 pub mod files;
+pub mod health_check;
 pub mod mandates;
 pub mod payment_methods;
 pub mod payments;
 pub mod payouts;
+pub mod process_tracker;
+pub mod reconciliation;
 pub mod refunds;
+pub mod retry_schedules;
 pub mod webhooks;
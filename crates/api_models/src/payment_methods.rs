@@ -46,6 +46,15 @@ pub struct PaymentMethodCreate {
     /// The card network
     #[schema(example = "Visa")]
     pub card_network: Option<String>,
+
+    /// Wallet details, such as the Apple Pay or Google Pay token or the PayPal billing agreement
+    pub wallet: Option<payments::WalletData>,
+
+    /// Bank account details for bank transfers
+    pub bank_transfer: Option<payments::BankTransferData>,
+
+    /// Bank account details for bank debits, such as ACH, SEPA or BACS
+    pub bank_debit: Option<payments::BankDebitData>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -134,6 +143,14 @@ pub struct PaymentMethodResponse {
     #[schema(value_type = Option<PrimitiveDateTime>, example = "2023-01-18T11:04:09.922Z")]
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub created: Option<time::PrimitiveDateTime>,
+
+    /// The status of the network token provisioned for the card, if any
+    #[schema(value_type = Option<NetworkTokenStatus>, example = "active")]
+    pub network_token_status: Option<api_enums::NetworkTokenStatus>,
+
+    /// The status of the card account, as last reported by the account updater
+    #[schema(value_type = Option<CardAccountStatus>, example = "active")]
+    pub card_account_status: Option<api_enums::CardAccountStatus>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -207,6 +224,9 @@ pub struct ResponsePaymentMethodTypes {
     pub bank_debits: Option<BankDebitTypes>,
     /// The Bank transfer payment method information, if applicable for a payment method type.
     pub bank_transfers: Option<BankTransferTypes>,
+
+    /// The list of installment plans offered, if applicable for a payment method type
+    pub installment_plans: Option<Vec<InstallmentPlanTypes>>,
 }
 
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -226,6 +246,25 @@ pub struct BankTransferTypes {
     pub eligible_connectors: Vec<String>,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq)]
+pub struct InstallmentPlanTypes {
+    /// The number of installments the payment is split into
+    #[schema(example = 6)]
+    pub installment_count: u8,
+
+    /// The amount of each installment. To be represented in the lowest denomination of the payment currency
+    #[schema(example = 1666)]
+    pub installment_amount: i64,
+
+    /// The amount of the first installment, which also carries the remainder of the split
+    #[schema(example = 1670)]
+    pub first_installment_amount: i64,
+
+    /// The list of eligible connectors for a given installment plan
+    #[schema(example = json!(["stripe"]))]
+    pub eligible_connectors: Vec<String>,
+}
+
 #[derive(Clone, Debug)]
 pub struct ResponsePaymentMethodIntermediate {
     pub payment_method_type: api_enums::PaymentMethodType,
@@ -233,6 +272,7 @@ pub struct ResponsePaymentMethodIntermediate {
     pub card_networks: Option<Vec<api_enums::CardNetwork>>,
     pub payment_method: api_enums::PaymentMethod,
     pub connector: String,
+    pub installment_counts: Vec<u8>,
 }
 
 impl ResponsePaymentMethodIntermediate {
@@ -240,6 +280,7 @@ impl ResponsePaymentMethodIntermediate {
         pm_type: RequestPaymentMethodTypes,
         connector: String,
         pm: api_enums::PaymentMethod,
+        installment_counts: Vec<u8>,
     ) -> Self {
         Self {
             payment_method_type: pm_type.payment_method_type,
@@ -247,6 +288,7 @@ impl ResponsePaymentMethodIntermediate {
             card_networks: pm_type.card_networks,
             payment_method: pm,
             connector,
+            installment_counts,
         }
     }
 }
@@ -290,6 +332,24 @@ pub struct RequestPaymentMethodTypes {
     /// Boolean to enable installment / EMI / BNPL payments. Default is true.
     #[schema(default = true, example = false)]
     pub installment_payment_enabled: bool,
+
+    /// Installment plans offered for this payment method type. Only the installment counts also supported by the connector are offered, and only when `installment_payment_enabled` is true
+    pub installment_plans: Option<Vec<InstallmentPlanConfig>>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq, Hash)]
+pub struct InstallmentPlanConfig {
+    /// The currency in which the installment plan is offered
+    #[schema(value_type = Currency, example = "MXN")]
+    pub currency: api_enums::Currency,
+
+    /// The numbers of installments a payment can be split into
+    #[schema(example = json!([3, 6, 12]))]
+    pub installment_counts: Vec<u8>,
+
+    /// Minimum payment amount for which the plan is offered. To be represented in the lowest denomination of the target currency
+    #[schema(example = 30000)]
+    pub minimum_amount: Option<i64>,
 }
 
 //List Payment Method
@@ -489,12 +549,34 @@ pub struct PaymentMethodDeleteResponse {
     pub deleted: bool,
 }
 
+#[derive(Debug, serde::Deserialize, ToSchema)]
+pub struct NetworkTokenUpdateRequest {
+    /// The status to which the network token of the payment method is to be moved
+    #[schema(value_type = NetworkTokenStatus, example = "suspended")]
+    pub status: api_enums::NetworkTokenStatus,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct NetworkTokenResponse {
+    /// The unique identifier of the Payment method
+    #[schema(example = "card_rGK4Vi5iSW70MY7J2mIy")]
+    pub payment_method_id: String,
+
+    /// The status of the network token provisioned for the card
+    #[schema(value_type = NetworkTokenStatus, example = "suspended")]
+    pub status: api_enums::NetworkTokenStatus,
+}
+
 #[derive(Debug, Clone, serde::Serialize, ToSchema)]
 pub struct CustomerPaymentMethod {
     /// Token for payment method in temporary card locker which gets refreshed often
     #[schema(example = "7ebf443f-a050-4067-84e5-e6f6d4800aef")]
     pub payment_token: String,
 
+    /// The unique identifier of the payment method
+    #[schema(example = "card_rGK4Vi5iSW70MY7J2mIy")]
+    pub payment_method_id: String,
+
     /// The unique identifier of the customer.
     #[schema(example = "cus_meowerunwiuwiwqw")]
     pub customer_id: String,
@@ -539,6 +621,10 @@ pub struct CustomerPaymentMethod {
     #[schema(value_type = Option<PrimitiveDateTime>,example = "2023-01-18T11:04:09.922Z")]
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub created: Option<time::PrimitiveDateTime>,
+
+    /// Indicates whether the payment method is the default payment method of the customer
+    #[schema(example = true)]
+    pub default_payment_method_set: bool,
 }
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct PaymentMethodId {
@@ -627,3 +713,13 @@ pub struct TokenizedBankTransferValue1 {
 pub struct TokenizedBankTransferValue2 {
     pub customer_id: Option<String>,
 }
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct TokenizedBankDebitValue1 {
+    pub data: payments::BankDebitData,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct TokenizedBankDebitValue2 {
+    pub customer_id: Option<String>,
+}
@@ -207,6 +207,18 @@ pub struct PaymentsRequest {
     /// The billing address for the payment
     pub billing: Option<Address>,
 
+    /// The identifier of an address saved for the customer, to be used as the shipping address
+    /// for the payment instead of passing `shipping`. When neither is passed, the default shipping
+    /// address of the customer is used, if any.
+    #[schema(example = "add_ld5fuv2sw3hvmg4smwc3")]
+    pub shipping_address_id: Option<String>,
+
+    /// The identifier of an address saved for the customer, to be used as the billing address
+    /// for the payment instead of passing `billing`. When neither is passed, the default billing
+    /// address of the customer is used, if any.
+    #[schema(example = "add_ld5fuv2sw3hvmg4smwc3")]
+    pub billing_address_id: Option<String>,
+
     /// For non-card charges, you can use this value as the complete description that appears on your customers’ statements. Must contain at least one letter, maximum 22 characters.
     #[schema(max_length = 255, example = "Hyperswitch Router")]
     pub statement_descriptor_name: Option<String>,
@@ -259,6 +271,11 @@ pub struct PaymentsRequest {
     #[schema(value_type = Option<PaymentMethodType>, example = "google_pay")]
     pub payment_method_type: Option<api_enums::PaymentMethodType>,
 
+    /// The number of installments to split the payment into. Must be one of the installment plans
+    /// offered for the payment method type in the payment methods list
+    #[schema(example = 6)]
+    pub installment_count: Option<u8>,
+
     /// Business country of the merchant for this payment
     #[schema(value_type = Option<CountryAlpha2>, example = "US")]
     pub business_country: Option<api_enums::CountryAlpha2>,
@@ -1379,6 +1396,10 @@ pub struct PaymentsResponse {
     #[schema(value_type = Option<PaymentMethodType>, example = "gpay")]
     pub payment_method_type: Option<api_enums::PaymentMethodType>,
 
+    /// The number of installments the payment is split into
+    #[schema(example = 6)]
+    pub installment_count: Option<u8>,
+
     /// The connector used for this payment along with the country and business details
     #[schema(example = "stripe_US_food")]
     pub connector_label: Option<String>,
@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums::ProcessTrackerStatus;
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerListConstraints {
+    /// Limit on the number of tasks to return, at most 100
+    #[schema(default = 10)]
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+    /// Runner which executes the task
+    #[schema(example = "PAYMENTS_SYNC_WORKFLOW")]
+    pub runner: Option<String>,
+    /// Status of the task
+    pub status: Option<ProcessTrackerStatus>,
+    /// Business status of the task
+    #[schema(example = "Pending")]
+    pub business_status: Option<String>,
+    /// Merchant for which the task was scheduled
+    pub merchant_id: Option<String>,
+    /// Time less than or equals to the schedule time of the task
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        rename = "schedule_time.lte"
+    )]
+    pub schedule_time_lte: Option<PrimitiveDateTime>,
+    /// Time greater than or equals to the schedule time of the task
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        rename = "schedule_time.gte"
+    )]
+    pub schedule_time_gte: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ProcessTrackerResponse {
+    /// The identifier for the task
+    pub id: String,
+    /// The name of the task
+    pub name: Option<String>,
+    /// Tags associated with the task
+    pub tag: Vec<String>,
+    /// Runner which executes the task
+    pub runner: Option<String>,
+    /// The number of times the task has been retried
+    pub retry_count: i32,
+    /// The time at which the task is scheduled to be executed
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+    /// The data required by the runner to execute the task
+    #[schema(value_type = Object)]
+    pub tracking_data: serde_json::Value,
+    /// Business status of the task
+    pub business_status: String,
+    /// Status of the task
+    pub status: ProcessTrackerStatus,
+    /// Events recorded for the task
+    pub event: Vec<String>,
+    /// Actions performed on the task through the admin APIs
+    #[schema(value_type = Vec<Object>)]
+    pub admin_audit_trail: Vec<serde_json::Value>,
+    /// The time at which the task was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The time at which the task was last updated
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerActionRequest {
+    /// The reason for performing the action, recorded in the audit trail of the task
+    pub reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerRescheduleRequest {
+    /// The time at which the task should be executed
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub schedule_time: PrimitiveDateTime,
+    /// The reason for rescheduling the task, recorded in the audit trail of the task
+    pub reason: Option<String>,
+}
+
+fn default_limit() -> i64 {
+    10
+}
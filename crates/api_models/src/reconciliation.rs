@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums::{Connector, ReconciliationReportType, ReconciliationRunStatus};
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReconciliationRunRequest {
+    /// The connector which generated the report
+    #[schema(example = "stripe")]
+    pub connector: Connector,
+    /// The type of the report
+    pub report_type: ReconciliationReportType,
+    /// The identifier of the report file uploaded through the files API, in the CSV format
+    #[schema(example = "file_sE8s9xMH2EJ4bIrlzLCj")]
+    pub file_id: String,
+    /// Start of the period covered by the report. Along with `period_end`, this is required to
+    /// detect transactions missing from the report.
+    #[schema(example = "2023-06-01T00:00:00Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub period_start: Option<PrimitiveDateTime>,
+    /// End of the period covered by the report
+    #[schema(example = "2023-06-30T23:59:59Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub period_end: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReconciliationRunListConstraints {
+    /// Limit on the number of runs to return
+    pub limit: Option<i64>,
+    /// The connector which generated the reconciled reports
+    pub connector: Option<Connector>,
+    /// Status of the run
+    pub status: Option<ReconciliationRunStatus>,
+    /// Time less than or equals to the creation time of the run
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        rename = "created.lte"
+    )]
+    pub created_lte: Option<PrimitiveDateTime>,
+    /// Time greater than or equals to the creation time of the run
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        rename = "created.gte"
+    )]
+    pub created_gte: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ReconciliationRunResponse {
+    /// The identifier for the reconciliation run
+    pub run_id: String,
+    /// The connector which generated the report
+    pub connector: String,
+    /// The type of the report
+    pub report_type: ReconciliationReportType,
+    /// The file or path from which the report was imported
+    pub report_source: String,
+    /// Status of the run
+    pub status: ReconciliationRunStatus,
+    /// Start of the period covered by the report
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub period_start: Option<PrimitiveDateTime>,
+    /// End of the period covered by the report
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub period_end: Option<PrimitiveDateTime>,
+    /// The number of transactions in the report
+    pub total_records: i32,
+    /// The number of transactions in the report which match the records of hyperswitch
+    pub matched_records: i32,
+    /// The discrepancies found between the report and the records of hyperswitch
+    pub mismatches: Vec<ReconciliationMismatch>,
+    /// The reason for the failure of the run
+    pub error_message: Option<String>,
+    /// The time at which the run was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The time at which the run was last updated
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ReconciliationMismatch {
+    /// The kind of discrepancy
+    pub mismatch_type: ReconciliationMismatchType,
+    /// The kind of transaction
+    pub transaction_type: ReconciliationTransactionType,
+    /// The identifier of the transaction at the connector
+    pub connector_transaction_id: String,
+    /// The identifier for the payment, if the transaction is known to hyperswitch
+    pub payment_id: Option<String>,
+    /// The identifier for the refund, if the transaction is a refund known to hyperswitch
+    pub refund_id: Option<String>,
+    /// The value recorded by hyperswitch
+    pub expected: Option<String>,
+    /// The value present in the report
+    pub reported: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconciliationMismatchType {
+    /// The amount in the report differs from the amount recorded by hyperswitch
+    AmountMismatch,
+    /// The currency in the report differs from the currency recorded by hyperswitch
+    CurrencyMismatch,
+    /// The transaction has a different outcome in the report than in hyperswitch
+    StatusMismatch,
+    /// The transaction is present in the report, but not in hyperswitch
+    ExtraTransaction,
+    /// The transaction is present in hyperswitch, but not in the report
+    MissingTransaction,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconciliationTransactionType {
+    Payment,
+    Refund,
+}
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::enums::PaymentMethod;
+
+/// Workflows retried according to a retry schedule configured per connector
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorRetryWorkflow {
+    /// Synchronizing the status of payments with the connector
+    PaymentSync,
+    /// Synchronizing the status of refunds with the connector
+    RefundSync,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RetryMapping {
+    /// Delay before the first attempt (in seconds)
+    #[schema(example = 60)]
+    pub start_after: i32,
+    /// Delays between retries (in seconds), applied for the corresponding number of retries in
+    /// `count`
+    #[schema(example = json!([300, 600]))]
+    pub frequency: Vec<i32>,
+    /// Number of retries for which each delay in `frequency` is applied
+    #[schema(example = json!([5, 3]))]
+    pub count: Vec<i32>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorRetrySchedule {
+    /// Retry mapping applied to merchants without a custom mapping
+    pub default_mapping: RetryMapping,
+    /// Retry mappings for specific merchants, keyed by the merchant ID
+    #[serde(default)]
+    pub custom_merchant_mapping: HashMap<String, RetryMapping>,
+    /// Maximum number of retries
+    #[schema(example = 5)]
+    pub max_retries_count: i32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodRetrySchedule {
+    /// Retry mapping applied to payment methods without a custom mapping
+    pub default_mapping: RetryMapping,
+    /// Retry mappings for specific payment methods
+    #[serde(default)]
+    pub custom_pm_mapping: HashMap<PaymentMethod, RetryMapping>,
+    /// Maximum number of retries
+    #[schema(example = 5)]
+    pub max_retries_count: i32,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ConnectorRetryScheduleResponse {
+    /// The connector to which the retry schedule applies
+    #[schema(example = "stripe")]
+    pub connector: String,
+    /// The workflow to which the retry schedule applies
+    pub workflow: ConnectorRetryWorkflow,
+    /// Whether the default retry schedule is applied, since none has been configured
+    pub is_default: bool,
+    pub schedule: ConnectorRetrySchedule,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct PaymentMethodRetryScheduleResponse {
+    /// The payment method to which the retry schedule applies
+    pub payment_method: PaymentMethod,
+    /// Whether the default retry schedule is applied, since none has been configured
+    pub is_default: bool,
+    pub schedule: PaymentMethodRetrySchedule,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorRetryTimelineRequest {
+    /// Merchant for which the retry timeline is to be previewed, the default mapping is used if
+    /// not provided
+    pub merchant_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct RetryTimelineEntry {
+    /// The attempt number, with `0` being the first attempt
+    pub attempt: i32,
+    /// Delay after the previous attempt (in seconds)
+    pub delay: i32,
+    /// Time elapsed since the task was created (in seconds)
+    pub elapsed: i64,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct RetryTimelineResponse {
+    /// The attempts made before the task is marked as `RETRIES_EXCEEDED`
+    pub timeline: Vec<RetryTimelineEntry>,
+}
@@ -2,7 +2,7 @@ use common_utils::custom_serde;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
-use crate::{disputes, enums as api_enums, payments, refunds};
+use crate::{api_keys, disputes, enums as api_enums, payment_methods, payments, refunds};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -96,6 +96,8 @@ pub enum OutgoingWebhookContent {
     PaymentDetails(payments::PaymentsResponse),
     RefundDetails(refunds::RefundResponse),
     DisputeDetails(Box<disputes::DisputeResponse>),
+    ApiKeyDetails(Box<api_keys::RetrieveApiKeyResponse>),
+    PaymentMethodDetails(Box<payment_methods::PaymentMethodResponse>),
 }
 
 pub trait OutgoingWebhookType:
@@ -1,4 +1,6 @@
-//! Interactions with the AWS SES SDK
+//! Interactions with the AWS SES SDK and SMTP servers
+
+use std::time::Duration;
 
 use aws_config::meta::region::RegionProviderChain;
 use aws_sdk_sesv2::{
@@ -9,7 +11,12 @@ use aws_sdk_sesv2::{
 };
 use common_utils::{errors::CustomResult, pii};
 use error_stack::{IntoReport, ResultExt};
-use masking::PeekInterface;
+use lettre::{
+    message::{header::ContentType, Mailbox},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+use masking::{PeekInterface, Secret};
 use serde::Deserialize;
 
 /// Custom Result type alias for Email operations.
@@ -40,6 +47,89 @@ pub struct EmailSettings {
 
     /// Base-url used when adding links that should redirect to self
     pub base_url: String,
+
+    /// The email client to be used for sending emails.
+    #[serde(default)]
+    pub active_email_client: EmailClientType,
+
+    /// Settings used when the active email client is an SMTP server.
+    #[serde(default)]
+    pub smtp: SmtpSettings,
+
+    /// Whether notification emails should be sent for events such as payments and refunds.
+    #[serde(default)]
+    pub notifications_enabled: bool,
+}
+
+/// The email clients that can be used for sending emails.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailClientType {
+    /// Send emails using AWS SES.
+    #[default]
+    AwsSes,
+
+    /// Send emails using an SMTP server.
+    Smtp,
+}
+
+/// Struct that contains the settings required to connect to an SMTP server.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SmtpSettings {
+    /// Hostname of the SMTP server.
+    pub host: String,
+
+    /// Port of the SMTP server.
+    pub port: u16,
+
+    /// Username used for authenticating with the SMTP server.
+    pub username: Option<String>,
+
+    /// Password used for authenticating with the SMTP server.
+    pub password: Option<Secret<String>>,
+
+    /// The kind of connection to be established with the SMTP server.
+    pub connection: SmtpConnection,
+
+    /// Timeout (in seconds) for the requests made to the SMTP server.
+    pub timeout: u64,
+}
+
+impl Default for SmtpSettings {
+    fn default() -> Self {
+        Self {
+            host: "localhost".into(),
+            port: 25,
+            username: None,
+            password: None,
+            connection: SmtpConnection::default(),
+            timeout: 10,
+        }
+    }
+}
+
+/// The kinds of connections that can be established with an SMTP server.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpConnection {
+    /// Unencrypted connection, to be used only for local development.
+    Plaintext,
+
+    /// Connection that is upgraded to TLS using the `STARTTLS` command.
+    #[default]
+    StartTls,
+
+    /// Connection that uses TLS from the start.
+    Tls,
+}
+
+/// Constructs the email client configured in the email settings.
+pub async fn get_email_client(conf: &EmailSettings) -> EmailResult<Box<dyn EmailClient>> {
+    match conf.active_email_client {
+        EmailClientType::AwsSes => Ok(Box::new(AwsSes::new(conf).await)),
+        EmailClientType::Smtp => Ok(Box::new(SmtpServer::new(conf)?)),
+    }
 }
 
 /// Client for AWS SES operation
@@ -102,6 +192,102 @@ impl EmailClient for AwsSes {
     }
 }
 
+/// Client for sending emails through an SMTP server
+#[derive(Clone)]
+pub struct SmtpServer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from_email: Mailbox,
+}
+
+impl std::fmt::Debug for SmtpServer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SmtpServer")
+            .field("from_email", &self.from_email)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SmtpServer {
+    /// Constructs a new SMTP email client
+    pub fn new(conf: &EmailSettings) -> EmailResult<Self> {
+        let smtp = &conf.smtp;
+        let builder = match smtp.connection {
+            SmtpConnection::Plaintext => {
+                AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&smtp.host)
+            }
+            SmtpConnection::StartTls => {
+                AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&smtp.host)
+                    .map_err(SmtpError::TransportFailure)
+                    .into_report()
+                    .change_context(EmailError::ClientBuildingFailure)?
+            }
+            SmtpConnection::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&smtp.host)
+                .map_err(SmtpError::TransportFailure)
+                .into_report()
+                .change_context(EmailError::ClientBuildingFailure)?,
+        }
+        .port(smtp.port)
+        .timeout(Some(Duration::from_secs(smtp.timeout)));
+
+        let builder = match (&smtp.username, &smtp.password) {
+            (Some(username), Some(password)) if !username.is_empty() => builder.credentials(
+                Credentials::new(username.to_owned(), password.peek().to_owned()),
+            ),
+            _ => builder,
+        };
+
+        let from_email = conf
+            .from_email
+            .parse::<Mailbox>()
+            .map_err(SmtpError::InvalidAddress)
+            .into_report()
+            .change_context(EmailError::ClientBuildingFailure)
+            .attach_printable("Invalid sender email address")?;
+
+        Ok(Self {
+            transport: builder.build(),
+            from_email,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailClient for SmtpServer {
+    async fn send_email(
+        &self,
+        recipient: pii::Email,
+        subject: String,
+        body: String,
+    ) -> EmailResult<()> {
+        let recipient = recipient
+            .peek()
+            .parse::<Mailbox>()
+            .map_err(SmtpError::InvalidAddress)
+            .into_report()
+            .change_context(EmailError::EmailSendingFailure)
+            .attach_printable("Invalid recipient email address")?;
+
+        let message = Message::builder()
+            .from(self.from_email.clone())
+            .to(recipient)
+            .subject(subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(body)
+            .map_err(SmtpError::MessageBuildingFailure)
+            .into_report()
+            .change_context(EmailError::EmailSendingFailure)?;
+
+        self.transport
+            .send(message)
+            .await
+            .map_err(SmtpError::TransportFailure)
+            .into_report()
+            .change_context(EmailError::EmailSendingFailure)?;
+
+        Ok(())
+    }
+}
+
 /// Errors that could occur from EmailClient.
 #[derive(Debug, thiserror::Error)]
 pub enum EmailError {
@@ -121,3 +307,84 @@ pub enum AwsSesError {
     #[error("Failed to Send Email {0:?}")]
     SendingFailure(aws_smithy_client::SdkError<SendEmailError>),
 }
+
+/// Errors that could occur during SMTP operations.
+#[derive(Debug, thiserror::Error)]
+pub enum SmtpError {
+    /// An error occurred while parsing an email address.
+    #[error("Invalid email address {0:?}")]
+    InvalidAddress(lettre::address::AddressError),
+
+    /// An error occurred while building the email message.
+    #[error("Failed to build email message {0:?}")]
+    MessageBuildingFailure(lettre::error::Error),
+
+    /// An error occurred while communicating with the SMTP server.
+    #[error("Failed to send email through SMTP {0:?}")]
+    TransportFailure(lettre::transport::smtp::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn smtp_settings(from_email: &str) -> EmailSettings {
+        EmailSettings {
+            from_email: from_email.into(),
+            active_email_client: EmailClientType::Smtp,
+            smtp: SmtpSettings {
+                connection: SmtpConnection::Plaintext,
+                username: Some("user".into()),
+                password: Some(Secret::new("password".into())),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_smtp_server_uses_configured_sender() {
+        let smtp_server =
+            SmtpServer::new(&smtp_settings("Notifications <notify@example.com>")).unwrap();
+
+        assert_eq!(
+            smtp_server.from_email.email.to_string(),
+            "notify@example.com"
+        );
+        assert_eq!(
+            smtp_server.from_email.name.as_deref(),
+            Some("Notifications")
+        );
+    }
+
+    #[test]
+    fn test_smtp_server_rejects_invalid_sender() {
+        let error = SmtpServer::new(&smtp_settings("not an email")).unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            EmailError::ClientBuildingFailure
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_smtp_server_rejects_invalid_recipient() {
+        let smtp_server = SmtpServer::new(&smtp_settings("notify@example.com")).unwrap();
+
+        let error = smtp_server
+            .send_email(
+                pii::Email::from(Secret::new("not an email".to_string())),
+                "Subject".into(),
+                "Body".into(),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            EmailError::EmailSendingFailure
+        ));
+    }
+}
@@ -15,7 +15,7 @@ use common_utils::{
 };
 use error_stack::{IntoReport, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, StreamsInterface},
+    interfaces::{HashesInterface, KeysInterface, LuaInterface, StreamsInterface},
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
         RedisKey, RedisMap, RedisValue, Scanner, SetOptions, XCap, XReadResponse,
@@ -26,9 +26,18 @@ use router_env::{instrument, logger, tracing};
 
 use crate::{
     errors,
-    types::{DelReply, HsetnxReply, MsetnxReply, RedisEntryId, SetnxReply},
+    types::{DelReply, HsetnxReply, MsetnxReply, RedisEntryId, SetnxReply, StreamPendingEntry},
 };
 
+/// Sets the hash field `ARGV[1]` of the key to `ARGV[3]` if it holds `ARGV[2]`, returning whether
+/// the field was set.
+const SET_HASH_FIELD_IF_EQUAL_SCRIPT: &str = "\
+if redis.call('HGET', KEYS[1], ARGV[1]) == ARGV[2] then
+    redis.call('HSET', KEYS[1], ARGV[1], ARGV[3])
+    return 1
+end
+return 0";
+
 impl super::RedisConnectionPool {
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_key<V>(&self, key: &str, value: V) -> CustomResult<(), errors::RedisError>
@@ -197,6 +206,32 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn set_key_if_not_exists_with_expiry<V>(
+        &self,
+        key: &str,
+        value: V,
+        seconds: Option<i64>,
+    ) -> CustomResult<SetnxReply, errors::RedisError>
+    where
+        V: TryInto<RedisValue> + Debug + Send + Sync,
+        V::Error: Into<fred::error::RedisError> + Send + Sync,
+    {
+        self.pool
+            .set(
+                key,
+                value,
+                Some(Expiration::EX(
+                    seconds.unwrap_or(self.config.default_ttl.into()),
+                )),
+                Some(SetOptions::NX),
+                false,
+            )
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SetFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_expiry(
         &self,
@@ -273,6 +308,35 @@ impl super::RedisConnectionPool {
             .await
     }
 
+    /// Sets the field of the hash to the value if the field holds the expected value, the
+    /// comparison and the update being performed atomically. Returns whether the field was set.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn set_hash_field_if_equal(
+        &self,
+        key: &str,
+        field: &str,
+        expected_value: &str,
+        value: &str,
+    ) -> CustomResult<bool, errors::RedisError> {
+        let reply: i64 = self
+            .pool
+            .eval(
+                SET_HASH_FIELD_IF_EQUAL_SCRIPT,
+                key,
+                vec![field, expected_value, value],
+            )
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SetHashFieldFailed)?;
+
+        let is_set = reply == 1;
+        if is_set {
+            self.set_expiry(key, self.config.default_hash_ttl.into())
+                .await?;
+        }
+        Ok(is_set)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn serialize_and_set_hash_field_if_not_exist<V>(
         &self,
@@ -630,6 +694,40 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::ConsumerGroupSetIdFailed)
     }
 
+    /// Obtains at most `count` entries of the consumer group which have not been acknowledged for
+    /// at least `min_idle_time` milliseconds.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn consumer_group_get_pending_entries(
+        &self,
+        stream: &str,
+        group: &str,
+        min_idle_time: u64,
+        count: u64,
+    ) -> CustomResult<Vec<StreamPendingEntry>, errors::RedisError> {
+        let entries = self
+            .pool
+            .xpending::<Vec<(String, String, u64, u64)>, _, _, _>(
+                stream,
+                group,
+                (min_idle_time, "-", "+", count),
+            )
+            .await
+            .into_report()
+            .change_context(errors::RedisError::ConsumerGroupPendingEntriesFailed)?;
+
+        Ok(entries
+            .into_iter()
+            .map(
+                |(id, consumer, idle_time, delivery_count)| StreamPendingEntry {
+                    id,
+                    consumer,
+                    idle_time,
+                    delivery_count,
+                },
+            )
+            .collect())
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn consumer_group_set_message_owner<Ids, R>(
         &self,
@@ -38,6 +38,8 @@ pub enum RedisError {
     ConsumerGroupSetIdFailed,
     #[error("Failed to set Redis stream message owner")]
     ConsumerGroupClaimFailed,
+    #[error("Failed to get pending entries of Redis consumer group")]
+    ConsumerGroupPendingEntriesFailed,
     #[error("Failed to serialize application type to JSON")]
     JsonSerializationFailed,
     #[error("Failed to deserialize application type from JSON")]
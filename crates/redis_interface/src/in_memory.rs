@@ -0,0 +1,1323 @@
+//!
+//! In-memory stand-in for a Redis server, to be used when no Redis server is available
+//! (such as while running tests).
+//!
+//! The commands issued by the `fred` clients are served from an in-memory store instead of being
+//! sent to a server. Only the key-value, hash, stream and pub/sub commands used by the
+//! application are supported.
+//!
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use fred::{
+    error::{RedisError, RedisErrorKind},
+    mocks::{MockCommand, Mocks},
+    types::RedisValue,
+};
+
+/// The in-memory store backing the Redis clients.
+#[derive(Debug, Default)]
+pub struct InMemoryRedis {
+    keys: Mutex<HashMap<String, Entry>>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    value: Value,
+    expires_at: Option<SystemTime>,
+}
+
+impl Entry {
+    fn new(value: Value) -> Self {
+        Self {
+            value,
+            expires_at: None,
+        }
+    }
+
+    fn is_expired(&self, now: SystemTime) -> bool {
+        self.expires_at
+            .map(|expires_at| expires_at <= now)
+            .unwrap_or(false)
+    }
+}
+
+#[derive(Debug)]
+enum Value {
+    String(Vec<u8>),
+    Hash(HashMap<String, Vec<u8>>),
+    Stream(Stream),
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+struct StreamId {
+    milliseconds: u64,
+    sequence_number: u64,
+}
+
+impl StreamId {
+    fn parse(id: &str) -> Result<Self, RedisError> {
+        let (milliseconds, sequence_number) = id.split_once('-').unwrap_or((id, "0"));
+        Ok(Self {
+            milliseconds: milliseconds.parse().map_err(|_| invalid_stream_id(id))?,
+            sequence_number: sequence_number.parse().map_err(|_| invalid_stream_id(id))?,
+        })
+    }
+}
+
+impl std::fmt::Display for StreamId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.milliseconds, self.sequence_number)
+    }
+}
+
+type StreamFields = Vec<(Vec<u8>, Vec<u8>)>;
+
+#[derive(Debug, Default)]
+struct Stream {
+    entries: BTreeMap<StreamId, StreamFields>,
+    last_id: StreamId,
+    groups: HashMap<String, ConsumerGroup>,
+}
+
+#[derive(Debug, Default)]
+struct ConsumerGroup {
+    last_delivered_id: StreamId,
+    pending: BTreeMap<StreamId, PendingEntry>,
+}
+
+#[derive(Debug)]
+struct PendingEntry {
+    consumer: String,
+    delivered_at: SystemTime,
+    delivery_count: u64,
+}
+
+impl Mocks for InMemoryRedis {
+    fn process_command(&self, command: MockCommand) -> Result<RedisValue, RedisError> {
+        let mut args = command.args;
+        let name = match command.subcommand {
+            Some(subcommand) => format!("{} {}", &*command.cmd, &*subcommand),
+            None => match command.cmd.to_uppercase().as_str() {
+                // Container commands which may have their subcommand sent as the first argument
+                cmd @ ("XGROUP" | "XINFO") if !args.is_empty() => {
+                    format!("{cmd} {}", to_string(&args.remove(0)))
+                }
+                cmd => cmd.to_string(),
+            },
+        };
+
+        let mut keys = self.keys.lock().unwrap_or_else(|error| error.into_inner());
+        let now = SystemTime::now();
+        keys.retain(|_, entry| !entry.is_expired(now));
+
+        let mut store = Store {
+            keys: &mut keys,
+            now,
+        };
+        store.execute(&name.to_uppercase(), &args)
+    }
+}
+
+struct Store<'a> {
+    keys: &'a mut HashMap<String, Entry>,
+    now: SystemTime,
+}
+
+impl<'a> Store<'a> {
+    fn execute(&mut self, command: &str, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        match command {
+            "PING" => Ok(ok_string("PONG")),
+            "QUIT" | "SUBSCRIBE" | "UNSUBSCRIBE" | "PSUBSCRIBE" | "PUNSUBSCRIBE" | "SELECT"
+            | "CLIENT SETNAME" => Ok(ok_string("OK")),
+            // Messages are not delivered to any subscribers, but are reported as being received
+            // so that publishers do not consider the publish to have failed.
+            "PUBLISH" => Ok(RedisValue::Integer(1)),
+
+            "GET" => self.get(args),
+            "MGET" => Ok(RedisValue::Array(
+                args.iter()
+                    .map(|key| self.get(std::slice::from_ref(key)))
+                    .collect::<Result<_, _>>()?,
+            )),
+            "SET" => self.set(args),
+            "MSETNX" => self.msetnx(args),
+            "EXISTS" => Ok(integer(
+                args.iter()
+                    .filter(|key| self.keys.contains_key(&to_string(key)))
+                    .count(),
+            )),
+            "DEL" => Ok(integer(
+                args.iter()
+                    .filter(|key| self.keys.remove(&to_string(key)).is_some())
+                    .count(),
+            )),
+            "EXPIRE" => {
+                let seconds = to_u64(arg(args, 1)?)?;
+                self.expire_at(args, self.now + Duration::from_secs(seconds))
+            }
+            "EXPIREAT" => {
+                let timestamp = to_u64(arg(args, 1)?)?;
+                self.expire_at(args, UNIX_EPOCH + Duration::from_secs(timestamp))
+            }
+
+            "HSET" => self.hset(args, false),
+            "HSETNX" => self.hset(args, true),
+            "HGET" => self.hget(args),
+            "HGETALL" => self.hgetall(args),
+            "HDEL" => self.hdel(args),
+            "HSCAN" => self.hscan(args),
+
+            "XADD" => self.xadd(args),
+            "XDEL" => self.xdel(args),
+            "XTRIM" => self.xtrim(args),
+            "XLEN" => Ok(integer(
+                self.stream(&to_string(arg(args, 0)?))?
+                    .map(|stream| stream.entries.len())
+                    .unwrap_or_default(),
+            )),
+            "XACK" => self.xack(args),
+            "XREAD" => self.xread(args),
+            "XREADGROUP" => self.xreadgroup(args),
+            "XCLAIM" => self.xclaim(args),
+            "XPENDING" => self.xpending(args),
+            "XGROUP CREATE" => self.xgroup_create(args),
+            "XGROUP DESTROY" => self.xgroup_destroy(args),
+            "XGROUP DELCONSUMER" => self.xgroup_delconsumer(args),
+            "XGROUP SETID" => self.xgroup_setid(args),
+
+            _ => Err(RedisError::new(
+                RedisErrorKind::Unknown,
+                format!("Command `{command}` is not supported by the in-memory store"),
+            )),
+        }
+    }
+
+    fn get(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        match self.keys.get(&to_string(arg(args, 0)?)) {
+            Some(Entry {
+                value: Value::String(value),
+                ..
+            }) => Ok(RedisValue::Bytes(value.clone().into())),
+            Some(_) => Err(wrong_type()),
+            None => Ok(RedisValue::Null),
+        }
+    }
+
+    fn set(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        let key = to_string(arg(args, 0)?);
+        let value = to_bytes(arg(args, 1)?);
+
+        let mut expires_at = None;
+        let mut only_if_not_exists = false;
+        let mut only_if_exists = false;
+        let mut options = args.iter().skip(2);
+        while let Some(option) = options.next() {
+            match to_string(option).to_uppercase().as_str() {
+                "EX" => {
+                    let seconds = to_u64(options.next().ok_or_else(syntax_error)?)?;
+                    expires_at = Some(self.now + Duration::from_secs(seconds));
+                }
+                "PX" => {
+                    let milliseconds = to_u64(options.next().ok_or_else(syntax_error)?)?;
+                    expires_at = Some(self.now + Duration::from_millis(milliseconds));
+                }
+                "EXAT" => {
+                    let timestamp = to_u64(options.next().ok_or_else(syntax_error)?)?;
+                    expires_at = Some(UNIX_EPOCH + Duration::from_secs(timestamp));
+                }
+                "PXAT" => {
+                    let timestamp = to_u64(options.next().ok_or_else(syntax_error)?)?;
+                    expires_at = Some(UNIX_EPOCH + Duration::from_millis(timestamp));
+                }
+                "NX" => only_if_not_exists = true,
+                "XX" => only_if_exists = true,
+                "KEEPTTL" => {
+                    expires_at = self.keys.get(&key).and_then(|entry| entry.expires_at);
+                }
+                _ => return Err(syntax_error()),
+            }
+        }
+
+        let exists = self.keys.contains_key(&key);
+        if (only_if_not_exists && exists) || (only_if_exists && !exists) {
+            return Ok(RedisValue::Null);
+        }
+
+        self.keys.insert(
+            key,
+            Entry {
+                value: Value::String(value),
+                expires_at,
+            },
+        );
+        Ok(ok_string("OK"))
+    }
+
+    fn msetnx(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        let pairs = to_pairs(args)?;
+        if pairs.iter().any(|(key, _)| {
+            self.keys
+                .contains_key(&String::from_utf8_lossy(key).to_string())
+        }) {
+            return Ok(RedisValue::Integer(0));
+        }
+
+        for (key, value) in pairs {
+            self.keys.insert(
+                String::from_utf8_lossy(&key).to_string(),
+                Entry::new(Value::String(value)),
+            );
+        }
+        Ok(RedisValue::Integer(1))
+    }
+
+    fn expire_at(
+        &mut self,
+        args: &[RedisValue],
+        expires_at: SystemTime,
+    ) -> Result<RedisValue, RedisError> {
+        match self.keys.get_mut(&to_string(arg(args, 0)?)) {
+            Some(entry) => {
+                entry.expires_at = Some(expires_at);
+                Ok(RedisValue::Integer(1))
+            }
+            None => Ok(RedisValue::Integer(0)),
+        }
+    }
+
+    fn hash(&mut self, key: &str) -> Result<Option<&mut HashMap<String, Vec<u8>>>, RedisError> {
+        match self.keys.get_mut(key) {
+            Some(Entry {
+                value: Value::Hash(hash),
+                ..
+            }) => Ok(Some(hash)),
+            Some(_) => Err(wrong_type()),
+            None => Ok(None),
+        }
+    }
+
+    fn hset(
+        &mut self,
+        args: &[RedisValue],
+        only_if_not_exists: bool,
+    ) -> Result<RedisValue, RedisError> {
+        let key = to_string(arg(args, 0)?);
+        let pairs = to_pairs(&args[1..])?;
+
+        if self.hash(&key)?.is_none() {
+            self.keys
+                .insert(key.clone(), Entry::new(Value::Hash(HashMap::new())));
+        }
+        let hash = self.hash(&key)?.ok_or_else(wrong_type)?;
+
+        let mut added = 0;
+        for (field, value) in pairs {
+            let field = String::from_utf8_lossy(&field).to_string();
+            if only_if_not_exists && hash.contains_key(&field) {
+                continue;
+            }
+            if hash.insert(field, value).is_none() {
+                added += 1;
+            }
+        }
+        Ok(RedisValue::Integer(added))
+    }
+
+    fn hget(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        let field = to_string(arg(args, 1)?);
+        Ok(self
+            .hash(&to_string(arg(args, 0)?))?
+            .and_then(|hash| hash.get(&field))
+            .map(|value| RedisValue::Bytes(value.clone().into()))
+            .unwrap_or(RedisValue::Null))
+    }
+
+    fn hgetall(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        Ok(RedisValue::Array(
+            self.hash(&to_string(arg(args, 0)?))?
+                .map(|hash| {
+                    hash.iter()
+                        .flat_map(|(field, value)| {
+                            [
+                                RedisValue::String(field.clone().into()),
+                                RedisValue::Bytes(value.clone().into()),
+                            ]
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        ))
+    }
+
+    fn hdel(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        let removed = match self.hash(&to_string(arg(args, 0)?))? {
+            Some(hash) => args[1..]
+                .iter()
+                .filter(|field| hash.remove(&to_string(field)).is_some())
+                .count(),
+            None => 0,
+        };
+        Ok(integer(removed))
+    }
+
+    fn hscan(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        let key = to_string(arg(args, 0)?);
+        let mut pattern = None;
+        let mut options = args.iter().skip(2);
+        while let Some(option) = options.next() {
+            match to_string(option).to_uppercase().as_str() {
+                "MATCH" => pattern = Some(to_string(options.next().ok_or_else(syntax_error)?)),
+                "COUNT" => {
+                    options.next().ok_or_else(syntax_error)?;
+                }
+                _ => return Err(syntax_error()),
+            }
+        }
+
+        // All the matching fields are returned in a single iteration
+        let fields = self
+            .hash(&key)?
+            .map(|hash| {
+                hash.iter()
+                    .filter(|(field, _)| {
+                        pattern
+                            .as_deref()
+                            .map(|pattern| glob_match(pattern.as_bytes(), field.as_bytes()))
+                            .unwrap_or(true)
+                    })
+                    .flat_map(|(field, value)| {
+                        [
+                            RedisValue::String(field.clone().into()),
+                            RedisValue::Bytes(value.clone().into()),
+                        ]
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(RedisValue::Array(vec![
+            RedisValue::String("0".into()),
+            RedisValue::Array(fields),
+        ]))
+    }
+
+    fn stream(&mut self, key: &str) -> Result<Option<&mut Stream>, RedisError> {
+        match self.keys.get_mut(key) {
+            Some(Entry {
+                value: Value::Stream(stream),
+                ..
+            }) => Ok(Some(stream)),
+            Some(_) => Err(wrong_type()),
+            None => Ok(None),
+        }
+    }
+
+    fn stream_or_create(&mut self, key: &str) -> Result<&mut Stream, RedisError> {
+        if self.stream(key)?.is_none() {
+            self.keys.insert(
+                key.to_string(),
+                Entry::new(Value::Stream(Stream::default())),
+            );
+        }
+        self.stream(key)?.ok_or_else(wrong_type)
+    }
+
+    fn xadd(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        let key = to_string(arg(args, 0)?);
+
+        let mut index = 1;
+        let mut create_stream = true;
+        let mut cap = None;
+        loop {
+            match to_string(arg(args, index)?).to_uppercase().as_str() {
+                "NOMKSTREAM" => {
+                    create_stream = false;
+                    index += 1;
+                }
+                kind @ ("MAXLEN" | "MINID") => {
+                    let (trim, consumed) = parse_trim(kind, &args[index + 1..])?;
+                    cap = Some(trim);
+                    index += 1 + consumed;
+                }
+                _ => break,
+            }
+        }
+
+        if !create_stream && self.stream(&key)?.is_none() {
+            return Ok(RedisValue::Null);
+        }
+
+        let id = to_string(arg(args, index)?);
+        let fields = to_pairs(&args[index + 1..])?;
+        let now = self.now;
+        let stream = self.stream_or_create(&key)?;
+
+        let id = if id == "*" {
+            let milliseconds = u64::try_from(
+                now.duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis(),
+            )
+            .unwrap_or(u64::MAX);
+            if milliseconds > stream.last_id.milliseconds {
+                StreamId {
+                    milliseconds,
+                    sequence_number: 0,
+                }
+            } else {
+                StreamId {
+                    milliseconds: stream.last_id.milliseconds,
+                    sequence_number: stream.last_id.sequence_number + 1,
+                }
+            }
+        } else {
+            let id = StreamId::parse(&id)?;
+            if id <= stream.last_id {
+                return Err(RedisError::new(
+                    RedisErrorKind::InvalidArgument,
+                    "The ID specified in XADD is equal or smaller than the target stream top item",
+                ));
+            }
+            id
+        };
+
+        stream.entries.insert(id, fields);
+        stream.last_id = id;
+        if let Some(cap) = cap {
+            cap.trim(stream);
+        }
+        Ok(RedisValue::String(id.to_string().into()))
+    }
+
+    fn xdel(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        let ids = args[1..]
+            .iter()
+            .map(|id| StreamId::parse(&to_string(id)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let removed = match self.stream(&to_string(arg(args, 0)?))? {
+            Some(stream) => ids
+                .iter()
+                .filter(|id| stream.entries.remove(id).is_some())
+                .count(),
+            None => 0,
+        };
+        Ok(integer(removed))
+    }
+
+    fn xtrim(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        let kind = to_string(arg(args, 1)?).to_uppercase();
+        let (trim, _) = parse_trim(&kind, &args[2..])?;
+        let removed = match self.stream(&to_string(arg(args, 0)?))? {
+            Some(stream) => trim.trim(stream),
+            None => 0,
+        };
+        Ok(integer(removed))
+    }
+
+    fn xack(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        let group = to_string(arg(args, 1)?);
+        let ids = args[2..]
+            .iter()
+            .map(|id| StreamId::parse(&to_string(id)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let acknowledged = match self
+            .stream(&to_string(arg(args, 0)?))?
+            .and_then(|stream| stream.groups.get_mut(&group))
+        {
+            Some(group) => ids
+                .iter()
+                .filter(|id| group.pending.remove(id).is_some())
+                .count(),
+            None => 0,
+        };
+        Ok(integer(acknowledged))
+    }
+
+    fn xread(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        let (count, streams) = parse_read_options(args)?;
+
+        let mut response = Vec::new();
+        for (key, id) in streams {
+            let Some(stream) = self.stream(&key)? else {
+                continue;
+            };
+            // Reading only entries added after the call is equivalent to reading no entries,
+            // since reads are never blocking
+            if id == "$" {
+                continue;
+            }
+            let id = StreamId::parse(&id)?;
+
+            let entries = stream
+                .entries
+                .range((std::ops::Bound::Excluded(id), std::ops::Bound::Unbounded))
+                .take(count.unwrap_or(usize::MAX))
+                .map(|(id, fields)| stream_entry(id, fields))
+                .collect::<Vec<_>>();
+            if !entries.is_empty() {
+                response.push(RedisValue::Array(vec![
+                    RedisValue::String(key.into()),
+                    RedisValue::Array(entries),
+                ]));
+            }
+        }
+
+        if response.is_empty() {
+            Ok(RedisValue::Null)
+        } else {
+            Ok(RedisValue::Array(response))
+        }
+    }
+
+    fn xreadgroup(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        if !to_string(arg(args, 0)?).eq_ignore_ascii_case("GROUP") {
+            return Err(syntax_error());
+        }
+        let group_name = to_string(arg(args, 1)?);
+        let consumer = to_string(arg(args, 2)?);
+        let (count, streams) = parse_read_options(&args[3..])?;
+        let no_ack = args[3..]
+            .iter()
+            .any(|option| to_string(option).eq_ignore_ascii_case("NOACK"));
+        let now = self.now;
+
+        let mut response = Vec::new();
+        for (key, id) in streams {
+            let stream = self
+                .stream(&key)?
+                .ok_or_else(|| no_group(&key, &group_name))?;
+            let Stream {
+                entries, groups, ..
+            } = stream;
+            let group = groups
+                .get_mut(&group_name)
+                .ok_or_else(|| no_group(&key, &group_name))?;
+
+            let entries = if id == ">" {
+                let new_entries = entries
+                    .range((
+                        std::ops::Bound::Excluded(group.last_delivered_id),
+                        std::ops::Bound::Unbounded,
+                    ))
+                    .take(count.unwrap_or(usize::MAX))
+                    .collect::<Vec<_>>();
+
+                if let Some((last_id, _)) = new_entries.last() {
+                    group.last_delivered_id = **last_id;
+                }
+                if !no_ack {
+                    for (id, _) in new_entries.iter() {
+                        group.pending.insert(
+                            **id,
+                            PendingEntry {
+                                consumer: consumer.clone(),
+                                delivered_at: now,
+                                delivery_count: 1,
+                            },
+                        );
+                    }
+                }
+
+                new_entries
+                    .into_iter()
+                    .map(|(id, fields)| stream_entry(id, fields))
+                    .collect::<Vec<_>>()
+            } else {
+                // Entries pending for the consumer, which have been delivered but not acknowledged
+                let id = StreamId::parse(&id)?;
+                group
+                    .pending
+                    .range((std::ops::Bound::Excluded(id), std::ops::Bound::Unbounded))
+                    .filter(|(_, pending)| pending.consumer == consumer)
+                    .take(count.unwrap_or(usize::MAX))
+                    .filter_map(|(id, _)| entries.get(id).map(|fields| stream_entry(id, fields)))
+                    .collect::<Vec<_>>()
+            };
+
+            if !entries.is_empty() {
+                response.push(RedisValue::Array(vec![
+                    RedisValue::String(key.into()),
+                    RedisValue::Array(entries),
+                ]));
+            }
+        }
+
+        if response.is_empty() {
+            Ok(RedisValue::Null)
+        } else {
+            Ok(RedisValue::Array(response))
+        }
+    }
+
+    fn xclaim(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        let key = to_string(arg(args, 0)?);
+        let group_name = to_string(arg(args, 1)?);
+        let consumer = to_string(arg(args, 2)?);
+        let min_idle_time = Duration::from_millis(to_u64(arg(args, 3)?)?);
+
+        let mut ids = Vec::new();
+        let mut just_id = false;
+        let mut force = false;
+        let mut options = args.iter().skip(4);
+        while let Some(option) = options.next() {
+            let option = to_string(option);
+            match option.to_uppercase().as_str() {
+                "IDLE" | "TIME" | "RETRYCOUNT" | "LASTID" => {
+                    options.next().ok_or_else(syntax_error)?;
+                }
+                "FORCE" => force = true,
+                "JUSTID" => just_id = true,
+                _ => ids.push(StreamId::parse(&option)?),
+            }
+        }
+
+        let now = self.now;
+        let stream = self
+            .stream(&key)?
+            .ok_or_else(|| no_group(&key, &group_name))?;
+        let Stream {
+            entries, groups, ..
+        } = stream;
+        let group = groups
+            .get_mut(&group_name)
+            .ok_or_else(|| no_group(&key, &group_name))?;
+
+        let mut claimed = Vec::new();
+        for id in ids {
+            if !entries.contains_key(&id) {
+                group.pending.remove(&id);
+                continue;
+            }
+
+            match group.pending.get_mut(&id) {
+                Some(pending) => {
+                    let idle_time = now.duration_since(pending.delivered_at).unwrap_or_default();
+                    if idle_time < min_idle_time {
+                        continue;
+                    }
+                    pending.consumer = consumer.clone();
+                    pending.delivered_at = now;
+                    if !just_id {
+                        pending.delivery_count += 1;
+                    }
+                }
+                None if force => {
+                    group.pending.insert(
+                        id,
+                        PendingEntry {
+                            consumer: consumer.clone(),
+                            delivered_at: now,
+                            delivery_count: 1,
+                        },
+                    );
+                }
+                None => continue,
+            }
+            claimed.push(id);
+        }
+
+        Ok(RedisValue::Array(
+            claimed
+                .iter()
+                .filter_map(|id| {
+                    if just_id {
+                        Some(RedisValue::String(id.to_string().into()))
+                    } else {
+                        entries.get(id).map(|fields| stream_entry(id, fields))
+                    }
+                })
+                .collect(),
+        ))
+    }
+
+    fn xpending(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        let key = to_string(arg(args, 0)?);
+        let group_name = to_string(arg(args, 1)?);
+        let now = self.now;
+
+        let group = self
+            .stream(&key)?
+            .and_then(|stream| stream.groups.get_mut(&group_name))
+            .ok_or_else(|| no_group(&key, &group_name))?;
+
+        // Summary form: total count, smallest and greatest IDs, and count per consumer
+        if args.len() == 2 {
+            let mut consumers = BTreeMap::<&str, usize>::new();
+            for pending in group.pending.values() {
+                *consumers.entry(pending.consumer.as_str()).or_default() += 1;
+            }
+            let id_value = |id: Option<&StreamId>| {
+                id.map(|id| RedisValue::String(id.to_string().into()))
+                    .unwrap_or(RedisValue::Null)
+            };
+            return Ok(RedisValue::Array(vec![
+                integer(group.pending.len()),
+                id_value(group.pending.keys().next()),
+                id_value(group.pending.keys().next_back()),
+                RedisValue::Array(
+                    consumers
+                        .into_iter()
+                        .map(|(consumer, count)| {
+                            RedisValue::Array(vec![
+                                RedisValue::String(consumer.to_string().into()),
+                                RedisValue::String(count.to_string().into()),
+                            ])
+                        })
+                        .collect(),
+                ),
+            ]));
+        }
+
+        // Extended form: `[IDLE min-idle-time] start end count [consumer]`
+        let mut index = 2;
+        let mut min_idle_time = Duration::ZERO;
+        if to_string(arg(args, index)?).eq_ignore_ascii_case("IDLE") {
+            min_idle_time = Duration::from_millis(to_u64(arg(args, index + 1)?)?);
+            index += 2;
+        }
+        let parse_bound = |value: &RedisValue, default: StreamId| {
+            let value = to_string(value);
+            match value.as_str() {
+                "-" | "+" => Ok(default),
+                value => StreamId::parse(value.trim_start_matches('(')),
+            }
+        };
+        let start = parse_bound(arg(args, index)?, StreamId::default())?;
+        let end = parse_bound(
+            arg(args, index + 1)?,
+            StreamId {
+                milliseconds: u64::MAX,
+                sequence_number: u64::MAX,
+            },
+        )?;
+        let count = usize::try_from(to_u64(arg(args, index + 2)?)?).map_err(|_| syntax_error())?;
+        let consumer = args.get(index + 3).map(to_string);
+
+        Ok(RedisValue::Array(
+            group
+                .pending
+                .range(start..=end)
+                .filter_map(|(id, pending)| {
+                    let idle_time = now.duration_since(pending.delivered_at).unwrap_or_default();
+                    let consumer_matches = consumer
+                        .as_ref()
+                        .map(|consumer| *consumer == pending.consumer)
+                        .unwrap_or(true);
+                    (consumer_matches && idle_time >= min_idle_time).then(|| {
+                        RedisValue::Array(vec![
+                            RedisValue::String(id.to_string().into()),
+                            RedisValue::String(pending.consumer.clone().into()),
+                            RedisValue::Integer(
+                                i64::try_from(idle_time.as_millis()).unwrap_or(i64::MAX),
+                            ),
+                            RedisValue::Integer(
+                                i64::try_from(pending.delivery_count).unwrap_or(i64::MAX),
+                            ),
+                        ])
+                    })
+                })
+                .take(count)
+                .collect(),
+        ))
+    }
+
+    fn xgroup_create(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        let key = to_string(arg(args, 0)?);
+        let group_name = to_string(arg(args, 1)?);
+        let id = to_string(arg(args, 2)?);
+        let create_stream = args[3..]
+            .iter()
+            .any(|option| to_string(option).eq_ignore_ascii_case("MKSTREAM"));
+
+        let stream = if create_stream {
+            self.stream_or_create(&key)?
+        } else {
+            self.stream(&key)?.ok_or_else(|| {
+                RedisError::new(
+                    RedisErrorKind::InvalidArgument,
+                    "The XGROUP subcommand requires the key to exist",
+                )
+            })?
+        };
+
+        if stream.groups.contains_key(&group_name) {
+            return Err(RedisError::new(
+                RedisErrorKind::InvalidArgument,
+                "BUSYGROUP Consumer Group name already exists",
+            ));
+        }
+
+        let last_delivered_id = if id == "$" {
+            stream.last_id
+        } else {
+            StreamId::parse(&id)?
+        };
+        stream.groups.insert(
+            group_name,
+            ConsumerGroup {
+                last_delivered_id,
+                pending: BTreeMap::new(),
+            },
+        );
+        Ok(ok_string("OK"))
+    }
+
+    fn xgroup_destroy(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        let group_name = to_string(arg(args, 1)?);
+        let destroyed = self
+            .stream(&to_string(arg(args, 0)?))?
+            .map(|stream| stream.groups.remove(&group_name).is_some())
+            .unwrap_or(false);
+        Ok(integer(usize::from(destroyed)))
+    }
+
+    fn xgroup_delconsumer(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        let key = to_string(arg(args, 0)?);
+        let group_name = to_string(arg(args, 1)?);
+        let consumer = to_string(arg(args, 2)?);
+
+        let group = self
+            .stream(&key)?
+            .and_then(|stream| stream.groups.get_mut(&group_name))
+            .ok_or_else(|| no_group(&key, &group_name))?;
+        let pending_count = group.pending.len();
+        group
+            .pending
+            .retain(|_, pending| pending.consumer != consumer);
+        Ok(integer(pending_count - group.pending.len()))
+    }
+
+    fn xgroup_setid(&mut self, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+        let key = to_string(arg(args, 0)?);
+        let group_name = to_string(arg(args, 1)?);
+        let id = to_string(arg(args, 2)?);
+
+        let stream = self
+            .stream(&key)?
+            .ok_or_else(|| no_group(&key, &group_name))?;
+        let last_id = stream.last_id;
+        let group = stream
+            .groups
+            .get_mut(&group_name)
+            .ok_or_else(|| no_group(&key, &group_name))?;
+        group.last_delivered_id = if id == "$" {
+            last_id
+        } else {
+            StreamId::parse(&id)?
+        };
+        Ok(ok_string("OK"))
+    }
+}
+
+/// Trimming strategy for streams
+enum StreamTrim {
+    MaxLength(usize),
+    MinId(StreamId),
+}
+
+impl StreamTrim {
+    /// Trims the stream, returning the number of entries removed
+    fn trim(&self, stream: &mut Stream) -> usize {
+        let initial_length = stream.entries.len();
+        match self {
+            Self::MaxLength(max_length) => {
+                while stream.entries.len() > *max_length {
+                    let first_id = stream.entries.keys().next().copied();
+                    if let Some(first_id) = first_id {
+                        stream.entries.remove(&first_id);
+                    }
+                }
+            }
+            Self::MinId(min_id) => {
+                stream.entries = stream.entries.split_off(min_id);
+            }
+        }
+        initial_length - stream.entries.len()
+    }
+}
+
+/// Parses the threshold of a trim operation, returning the number of arguments consumed.
+fn parse_trim(kind: &str, args: &[RedisValue]) -> Result<(StreamTrim, usize), RedisError> {
+    let mut consumed = 0;
+    let mut threshold = to_string(arg(args, consumed)?);
+    if threshold == "=" || threshold == "~" {
+        consumed += 1;
+        threshold = to_string(arg(args, consumed)?);
+    }
+    consumed += 1;
+
+    if args
+        .get(consumed)
+        .map(|option| to_string(option).eq_ignore_ascii_case("LIMIT"))
+        .unwrap_or(false)
+    {
+        consumed += 2;
+    }
+
+    let trim = match kind {
+        "MAXLEN" => StreamTrim::MaxLength(
+            threshold
+                .parse()
+                .map_err(|_| RedisError::new(RedisErrorKind::InvalidArgument, "Invalid MAXLEN"))?,
+        ),
+        "MINID" => StreamTrim::MinId(StreamId::parse(&threshold)?),
+        _ => return Err(syntax_error()),
+    };
+    Ok((trim, consumed))
+}
+
+/// Parses the `COUNT` and `STREAMS` options of `XREAD` and `XREADGROUP` commands.
+#[allow(clippy::type_complexity)]
+fn parse_read_options(
+    args: &[RedisValue],
+) -> Result<(Option<usize>, Vec<(String, String)>), RedisError> {
+    let mut count = None;
+    let mut index = 0;
+    while index < args.len() {
+        match to_string(&args[index]).to_uppercase().as_str() {
+            "COUNT" => {
+                count = Some(
+                    usize::try_from(to_u64(arg(args, index + 1)?)?).map_err(|_| syntax_error())?,
+                );
+                index += 2;
+            }
+            "BLOCK" => index += 2,
+            "NOACK" => index += 1,
+            "STREAMS" => {
+                let streams = &args[index + 1..];
+                if streams.is_empty() || streams.len() % 2 != 0 {
+                    return Err(syntax_error());
+                }
+                let (keys, ids) = streams.split_at(streams.len() / 2);
+                return Ok((
+                    count,
+                    keys.iter()
+                        .map(to_string)
+                        .zip(ids.iter().map(to_string))
+                        .collect(),
+                ));
+            }
+            _ => return Err(syntax_error()),
+        }
+    }
+    Err(syntax_error())
+}
+
+fn stream_entry(id: &StreamId, fields: &StreamFields) -> RedisValue {
+    RedisValue::Array(vec![
+        RedisValue::String(id.to_string().into()),
+        RedisValue::Array(
+            fields
+                .iter()
+                .flat_map(|(field, value)| {
+                    [
+                        RedisValue::Bytes(field.clone().into()),
+                        RedisValue::Bytes(value.clone().into()),
+                    ]
+                })
+                .collect(),
+        ),
+    ])
+}
+
+/// Matches `value` against a glob-style `pattern`, supporting the `*` and `?` wildcards.
+fn glob_match(pattern: &[u8], value: &[u8]) -> bool {
+    match (pattern.first(), value.first()) {
+        (None, None) => true,
+        (Some(b'*'), _) => {
+            glob_match(&pattern[1..], value)
+                || (!value.is_empty() && glob_match(pattern, &value[1..]))
+        }
+        (Some(b'?'), Some(_)) => glob_match(&pattern[1..], &value[1..]),
+        (Some(expected), Some(actual)) if expected == actual => {
+            glob_match(&pattern[1..], &value[1..])
+        }
+        _ => false,
+    }
+}
+
+fn arg(args: &[RedisValue], index: usize) -> Result<&RedisValue, RedisError> {
+    args.get(index).ok_or_else(syntax_error)
+}
+
+fn to_bytes(value: &RedisValue) -> Vec<u8> {
+    match value {
+        RedisValue::String(value) => value.as_bytes().to_vec(),
+        RedisValue::Bytes(value) => value.to_vec(),
+        RedisValue::Integer(value) => value.to_string().into_bytes(),
+        RedisValue::Double(value) => value.to_string().into_bytes(),
+        RedisValue::Boolean(value) => value.to_string().into_bytes(),
+        _ => Vec::new(),
+    }
+}
+
+fn to_string(value: &RedisValue) -> String {
+    String::from_utf8_lossy(&to_bytes(value)).to_string()
+}
+
+fn to_u64(value: &RedisValue) -> Result<u64, RedisError> {
+    to_string(value)
+        .parse()
+        .map_err(|_| RedisError::new(RedisErrorKind::InvalidArgument, "Expected an integer"))
+}
+
+fn to_pairs(args: &[RedisValue]) -> Result<StreamFields, RedisError> {
+    if args.is_empty() || args.len() % 2 != 0 {
+        return Err(syntax_error());
+    }
+    Ok(args
+        .chunks(2)
+        .map(|pair| (to_bytes(&pair[0]), to_bytes(&pair[1])))
+        .collect())
+}
+
+fn integer(value: usize) -> RedisValue {
+    RedisValue::Integer(i64::try_from(value).unwrap_or(i64::MAX))
+}
+
+fn ok_string(value: &'static str) -> RedisValue {
+    RedisValue::String(value.into())
+}
+
+fn syntax_error() -> RedisError {
+    RedisError::new(RedisErrorKind::InvalidArgument, "Syntax error")
+}
+
+fn wrong_type() -> RedisError {
+    RedisError::new(
+        RedisErrorKind::InvalidArgument,
+        "WRONGTYPE Operation against a key holding the wrong kind of value",
+    )
+}
+
+fn invalid_stream_id(id: &str) -> RedisError {
+    RedisError::new(
+        RedisErrorKind::InvalidArgument,
+        format!("Invalid stream ID specified as stream command argument: {id}"),
+    )
+}
+
+fn no_group(key: &str, group: &str) -> RedisError {
+    RedisError::new(
+        RedisErrorKind::InvalidArgument,
+        format!("NOGROUP No such key '{key}' or consumer group '{group}'"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+
+    fn command(cmd: &'static str, args: &[&str]) -> MockCommand {
+        MockCommand {
+            cmd: cmd.into(),
+            subcommand: None,
+            args: args
+                .iter()
+                .map(|arg| RedisValue::String(arg.to_string().into()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn set_if_not_exists_does_not_overwrite_key() {
+        let redis = InMemoryRedis::default();
+        redis
+            .process_command(command("SET", &["key", "first", "NX"]))
+            .expect("SET failed");
+        let reply = redis
+            .process_command(command("SET", &["key", "second", "NX"]))
+            .expect("SET failed");
+        assert_eq!(reply, RedisValue::Null);
+
+        let value = redis
+            .process_command(command("GET", &["key"]))
+            .expect("GET failed");
+        assert_eq!(value.as_bytes(), Some("first".as_bytes()));
+    }
+
+    #[test]
+    fn stream_entries_are_delivered_to_consumer_group_once() {
+        let redis = InMemoryRedis::default();
+        redis
+            .process_command(command(
+                "XGROUP",
+                &["CREATE", "stream", "group", "$", "MKSTREAM"],
+            ))
+            .expect("XGROUP CREATE failed");
+        redis
+            .process_command(command("XADD", &["stream", "*", "field", "value"]))
+            .expect("XADD failed");
+
+        let read = || {
+            redis
+                .process_command(command(
+                    "XREADGROUP",
+                    &["GROUP", "group", "consumer", "STREAMS", "stream", ">"],
+                ))
+                .expect("XREADGROUP failed")
+        };
+        assert!(matches!(read(), RedisValue::Array(streams) if streams.len() == 1));
+        assert_eq!(read(), RedisValue::Null);
+    }
+
+    #[test]
+    fn expired_keys_are_removed() {
+        let redis = InMemoryRedis::default();
+        redis
+            .process_command(command("SET", &["key", "value"]))
+            .expect("SET failed");
+        let reply = redis
+            .process_command(command("EXPIREAT", &["key", "1"]))
+            .expect("EXPIREAT failed");
+        assert_eq!(reply, RedisValue::Integer(1));
+
+        let value = redis
+            .process_command(command("GET", &["key"]))
+            .expect("GET failed");
+        assert_eq!(value, RedisValue::Null);
+        let exists = redis
+            .process_command(command("EXISTS", &["key"]))
+            .expect("EXISTS failed");
+        assert_eq!(exists, RedisValue::Integer(0));
+    }
+
+    #[test]
+    fn msetnx_does_not_set_any_key_if_one_exists() {
+        let redis = InMemoryRedis::default();
+        redis
+            .process_command(command("SET", &["first", "value"]))
+            .expect("SET failed");
+        let reply = redis
+            .process_command(command("MSETNX", &["first", "new", "second", "new"]))
+            .expect("MSETNX failed");
+        assert_eq!(reply, RedisValue::Integer(0));
+
+        let values = redis
+            .process_command(command("MGET", &["first", "second"]))
+            .expect("MGET failed");
+        assert_eq!(
+            values,
+            RedisValue::Array(vec![
+                RedisValue::Bytes("value".as_bytes().to_vec().into()),
+                RedisValue::Null,
+            ])
+        );
+    }
+
+    #[test]
+    fn hash_fields_are_set_read_and_deleted() {
+        let redis = InMemoryRedis::default();
+        let added = redis
+            .process_command(command("HSET", &["hash", "first", "1", "second", "2"]))
+            .expect("HSET failed");
+        assert_eq!(added, RedisValue::Integer(2));
+        let added = redis
+            .process_command(command("HSETNX", &["hash", "first", "3"]))
+            .expect("HSETNX failed");
+        assert_eq!(added, RedisValue::Integer(0));
+
+        let value = redis
+            .process_command(command("HGET", &["hash", "first"]))
+            .expect("HGET failed");
+        assert_eq!(value.as_bytes(), Some("1".as_bytes()));
+
+        let scanned = redis
+            .process_command(command("HSCAN", &["hash", "0", "MATCH", "sec*"]))
+            .expect("HSCAN failed");
+        assert_eq!(
+            scanned,
+            RedisValue::Array(vec![
+                RedisValue::String("0".into()),
+                RedisValue::Array(vec![
+                    RedisValue::String("second".into()),
+                    RedisValue::Bytes("2".as_bytes().to_vec().into()),
+                ]),
+            ])
+        );
+
+        let removed = redis
+            .process_command(command("HDEL", &["hash", "first", "missing"]))
+            .expect("HDEL failed");
+        assert_eq!(removed, RedisValue::Integer(1));
+        let value = redis
+            .process_command(command("HGET", &["hash", "first"]))
+            .expect("HGET failed");
+        assert_eq!(value, RedisValue::Null);
+    }
+
+    #[test]
+    fn acknowledged_entries_are_no_longer_pending() {
+        let redis = InMemoryRedis::default();
+        redis
+            .process_command(command(
+                "XGROUP",
+                &["CREATE", "stream", "group", "$", "MKSTREAM"],
+            ))
+            .expect("XGROUP CREATE failed");
+        let id = redis
+            .process_command(command("XADD", &["stream", "*", "field", "value"]))
+            .expect("XADD failed");
+        let id = id.as_string().expect("XADD did not return an ID");
+        redis
+            .process_command(command(
+                "XREADGROUP",
+                &["GROUP", "group", "consumer", "STREAMS", "stream", ">"],
+            ))
+            .expect("XREADGROUP failed");
+
+        let pending_count = || match redis
+            .process_command(command("XPENDING", &["stream", "group"]))
+            .expect("XPENDING failed")
+        {
+            RedisValue::Array(summary) => summary.first().cloned(),
+            _ => None,
+        };
+        assert_eq!(pending_count(), Some(RedisValue::Integer(1)));
+
+        let acknowledged = redis
+            .process_command(command("XACK", &["stream", "group", &id]))
+            .expect("XACK failed");
+        assert_eq!(acknowledged, RedisValue::Integer(1));
+        assert_eq!(pending_count(), Some(RedisValue::Integer(0)));
+    }
+
+    #[test]
+    fn streams_are_trimmed_to_max_length() {
+        let redis = InMemoryRedis::default();
+        for id in ["1-0", "2-0", "3-0"] {
+            redis
+                .process_command(command("XADD", &["stream", id, "field", "value"]))
+                .expect("XADD failed");
+        }
+
+        let removed = redis
+            .process_command(command("XTRIM", &["stream", "MAXLEN", "1"]))
+            .expect("XTRIM failed");
+        assert_eq!(removed, RedisValue::Integer(2));
+        let length = redis
+            .process_command(command("XLEN", &["stream"]))
+            .expect("XLEN failed");
+        assert_eq!(length, RedisValue::Integer(1));
+    }
+
+    #[test]
+    fn wrong_type_and_unsupported_commands_fail() {
+        let redis = InMemoryRedis::default();
+        redis
+            .process_command(command("HSET", &["hash", "field", "value"]))
+            .expect("HSET failed");
+
+        assert!(redis.process_command(command("GET", &["hash"])).is_err());
+        assert!(redis
+            .process_command(command("LPUSH", &["list", "value"]))
+            .is_err());
+    }
+}
@@ -19,6 +19,8 @@
 
 pub mod commands;
 pub mod errors;
+#[cfg(feature = "mocks")]
+pub mod in_memory;
 pub mod types;
 
 use std::sync::{atomic, Arc};
@@ -129,6 +131,30 @@ impl RedisConnectionPool {
             conf.reconnect_delay,
         );
 
+        Self::with_config(conf, config, reconnect_policy).await
+    }
+
+    /// Create a Redis connection pool backed by an in-memory store, without connecting to a
+    /// Redis server
+    #[cfg(feature = "mocks")]
+    pub async fn new_in_memory(conf: &RedisSettings) -> CustomResult<Self, errors::RedisError> {
+        let config = fred::types::RedisConfig {
+            mocks: Arc::new(in_memory::InMemoryRedis::default()),
+            ..Default::default()
+        };
+        let reconnect_policy = fred::types::ReconnectPolicy::new_constant(
+            conf.reconnect_max_attempts,
+            conf.reconnect_delay,
+        );
+
+        Self::with_config(conf, config, reconnect_policy).await
+    }
+
+    async fn with_config(
+        conf: &RedisSettings,
+        config: fred::types::RedisConfig,
+        reconnect_policy: fred::types::ReconnectPolicy,
+    ) -> CustomResult<Self, errors::RedisError> {
         let subscriber = SubscriberClient::new(config.clone(), reconnect_policy.clone()).await?;
 
         let publisher = RedisClient::new(config.clone(), reconnect_policy.clone()).await?;
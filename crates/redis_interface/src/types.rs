@@ -137,6 +137,16 @@ impl From<&RedisEntryId> for fred::types::XID {
     }
 }
 
+/// An entry pending acknowledgement in a consumer group, as returned by `XPENDING`.
+#[derive(Debug, Clone)]
+pub struct StreamPendingEntry {
+    pub id: String,
+    pub consumer: String,
+    /// Time elapsed since the entry was last delivered to a consumer (in milliseconds)
+    pub idle_time: u64,
+    pub delivery_count: u64,
+}
+
 #[derive(Eq, PartialEq)]
 pub enum SetnxReply {
     KeySet,
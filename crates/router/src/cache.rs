@@ -6,7 +6,7 @@ use moka::future::Cache as MokaCache;
 use once_cell::sync::Lazy;
 use redis_interface::RedisValue;
 
-use crate::core::errors;
+use crate::{core::errors, routes::metrics};
 
 /// Prefix for config cache key
 const CONFIG_CACHE_PREFIX: &str = "config";
@@ -14,6 +14,12 @@ const CONFIG_CACHE_PREFIX: &str = "config";
 /// Prefix for accounts cache key
 const ACCOUNTS_CACHE_PREFIX: &str = "accounts";
 
+/// Prefix for merchant connector accounts cache key
+const MERCHANT_CONNECTOR_ACCOUNTS_CACHE_PREFIX: &str = "merchant_connector_accounts";
+
+/// Prefix for card info cache key
+const CARD_INFO_CACHE_PREFIX: &str = "card_info";
+
 /// Prefix for all kinds of cache key
 const ALL_CACHE_PREFIX: &str = "all_cache_kind";
 
@@ -26,12 +32,57 @@ const CACHE_TTI: u64 = 10 * 60;
 /// Max Capacity of Cache in MB
 const MAX_CAPACITY: u64 = 30;
 
+/// Max Capacity of the card info cache in MB, larger than that of the other caches since a lookup
+/// is cached for each distinct card BIN seen in payments
+const CARD_INFO_CACHE_MAX_CAPACITY: u64 = 256;
+
 /// Config Cache with time_to_live as 30 mins and time_to_idle as 10 mins.
-pub static CONFIG_CACHE: Lazy<Cache> = Lazy::new(|| Cache::new(CACHE_TTL, CACHE_TTI, None));
+pub static CONFIG_CACHE: Lazy<Cache> =
+    Lazy::new(|| Cache::new(CONFIG_CACHE_PREFIX, CACHE_TTL, CACHE_TTI, None));
 
 /// Accounts cache with time_to_live as 30 mins and size limit
-pub static ACCOUNTS_CACHE: Lazy<Cache> =
-    Lazy::new(|| Cache::new(CACHE_TTL, CACHE_TTI, Some(MAX_CAPACITY)));
+pub static ACCOUNTS_CACHE: Lazy<Cache> = Lazy::new(|| {
+    Cache::new(
+        ACCOUNTS_CACHE_PREFIX,
+        CACHE_TTL,
+        CACHE_TTI,
+        Some(MAX_CAPACITY),
+    )
+});
+
+/// Merchant connector accounts cache with time_to_live as 30 mins and size limit
+pub static MERCHANT_CONNECTOR_ACCOUNTS_CACHE: Lazy<Cache> = Lazy::new(|| {
+    Cache::new(
+        MERCHANT_CONNECTOR_ACCOUNTS_CACHE_PREFIX,
+        CACHE_TTL,
+        CACHE_TTI,
+        Some(MAX_CAPACITY),
+    )
+});
+
+/// Card info (BIN) cache with time_to_live as 30 mins and size limit
+pub static CARD_INFO_CACHE: Lazy<Cache> = Lazy::new(|| {
+    Cache::new(
+        CARD_INFO_CACHE_PREFIX,
+        CACHE_TTL,
+        CACHE_TTI,
+        Some(CARD_INFO_CACHE_MAX_CAPACITY),
+    )
+});
+
+/// Registry of all the in-memory caches, along with the prefix used to address them in
+/// invalidation messages published on the pub/sub channel.
+static CACHE_REGISTRY: Lazy<[(&'static str, &'static Cache); 4]> = Lazy::new(|| {
+    [
+        (CONFIG_CACHE_PREFIX, &CONFIG_CACHE),
+        (ACCOUNTS_CACHE_PREFIX, &ACCOUNTS_CACHE),
+        (
+            MERCHANT_CONNECTOR_ACCOUNTS_CACHE_PREFIX,
+            &MERCHANT_CONNECTOR_ACCOUNTS_CACHE,
+        ),
+        (CARD_INFO_CACHE_PREFIX, &CARD_INFO_CACHE),
+    ]
+});
 
 /// Trait which defines the behaviour of types that's gonna be stored in Cache
 pub trait Cacheable: Any + Send + Sync + DynClone {
@@ -41,17 +92,47 @@ pub trait Cacheable: Any + Send + Sync + DynClone {
 pub enum CacheKind<'a> {
     Config(Cow<'a, str>),
     Accounts(Cow<'a, str>),
+    MerchantConnectorAccounts(Cow<'a, str>),
+    CardInfo(Cow<'a, str>),
     All(Cow<'a, str>),
 }
 
+impl<'a> CacheKind<'a> {
+    fn prefix(&self) -> &'static str {
+        match self {
+            Self::Config(_) => CONFIG_CACHE_PREFIX,
+            Self::Accounts(_) => ACCOUNTS_CACHE_PREFIX,
+            Self::MerchantConnectorAccounts(_) => MERCHANT_CONNECTOR_ACCOUNTS_CACHE_PREFIX,
+            Self::CardInfo(_) => CARD_INFO_CACHE_PREFIX,
+            Self::All(_) => ALL_CACHE_PREFIX,
+        }
+    }
+
+    /// The key to be invalidated
+    pub fn key(&self) -> &str {
+        match self {
+            Self::Config(key)
+            | Self::Accounts(key)
+            | Self::MerchantConnectorAccounts(key)
+            | Self::CardInfo(key)
+            | Self::All(key) => key.as_ref(),
+        }
+    }
+
+    /// The in-memory caches from which the key has to be invalidated
+    pub fn caches(&self) -> Vec<&'static Cache> {
+        let prefix = self.prefix();
+        CACHE_REGISTRY
+            .iter()
+            .filter(|(cache_prefix, _)| prefix == ALL_CACHE_PREFIX || *cache_prefix == prefix)
+            .map(|(_, cache)| *cache)
+            .collect()
+    }
+}
+
 impl<'a> From<CacheKind<'a>> for RedisValue {
     fn from(kind: CacheKind<'a>) -> Self {
-        let value = match kind {
-            CacheKind::Config(s) => format!("{CONFIG_CACHE_PREFIX},{s}"),
-            CacheKind::Accounts(s) => format!("{ACCOUNTS_CACHE_PREFIX},{s}"),
-            CacheKind::All(s) => format!("{ALL_CACHE_PREFIX},{s}"),
-        };
-        Self::from_string(value)
+        Self::from_string(format!("{},{}", kind.prefix(), kind.key()))
     }
 }
 
@@ -66,6 +147,10 @@ impl<'a> TryFrom<RedisValue> for CacheKind<'a> {
         match split.0 {
             ACCOUNTS_CACHE_PREFIX => Ok(Self::Accounts(Cow::Owned(split.1.to_string()))),
             CONFIG_CACHE_PREFIX => Ok(Self::Config(Cow::Owned(split.1.to_string()))),
+            MERCHANT_CONNECTOR_ACCOUNTS_CACHE_PREFIX => Ok(Self::MerchantConnectorAccounts(
+                Cow::Owned(split.1.to_string()),
+            )),
+            CARD_INFO_CACHE_PREFIX => Ok(Self::CardInfo(Cow::Owned(split.1.to_string()))),
             ALL_CACHE_PREFIX => Ok(Self::All(Cow::Owned(split.1.to_string()))),
             _ => Err(validation_err.into()),
         }
@@ -84,6 +169,7 @@ where
 dyn_clone::clone_trait_object!(Cacheable);
 
 pub struct Cache {
+    name: &'static str,
     inner: MokaCache<String, Arc<dyn Cacheable>>,
 }
 
@@ -97,10 +183,16 @@ impl std::ops::Deref for Cache {
 impl Cache {
     /// With given `time_to_live` and `time_to_idle` creates a moka cache.
     ///
+    /// `name`: Name of the cache, used to identify the cache in metrics
     /// `time_to_live`: Time in seconds before an object is stored in a caching system before it’s deleted
     /// `time_to_idle`: Time in seconds before a `get` or `insert` operation an object is stored in a caching system before it's deleted
     /// `max_capacity`: Max size in MB's that the cache can hold
-    pub fn new(time_to_live: u64, time_to_idle: u64, max_capacity: Option<u64>) -> Self {
+    pub fn new(
+        name: &'static str,
+        time_to_live: u64,
+        time_to_idle: u64,
+        max_capacity: Option<u64>,
+    ) -> Self {
         let mut cache_builder = MokaCache::builder()
             .eviction_listener_with_queued_delivery_mode(|_, _, _| {})
             .time_to_live(std::time::Duration::from_secs(time_to_live))
@@ -111,6 +203,7 @@ impl Cache {
         }
 
         Self {
+            name,
             inner: cache_builder.build(),
         }
     }
@@ -120,8 +213,22 @@ impl Cache {
     }
 
     pub fn get_val<T: Clone + Cacheable>(&self, key: &str) -> Option<T> {
-        let val = self.get(key)?;
-        (*val).as_any().downcast_ref::<T>().cloned()
+        let val = self
+            .get(key)
+            .and_then(|val| (*val).as_any().downcast_ref::<T>().cloned());
+
+        let metric = if val.is_some() {
+            &metrics::IN_MEMORY_CACHE_HIT
+        } else {
+            &metrics::IN_MEMORY_CACHE_MISS
+        };
+        metric.add(
+            &metrics::CONTEXT,
+            1,
+            &[metrics::request::add_attributes("cache_type", self.name)],
+        );
+
+        val
     }
 
     pub async fn remove(&self, key: &str) {
@@ -131,25 +238,42 @@ impl Cache {
 
 #[cfg(test)]
 mod cache_tests {
+    #![allow(clippy::expect_used)]
     use super::*;
 
+    #[test]
+    fn cache_kind_is_parsed_from_published_value() {
+        let value = RedisValue::from(CacheKind::MerchantConnectorAccounts("mca_key".into()));
+        let kind = CacheKind::try_from(value).expect("Failed to parse cache kind");
+
+        assert!(matches!(kind, CacheKind::MerchantConnectorAccounts(_)));
+        assert_eq!(kind.key(), "mca_key");
+        assert_eq!(kind.caches().len(), 1);
+    }
+
+    #[test]
+    fn all_cache_kind_invalidates_every_cache() {
+        let kind = CacheKind::All("key".into());
+        assert_eq!(kind.caches().len(), CACHE_REGISTRY.len());
+    }
+
     #[tokio::test]
     async fn construct_and_get_cache() {
-        let cache = Cache::new(1800, 1800, None);
+        let cache = Cache::new("test", 1800, 1800, None);
         cache.push("key".to_string(), "val".to_string()).await;
         assert_eq!(cache.get_val::<String>("key"), Some(String::from("val")));
     }
 
     #[tokio::test]
     async fn eviction_on_size_test() {
-        let cache = Cache::new(2, 2, Some(0));
+        let cache = Cache::new("test", 2, 2, Some(0));
         cache.push("key".to_string(), "val".to_string()).await;
         assert_eq!(cache.get_val::<String>("key"), None);
     }
 
     #[tokio::test]
     async fn invalidate_cache_for_key() {
-        let cache = Cache::new(1800, 1800, None);
+        let cache = Cache::new("test", 1800, 1800, None);
         cache.push("key".to_string(), "val".to_string()).await;
 
         cache.remove("key").await;
@@ -159,7 +283,7 @@ mod cache_tests {
 
     #[tokio::test]
     async fn eviction_on_time_test() {
-        let cache = Cache::new(2, 2, None);
+        let cache = Cache::new("test", 2, 2, None);
         cache.push("key".to_string(), "val".to_string()).await;
         tokio::time::sleep(std::time::Duration::from_secs(3)).await;
         assert_eq!(cache.get_val::<String>("key"), None);
@@ -503,6 +503,22 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 object: "dispute".to_owned(),
                 id: dispute_id,
             },
+            errors::ApiErrorResponse::ProcessTrackerTaskNotFound { id } => Self::ResourceMissing {
+                object: "process_tracker_task".to_owned(),
+                id,
+            },
+            errors::ApiErrorResponse::ReconciliationRunNotFound { run_id } => {
+                Self::ResourceMissing {
+                    object: "reconciliation_run".to_owned(),
+                    id: run_id,
+                }
+            }
+            errors::ApiErrorResponse::CustomerErasureNotFound { erasure_id } => {
+                Self::ResourceMissing {
+                    object: "customer_erasure".to_owned(),
+                    id: erasure_id,
+                }
+            }
             errors::ApiErrorResponse::DisputeStatusValidationFailed { reason } => {
                 Self::InternalServerError
             }
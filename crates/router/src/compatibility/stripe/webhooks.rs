@@ -24,6 +24,8 @@ pub enum StripeWebhookObject {
     PaymentIntent(StripePaymentIntentResponse),
     Refund(StripeRefundResponse),
     Dispute(StripeDisputeResponse),
+    ApiKey(Box<api_models::api_keys::RetrieveApiKeyResponse>),
+    PaymentMethod(Box<api_models::payment_methods::PaymentMethodResponse>),
 }
 
 #[derive(Serialize, Debug)]
@@ -97,6 +99,10 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api::OutgoingWebhookContent::DisputeDetails(dispute) => {
                 Self::Dispute((*dispute).into())
             }
+            api::OutgoingWebhookContent::ApiKeyDetails(api_key) => Self::ApiKey(api_key),
+            api::OutgoingWebhookContent::PaymentMethodDetails(payment_method) => {
+                Self::PaymentMethod(payment_method)
+            }
         }
     }
 }
@@ -107,6 +113,8 @@ impl StripeWebhookObject {
             Self::PaymentIntent(p) => p.id.to_owned(),
             Self::Refund(r) => Some(r.id.to_owned()),
             Self::Dispute(d) => Some(d.id.to_owned()),
+            Self::ApiKey(k) => Some(k.key_id.to_owned()),
+            Self::PaymentMethod(p) => Some(p.payment_method_id.to_owned()),
         }
     }
 }
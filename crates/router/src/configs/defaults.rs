@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 impl Default for super::settings::Server {
     fn default() -> Self {
         Self {
@@ -56,6 +58,20 @@ impl Default for super::settings::Locker {
     }
 }
 
+impl Default for super::settings::ApiKeys {
+    fn default() -> Self {
+        Self {
+            #[cfg(feature = "kms")]
+            kms_encrypted_hash_key: String::new(),
+            #[cfg(not(feature = "kms"))]
+            hash_key: String::new(),
+            last_used_update_interval: 300, // Update the last used time at most once in 5 minutes
+            expiry_reminder_days: vec![7, 3, 1],
+            unused_key_report_days: 90,
+        }
+    }
+}
+
 impl Default for super::settings::SupportedConnectors {
     fn default() -> Self {
         Self {
@@ -85,6 +101,59 @@ impl Default for super::settings::Refund {
     }
 }
 
+impl Default for super::settings::DisputeSettings {
+    fn default() -> Self {
+        Self {
+            evidence_reminder_hours: vec![72, 24],
+            // 1 hour
+            expiry_grace_period: 60 * 60,
+        }
+    }
+}
+
+impl Default for super::settings::StaleObjectSync {
+    fn default() -> Self {
+        Self {
+            // 1 hour
+            payment_threshold: 60 * 60,
+            // 1 day
+            refund_threshold: 24 * 60 * 60,
+            // 7 days
+            dispute_threshold: 7 * 24 * 60 * 60,
+            // 30 days
+            max_age: 30 * 24 * 60 * 60,
+            batch_size: 100,
+            connector_rate_limit: 20,
+            connector_rate_limits: HashMap::new(),
+        }
+    }
+}
+
+impl Default for super::settings::CardAccountUpdater {
+    fn default() -> Self {
+        Self {
+            provider: None,
+            expiry_window_days: 30,
+            recheck_interval_days: 7,
+            batch_size: 100,
+        }
+    }
+}
+
+impl Default for super::settings::BuiltinVault {
+    fn default() -> Self {
+        Self {
+            vault_private_key: String::new(),
+            router_public_key: String::new(),
+            active_key_id: String::new(),
+            keys: HashMap::new(),
+            fingerprint_key: String::new(),
+            token_ttl: 900,
+            key_rotation_batch_size: 100,
+        }
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
@@ -95,6 +164,8 @@ impl Default for super::settings::SchedulerSettings {
     fn default() -> Self {
         Self {
             stream: "SCHEDULER_STREAM".into(),
+            runner_streams: HashMap::new(),
+            recurring_jobs: HashMap::new(),
             producer: super::settings::ProducerSettings::default(),
             consumer: super::settings::ConsumerSettings::default(),
             graceful_shutdown_interval: 60000,
@@ -120,6 +191,11 @@ impl Default for super::settings::ConsumerSettings {
         Self {
             disabled: false,
             consumer_group: "SCHEDULER_GROUP".into(),
+            streams: HashMap::new(),
+            runner_concurrency: HashMap::new(),
+            reclaim_idle_time: 600000,
+            reclaim_interval: 60000,
+            lease_duration: 60000,
         }
     }
 }
@@ -50,11 +50,49 @@ impl KmsDecrypt for settings::Jwekey {
     }
 }
 
+#[async_trait::async_trait]
+impl KmsDecrypt for settings::BuiltinVault {
+    async fn decrypt_inner(self, kms_config: &kms::KmsConfig) -> CustomResult<Self, kms::KmsError> {
+        let client = kms::get_kms_client(kms_config).await;
+
+        let (vault_private_key, fingerprint_key) = tokio::try_join!(
+            client.decrypt(self.vault_private_key),
+            client.decrypt(self.fingerprint_key),
+        )?;
+        let keys =
+            futures::future::try_join_all(self.keys.into_iter().map(|(key_id, key)| async move {
+                client.decrypt(key).await.map(|key| (key_id, key))
+            }))
+            .await?
+            .into_iter()
+            .collect();
+
+        Ok(Self {
+            vault_private_key,
+            fingerprint_key,
+            keys,
+            ..self
+        })
+    }
+}
+
 #[async_trait::async_trait]
 impl KmsDecrypt for settings::ActiveKmsSecrets {
     async fn decrypt_inner(self, kms_config: &kms::KmsConfig) -> CustomResult<Self, kms::KmsError> {
+        let builtin_vault = match self.builtin_vault {
+            Some(builtin_vault) => Some(
+                builtin_vault
+                    .expose()
+                    .decrypt_inner(kms_config)
+                    .await?
+                    .into(),
+            ),
+            None => None,
+        };
+
         Ok(Self {
             jwekey: self.jwekey.expose().decrypt_inner(kms_config).await?.into(),
+            builtin_vault,
         })
     }
 }
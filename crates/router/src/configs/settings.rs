@@ -47,6 +47,8 @@ pub enum Subcommand {
 #[derive(Clone)]
 pub struct ActiveKmsSecrets {
     pub jwekey: masking::Secret<Jwekey>,
+    /// Keys of the built-in vault, only decrypted when it is the configured locker
+    pub builtin_vault: Option<masking::Secret<BuiltinVault>>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -71,6 +73,7 @@ pub struct Settings {
     pub jwekey: Jwekey,
     pub webhooks: WebhooksSettings,
     pub pm_filters: ConnectorFilters,
+    pub installments: ConnectorInstallments,
     pub bank_config: BankRedirectConfig,
     pub api_keys: ApiKeys,
     #[cfg(feature = "kms")]
@@ -84,6 +87,12 @@ pub struct Settings {
     #[cfg(feature = "email")]
     pub email: EmailSettings,
     pub delayed_session_response: DelayedSessionConfig,
+    pub reconciliation: ReconciliationSettings,
+    pub stale_object_sync: StaleObjectSync,
+    pub disputes: DisputeSettings,
+    pub network_tokenization: NetworkTokenization,
+    pub card_account_updater: CardAccountUpdater,
+    pub builtin_vault: BuiltinVault,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -223,6 +232,34 @@ pub struct NotAvailableFlows {
     pub capture_method: Option<enums::CaptureMethod>,
 }
 
+/// Installment plans supported by each connector, keyed by connector name and payment method type
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct ConnectorInstallments(
+    pub HashMap<String, HashMap<api_models::enums::PaymentMethodType, InstallmentFilter>>,
+);
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct InstallmentFilter {
+    #[serde(deserialize_with = "currency_set_deser")]
+    pub currency: Option<HashSet<api_models::enums::Currency>>,
+    #[serde(deserialize_with = "installment_count_set_deser")]
+    pub installment_counts: HashSet<u8>,
+}
+
+fn installment_count_set_deser<'a, D>(deserializer: D) -> Result<HashSet<u8>, D::Error>
+where
+    D: Deserializer<'a>,
+{
+    let value = <String>::deserialize(deserializer)?;
+    Ok(value
+        .trim()
+        .split(',')
+        .flat_map(|count| count.trim().parse::<u8>())
+        .collect())
+}
+
 fn string_set_deser<'a, D>(
     deserializer: D,
 ) -> Result<Option<HashSet<api_models::enums::CountryAlpha2>>, D::Error>
@@ -299,12 +336,38 @@ pub struct Locker {
     pub locker_signing_key_id: String,
 }
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone, Default, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum LockerSetup {
     #[default]
     LegacyLocker,
     BasiliskLocker,
+    /// Vault served in-process by the router, speaking the same contract as the basilisk locker
+    BuiltinVault,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct BuiltinVault {
+    /// Private key of the vault, with which the requests of the router are decrypted and the
+    /// responses are signed. Its public key is the `vault_encryption_key` of the router. KMS
+    /// encrypted when the `kms` feature is enabled, as are the key encryption keys and the
+    /// fingerprint key.
+    pub vault_private_key: String,
+    /// Public key of the router, with which the signature of the requests is verified and the
+    /// responses are encrypted. Its private key is the `vault_private_key` of the router.
+    pub router_public_key: String,
+    /// Identifier of the key encryption key under which the data keys of new records are wrapped
+    pub active_key_id: String,
+    /// Hex encoded 256-bit key encryption keys, by their identifier. Retired keys must be kept until
+    /// no record is wrapped under them anymore.
+    pub keys: HashMap<String, String>,
+    /// Hex encoded key with which the fingerprints of the stored cards are computed
+    pub fingerprint_key: String,
+    /// Time in seconds after which temporary tokens expire
+    pub token_ttl: i64,
+    /// Maximum number of cards whose data key is wrapped again under the active key in a single run
+    pub key_rotation_batch_size: i64,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -314,6 +377,40 @@ pub struct Refund {
     pub max_age: i64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct DisputeSettings {
+    /// Number of hours before the evidence deadline of a dispute at which the merchant should be
+    /// reminded about the upcoming deadline
+    pub evidence_reminder_hours: Vec<u16>,
+    /// Time in seconds after the evidence deadline, beyond which a dispute that is still open is
+    /// marked as expired, allowing for any status update from the connector to arrive first
+    pub expiry_grace_period: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct StaleObjectSync {
+    /// Time in seconds after its last update, beyond which a payment attempt in a non-terminal
+    /// status is synced with the connector
+    pub payment_threshold: i64,
+    /// Time in seconds after its last update, beyond which a refund in a non-terminal status is
+    /// synced with the connector
+    pub refund_threshold: i64,
+    /// Time in seconds after its last update, beyond which a dispute in a non-terminal status is
+    /// synced, or reported as stale if its connector does not support retrieving disputes
+    pub dispute_threshold: i64,
+    /// Time in seconds after its last update, beyond which an object is no longer swept, so that
+    /// objects which cannot be synced do not hold up the more recent ones
+    pub max_age: i64,
+    /// Maximum number of objects of each kind fetched in a single sweep
+    pub batch_size: i64,
+    /// Maximum number of sync calls made to a connector in a single sweep
+    pub connector_rate_limit: usize,
+    /// Overrides of the maximum number of sync calls made in a single sweep, keyed by connector
+    pub connector_rate_limits: HashMap<String, usize>,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct EphemeralConfig {
@@ -443,13 +540,28 @@ pub struct ConnectorParamsWithFileUploadUrl {
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct SchedulerSettings {
+    /// Stream to which tasks are added, unless their runner has a dedicated stream
     pub stream: String,
+    /// Dedicated streams for runners, keyed by the runner name
+    pub runner_streams: HashMap<String, String>,
+    /// Jobs scheduled by the producer on a recurring basis, keyed by the job name
+    pub recurring_jobs: HashMap<String, RecurringJob>,
     pub producer: ProducerSettings,
     pub consumer: ConsumerSettings,
     pub loop_interval: u64,
     pub graceful_shutdown_interval: u64,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecurringJob {
+    /// Runner of the tasks created for each occurrence of the job
+    pub runner: String,
+    /// Cron expression specifying the occurrences of the job, evaluated in UTC
+    pub schedule: String,
+    #[serde(default)]
+    pub disabled: bool,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct ProducerSettings {
@@ -466,6 +578,21 @@ pub struct ProducerSettings {
 pub struct ConsumerSettings {
     pub disabled: bool,
     pub consumer_group: String,
+    /// Streams consumed by this consumer along with their relative weights. Only the default
+    /// scheduler stream is consumed if this is empty.
+    pub streams: HashMap<String, u32>,
+    /// Maximum number of tasks of a runner executed concurrently by this consumer, keyed by the
+    /// runner name
+    pub runner_concurrency: HashMap<String, usize>,
+    /// Time after which batches read but not acknowledged by a consumer are claimed by another
+    /// consumer, since the consumer which read them is presumed dead (in milliseconds)
+    pub reclaim_idle_time: u64,
+    /// Interval between checks for batches to be reclaimed (in milliseconds)
+    pub reclaim_interval: u64,
+    /// Duration of the lease a consumer holds on the tasks it executes, which is renewed while the
+    /// task is being executed. Tasks in reclaimed batches are executed again only once their lease
+    /// has expired (in milliseconds)
+    pub lease_duration: u64,
 }
 
 #[cfg(feature = "kv_store")]
@@ -485,7 +612,7 @@ pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
 }
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct ApiKeys {
     /// Base64-encoded (KMS encrypted) ciphertext of the key used for calculating hashes of API
@@ -497,6 +624,18 @@ pub struct ApiKeys {
     /// hashes of API keys
     #[cfg(not(feature = "kms"))]
     pub hash_key: String,
+
+    /// Minimum interval (in seconds) between two consecutive updates of the time at which an API
+    /// key was last used
+    pub last_used_update_interval: i64,
+
+    /// Number of days before the expiry of an API key at which the merchant should be reminded
+    /// about the upcoming expiry
+    pub expiry_reminder_days: Vec<u8>,
+
+    /// Default number of days after which an API key that has not been used is included in the
+    /// unused API keys report
+    pub unused_key_report_days: i64,
 }
 
 #[cfg(feature = "s3")]
@@ -509,6 +648,58 @@ pub struct FileUploadConfig {
     pub bucket_name: String,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct NetworkTokenization {
+    /// Whether network tokens are provisioned for the cards saved in the locker
+    pub enabled: bool,
+    /// Token service provider with which network tokens are provisioned
+    pub provider: TokenServiceProviderKind,
+    /// Connectors to which the network token and cryptogram of a saved card are sent in place of
+    /// the card number
+    #[serde(deserialize_with = "connector_deser")]
+    pub connector_list: HashSet<api_models::enums::Connector>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenServiceProviderKind {
+    /// Token service provider emulated locally, for use in development and testing. It cannot be
+    /// enabled in release builds.
+    #[default]
+    Mock,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CardAccountUpdater {
+    /// Account updater with which saved cards about to expire are checked for updates. Saved cards
+    /// are not checked if no account updater is configured.
+    pub provider: Option<AccountUpdaterProviderKind>,
+    /// Number of days before their expiry, within which saved cards are checked for updates
+    pub expiry_window_days: i64,
+    /// Number of days after which a saved card for which no update was reported is checked again
+    pub recheck_interval_days: i64,
+    /// Maximum number of saved cards checked in a single run
+    pub batch_size: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountUpdaterProviderKind {
+    /// Account updater emulated locally, available only in tests
+    #[cfg(test)]
+    Mock,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ReconciliationSettings {
+    /// Directory from which connector reports are imported by the settlement reconciliation
+    /// workflow, laid out as `<merchant_id>/<connector>/<report_type>/<report>.csv`
+    pub report_directory: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct DelayedSessionConfig {
     #[serde(deserialize_with = "delayed_session_deser")]
@@ -595,6 +786,9 @@ impl Settings {
         }
         self.secrets.validate()?;
         self.locker.validate()?;
+        if self.locker.locker_setup == LockerSetup::BuiltinVault && !self.locker.mock_locker {
+            self.builtin_vault.validate()?;
+        }
         self.connectors.validate()?;
 
         self.scheduler
@@ -604,6 +798,10 @@ impl Settings {
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
         self.api_keys.validate()?;
+        self.stale_object_sync.validate()?;
+        self.network_tokenization.validate()?;
+        self.card_account_updater.validate()?;
+        self.disputes.validate()?;
         #[cfg(feature = "kms")]
         self.kms
             .validate()
@@ -630,4 +828,11 @@ mod payment_method_deserialization_test {
         let test_pm = pm_deser(deserializer);
         assert!(test_pm.is_ok())
     }
+
+    #[test]
+    fn test_installment_count_deserializer() {
+        let deserializer: StrDeserializer<'_, ValueError> = "3, 6,12,invalid".into_deserializer();
+        let installment_counts = installment_count_set_deser(deserializer).unwrap();
+        assert_eq!(installment_counts, HashSet::from([3, 6, 12]))
+    }
 }
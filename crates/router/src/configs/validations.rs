@@ -50,17 +50,82 @@ impl super::settings::Locker {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
 
-        when(!self.mock_locker && self.host.is_default_or_empty(), || {
+        // The built-in vault is served in-process, without any locker host
+        let is_external_locker =
+            !self.mock_locker && self.locker_setup != super::settings::LockerSetup::BuiltinVault;
+
+        when(
+            is_external_locker && self.host.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "locker host must not be empty when mock locker is disabled".into(),
+                ))
+            },
+        )?;
+
+        when(
+            is_external_locker && self.basilisk_host.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "basilisk host must not be empty when mock locker is disabled".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::BuiltinVault {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.vault_private_key.is_default_or_empty()
+                || self.router_public_key.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "built-in vault private key and router public key must not be empty".into(),
+                ))
+            },
+        )?;
+
+        when(!self.keys.contains_key(&self.active_key_id), || {
             Err(ApplicationError::InvalidConfigurationValueError(
-                "locker host must not be empty when mock locker is disabled".into(),
+                "built-in vault active key must be one of the configured keys".into(),
             ))
         })?;
 
+        // KMS encrypted keys can only be checked once they have been decrypted
+        #[cfg(not(feature = "kms"))]
         when(
-            !self.mock_locker && self.basilisk_host.is_default_or_empty(),
+            self.keys
+                .values()
+                .chain(std::iter::once(&self.fingerprint_key))
+                .any(|key| !matches!(hex::decode(key), Ok(key) if key.len() == 32)),
             || {
                 Err(ApplicationError::InvalidConfigurationValueError(
-                    "basilisk host must not be empty when mock locker is disabled".into(),
+                    "built-in vault keys must be hex encoded 256-bit keys".into(),
+                ))
+            },
+        )?;
+
+        #[cfg(feature = "kms")]
+        when(
+            self.keys
+                .values()
+                .chain(std::iter::once(&self.fingerprint_key))
+                .any(|key| key.is_default_or_empty()),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "built-in vault KMS encrypted keys must not be empty".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.token_ttl <= 0 || self.key_rotation_batch_size <= 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "built-in vault token TTL and key rotation batch size must be positive".into(),
                 ))
             },
         )
@@ -191,12 +256,90 @@ impl super::settings::SchedulerSettings {
             ))
         })?;
 
+        when(
+            self.runner_streams
+                .values()
+                .any(|stream| stream.is_default_or_empty()),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "scheduler runner streams must not be empty".into(),
+                ))
+            },
+        )?;
+
+        for (job_name, job) in &self.recurring_jobs {
+            job.validate(job_name)?;
+        }
+
         self.producer.validate()?;
+        self.consumer.validate()?;
+
+        Ok(())
+    }
+}
+
+impl super::settings::RecurringJob {
+    pub fn validate(&self, job_name: &str) -> Result<(), ApplicationError> {
+        use std::str::FromStr;
+
+        crate::scheduler::workflows::PTRunner::from_str(&self.runner).map_err(|_| {
+            ApplicationError::InvalidConfigurationValueError(format!(
+                "runner of scheduler recurring job {job_name} is not a known runner: {}",
+                self.runner
+            ))
+        })?;
+
+        crate::scheduler::cron::CronSchedule::from_str(&self.schedule).map_err(|error| {
+            ApplicationError::InvalidConfigurationValueError(format!(
+                "schedule of scheduler recurring job {job_name} is invalid: {error:?}"
+            ))
+        })?;
 
         Ok(())
     }
 }
 
+impl super::settings::ConsumerSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.streams
+                .iter()
+                .any(|(stream, weight)| stream.is_default_or_empty() || *weight == 0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "consumer streams must not be empty and must have a non-zero weight".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.runner_concurrency.values().any(|limit| *limit == 0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "consumer runner concurrency limits must be greater than zero".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.reclaim_idle_time == 0 || self.reclaim_interval == 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "consumer reclaim idle time and interval must be greater than zero".into(),
+                ))
+            },
+        )?;
+
+        when(self.lease_duration == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "consumer lease duration must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::ProducerSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         common_utils::fp_utils::when(self.lock_key.is_default_or_empty(), || {
@@ -237,6 +380,98 @@ impl super::settings::FileUploadConfig {
     }
 }
 
+impl super::settings::DisputeSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.expiry_grace_period < 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "dispute expiry grace period must not be negative".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::StaleObjectSync {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.payment_threshold <= 0
+                || self.refund_threshold <= 0
+                || self.dispute_threshold <= 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "stale object sync thresholds must be positive".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.max_age <= self.payment_threshold
+                || self.max_age <= self.refund_threshold
+                || self.max_age <= self.dispute_threshold,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "stale object sync max age must be greater than the thresholds".into(),
+                ))
+            },
+        )?;
+
+        when(self.batch_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "stale object sync batch size must be positive".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::NetworkTokenization {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        // The mock token service provider provisions fabricated tokens under the BIN of the card,
+        // which must never be sent to a connector in production
+        when(
+            cfg!(feature = "release")
+                && self.enabled
+                && matches!(
+                    self.provider,
+                    super::settings::TokenServiceProviderKind::Mock
+                ),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "network tokenization must not be enabled with the mock token service provider \
+                     in release builds"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::CardAccountUpdater {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.expiry_window_days <= 0 || self.recheck_interval_days <= 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "card account updater expiry window and recheck interval must be positive"
+                        .into(),
+                ))
+            },
+        )?;
+
+        when(self.batch_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card account updater batch size must be positive".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::ApiKeys {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -256,3 +491,31 @@ impl super::settings::ApiKeys {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::settings::RecurringJob;
+
+    fn recurring_job(runner: &str) -> RecurringJob {
+        RecurringJob {
+            runner: runner.to_string(),
+            schedule: "*/30 * * * *".to_string(),
+            disabled: false,
+        }
+    }
+
+    #[test]
+    fn test_recurring_job_with_known_runner_is_valid() {
+        assert!(recurring_job("STALE_OBJECT_SYNC_WORKFLOW")
+            .validate("stale_object_sync")
+            .is_ok());
+    }
+
+    #[test]
+    fn test_recurring_job_with_unknown_runner_is_invalid() {
+        assert!(recurring_job("STALE_OBJECTS_SYNC_WORKFLOW")
+            .validate("stale_object_sync")
+            .is_err());
+        assert!(recurring_job("").validate("stale_object_sync").is_err());
+    }
+}
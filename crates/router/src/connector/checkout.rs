@@ -133,6 +133,7 @@ impl api::PaymentToken for Checkout {}
 impl api::Dispute for Checkout {}
 impl api::RetrieveFile for Checkout {}
 impl api::DefendDispute for Checkout {}
+impl api::FetchDispute for Checkout {}
 
 impl
     ConnectorIntegration<
@@ -834,6 +835,11 @@ impl api::FileUpload for Checkout {
                     })?
                 }
             }
+            api::FilePurpose::SettlementReport => {
+                Err(errors::ConnectorError::FileValidationFailed {
+                    reason: "settlement reports are not uploaded to the connector".to_owned(),
+                })?
+            }
         }
         Ok(())
     }
@@ -1080,6 +1086,80 @@ impl
     }
 }
 
+impl ConnectorIntegration<api::Fetch, types::FetchDisputeRequestData, types::FetchDisputeResponse>
+    for Checkout
+{
+    fn get_headers(
+        &self,
+        req: &types::FetchDisputeRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::FetchDisputeType::get_content_type(self)
+                .to_string()
+                .into(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        req: &types::FetchDisputeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}disputes/{}",
+            self.base_url(connectors),
+            req.request.connector_dispute_id,
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::FetchDisputeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Get)
+                .url(&types::FetchDisputeType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::FetchDisputeType::get_headers(self, req, connectors)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::FetchDisputeRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::FetchDisputeRouterData, errors::ConnectorError> {
+        let response: checkout::CheckoutDisputeResponse = res
+            .response
+            .parse_struct("CheckoutDisputeResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        router_env::logger::info!(connector_response=?response);
+        let connector_status = response.status.to_string();
+        Ok(types::FetchDisputeRouterData {
+            response: Ok(types::FetchDisputeResponse {
+                dispute_status: response.status.into(),
+                connector_status: Some(connector_status),
+            }),
+            ..data.clone()
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Checkout {
     fn get_webhook_source_verification_algorithm(
@@ -6,7 +6,7 @@ use time::PrimitiveDateTime;
 use url::Url;
 
 use crate::{
-    connector::utils::{RouterData, WalletData},
+    connector::utils::{self, NetworkTokenData, RouterData, WalletData},
     core::errors,
     pii, services,
     types::{self, api, storage::enums, transformers::ForeignFrom},
@@ -104,6 +104,25 @@ pub struct CardSource {
     pub cvv: pii::Secret<String>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct NetworkTokenSource {
+    #[serde(rename = "type")]
+    pub source_type: CheckoutSourceTypes,
+    pub token: cards::CardNumber,
+    pub expiry_month: pii::Secret<String>,
+    pub expiry_year: pii::Secret<String>,
+    pub token_type: NetworkTokenType,
+    pub cryptogram: pii::Secret<String>,
+    pub eci: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NetworkTokenType {
+    Vts,
+    Mdes,
+}
+
 #[derive(Debug, Serialize)]
 pub struct WalletSource {
     #[serde(rename = "type")]
@@ -115,6 +134,7 @@ pub struct WalletSource {
 #[serde(untagged)]
 pub enum PaymentSource {
     Card(CardSource),
+    NetworkToken(NetworkTokenSource),
     Wallets(WalletSource),
 }
 
@@ -123,6 +143,8 @@ pub enum PaymentSource {
 pub enum CheckoutSourceTypes {
     Card,
     Token,
+    #[serde(rename = "network_token")]
+    NetworkToken,
 }
 
 pub struct CheckoutAuthType {
@@ -180,12 +202,32 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
     fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
         let source_var = match item.request.payment_method_data.clone() {
             api::PaymentMethodData::Card(ccard) => {
-                let a = PaymentSource::Card(CardSource {
-                    source_type: CheckoutSourceTypes::Card,
-                    number: ccard.card_number.clone(),
-                    expiry_month: ccard.card_exp_month.clone(),
-                    expiry_year: ccard.card_exp_year.clone(),
-                    cvv: ccard.card_cvc,
+                // The network token of a saved card is sent in place of the card number, for the
+                // card networks whose token services are supported
+                let network_token_source = item.network_token.as_ref().and_then(|network_token| {
+                    let token_type = match network_token.get_card_issuer().ok()? {
+                        utils::CardIssuer::Visa => NetworkTokenType::Vts,
+                        utils::CardIssuer::Master => NetworkTokenType::Mdes,
+                        _ => return None,
+                    };
+                    Some(PaymentSource::NetworkToken(NetworkTokenSource {
+                        source_type: CheckoutSourceTypes::NetworkToken,
+                        token: network_token.token_number.clone(),
+                        expiry_month: network_token.token_exp_month.clone(),
+                        expiry_year: network_token.token_exp_year.clone(),
+                        token_type,
+                        cryptogram: network_token.token_cryptogram.clone(),
+                        eci: network_token.eci.clone(),
+                    }))
+                });
+                let a = network_token_source.unwrap_or_else(|| {
+                    PaymentSource::Card(CardSource {
+                        source_type: CheckoutSourceTypes::Card,
+                        number: ccard.card_number.clone(),
+                        expiry_month: ccard.card_exp_month.clone(),
+                        expiry_year: ccard.card_exp_year.clone(),
+                        cvv: ccard.card_cvc,
+                    })
                 });
                 Ok(a)
             }
@@ -822,6 +864,47 @@ impl From<CheckoutWebhookEventType> for api::IncomingWebhookEvent {
     }
 }
 
+#[derive(Debug, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CheckoutDisputeStatus {
+    EvidenceRequired,
+    EvidenceUnderReview,
+    Resolved,
+    ArbitrationUnderReview,
+    Won,
+    Lost,
+    ArbitrationWon,
+    ArbitrationLost,
+    Expired,
+    Accepted,
+    Canceled,
+}
+
+impl From<CheckoutDisputeStatus> for api_models::enums::DisputeStatus {
+    fn from(status: CheckoutDisputeStatus) -> Self {
+        match status {
+            CheckoutDisputeStatus::EvidenceRequired => Self::DisputeOpened,
+            CheckoutDisputeStatus::EvidenceUnderReview
+            | CheckoutDisputeStatus::Resolved
+            | CheckoutDisputeStatus::ArbitrationUnderReview => Self::DisputeChallenged,
+            CheckoutDisputeStatus::Won | CheckoutDisputeStatus::ArbitrationWon => Self::DisputeWon,
+            CheckoutDisputeStatus::Lost | CheckoutDisputeStatus::ArbitrationLost => {
+                Self::DisputeLost
+            }
+            CheckoutDisputeStatus::Expired => Self::DisputeExpired,
+            CheckoutDisputeStatus::Accepted => Self::DisputeAccepted,
+            CheckoutDisputeStatus::Canceled => Self::DisputeCancelled,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutDisputeResponse {
+    pub id: String,
+    pub status: CheckoutDisputeStatus,
+}
+
 impl From<CheckoutTransactionType> for api_models::enums::DisputeStage {
     fn from(code: CheckoutTransactionType) -> Self {
         match code {
@@ -78,6 +78,28 @@ pub enum StripeMandateType {
     Online,
 }
 
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeInstallmentPlanType {
+    FixedCount,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeInstallmentPlanInterval {
+    Month,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeInstallmentPlan {
+    #[serde(rename = "payment_method_options[card][installments][plan][type]")]
+    pub plan_type: StripeInstallmentPlanType,
+    #[serde(rename = "payment_method_options[card][installments][plan][interval]")]
+    pub interval: StripeInstallmentPlanInterval,
+    #[serde(rename = "payment_method_options[card][installments][plan][count]")]
+    pub count: u8,
+}
+
 #[derive(Debug, Eq, PartialEq, Serialize)]
 pub struct StripeMandateRequest {
     #[serde(rename = "mandate_data[customer_acceptance][type]")]
@@ -107,6 +129,8 @@ pub struct PaymentIntentRequest {
     pub customer: Option<Secret<String>>,
     #[serde(flatten)]
     pub setup_mandate_details: Option<StripeMandateRequest>,
+    #[serde(flatten)]
+    pub installments: Option<StripeInstallmentPlan>,
     pub description: Option<String>,
     #[serde(flatten)]
     pub shipping: StripeShippingAddress,
@@ -1283,6 +1307,29 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             })
             .transpose()?;
 
+        // Stripe offers installment plans only for card payments
+        let installments = item
+            .request
+            .installment_count
+            .map(|count| match item.request.payment_method_data {
+                payments::PaymentMethodData::Card(_) => Ok(StripeInstallmentPlan {
+                    plan_type: StripeInstallmentPlanType::FixedCount,
+                    interval: StripeInstallmentPlanInterval::Month,
+                    count,
+                }),
+                _ => Err(errors::ConnectorError::NotSupported {
+                    message: "Installments for payment methods other than cards".to_string(),
+                    connector: "stripe",
+                    payment_experience: item
+                        .request
+                        .payment_experience
+                        .as_ref()
+                        .map(|experience| experience.to_string())
+                        .unwrap_or_default(),
+                }),
+            })
+            .transpose()?;
+
         Ok(Self {
             amount: item.request.amount, //hopefully we don't loose some cents here
             currency: item.request.currency.to_string(), //we need to copy the value and not transfer ownership
@@ -1307,6 +1354,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             payment_method,
             customer: item.connector_customer.to_owned().map(Secret::new),
             setup_mandate_details,
+            installments,
             off_session: item.request.off_session,
             setup_future_usage: item.request.setup_future_usage,
         })
@@ -2759,6 +2807,11 @@ pub struct DisputeObj {
     pub status: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct DetachedPaymentMethod {
+    pub id: String,
+}
+
 #[cfg(test)]
 mod test_validate_shipping_address_against_payment_method {
     #![allow(clippy::unwrap_used)]
@@ -2947,3 +3000,27 @@ mod test_validate_shipping_address_against_payment_method {
         }
     }
 }
+
+#[cfg(test)]
+mod test_installment_plan {
+    #![allow(clippy::unwrap_used)]
+    use super::{StripeInstallmentPlan, StripeInstallmentPlanInterval, StripeInstallmentPlanType};
+
+    #[test]
+    fn should_encode_installment_plan() {
+        let installment_plan = StripeInstallmentPlan {
+            plan_type: StripeInstallmentPlanType::FixedCount,
+            interval: StripeInstallmentPlanInterval::Month,
+            count: 6,
+        };
+
+        let encoded = serde_urlencoded::to_string(&installment_plan).unwrap();
+
+        assert_eq!(
+            encoded,
+            "payment_method_options%5Bcard%5D%5Binstallments%5D%5Bplan%5D%5Btype%5D=fixed_count\
+             &payment_method_options%5Bcard%5D%5Binstallments%5D%5Bplan%5D%5Binterval%5D=month\
+             &payment_method_options%5Bcard%5D%5Binstallments%5D%5Bplan%5D%5Bcount%5D=6"
+        );
+    }
+}
@@ -7,10 +7,14 @@ pub mod customers;
 pub mod disputes;
 pub mod errors;
 pub mod files;
+pub mod health_check;
 pub mod mandate;
 pub mod metrics;
 pub mod payment_methods;
 pub mod payments;
+pub mod process_tracker;
+pub mod reconciliation;
 pub mod refunds;
+pub mod retry_schedules;
 pub mod utils;
 pub mod webhooks;
@@ -64,6 +64,50 @@ fn get_primary_business_details(
     }
 }
 
+fn get_capture_schedule_value(
+    capture_schedule: Option<&api::CaptureScheduleConfig>,
+) -> RouterResult<Option<serde_json::Value>> {
+    let Some(capture_schedule) = capture_schedule else {
+        return Ok(None);
+    };
+
+    for schedule in capture_schedule
+        .default_schedule
+        .iter()
+        .chain(capture_schedule.payment_method_schedules.values())
+    {
+        validate_capture_schedule(schedule)?;
+    }
+
+    utils::Encode::<api::CaptureScheduleConfig>::encode_to_value(capture_schedule)
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "capture_schedule",
+        })
+        .map(Some)
+}
+
+fn validate_capture_schedule(schedule: &api::CaptureSchedule) -> RouterResult<()> {
+    if schedule.auto_capture_after_hours == Some(0) || schedule.auto_void_after_days == Some(0) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Capture schedule delays must be greater than zero".to_string(),
+        }));
+    }
+
+    if let (Some(auto_capture_after_hours), Some(auto_void_after_days)) = (
+        schedule.auto_capture_after_hours,
+        schedule.auto_void_after_days,
+    ) {
+        if u64::from(auto_capture_after_hours) >= u64::from(auto_void_after_days) * 24 {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Payments must be captured automatically before they are voided"
+                    .to_string(),
+            }));
+        }
+    }
+
+    Ok(())
+}
+
 pub async fn create_merchant_account(
     db: &dyn StorageInterface,
     req: api::MerchantAccountCreate,
@@ -83,6 +127,8 @@ pub async fn create_merchant_account(
         field_name: "primary_business_details",
     })?;
 
+    let capture_schedule = get_capture_schedule_value(req.capture_schedule.as_ref())?;
+
     let merchant_details: OptionalSecretValue =
         req.merchant_details
             .as_ref()
@@ -172,6 +218,7 @@ pub async fn create_merchant_account(
             modified_at: date_time::now(),
             frm_routing_algorithm: req.frm_routing_algorithm,
             intent_fulfillment_time: req.intent_fulfillment_time.map(i64::from),
+            capture_schedule,
             id: None,
         })
     }
@@ -260,6 +307,8 @@ pub async fn merchant_account_update(
         })
         .transpose()?;
 
+    let capture_schedule = get_capture_schedule_value(req.capture_schedule.as_ref())?;
+
     let key = key_store.key.get_inner().peek();
 
     let updated_merchant_account = storage::MerchantAccountUpdate::Update {
@@ -312,6 +361,7 @@ pub async fn merchant_account_update(
         primary_business_details,
         frm_routing_algorithm: req.frm_routing_algorithm,
         intent_fulfillment_time: req.intent_fulfillment_time.map(i64::from),
+        capture_schedule,
     };
 
     let response = db
@@ -3,6 +3,7 @@ use error_stack::{report, IntoReport, ResultExt};
 #[cfg(feature = "kms")]
 use external_services::kms;
 use masking::{PeekInterface, StrongSecret};
+use redis_interface::SetnxReply;
 use router_env::{instrument, tracing};
 
 use crate::{
@@ -10,12 +11,23 @@ use crate::{
     consts,
     core::errors::{self, RouterResponse, StorageErrorExt},
     db::StorageInterface,
+    logger,
     routes::metrics,
-    services::ApplicationResponse,
-    types::{api, storage, transformers::ForeignInto},
+    scheduler::utils as pt_utils,
+    services::{ApplicationResponse, RedisConnInterface},
+    types::{
+        api,
+        storage::{self, enums, ProcessTrackerExt},
+        transformers::ForeignInto,
+    },
     utils,
 };
 
+const API_KEY_EXPIRY_TAG: &str = "API_KEY";
+const API_KEY_EXPIRY_NAME: &str = "API_KEY_EXPIRY";
+const API_KEY_EXPIRY_RUNNER: &str = "API_KEY_EXPIRY_WORKFLOW";
+const API_KEY_LAST_USED_KEY_PREFIX: &str = "api_key_last_used";
+
 static HASH_KEY: tokio::sync::OnceCell<StrongSecret<[u8; PlaintextApiKey::HASH_KEY_LEN]>> =
     tokio::sync::OnceCell::const_new();
 
@@ -148,6 +160,18 @@ pub async fn create_api_key(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to insert new API key")?;
 
+    if let Some(expires_at) = api_key.expires_at {
+        add_api_key_expiry_task(
+            store,
+            &api_key,
+            expires_at,
+            &api_key_config.expiry_reminder_days,
+        )
+        .await
+        .map_err(|error| logger::error!(api_key_expiry_task_error=?error))
+        .ok();
+    }
+
     metrics::API_KEY_CREATED.add(
         &metrics::CONTEXT,
         1,
@@ -178,10 +202,12 @@ pub async fn retrieve_api_key(
 #[instrument(skip_all)]
 pub async fn update_api_key(
     store: &dyn StorageInterface,
+    api_key_config: &settings::ApiKeys,
     merchant_id: &str,
     key_id: &str,
     api_key: api::UpdateApiKeyRequest,
 ) -> RouterResponse<api::RetrieveApiKeyResponse> {
+    let expiry_updated = api_key.expiration.is_some();
     let api_key = store
         .update_api_key(
             merchant_id.to_owned(),
@@ -191,6 +217,13 @@ pub async fn update_api_key(
         .await
         .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
 
+    if expiry_updated {
+        update_api_key_expiry_task(store, &api_key, &api_key_config.expiry_reminder_days)
+            .await
+            .map_err(|error| logger::error!(api_key_expiry_task_error=?error))
+            .ok();
+    }
+
     Ok(ApplicationResponse::Json(api_key.foreign_into()))
 }
 
@@ -205,6 +238,13 @@ pub async fn revoke_api_key(
         .await
         .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
 
+    if revoked {
+        finish_api_key_expiry_task(store, key_id, merchant_id, "REVOKED")
+            .await
+            .map_err(|error| logger::error!(api_key_expiry_task_error=?error))
+            .ok();
+    }
+
     metrics::API_KEY_REVOKED.add(&metrics::CONTEXT, 1, &[]);
 
     Ok(ApplicationResponse::Json(api::RevokeApiKeyResponse {
@@ -234,6 +274,231 @@ pub async fn list_api_keys(
     Ok(ApplicationResponse::Json(api_keys))
 }
 
+#[instrument(skip_all)]
+pub async fn list_unused_api_keys(
+    store: &dyn StorageInterface,
+    api_key_config: &settings::ApiKeys,
+    merchant_id: String,
+    unused_for_days: Option<i64>,
+) -> RouterResponse<api::UnusedApiKeysResponse> {
+    let unused_for_days = unused_for_days.unwrap_or(api_key_config.unused_key_report_days);
+    if unused_for_days < 0 {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`unused_for_days` must not be negative".to_string(),
+        }));
+    }
+
+    let unused_since = date_time::now().saturating_sub(time::Duration::days(unused_for_days));
+    let api_keys = store
+        .list_unused_api_keys_by_merchant_id(&merchant_id, unused_since)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list unused merchant API keys")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(ApplicationResponse::Json(api::UnusedApiKeysResponse {
+        merchant_id,
+        unused_for_days,
+        api_keys,
+    }))
+}
+
+/// Updates the time at which the API key was last used. The update is debounced using Redis, so
+/// that the database is updated at most once in the configured interval for an API key.
+#[instrument(skip_all)]
+pub async fn update_last_used(
+    store: &dyn StorageInterface,
+    api_key: &storage::ApiKey,
+    update_interval: i64,
+) -> errors::RouterResult<()> {
+    let redis_key = format!("{API_KEY_LAST_USED_KEY_PREFIX}_{}", api_key.key_id);
+    let is_update_due = store
+        .get_redis_conn()
+        .set_key_if_not_exists_with_expiry(&redis_key, "true", Some(update_interval))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to check when the API key was last used")?;
+
+    if matches!(is_update_due, SetnxReply::KeySet) {
+        store
+            .update_api_key(
+                api_key.merchant_id.to_owned(),
+                api_key.key_id.to_owned(),
+                storage::ApiKeyUpdate::LastUsedUpdate {
+                    last_used: date_time::now(),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the time at which the API key was last used")?;
+    }
+
+    Ok(())
+}
+
+pub fn get_api_key_expiry_process_tracker_id(key_id: &str, merchant_id: &str) -> String {
+    pt_utils::get_process_tracker_id(
+        API_KEY_EXPIRY_RUNNER,
+        API_KEY_EXPIRY_NAME,
+        key_id,
+        merchant_id,
+    )
+}
+
+/// Obtains the index of the next expiry reminder to be sent, starting from `from_index`, along
+/// with the time at which the reminder should be sent. Reminders whose time has already passed
+/// are skipped.
+pub fn get_next_expiry_reminder(
+    api_key_expiry: time::PrimitiveDateTime,
+    expiry_reminder_days: &[u8],
+    from_index: usize,
+) -> Option<(usize, time::PrimitiveDateTime)> {
+    let now = date_time::now();
+    expiry_reminder_days
+        .iter()
+        .enumerate()
+        .skip(from_index)
+        .map(|(index, days)| {
+            (
+                index,
+                api_key_expiry.saturating_sub(time::Duration::days((*days).into())),
+            )
+        })
+        .find(|(_, reminder_time)| *reminder_time > now)
+}
+
+/// Obtains the first expiry reminder to be sent for an API key. If the API key expires sooner
+/// than the closest reminder, a single reminder is sent right away.
+fn get_first_expiry_reminder(
+    api_key_expiry: time::PrimitiveDateTime,
+    expiry_reminder_days: &[u8],
+) -> Option<(usize, time::PrimitiveDateTime)> {
+    let now = date_time::now();
+    get_next_expiry_reminder(api_key_expiry, expiry_reminder_days, 0)
+        .or_else(|| (api_key_expiry > now).then_some((expiry_reminder_days.len(), now)))
+}
+
+/// Obtains the tracking data of the expiry reminder task of an API key, along with the time at
+/// which the first reminder should be sent. Returns `None` if the API key has already expired.
+fn get_api_key_expiry_tracking_data(
+    api_key: &storage::ApiKey,
+    api_key_expiry: time::PrimitiveDateTime,
+    expiry_reminder_days: &[u8],
+) -> Option<(storage::ApiKeyExpiryTrackingData, time::PrimitiveDateTime)> {
+    let mut expiry_reminder_days = expiry_reminder_days.to_vec();
+    expiry_reminder_days.sort_unstable_by(|a, b| b.cmp(a));
+    expiry_reminder_days.dedup();
+
+    let (reminder_index, schedule_time) =
+        get_first_expiry_reminder(api_key_expiry, &expiry_reminder_days)?;
+
+    Some((
+        storage::ApiKeyExpiryTrackingData {
+            key_id: api_key.key_id.clone(),
+            merchant_id: api_key.merchant_id.clone(),
+            api_key_name: api_key.name.clone(),
+            prefix: api_key.prefix.clone(),
+            api_key_expiry,
+            expiry_reminder_days,
+            reminder_index,
+        },
+        schedule_time,
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn add_api_key_expiry_task(
+    store: &dyn StorageInterface,
+    api_key: &storage::ApiKey,
+    api_key_expiry: time::PrimitiveDateTime,
+    expiry_reminder_days: &[u8],
+) -> Result<(), errors::ProcessTrackerError> {
+    let Some((tracking_data, schedule_time)) =
+        get_api_key_expiry_tracking_data(api_key, api_key_expiry, expiry_reminder_days)
+    else {
+        logger::debug!("API key has already expired, skipping expiry reminders");
+        return Ok(());
+    };
+
+    let mut process_tracker_entry = storage::ProcessTracker::make_process_tracker_new(
+        get_api_key_expiry_process_tracker_id(&api_key.key_id, &api_key.merchant_id),
+        API_KEY_EXPIRY_NAME,
+        API_KEY_EXPIRY_RUNNER,
+        tracking_data,
+        schedule_time,
+    )?;
+    process_tracker_entry.tag = vec![API_KEY_EXPIRY_TAG.to_string()];
+
+    store.insert_process(process_tracker_entry).await?;
+    metrics::API_KEY_EXPIRY_TASKS_CREATED.add(&metrics::CONTEXT, 1, &[]);
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn update_api_key_expiry_task(
+    store: &dyn StorageInterface,
+    api_key: &storage::ApiKey,
+    expiry_reminder_days: &[u8],
+) -> Result<(), errors::ProcessTrackerError> {
+    let task_id = get_api_key_expiry_process_tracker_id(&api_key.key_id, &api_key.merchant_id);
+    let existing_task = store.find_process_by_id(&task_id).await?;
+
+    match (existing_task, api_key.expires_at) {
+        (None, None) => Ok(()),
+        (None, Some(api_key_expiry)) => {
+            add_api_key_expiry_task(store, api_key, api_key_expiry, expiry_reminder_days).await
+        }
+        (Some(task), None) => {
+            task.finish_with_status(store, "EXPIRY_REMOVED".to_string())
+                .await
+        }
+        (Some(task), Some(api_key_expiry)) => {
+            match get_api_key_expiry_tracking_data(api_key, api_key_expiry, expiry_reminder_days) {
+                Some((tracking_data, schedule_time)) => {
+                    let tracking_data = serde_json::to_value(tracking_data)
+                        .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+                    store
+                        .update_process(
+                            task,
+                            storage::ProcessTrackerUpdate::Update {
+                                name: None,
+                                retry_count: None,
+                                schedule_time: Some(schedule_time),
+                                tracking_data: Some(tracking_data),
+                                business_status: Some(String::from("Pending")),
+                                status: Some(enums::ProcessTrackerStatus::New),
+                                updated_at: Some(date_time::now()),
+                            },
+                        )
+                        .await?;
+                    Ok(())
+                }
+                None => task.finish_with_status(store, "EXPIRED".to_string()).await,
+            }
+        }
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn finish_api_key_expiry_task(
+    store: &dyn StorageInterface,
+    key_id: &str,
+    merchant_id: &str,
+    business_status: &str,
+) -> Result<(), errors::ProcessTrackerError> {
+    let task_id = get_api_key_expiry_process_tracker_id(key_id, merchant_id);
+    match store.find_process_by_id(&task_id).await? {
+        Some(task) if task.status != enums::ProcessTrackerStatus::Finish => {
+            task.finish_with_status(store, business_status.to_string())
+                .await
+        }
+        _ => Ok(()),
+    }
+}
+
 impl From<&str> for PlaintextApiKey {
     fn from(s: &str) -> Self {
         Self(s.to_owned().into())
@@ -285,4 +550,140 @@ mod tests {
         let new_hashed_api_key = plaintext_api_key.keyed_hash(hash_key.peek());
         assert_eq!(hashed_api_key, new_hashed_api_key)
     }
+
+    fn get_api_key(expires_at: Option<time::PrimitiveDateTime>) -> storage::ApiKey {
+        storage::ApiKey {
+            key_id: "key_id".to_string(),
+            merchant_id: "merchant_id".to_string(),
+            name: "Key".to_string(),
+            description: None,
+            hashed_api_key: "hashed_key".to_string().into(),
+            prefix: "abc".to_string(),
+            created_at: date_time::now(),
+            expires_at,
+            last_used: None,
+        }
+    }
+
+    #[test]
+    fn test_expiry_reminder_schedule() {
+        let api_key_expiry = date_time::now() + time::Duration::days(10);
+        let api_key = get_api_key(Some(api_key_expiry));
+
+        // The reminder 30 days before expiry has already passed
+        let (tracking_data, schedule_time) =
+            get_api_key_expiry_tracking_data(&api_key, api_key_expiry, &[1, 7, 30, 7]).unwrap();
+        assert_eq!(tracking_data.expiry_reminder_days, vec![30, 7, 1]);
+        assert_eq!(tracking_data.reminder_index, 1);
+        assert_eq!(schedule_time, api_key_expiry - time::Duration::days(7));
+
+        let (reminder_index, schedule_time) = get_next_expiry_reminder(
+            api_key_expiry,
+            &tracking_data.expiry_reminder_days,
+            tracking_data.reminder_index + 1,
+        )
+        .unwrap();
+        assert_eq!(reminder_index, 2);
+        assert_eq!(schedule_time, api_key_expiry - time::Duration::days(1));
+
+        assert!(get_next_expiry_reminder(
+            api_key_expiry,
+            &tracking_data.expiry_reminder_days,
+            reminder_index + 1,
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_expiry_reminder_for_api_key_expiring_soon() {
+        let api_key_expiry = date_time::now() + time::Duration::hours(12);
+        let api_key = get_api_key(Some(api_key_expiry));
+
+        // A single reminder is sent right away, after which there are no more reminders
+        let (tracking_data, schedule_time) =
+            get_api_key_expiry_tracking_data(&api_key, api_key_expiry, &[7, 1]).unwrap();
+        assert_eq!(tracking_data.reminder_index, 2);
+        assert!(schedule_time <= date_time::now());
+        assert!(get_next_expiry_reminder(
+            api_key_expiry,
+            &tracking_data.expiry_reminder_days,
+            tracking_data.reminder_index + 1,
+        )
+        .is_none());
+
+        let api_key_expiry = date_time::now() - time::Duration::hours(1);
+        let api_key = get_api_key(Some(api_key_expiry));
+        assert!(get_api_key_expiry_tracking_data(&api_key, api_key_expiry, &[7, 1]).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_expiry_task_tracks_reminder_in_tracking_data() {
+        use crate::db::{process_tracker::ProcessTrackerInterface, MockDb};
+
+        let mockdb = MockDb::new(&Default::default()).await.unwrap();
+        let api_key_expiry = date_time::now() + time::Duration::days(10);
+        let api_key = get_api_key(Some(api_key_expiry));
+
+        add_api_key_expiry_task(&mockdb, &api_key, api_key_expiry, &[7, 1])
+            .await
+            .unwrap();
+
+        let task = mockdb
+            .find_process_by_id(&get_api_key_expiry_process_tracker_id(
+                &api_key.key_id,
+                &api_key.merchant_id,
+            ))
+            .await
+            .unwrap()
+            .unwrap();
+        let tracking_data: storage::ApiKeyExpiryTrackingData =
+            serde_json::from_value(task.tracking_data).unwrap();
+        assert_eq!(tracking_data.reminder_index, 0);
+        assert_eq!(task.retry_count, 0);
+        assert_eq!(
+            task.schedule_time,
+            Some(api_key_expiry - time::Duration::days(7))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_last_used_update_is_debounced() {
+        use crate::db::{api_keys::ApiKeyInterface, MockDb};
+
+        let mockdb = MockDb::new(&Default::default()).await.unwrap();
+        let api_key = get_api_key(None);
+        mockdb
+            .insert_api_key(storage::ApiKeyNew {
+                key_id: api_key.key_id.clone(),
+                merchant_id: api_key.merchant_id.clone(),
+                name: api_key.name.clone(),
+                description: None,
+                hashed_api_key: api_key.hashed_api_key.clone(),
+                prefix: api_key.prefix.clone(),
+                created_at: api_key.created_at,
+                expires_at: None,
+                last_used: None,
+            })
+            .await
+            .unwrap();
+
+        update_last_used(&mockdb, &api_key, 60).await.unwrap();
+        let last_used = mockdb
+            .find_api_key_by_merchant_id_key_id_optional(&api_key.merchant_id, &api_key.key_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .last_used;
+        assert!(last_used.is_some());
+
+        // Usages within the update interval do not update the database again
+        update_last_used(&mockdb, &api_key, 60).await.unwrap();
+        let debounced_last_used = mockdb
+            .find_api_key_by_merchant_id_key_id_optional(&api_key.merchant_id, &api_key.key_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .last_used;
+        assert_eq!(debounced_last_used, last_used);
+    }
 }
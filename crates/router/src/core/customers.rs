@@ -1,30 +1,38 @@
+use std::collections::HashMap;
+
 use common_utils::{
     crypto::{Encryptable, GcmAes256},
     ext_traits::ValueExt,
 };
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use masking::ExposeInterface;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 
 use crate::{
     consts,
     core::{
         errors::{self, RouterResponse, StorageErrorExt},
+        files, mandate,
         payment_methods::cards,
     },
     db::StorageInterface,
     pii::PeekInterface,
     routes::{metrics, AppState},
+    scheduler::utils as pt_utils,
     services,
     types::{
-        api::customers,
+        api::{
+            self, customers,
+            mandates::{self, MandateResponseExt},
+        },
         domain::{
             self,
             types::{self, AsyncLift, TypeEncryption},
         },
         storage::{self, enums},
+        transformers::ForeignFrom,
     },
-    utils::generate_id,
+    utils::{self, generate_id},
 };
 
 pub const REDACTED: &str = "Redacted";
@@ -92,6 +100,7 @@ pub async fn create_customer(
                 address_id: generate_id(consts::ID_LENGTH, "add"),
                 created_at: common_utils::date_time::now(),
                 modified_at: common_utils::date_time::now(),
+                is_saved: false,
             })
         }
         .await
@@ -104,6 +113,21 @@ pub async fn create_customer(
             .attach_printable("Failed while inserting new address")?;
     }
 
+    let email_blind_index = customer_data
+        .email
+        .as_ref()
+        .map(|email| domain::get_email_blind_index(email.peek(), key))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating blind index of customer email")?;
+    let phone_blind_index = customer_data
+        .phone
+        .as_ref()
+        .map(|phone| domain::get_phone_blind_index(phone.peek(), key))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating blind index of customer phone")?;
+
     let new_customer = async {
         Ok(domain::Customer {
             customer_id: customer_id.to_string(),
@@ -125,6 +149,11 @@ pub async fn create_customer(
             metadata: customer_data.metadata,
             id: None,
             connector_customer: None,
+            default_payment_method_id: None,
+            email_blind_index,
+            phone_blind_index,
+            default_billing_address_id: None,
+            default_shipping_address_id: None,
             created_at: common_utils::date_time::now(),
             modified_at: common_utils::date_time::now(),
         })
@@ -175,6 +204,66 @@ pub async fn retrieve_customer(
     Ok(services::ApplicationResponse::Json(response.into()))
 }
 
+#[instrument(skip(db))]
+pub async fn list_customers(
+    db: &dyn StorageInterface,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    constraints: customers::CustomerListConstraints,
+) -> RouterResponse<customers::CustomerListResponse> {
+    utils::when(constraints.limit > 100 || constraints.limit < 1, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "limit should be in between 1 and 100".to_string(),
+        })
+    })?;
+
+    let key = key_store.key.get_inner().peek();
+    let email_blind_index = constraints
+        .email
+        .as_ref()
+        .map(|email| domain::get_email_blind_index(email.peek(), key))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating blind index of customer email")?;
+    let phone_blind_index = constraints
+        .phone
+        .as_ref()
+        .map(|phone| domain::get_phone_blind_index(phone.peek(), key))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating blind index of customer phone")?;
+
+    let customers = db
+        .list_customers_by_merchant_id(
+            &merchant_account.merchant_id,
+            &storage::CustomerListConstraints {
+                email_blind_index,
+                phone_blind_index,
+                starting_after: constraints.starting_after,
+                ending_before: constraints.ending_before,
+                limit: constraints.limit,
+                created: constraints.created,
+                created_lt: constraints.created_lt,
+                created_gt: constraints.created_gt,
+                created_lte: constraints.created_lte,
+                created_gte: constraints.created_gte,
+            },
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?
+        .into_iter()
+        .map(|customer| customers::CustomerResponse::from(customer).into_inner())
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        customers::CustomerListResponse {
+            size: customers.len(),
+            data: customers,
+        },
+    ))
+}
+
 #[instrument(skip_all)]
 pub async fn delete_customer(
     state: &AppState,
@@ -203,43 +292,88 @@ pub async fn delete_customer(
         }
     }
 
+    delete_customer_payment_methods(state, &merchant_account.merchant_id, &req.customer_id).await?;
+    redact_customer_details(
+        &**db,
+        &merchant_account.merchant_id,
+        &req.customer_id,
+        &key_store,
+    )
+    .await?;
+
+    let response = customers::CustomerDeleteResponse {
+        customer_id: req.customer_id,
+        customer_deleted: true,
+        address_deleted: true,
+        payment_methods_deleted: true,
+    };
+    metrics::CUSTOMER_REDACTED.add(&metrics::CONTEXT, 1, &[]);
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Finds the payment methods saved for the customer
+async fn find_customer_payment_methods(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    customer_id: &str,
+) -> errors::RouterResult<Vec<storage::PaymentMethod>> {
     match db
-        .find_payment_method_by_customer_id_merchant_id_list(
-            &req.customer_id,
-            &merchant_account.merchant_id,
-        )
+        .find_payment_method_by_customer_id_merchant_id_list(customer_id, merchant_id)
         .await
     {
-        Ok(customer_payment_methods) => {
-            for pm in customer_payment_methods.into_iter() {
-                if pm.payment_method == enums::PaymentMethod::Card {
-                    cards::delete_card_from_locker(
-                        state,
-                        &req.customer_id,
-                        &merchant_account.merchant_id,
-                        &pm.payment_method_id,
-                    )
-                    .await?;
-                }
-                db.delete_payment_method_by_merchant_id_payment_method_id(
-                    &merchant_account.merchant_id,
-                    &pm.payment_method_id,
-                )
-                .await
-                .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
-            }
-        }
+        Ok(customer_payment_methods) => Ok(customer_payment_methods),
         Err(error) => {
             if error.current_context().is_db_not_found() {
-                Ok(())
+                Ok(Vec::new())
             } else {
                 Err(error)
                     .change_context(errors::ApiErrorResponse::InternalServerError)
                     .attach_printable("failed find_payment_method_by_customer_id_merchant_id_list")
-            }?
+            }
         }
-    };
+    }
+}
 
+/// Deletes the payment methods saved for the customer, along with the cards saved in the locker,
+/// and returns the number of payment methods deleted
+async fn delete_customer_payment_methods(
+    state: &AppState,
+    merchant_id: &str,
+    customer_id: &str,
+) -> errors::RouterResult<usize> {
+    let db = &*state.store;
+    let customer_payment_methods =
+        find_customer_payment_methods(db, merchant_id, customer_id).await?;
+
+    for pm in customer_payment_methods.iter() {
+        if pm.payment_method == enums::PaymentMethod::Card {
+            cards::delete_card_from_locker(
+                state,
+                customer_id,
+                merchant_id,
+                pm.get_card_reference(),
+            )
+            .await?;
+        }
+        db.delete_payment_method_by_merchant_id_payment_method_id(
+            merchant_id,
+            &pm.payment_method_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    }
+
+    Ok(customer_payment_methods.len())
+}
+
+/// Redacts the personal details of the customer and of the addresses saved for the customer, and
+/// returns the number of addresses redacted
+async fn redact_customer_details(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    customer_id: &str,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<usize> {
     let key = key_store.key.get_inner().peek();
 
     let redacted_encrypted_value: Encryptable<masking::Secret<_>> =
@@ -261,19 +395,19 @@ pub async fn delete_customer(
         country_code: Some(REDACTED.to_string()),
     };
 
-    match db
+    let redacted_addresses = match db
         .update_address_by_merchant_id_customer_id(
-            &req.customer_id,
-            &merchant_account.merchant_id,
+            customer_id,
+            merchant_id,
             update_address,
-            &key_store,
+            key_store,
         )
         .await
     {
-        Ok(_) => Ok(()),
+        Ok(addresses) => Ok(addresses.len()),
         Err(error) => {
             if error.current_context().is_db_not_found() {
-                Ok(())
+                Ok(0)
             } else {
                 Err(error)
                     .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -294,24 +428,25 @@ pub async fn delete_customer(
         phone_country_code: Some(REDACTED.to_string()),
         metadata: None,
         connector_customer: None,
+        email_blind_index: Some(
+            domain::get_email_blind_index(REDACTED, key)
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        ),
+        phone_blind_index: Some(
+            domain::get_phone_blind_index(REDACTED, key)
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        ),
     };
     db.update_customer_by_customer_id_merchant_id(
-        req.customer_id.clone(),
-        merchant_account.merchant_id,
+        customer_id.to_owned(),
+        merchant_id.to_owned(),
         updated_customer,
-        &key_store,
+        key_store,
     )
     .await
     .change_context(errors::ApiErrorResponse::CustomerNotFound)?;
 
-    let response = customers::CustomerDeleteResponse {
-        customer_id: req.customer_id,
-        customer_deleted: true,
-        address_deleted: true,
-        payment_methods_deleted: true,
-    };
-    metrics::CUSTOMER_REDACTED.add(&metrics::CONTEXT, 1, &[]);
-    Ok(services::ApplicationResponse::Json(response))
+    Ok(redacted_addresses)
 }
 
 #[instrument(skip(db))]
@@ -381,7 +516,8 @@ pub async fn update_customer(
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed while encrypting Address while Update")?;
-        db.update_address_by_merchant_id_customer_id(
+        // The addresses saved in the address book of the customer are managed separately
+        db.update_unsaved_addresses_by_merchant_id_customer_id(
             &update_customer.customer_id,
             &merchant_account.merchant_id,
             update_address,
@@ -395,6 +531,21 @@ pub async fn update_customer(
         ))?;
     };
 
+    let email_blind_index = update_customer
+        .email
+        .as_ref()
+        .map(|email| domain::get_email_blind_index(email.peek(), key))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating blind index of customer email")?;
+    let phone_blind_index = update_customer
+        .phone
+        .as_ref()
+        .map(|phone| domain::get_phone_blind_index(phone.peek(), key))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating blind index of customer phone")?;
+
     let response = db
         .update_customer_by_customer_id_merchant_id(
             update_customer.customer_id.to_owned(),
@@ -419,6 +570,8 @@ pub async fn update_customer(
                     metadata: update_customer.metadata,
                     description: update_customer.description,
                     connector_customer: None,
+                    email_blind_index,
+                    phone_blind_index,
                 })
             }
             .await
@@ -435,3 +588,1088 @@ pub async fn update_customer(
         customer_update_response,
     ))
 }
+
+#[instrument(skip(db))]
+pub async fn create_customer_address(
+    db: &dyn StorageInterface,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    customer_id: String,
+    req: customers::CustomerAddressRequest,
+) -> RouterResponse<customers::CustomerAddressResponse> {
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            &customer_id,
+            &merchant_account.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let address = encrypt_customer_address(
+        &customer,
+        generate_id(consts::ID_LENGTH, "add"),
+        req.address,
+        req.phone,
+        &key_store,
+    )
+    .await?;
+    let address = db
+        .insert_address(address, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting new address")?;
+
+    let customer = update_customer_default_addresses(
+        db,
+        customer,
+        &address.address_id,
+        req.default_billing,
+        req.default_shipping,
+        &key_store,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_customer_address_response(address, &customer),
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn list_customer_addresses(
+    db: &dyn StorageInterface,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customers::CustomerId,
+) -> RouterResponse<customers::CustomerAddressListResponse> {
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            &req.customer_id,
+            &merchant_account.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let addresses = db
+        .find_saved_addresses_by_merchant_id_customer_id(
+            &merchant_account.merchant_id,
+            &req.customer_id,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while finding the saved addresses of the customer")?
+        .into_iter()
+        .map(|address| get_customer_address_response(address, &customer))
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        customers::CustomerAddressListResponse {
+            size: addresses.len(),
+            data: addresses,
+        },
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn retrieve_customer_address(
+    db: &dyn StorageInterface,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    customer_id: String,
+    address_id: String,
+) -> RouterResponse<customers::CustomerAddressResponse> {
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            &customer_id,
+            &merchant_account.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+    let address = find_saved_address(
+        db,
+        &merchant_account.merchant_id,
+        &customer_id,
+        &address_id,
+        &key_store,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_customer_address_response(address, &customer),
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn update_customer_address(
+    db: &dyn StorageInterface,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    customer_id: String,
+    address_id: String,
+    req: customers::CustomerAddressRequest,
+) -> RouterResponse<customers::CustomerAddressResponse> {
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            &customer_id,
+            &merchant_account.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+    let address = find_saved_address(
+        db,
+        &merchant_account.merchant_id,
+        &customer_id,
+        &address_id,
+        &key_store,
+    )
+    .await?;
+
+    // The address details and the phone details are each replaced as a whole when present
+    let address = match (req.address, req.phone) {
+        (None, None) => address,
+        (address_details, phone) => {
+            let current_address = api::Address::from(&address);
+            let updated_address = encrypt_customer_address(
+                &customer,
+                address.address_id,
+                address_details.or(current_address.address),
+                phone.or(current_address.phone),
+                &key_store,
+            )
+            .await?;
+            let address_update = storage::AddressUpdate::Update {
+                city: updated_address.city,
+                country: updated_address.country,
+                line1: updated_address.line1,
+                line2: updated_address.line2,
+                line3: updated_address.line3,
+                state: updated_address.state,
+                zip: updated_address.zip,
+                first_name: updated_address.first_name,
+                last_name: updated_address.last_name,
+                phone_number: updated_address.phone_number,
+                country_code: updated_address.country_code,
+            };
+            db.update_address(updated_address.address_id, address_update, &key_store)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::AddressNotFound)?
+        }
+    };
+
+    let customer = update_customer_default_addresses(
+        db,
+        customer,
+        &address.address_id,
+        req.default_billing,
+        req.default_shipping,
+        &key_store,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_customer_address_response(address, &customer),
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn delete_customer_address(
+    db: &dyn StorageInterface,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    customer_id: String,
+    address_id: String,
+) -> RouterResponse<customers::CustomerAddressDeleteResponse> {
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            &customer_id,
+            &merchant_account.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+    let address = find_saved_address(
+        db,
+        &merchant_account.merchant_id,
+        &customer_id,
+        &address_id,
+        &key_store,
+    )
+    .await?;
+
+    let deleted = db
+        .delete_address_by_address_id(&address.address_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::AddressNotFound)?;
+    update_customer_default_addresses(
+        db,
+        customer,
+        &address.address_id,
+        Some(false),
+        Some(false),
+        &key_store,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        customers::CustomerAddressDeleteResponse {
+            address_id: address.address_id,
+            customer_id,
+            deleted,
+        },
+    ))
+}
+
+/// Finds an address saved in the address book of the customer. Addresses of payments, and
+/// addresses of other customers, are reported as not found.
+pub(crate) async fn find_saved_address(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    customer_id: &str,
+    address_id: &str,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<domain::Address> {
+    let address = db
+        .find_address(address_id, key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::AddressNotFound)?;
+
+    utils::when(
+        !address.is_saved
+            || address.merchant_id != merchant_id
+            || address.customer_id != customer_id,
+        || Err(errors::ApiErrorResponse::AddressNotFound),
+    )?;
+
+    Ok(address)
+}
+
+/// Encrypts the details of an address to be saved in the address book of the customer
+async fn encrypt_customer_address(
+    customer: &domain::Customer,
+    address_id: String,
+    address_details: Option<api_models::payments::AddressDetails>,
+    phone: Option<api_models::payments::PhoneDetails>,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<domain::Address> {
+    let key = key_store.key.get_inner().peek();
+    let address_details = address_details.unwrap_or_default();
+    let phone = phone.unwrap_or_default();
+
+    async {
+        Ok(domain::Address {
+            id: None,
+            address_id,
+            city: address_details.city,
+            country: address_details.country,
+            line1: address_details
+                .line1
+                .async_lift(|inner| types::encrypt_optional(inner, key))
+                .await?,
+            line2: address_details
+                .line2
+                .async_lift(|inner| types::encrypt_optional(inner, key))
+                .await?,
+            line3: address_details
+                .line3
+                .async_lift(|inner| types::encrypt_optional(inner, key))
+                .await?,
+            state: address_details
+                .state
+                .async_lift(|inner| types::encrypt_optional(inner, key))
+                .await?,
+            zip: address_details
+                .zip
+                .async_lift(|inner| types::encrypt_optional(inner, key))
+                .await?,
+            first_name: address_details
+                .first_name
+                .async_lift(|inner| types::encrypt_optional(inner, key))
+                .await?,
+            last_name: address_details
+                .last_name
+                .async_lift(|inner| types::encrypt_optional(inner, key))
+                .await?,
+            phone_number: phone
+                .number
+                .async_lift(|inner| types::encrypt_optional(inner, key))
+                .await?,
+            country_code: phone.country_code,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            customer_id: customer.customer_id.to_owned(),
+            merchant_id: customer.merchant_id.to_owned(),
+            is_saved: true,
+        })
+    }
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while encrypting address")
+}
+
+/// Sets or unsets the given saved address as the default billing and shipping address of the
+/// customer, as requested
+async fn update_customer_default_addresses(
+    db: &dyn StorageInterface,
+    customer: domain::Customer,
+    address_id: &str,
+    default_billing: Option<bool>,
+    default_shipping: Option<bool>,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<domain::Customer> {
+    let get_default_update =
+        |is_default: Option<bool>, current_default: &Option<String>| match is_default {
+            Some(true) => Some(Some(address_id.to_owned())),
+            Some(false) if current_default.as_deref() == Some(address_id) => Some(None),
+            _ => None,
+        };
+    let default_billing_address_id =
+        get_default_update(default_billing, &customer.default_billing_address_id);
+    let default_shipping_address_id =
+        get_default_update(default_shipping, &customer.default_shipping_address_id);
+
+    if default_billing_address_id.is_none() && default_shipping_address_id.is_none() {
+        return Ok(customer);
+    }
+
+    db.update_customer_by_customer_id_merchant_id(
+        customer.customer_id,
+        customer.merchant_id,
+        domain::CustomerUpdate::DefaultAddresses {
+            default_billing_address_id,
+            default_shipping_address_id,
+        },
+        key_store,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the default addresses of the customer")
+}
+
+fn get_customer_address_response(
+    address: domain::Address,
+    customer: &domain::Customer,
+) -> customers::CustomerAddressResponse {
+    let api_address = api::Address::from(&address);
+    customers::CustomerAddressResponse {
+        default_billing: customer.default_billing_address_id.as_ref() == Some(&address.address_id),
+        default_shipping: customer.default_shipping_address_id.as_ref()
+            == Some(&address.address_id),
+        address_id: address.address_id,
+        customer_id: address.customer_id,
+        address: api_address.address,
+        phone: api_address.phone,
+        created_at: address.created_at,
+        modified_at: address.modified_at,
+    }
+}
+
+/// Exports the data held for the customer. The payments are exported a page at a time, most
+/// recent first, and are read from the database along with their attempts, refunds and disputes.
+#[instrument(skip(state))]
+pub async fn export_customer_data(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customers::CustomerId,
+    constraints: customers::CustomerDataExportConstraints,
+) -> RouterResponse<customers::CustomerDataExport> {
+    utils::when(constraints.limit > 100 || constraints.limit < 1, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "limit should be in between 1 and 100".to_string(),
+        })
+    })?;
+    utils::when(constraints.offset < 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "offset should not be negative".to_string(),
+        })
+    })?;
+
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(&req.customer_id, merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let addresses = db
+        .find_addresses_by_merchant_id_customer_id(merchant_id, &req.customer_id, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while finding the addresses of the customer")?
+        .iter()
+        .map(api_models::payments::Address::from)
+        .collect();
+
+    let mut payment_methods = Vec::new();
+    for pm in find_customer_payment_methods(db, merchant_id, &req.customer_id).await? {
+        payment_methods.push(
+            get_masked_payment_method_response(state, pm, merchant_account.locker_id.clone())
+                .await?,
+        );
+    }
+
+    // One more payment than requested is fetched, to find whether there are more payments
+    let limit = usize::try_from(constraints.limit).unwrap_or_default();
+    let mut payment_intents = db
+        .find_payment_intents_by_merchant_id_customer_id(
+            merchant_id,
+            &req.customer_id,
+            Some(constraints.limit + 1),
+            Some(constraints.offset),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while finding the payments of the customer")?;
+    let has_more_payments = payment_intents.len() > limit;
+    payment_intents.truncate(limit);
+
+    let payment_ids = payment_intents
+        .iter()
+        .map(|payment_intent| payment_intent.payment_id.clone())
+        .collect::<Vec<_>>();
+    let mut payment_attempts = db
+        .find_payment_attempts_by_merchant_id_attempt_ids(
+            merchant_id,
+            payment_intents
+                .iter()
+                .map(|payment_intent| payment_intent.active_attempt_id.clone())
+                .collect(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while finding the attempts of the payments")?
+        .into_iter()
+        .map(|payment_attempt| (payment_attempt.attempt_id.clone(), payment_attempt))
+        .collect::<HashMap<_, _>>();
+    let payments = payment_intents
+        .into_iter()
+        .map(|payment_intent| {
+            let payment_attempt = payment_attempts
+                .remove(&payment_intent.active_attempt_id)
+                .ok_or(errors::ApiErrorResponse::PaymentNotFound)
+                .into_report()
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Active attempt of the payment {} not found",
+                        payment_intent.payment_id
+                    )
+                })?;
+            Ok(api::PaymentsResponse::foreign_from((
+                payment_intent,
+                payment_attempt,
+            )))
+        })
+        .collect::<errors::RouterResult<Vec<_>>>()?;
+
+    let refunds = db
+        .find_refunds_by_merchant_id_payment_ids(merchant_id, payment_ids.clone())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while finding the refunds of the payments")?
+        .into_iter()
+        .map(api_models::refunds::RefundResponse::foreign_from)
+        .collect();
+    let disputes = db
+        .find_disputes_by_merchant_id_payment_ids(merchant_id, payment_ids)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while finding the disputes of the payments")?
+        .into_iter()
+        .map(api_models::disputes::DisputeResponse::foreign_from)
+        .collect();
+
+    let mut mandates = Vec::new();
+    for mandate in db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, &req.customer_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?
+    {
+        mandates.push(
+            mandates::MandateResponse::from_db_mandate(state, mandate, &merchant_account).await?,
+        );
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        customers::CustomerDataExport {
+            customer: customers::CustomerResponse::from(customer).into_inner(),
+            addresses,
+            payment_methods,
+            payments,
+            refunds,
+            mandates,
+            disputes,
+            has_more_payments,
+        },
+    ))
+}
+
+/// Returns the saved payment method with only the masked details of the card, if it is a card
+async fn get_masked_payment_method_response(
+    state: &AppState,
+    pm: storage::PaymentMethod,
+    locker_id: Option<String>,
+) -> errors::RouterResult<api::PaymentMethodResponse> {
+    let mut payment_method = cards::get_payment_method_response(state, pm, locker_id).await?;
+    if let Some(card) = payment_method.card.as_mut() {
+        card.card_number = None;
+        card.card_token = None;
+        card.card_fingerprint = None;
+    }
+    Ok(payment_method)
+}
+
+#[instrument(skip(db))]
+pub async fn request_customer_erasure(
+    db: &dyn StorageInterface,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customers::CustomerId,
+) -> RouterResponse<customers::CustomerErasureResponse> {
+    db.find_customer_by_customer_id_merchant_id(
+        &req.customer_id,
+        &merchant_account.merchant_id,
+        &key_store,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let customer_erasure = db
+        .insert_customer_erasure(storage::CustomerErasureNew {
+            erasure_id: generate_id(consts::ID_LENGTH, "erasure"),
+            merchant_id: merchant_account.merchant_id,
+            customer_id: req.customer_id,
+            status: enums::CustomerErasureStatus::Pending,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting the customer erasure")?;
+
+    add_customer_erasure_task(db, &customer_erasure)
+        .await
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while scheduling the customer erasure")?;
+
+    Ok(services::ApplicationResponse::Json(
+        customers::CustomerErasureResponse::foreign_from(customer_erasure),
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn retrieve_customer_erasure(
+    db: &dyn StorageInterface,
+    merchant_account: domain::MerchantAccount,
+    customer_id: String,
+    erasure_id: String,
+) -> RouterResponse<customers::CustomerErasureResponse> {
+    let customer_erasure = db
+        .find_customer_erasure_by_merchant_id_erasure_id(&merchant_account.merchant_id, &erasure_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerErasureNotFound {
+            erasure_id: erasure_id.clone(),
+        })?;
+
+    utils::when(customer_erasure.customer_id != customer_id, || {
+        Err(errors::ApiErrorResponse::CustomerErasureNotFound { erasure_id })
+    })?;
+
+    Ok(services::ApplicationResponse::Json(
+        customers::CustomerErasureResponse::foreign_from(customer_erasure),
+    ))
+}
+
+/// Schedules the erasure of the personal data of the customer, to be run by the scheduler
+async fn add_customer_erasure_task(
+    db: &dyn StorageInterface,
+    customer_erasure: &storage::CustomerErasure,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data = storage::CustomerErasureTrackingData {
+        erasure_id: customer_erasure.erasure_id.clone(),
+        merchant_id: customer_erasure.merchant_id.clone(),
+        customer_id: customer_erasure.customer_id.clone(),
+    };
+    let runner = "CUSTOMER_ERASURE_WORKFLOW";
+    let task = "CUSTOMER_ERASURE";
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &customer_erasure.erasure_id,
+        &customer_erasure.merchant_id,
+    );
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            task,
+            runner,
+            tracking_data,
+            common_utils::date_time::now(),
+        )?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// The number of records erased for each kind of data held for a customer
+#[derive(Debug, Default, serde::Serialize)]
+pub struct CustomerErasedRecords {
+    pub customers: usize,
+    pub addresses: usize,
+    pub payment_methods: usize,
+    pub mandates: usize,
+    pub payments: usize,
+    pub payment_attempts: usize,
+    pub refunds: usize,
+    pub disputes: usize,
+}
+
+/// Erases the personal data of the customer from the customer, address, payment method, mandate,
+/// payment, refund and dispute records. Active mandates of the customer are revoked, both at the
+/// connector and locally, and saved payment methods are deleted, while the remaining records are
+/// redacted in place, so that the financial history of the merchant is preserved. The evidence of
+/// open disputes is kept until the disputes are closed.
+#[instrument(skip_all)]
+pub async fn erase_customer_data(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &str,
+) -> errors::RouterResult<CustomerErasedRecords> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let storage_scheme = merchant_account.storage_scheme;
+    let mut erased_records = CustomerErasedRecords::default();
+
+    let customer_mandates = db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, customer_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+    for mandate in customer_mandates {
+        if mandate.mandate_status == enums::MandateStatus::Active {
+            mandate::revoke_mandate_at_connector(state, merchant_account, key_store, &mandate)
+                .await?;
+            db.update_mandate_by_merchant_id_mandate_id(
+                merchant_id,
+                &mandate.mandate_id,
+                storage::MandateUpdate::StatusUpdate {
+                    mandate_status: enums::MandateStatus::Revoked,
+                },
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+        }
+        db.update_mandate_by_merchant_id_mandate_id(
+            merchant_id,
+            &mandate.mandate_id,
+            storage::MandateUpdate::RedactionUpdate,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+        erased_records.mandates += 1;
+    }
+
+    erased_records.payment_methods =
+        delete_customer_payment_methods(state, merchant_id, customer_id).await?;
+
+    for payment_intent in find_customer_payment_intents(db, merchant_account, customer_id).await? {
+        let payment_id = payment_intent.payment_id.clone();
+
+        for payment_attempt in db
+            .find_payment_attempts_by_payment_id_merchant_id(&payment_id, merchant_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while finding the attempts of the payment")?
+        {
+            // The attempt is fetched again with the storage scheme of the merchant, as the copy
+            // in the database may lag behind the one in the KV store
+            let payment_attempt = db
+                .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                    &payment_id,
+                    merchant_id,
+                    &payment_attempt.attempt_id,
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+            db.update_payment_attempt_with_attempt_id(
+                payment_attempt,
+                storage::PaymentAttemptUpdate::RedactionUpdate,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+            erased_records.payment_attempts += 1;
+        }
+
+        db.update_payment_intent(
+            payment_intent,
+            storage::PaymentIntentUpdate::RedactionUpdate,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        erased_records.payments += 1;
+
+        for refund in find_payment_refunds(db, &payment_id, merchant_id, storage_scheme).await? {
+            db.update_refund(
+                refund,
+                storage::RefundUpdate::RedactionUpdate,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+            erased_records.refunds += 1;
+        }
+
+        for dispute in db
+            .find_disputes_by_merchant_id_payment_id(merchant_id, &payment_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while finding the disputes of the payment")?
+        {
+            if redact_dispute_evidence(state, merchant_account, dispute).await? {
+                erased_records.disputes += 1;
+            }
+        }
+    }
+
+    erased_records.addresses =
+        redact_customer_details(db, merchant_id, customer_id, key_store).await?;
+    erased_records.customers = 1;
+
+    metrics::CUSTOMER_REDACTED.add(&metrics::CONTEXT, 1, &[]);
+    Ok(erased_records)
+}
+
+/// Removes the evidence files which hold personal data of the customer from the dispute, and
+/// returns whether the dispute was redacted. The evidence of disputes which are still open is left
+/// untouched, so that the merchant can still defend them.
+async fn redact_dispute_evidence(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    dispute: storage::Dispute,
+) -> errors::RouterResult<bool> {
+    if matches!(
+        dispute.dispute_status,
+        enums::DisputeStatus::DisputeOpened | enums::DisputeStatus::DisputeChallenged
+    ) {
+        return Ok(false);
+    }
+
+    let mut evidence: api::DisputeEvidence = dispute
+        .evidence
+        .clone()
+        .parse_value("DisputeEvidence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing dispute evidence record")?;
+    let customer_file_ids = evidence.take_customer_file_ids();
+    if customer_file_ids.is_empty() {
+        return Ok(false);
+    }
+
+    for file_id in customer_file_ids {
+        files::files_delete_core(state, merchant_account.clone(), api::FileId { file_id })
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to delete a dispute evidence file"))
+            .ok();
+    }
+
+    let dispute_id = dispute.dispute_id.clone();
+    state
+        .store
+        .update_dispute(
+            dispute,
+            storage::DisputeUpdate::EvidenceUpdate {
+                evidence: utils::Encode::<api::DisputeEvidence>::encode_to_value(&evidence)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Error while encoding dispute evidence")?
+                    .into(),
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound { dispute_id })?;
+    Ok(true)
+}
+
+/// Finds the payments of the customer. The payments are listed from the database and fetched
+/// again with the storage scheme of the merchant, as the copy in the database may lag behind the
+/// one in the KV store.
+async fn find_customer_payment_intents(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    customer_id: &str,
+) -> errors::RouterResult<Vec<storage::PaymentIntent>> {
+    let payment_intents = db
+        .find_payment_intents_by_merchant_id_customer_id(
+            &merchant_account.merchant_id,
+            customer_id,
+            None,
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while finding the payments of the customer")?;
+
+    let mut output = Vec::with_capacity(payment_intents.len());
+    for payment_intent in payment_intents {
+        output.push(
+            db.find_payment_intent_by_payment_id_merchant_id(
+                &payment_intent.payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?,
+        );
+    }
+    Ok(output)
+}
+
+/// Finds the refunds of the payment, if any
+async fn find_payment_refunds(
+    db: &dyn StorageInterface,
+    payment_id: &str,
+    merchant_id: &str,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> errors::RouterResult<Vec<storage::Refund>> {
+    match db
+        .find_refund_by_payment_id_merchant_id(payment_id, merchant_id, storage_scheme)
+        .await
+    {
+        Ok(refunds) => Ok(refunds),
+        Err(error) => {
+            if error.current_context().is_db_not_found() {
+                Ok(Vec::new())
+            } else {
+                Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("failed find_refund_by_payment_id_merchant_id")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::Secret;
+    use storage_models::dispute::DisputeNew;
+
+    use super::*;
+    use crate::{
+        core::payments::helpers::{get_saved_address_for_payment_request, PaymentAddressType},
+        db::StorageImpl,
+        routes,
+        utils::test_utils,
+    };
+
+    fn dispute_new(
+        dispute_id: &str,
+        dispute_status: enums::DisputeStatus,
+        evidence: &api::DisputeEvidence,
+    ) -> DisputeNew {
+        DisputeNew {
+            dispute_id: dispute_id.into(),
+            amount: "1000".into(),
+            currency: "USD".into(),
+            dispute_stage: enums::DisputeStage::Dispute,
+            dispute_status,
+            payment_id: "payment_1".into(),
+            attempt_id: "payment_1_1".into(),
+            merchant_id: "merchant_1".into(),
+            connector_status: "connector_status".into(),
+            connector_dispute_id: dispute_id.into(),
+            connector_reason: None,
+            connector_reason_code: None,
+            challenge_required_by: None,
+            connector_created_at: None,
+            connector_updated_at: None,
+            connector: "stripe".into(),
+            evidence: Some(Secret::new(serde_json::to_value(evidence).unwrap())),
+        }
+    }
+
+    async fn find_dispute_evidence(state: &AppState, dispute_id: &str) -> api::DisputeEvidence {
+        state
+            .store
+            .find_dispute_by_merchant_id_dispute_id("merchant_1", dispute_id)
+            .await
+            .unwrap()
+            .evidence
+            .parse_value("DisputeEvidence")
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_redact_dispute_evidence() {
+        let (tx, _rx) = tokio::sync::oneshot::channel();
+        let state = routes::AppState::with_storage(Default::default(), StorageImpl::Mock, tx).await;
+        let merchant_account = test_utils::merchant_account();
+        let evidence = api::DisputeEvidence {
+            customer_signature: Some("file_signature".into()),
+            refund_policy: Some("file_refund_policy".into()),
+            ..Default::default()
+        };
+
+        let closed_dispute = state
+            .store
+            .insert_dispute(dispute_new(
+                "dispute_1",
+                enums::DisputeStatus::DisputeLost,
+                &evidence,
+            ))
+            .await
+            .unwrap();
+        assert!(
+            redact_dispute_evidence(&state, &merchant_account, closed_dispute)
+                .await
+                .unwrap()
+        );
+        let redacted_evidence = find_dispute_evidence(&state, "dispute_1").await;
+        assert_eq!(redacted_evidence.customer_signature, None);
+        assert_eq!(
+            redacted_evidence.refund_policy.as_deref(),
+            Some("file_refund_policy")
+        );
+
+        let open_dispute = state
+            .store
+            .insert_dispute(dispute_new(
+                "dispute_2",
+                enums::DisputeStatus::DisputeOpened,
+                &evidence,
+            ))
+            .await
+            .unwrap();
+        assert!(
+            !redact_dispute_evidence(&state, &merchant_account, open_dispute)
+                .await
+                .unwrap()
+        );
+        let kept_evidence = find_dispute_evidence(&state, "dispute_2").await;
+        assert_eq!(
+            kept_evidence.customer_signature.as_deref(),
+            Some("file_signature")
+        );
+    }
+
+    #[test]
+    fn test_take_customer_file_ids() {
+        let mut evidence = api::DisputeEvidence {
+            customer_communication: Some("file_communication".into()),
+            receipt: Some("file_receipt".into()),
+            cancellation_policy: Some("file_cancellation_policy".into()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            evidence.take_customer_file_ids(),
+            vec!["file_communication", "file_receipt"]
+        );
+        assert_eq!(evidence.customer_communication, None);
+        assert_eq!(evidence.receipt, None);
+        assert_eq!(
+            evidence.cancellation_policy.as_deref(),
+            Some("file_cancellation_policy")
+        );
+        assert!(evidence.take_customer_file_ids().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_default_address_is_used_for_payments_without_address() {
+        let (tx, _rx) = tokio::sync::oneshot::channel();
+        let state = routes::AppState::with_storage(Default::default(), StorageImpl::Mock, tx).await;
+        let db = &*state.store;
+        let key_store = test_utils::merchant_key_store(test_utils::MERCHANT_KEY);
+        let now = common_utils::date_time::now();
+        db.insert_customer(
+            domain::Customer {
+                id: Some(1),
+                customer_id: "cus_1".into(),
+                merchant_id: "merchant_1".into(),
+                name: None,
+                email: None,
+                phone: None,
+                phone_country_code: None,
+                description: None,
+                created_at: now,
+                metadata: None,
+                modified_at: now,
+                connector_customer: None,
+                default_payment_method_id: None,
+                email_blind_index: None,
+                phone_blind_index: None,
+                default_billing_address_id: None,
+                default_shipping_address_id: None,
+            },
+            &key_store,
+        )
+        .await
+        .unwrap();
+        create_customer_address(
+            db,
+            test_utils::merchant_account(),
+            key_store.clone(),
+            "cus_1".into(),
+            customers::CustomerAddressRequest {
+                address: Some(api_models::payments::AddressDetails {
+                    city: Some("Berlin".into()),
+                    ..Default::default()
+                }),
+                default_billing: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        let customer_id = "cus_1".to_string();
+
+        let billing_address = get_saved_address_for_payment_request(
+            db,
+            None,
+            PaymentAddressType::Billing,
+            true,
+            "merchant_1",
+            Some(&customer_id),
+            &key_store,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            billing_address
+                .and_then(|address| address.address)
+                .and_then(|address| address.city),
+            Some("Berlin".into())
+        );
+
+        // Addresses without a default, or which are not to be defaulted, are not filled in
+        let shipping_address = get_saved_address_for_payment_request(
+            db,
+            None,
+            PaymentAddressType::Shipping,
+            true,
+            "merchant_1",
+            Some(&customer_id),
+            &key_store,
+        )
+        .await
+        .unwrap();
+        assert!(shipping_address.is_none());
+        let billing_address = get_saved_address_for_payment_request(
+            db,
+            None,
+            PaymentAddressType::Billing,
+            false,
+            "merchant_1",
+            Some(&customer_id),
+            &key_store,
+        )
+        .await
+        .unwrap();
+        assert!(billing_address.is_none());
+    }
+}
@@ -1,28 +1,42 @@
+use std::collections::HashMap;
+
 use api_models::{disputes as dispute_models, files as files_api_models};
-use common_utils::ext_traits::ValueExt;
+use common_utils::{
+    date_time,
+    ext_traits::{StringExt, ValueExt},
+};
 use error_stack::{IntoReport, ResultExt};
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 pub mod transformers;
 
 use super::{
-    errors::{self, ConnectorErrorExt, RouterResponse, StorageErrorExt},
+    errors::{
+        self, ConnectorErrorExt, CustomResult, RouterResponse, RouterResult, StorageErrorExt,
+    },
     metrics,
 };
 use crate::{
     core::{files, payments, utils as core_utils},
+    db::StorageInterface,
     routes::AppState,
+    scheduler::utils as pt_utils,
     services,
     types::{
         api::{self, disputes},
         domain,
-        storage::enums as storage_enums,
+        storage::{self, enums as storage_enums, ProcessTrackerExt},
         transformers::{ForeignFrom, ForeignInto},
         AcceptDisputeRequestData, AcceptDisputeResponse, DefendDisputeRequestData,
-        DefendDisputeResponse, SubmitEvidenceRequestData, SubmitEvidenceResponse,
+        DefendDisputeResponse, FetchDisputeRequestData, FetchDisputeResponse,
+        SubmitEvidenceRequestData, SubmitEvidenceResponse,
     },
     utils,
 };
 
+const DISPUTE_LIFECYCLE_TAG: &str = "DISPUTE";
+const DISPUTE_LIFECYCLE_NAME: &str = "DISPUTE_LIFECYCLE";
+const DISPUTE_LIFECYCLE_RUNNER: &str = "DISPUTE_LIFECYCLE_WORKFLOW";
+
 #[instrument(skip(state))]
 pub async fn retrieve_dispute(
     state: &AppState,
@@ -423,3 +437,418 @@ pub async fn retrieve_dispute_evidence(
         transformers::get_dispute_evidence_vec(state, merchant_account, dispute_evidence).await?;
     Ok(services::ApplicationResponse::Json(dispute_evidence_vec))
 }
+
+/// Fetches the latest status of the dispute from the connector and records it. Returns `None`
+/// when the connector does not support fetching disputes.
+#[instrument(skip_all)]
+pub async fn sync_dispute(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: storage::Dispute,
+) -> RouterResult<Option<storage::Dispute>> {
+    let db = &state.store;
+    let dispute_id = dispute.dispute_id.clone();
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &dispute.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &dispute.attempt_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &dispute.connector,
+        api::GetToken::Connector,
+    )?;
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Fetch,
+        FetchDisputeRequestData,
+        FetchDisputeResponse,
+    > = connector_data.connector.get_connector_integration();
+    let router_data = core_utils::construct_fetch_dispute_router_data(
+        state,
+        &payment_intent,
+        &payment_attempt,
+        merchant_account,
+        key_store,
+        &dispute,
+    )
+    .await?;
+    let Some(connector_request) = connector_integration
+        .build_request(&router_data, &state.conf.connectors)
+        .to_dispute_failed_response()
+        .attach_printable("Failed while building fetch dispute connector request")?
+    else {
+        logger::info!(
+            "Connector {} does not support fetching dispute {dispute_id}",
+            dispute.connector
+        );
+        return Ok(None);
+    };
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        Some(connector_request),
+    )
+    .await
+    .to_dispute_failed_response()
+    .attach_printable("Failed while calling fetch dispute connector api")?;
+    let fetch_dispute_response =
+        response
+            .response
+            .map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
+                code: err.code,
+                message: err.message,
+                connector: dispute.connector.clone(),
+                status_code: err.status_code,
+                reason: err.reason,
+            })?;
+    let update_dispute = storage_models::dispute::DisputeUpdate::StatusUpdate {
+        dispute_status: fetch_dispute_response.dispute_status.foreign_into(),
+        connector_status: fetch_dispute_response.connector_status,
+    };
+    let updated_dispute = db
+        .update_dispute(dispute, update_dispute)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to update dispute with dispute_id: {dispute_id}")
+        })?;
+    Ok(Some(updated_dispute))
+}
+
+/// Rules according to which disputes opened against the payments of a merchant are accepted
+/// automatically, stored in the configs under the key returned by
+/// [`get_dispute_auto_accept_rules_key`].
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct DisputeAutoAcceptRules {
+    /// Maximum amount, in the lowest denomination of the currency, of the disputes to be accepted,
+    /// keyed by currency. Disputes in other currencies are not accepted automatically.
+    pub max_amounts: HashMap<String, i64>,
+    /// Connectors whose disputes are to be accepted. Disputes of all connectors are accepted if
+    /// this is not specified.
+    pub connectors: Option<Vec<String>>,
+}
+
+impl DisputeAutoAcceptRules {
+    pub fn should_accept(&self, dispute: &storage::Dispute) -> bool {
+        let Some(max_amount) = self.max_amounts.get(&dispute.currency) else {
+            return false;
+        };
+        let Ok(amount) = dispute.amount.parse::<i64>() else {
+            return false;
+        };
+
+        dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+            && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened
+            && amount <= *max_amount
+            && self
+                .connectors
+                .as_ref()
+                .map_or(true, |connectors| connectors.contains(&dispute.connector))
+    }
+}
+
+pub fn get_dispute_auto_accept_rules_key(merchant_id: &str) -> String {
+    format!("dispute_auto_accept_rules_{merchant_id}")
+}
+
+/// Obtains the dispute auto accept rules configured for the merchant, if any.
+pub async fn find_dispute_auto_accept_rules(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> CustomResult<Option<DisputeAutoAcceptRules>, errors::StorageError> {
+    let config = match db
+        .find_config_by_key(&get_dispute_auto_accept_rules_key(merchant_id))
+        .await
+    {
+        Ok(config) => config,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => return Err(error),
+    };
+
+    let rules: DisputeAutoAcceptRules = config
+        .config
+        .parse_struct("DisputeAutoAcceptRules")
+        .change_context(errors::StorageError::DeserializationFailed)
+        .attach_printable("Failed to parse dispute auto accept rules")?;
+    Ok(Some(rules))
+}
+
+pub fn get_dispute_lifecycle_process_tracker_id(dispute_id: &str, merchant_id: &str) -> String {
+    pt_utils::get_process_tracker_id(
+        DISPUTE_LIFECYCLE_RUNNER,
+        DISPUTE_LIFECYCLE_NAME,
+        dispute_id,
+        merchant_id,
+    )
+}
+
+/// Obtains the next step of the lifecycle of a dispute to be executed, starting from the step
+/// `from_index`, along with the time at which it should be executed. The steps are the evidence
+/// reminders, whose time has not passed yet, followed by the expiry of the dispute, which is
+/// identified by the index `evidence_reminder_hours.len()`.
+pub fn get_next_dispute_lifecycle_step(
+    challenge_required_by: time::PrimitiveDateTime,
+    evidence_reminder_hours: &[u16],
+    expiry_grace_period: i64,
+    from_index: usize,
+) -> (usize, time::PrimitiveDateTime) {
+    let now = date_time::now();
+    evidence_reminder_hours
+        .iter()
+        .enumerate()
+        .skip(from_index)
+        .map(|(index, hours)| {
+            (
+                index,
+                challenge_required_by.saturating_sub(time::Duration::hours((*hours).into())),
+            )
+        })
+        .find(|(_, reminder_time)| *reminder_time > now)
+        .unwrap_or_else(|| {
+            (
+                evidence_reminder_hours.len(),
+                std::cmp::max(
+                    challenge_required_by
+                        .saturating_add(time::Duration::seconds(expiry_grace_period)),
+                    now,
+                ),
+            )
+        })
+}
+
+/// Schedules the automation of the lifecycle of a newly opened dispute: evaluating the auto accept
+/// rules of the merchant right away, reminding the merchant about the evidence deadline and
+/// expiring the dispute once the deadline has passed.
+#[instrument(skip_all)]
+pub async fn add_dispute_lifecycle_task(
+    db: &dyn StorageInterface,
+    dispute: &storage::Dispute,
+    evidence_reminder_hours: &[u16],
+) -> Result<(), errors::ProcessTrackerError> {
+    if dispute.dispute_status != storage_enums::DisputeStatus::DisputeOpened
+        || dispute.challenge_required_by.is_none()
+    {
+        logger::debug!("Dispute does not have an evidence deadline, skipping lifecycle task");
+        return Ok(());
+    }
+
+    let mut evidence_reminder_hours = evidence_reminder_hours.to_vec();
+    evidence_reminder_hours.sort_unstable_by(|a, b| b.cmp(a));
+    evidence_reminder_hours.dedup();
+    let tracking_data = storage::DisputeLifecycleTrackingData {
+        dispute_id: dispute.dispute_id.clone(),
+        merchant_id: dispute.merchant_id.clone(),
+        evidence_reminder_hours,
+        step_index: None,
+    };
+
+    let mut process_tracker_entry = storage::ProcessTracker::make_process_tracker_new(
+        get_dispute_lifecycle_process_tracker_id(&dispute.dispute_id, &dispute.merchant_id),
+        DISPUTE_LIFECYCLE_NAME,
+        DISPUTE_LIFECYCLE_RUNNER,
+        tracking_data,
+        date_time::now(),
+    )?;
+    process_tracker_entry.tag = vec![DISPUTE_LIFECYCLE_TAG.to_string()];
+
+    db.insert_process(process_tracker_entry).await?;
+    metrics::DISPUTE_LIFECYCLE_TASKS_CREATED.add(&metrics::CONTEXT, 1, &[]);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_dispute(
+        dispute_id: &str,
+        dispute_status: storage_enums::DisputeStatus,
+        challenge_required_by: Option<time::PrimitiveDateTime>,
+    ) -> storage::Dispute {
+        let now = date_time::now();
+        storage::Dispute {
+            id: 1,
+            dispute_id: dispute_id.to_string(),
+            amount: "500".to_string(),
+            currency: "USD".to_string(),
+            dispute_stage: storage_enums::DisputeStage::Dispute,
+            dispute_status,
+            payment_id: "payment_id".to_string(),
+            attempt_id: "attempt_id".to_string(),
+            merchant_id: "merchant_id".to_string(),
+            connector_status: "needs_response".to_string(),
+            connector_dispute_id: "connector_dispute_id".to_string(),
+            connector_reason: None,
+            connector_reason_code: None,
+            challenge_required_by,
+            connector_created_at: None,
+            connector_updated_at: None,
+            created_at: now,
+            modified_at: now,
+            connector: "stripe".to_string(),
+            evidence: masking::Secret::new(serde_json::Value::Null),
+        }
+    }
+
+    #[test]
+    fn test_dispute_auto_accept_rules() {
+        let rules = DisputeAutoAcceptRules {
+            max_amounts: HashMap::from([("USD".to_string(), 1000)]),
+            connectors: Some(vec!["stripe".to_string()]),
+        };
+        let dispute = get_dispute(
+            "dispute_1",
+            storage_enums::DisputeStatus::DisputeOpened,
+            None,
+        );
+        assert!(rules.should_accept(&dispute));
+
+        assert!(!rules.should_accept(&storage::Dispute {
+            amount: "1500".to_string(),
+            ..dispute.clone()
+        }));
+        assert!(!rules.should_accept(&storage::Dispute {
+            currency: "EUR".to_string(),
+            ..dispute.clone()
+        }));
+        assert!(!rules.should_accept(&storage::Dispute {
+            connector: "adyen".to_string(),
+            ..dispute.clone()
+        }));
+        assert!(!rules.should_accept(&storage::Dispute {
+            dispute_status: storage_enums::DisputeStatus::DisputeChallenged,
+            ..dispute
+        }));
+    }
+
+    #[test]
+    fn test_lifecycle_step_is_tracked_in_tracking_data() {
+        // Tasks scheduled before the step was tracked start with the auto accept rules
+        let tracking_data: storage::DisputeLifecycleTrackingData =
+            serde_json::from_value(serde_json::json!({
+                "dispute_id": "dispute_1",
+                "merchant_id": "merchant_id",
+                "evidence_reminder_hours": [24, 1]
+            }))
+            .unwrap();
+        assert_eq!(tracking_data.step_index, None);
+
+        let tracking_data = storage::DisputeLifecycleTrackingData {
+            step_index: Some(1),
+            ..tracking_data
+        };
+        let value = serde_json::to_value(&tracking_data).unwrap();
+        assert_eq!(
+            serde_json::from_value::<storage::DisputeLifecycleTrackingData>(value).unwrap(),
+            tracking_data
+        );
+    }
+
+    #[tokio::test]
+    async fn test_add_dispute_lifecycle_task() {
+        use crate::db::{process_tracker::ProcessTrackerInterface, MockDb};
+
+        let mockdb = MockDb::new(&Default::default()).await.unwrap();
+        let challenge_required_by = date_time::now() + time::Duration::days(7);
+
+        let dispute = get_dispute(
+            "dispute_1",
+            storage_enums::DisputeStatus::DisputeOpened,
+            Some(challenge_required_by),
+        );
+        add_dispute_lifecycle_task(&mockdb, &dispute, &[1, 72, 24, 72])
+            .await
+            .unwrap();
+        let task = mockdb
+            .find_process_by_id(&get_dispute_lifecycle_process_tracker_id(
+                &dispute.dispute_id,
+                &dispute.merchant_id,
+            ))
+            .await
+            .unwrap()
+            .unwrap();
+        let tracking_data: storage::DisputeLifecycleTrackingData =
+            serde_json::from_value(task.tracking_data).unwrap();
+        assert_eq!(tracking_data.evidence_reminder_hours, vec![72, 24, 1]);
+        assert_eq!(tracking_data.step_index, None);
+        assert_eq!(task.retry_count, 0);
+
+        // Disputes which are not open or have no evidence deadline have no lifecycle to automate
+        for dispute in [
+            get_dispute(
+                "dispute_2",
+                storage_enums::DisputeStatus::DisputeWon,
+                Some(challenge_required_by),
+            ),
+            get_dispute(
+                "dispute_3",
+                storage_enums::DisputeStatus::DisputeOpened,
+                None,
+            ),
+        ] {
+            add_dispute_lifecycle_task(&mockdb, &dispute, &[24])
+                .await
+                .unwrap();
+            assert!(mockdb
+                .find_process_by_id(&get_dispute_lifecycle_process_tracker_id(
+                    &dispute.dispute_id,
+                    &dispute.merchant_id,
+                ))
+                .await
+                .unwrap()
+                .is_none());
+        }
+    }
+
+    #[test]
+    fn test_get_next_dispute_lifecycle_step() {
+        let now = date_time::now();
+        let challenge_required_by = now + time::Duration::hours(48);
+        let evidence_reminder_hours = [72, 24, 1];
+
+        // The 72 hour reminder has already passed
+        let (index, schedule_time) =
+            get_next_dispute_lifecycle_step(challenge_required_by, &evidence_reminder_hours, 60, 0);
+        assert_eq!(index, 1);
+        assert_eq!(
+            schedule_time,
+            challenge_required_by - time::Duration::hours(24)
+        );
+
+        // Expiry follows the last reminder
+        let (index, schedule_time) =
+            get_next_dispute_lifecycle_step(challenge_required_by, &evidence_reminder_hours, 60, 3);
+        assert_eq!(index, 3);
+        assert_eq!(
+            schedule_time,
+            challenge_required_by + time::Duration::seconds(60)
+        );
+
+        // Expiry is scheduled right away if the deadline has already passed
+        let (index, schedule_time) = get_next_dispute_lifecycle_step(
+            now - time::Duration::hours(1),
+            &evidence_reminder_hours,
+            60,
+            0,
+        );
+        assert_eq!(index, 3);
+        assert!(schedule_time >= now);
+    }
+}
@@ -329,6 +329,48 @@ pub enum VaultError {
     UpdateInPaymentMethodDataTableFailed,
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum NetworkTokenizationError {
+    #[error("Failed to provision network token with the token service provider")]
+    ProvisionTokenFailed,
+    #[error("Failed to fetch cryptogram from the token service provider")]
+    FetchCryptogramFailed,
+    #[error("Failed to update network token with the token service provider")]
+    UpdateTokenFailed,
+    #[error("Failed to delete network token with the token service provider")]
+    DeleteTokenFailed,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AccountUpdaterError {
+    #[error("Failed to fetch card update from the account updater")]
+    FetchCardUpdateFailed,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BuiltinVaultError {
+    #[error("Failed to decrypt or verify the request to the built-in vault")]
+    RequestDecryptionFailed,
+    #[error("Failed to sign or encrypt the response of the built-in vault")]
+    ResponseEncryptionFailed,
+    #[error("Failed to encrypt data under the built-in vault keys")]
+    EncryptionFailed,
+    #[error("Failed to decrypt data under the built-in vault keys")]
+    DecryptionFailed,
+    #[error("Key encryption key {key_id} is not configured for the built-in vault")]
+    KeyNotFound { key_id: String },
+    #[error("The requested card was not found in the built-in vault")]
+    CardNotFound,
+    #[error("The requested token was not found in the built-in vault")]
+    TokenNotFound,
+    #[error("The requested token has expired")]
+    TokenExpired,
+    #[error("The built-in vault does not serve the requested endpoint: {0}")]
+    UnknownEndpoint(String),
+    #[error("Database operation of the built-in vault failed")]
+    DatabaseError,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum KmsError {
     #[error("Failed to base64 decode input data")]
@@ -430,6 +472,34 @@ error_to_process_tracker_error!(
     ProcessTrackerError::EValidationError(error_stack::Report<ValidationError>)
 );
 
+#[derive(Debug, thiserror::Error)]
+pub enum ReconciliationError {
+    #[error("Reports of {connector} are not supported for reconciliation")]
+    ReportNotSupported { connector: String },
+    #[error("Failed to parse the report: {message}")]
+    ReportParsingFailed { message: String },
+    #[error("Failed to read the report")]
+    ReportReadFailed,
+    #[error("Failed to fetch the records to be reconciled")]
+    RecordFetchingFailed,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum HealthCheckError {
+    #[error("Failed to run query on the master database")]
+    DatabaseMasterFailed,
+    #[error("Failed to run query on the replica database")]
+    DatabaseReplicaFailed,
+    #[error("Failed to perform round trip to redis")]
+    RedisFailed,
+    #[error("Failed to reach the locker")]
+    LockerFailed,
+    #[error("Failed to query the process tracker")]
+    ProcessTrackerFailed,
+    #[error("Timed out waiting for the health check to complete")]
+    TimedOut,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum WebhooksFlowError {
     #[error("Merchant webhook config not found")]
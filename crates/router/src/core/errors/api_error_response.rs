@@ -180,6 +180,12 @@ pub enum ApiErrorResponse {
     FileNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_04", message = "File not available")]
     FileNotAvailable,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_04", message = "Process tracker task does not exist in our records")]
+    ProcessTrackerTaskNotFound { id: String },
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_04", message = "Reconciliation run does not exist in our records")]
+    ReconciliationRunNotFound { run_id: String },
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_04", message = "Customer erasure does not exist in our records")]
+    CustomerErasureNotFound { erasure_id: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "HE_04", message = "Dispute status validation failed")]
     DisputeStatusValidationFailed { reason: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "HE_04", message = "Card with the provided iin does not exist")]
@@ -453,6 +459,15 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::FileNotAvailable => {
                 AER::NotFound(ApiError::new("HE", 2, "File not available", None))
             }
+            Self::ProcessTrackerTaskNotFound { .. } => {
+                AER::NotFound(ApiError::new("HE", 2, "Process tracker task does not exist in our records", None))
+            }
+            Self::ReconciliationRunNotFound { .. } => {
+                AER::NotFound(ApiError::new("HE", 2, "Reconciliation run does not exist in our records", None))
+            }
+            Self::CustomerErasureNotFound { .. } => {
+                AER::NotFound(ApiError::new("HE", 2, "Customer erasure does not exist in our records", None))
+            }
             Self::DisputeStatusValidationFailed { .. } => {
                 AER::BadRequest(ApiError::new("HE", 2, "Dispute status validation failed", None))
             }
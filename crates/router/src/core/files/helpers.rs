@@ -32,6 +32,7 @@ pub async fn get_file_purpose(field: &mut Field) -> Option<api::FilePurpose> {
     let purpose = read_string(field).await;
     match purpose.as_deref() {
         Some("dispute_evidence") => Some(api::FilePurpose::DisputeEvidence),
+        Some("settlement_report") => Some(api::FilePurpose::SettlementReport),
         _ => None,
     }
 }
@@ -111,6 +112,14 @@ pub async fn validate_file_upload(
                 },
             }
         }
+        api::FilePurpose::SettlementReport => {
+            if create_file_request.file_type != mime::TEXT_CSV {
+                Err(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: "file_type of a settlement report must be text/csv".to_owned(),
+                })?
+            }
+            Ok(())
+        }
     }
 }
 
@@ -382,5 +391,19 @@ pub async fn upload_and_get_provider_provider_file_id_connector_label(
                 ))
             }
         }
+        api::FilePurpose::SettlementReport => {
+            upload_file(
+                #[cfg(feature = "s3")]
+                state,
+                file_key.clone(),
+                create_file_request.file.clone(),
+            )
+            .await?;
+            Ok((
+                file_key,
+                api_models::enums::FileUploadProvider::Router,
+                None,
+            ))
+        }
     }
 }
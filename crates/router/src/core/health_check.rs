@@ -0,0 +1,227 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use api_models::health_check as api;
+use common_utils::date_time;
+use error_stack::{IntoReport, ResultExt};
+use futures::future::OptionFuture;
+use router_env::{instrument, logger, tracing};
+
+use super::errors::{self, CustomResult};
+use crate::{
+    configs::settings,
+    routes::AppState,
+    scheduler::utils as scheduler_utils,
+    services::{self, RedisConnInterface},
+    types::storage::enums,
+};
+
+/// Card ID used for looking up the mock locker, which is not expected to exist
+const MOCK_LOCKER_HEALTH_CHECK_CARD_ID: &str = "health_check";
+
+/// Maximum time any single dependency is given to respond before it is considered unhealthy
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Checks connectivity to each of the dependencies of the application, along with the round trip
+/// latency for each of them. The dependencies are checked concurrently, each bounded by a timeout.
+#[instrument(skip_all)]
+pub async fn deep_health_check(state: &AppState) -> api::RouterHealthCheckResponse {
+    let db = &*state.store;
+
+    let (database_master, database_replica, redis, locker, process_tracker) = futures::join!(
+        check_component(HEALTH_CHECK_TIMEOUT, || async {
+            db.health_check_master_db()
+                .await
+                .change_context(errors::HealthCheckError::DatabaseMasterFailed)
+        }),
+        check_component(HEALTH_CHECK_TIMEOUT, || async {
+            db.health_check_replica_db()
+                .await
+                .change_context(errors::HealthCheckError::DatabaseReplicaFailed)
+        }),
+        check_component(HEALTH_CHECK_TIMEOUT, || async {
+            db.health_check_redis()
+                .await
+                .change_context(errors::HealthCheckError::RedisFailed)
+        }),
+        check_component(HEALTH_CHECK_TIMEOUT, || health_check_locker(state)),
+        OptionFuture::from(state.conf.scheduler.as_ref().map(|scheduler| {
+            check_component(HEALTH_CHECK_TIMEOUT, || {
+                health_check_process_tracker(state, scheduler)
+            })
+        })),
+    );
+
+    let mut components = HashMap::from([
+        (api::HealthCheckComponent::DatabaseMaster, database_master),
+        (api::HealthCheckComponent::DatabaseReplica, database_replica),
+        (api::HealthCheckComponent::Redis, redis),
+        (api::HealthCheckComponent::Locker, locker),
+    ]);
+    if let Some(process_tracker) = process_tracker {
+        components.insert(api::HealthCheckComponent::ProcessTracker, process_tracker);
+    }
+
+    let status = if components
+        .values()
+        .all(|component| component.status == api::HealthStatus::Healthy)
+    {
+        api::HealthStatus::Healthy
+    } else {
+        api::HealthStatus::Unhealthy
+    };
+
+    api::RouterHealthCheckResponse { status, components }
+}
+
+async fn check_component<F, Fut>(timeout: Duration, check: F) -> api::ComponentHealth
+where
+    F: FnOnce() -> Fut,
+    Fut: futures::Future<Output = CustomResult<(), errors::HealthCheckError>>,
+{
+    let start = Instant::now();
+    let result = tokio::time::timeout(timeout, check())
+        .await
+        .unwrap_or_else(|_| Err(errors::HealthCheckError::TimedOut).into_report());
+    let latency_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+    match result {
+        Ok(()) => api::ComponentHealth {
+            status: api::HealthStatus::Healthy,
+            latency_ms,
+            error: None,
+        },
+        Err(error) => {
+            logger::error!(health_check_error=?error);
+            api::ComponentHealth {
+                status: api::HealthStatus::Unhealthy,
+                latency_ms,
+                error: Some(error.current_context().to_string()),
+            }
+        }
+    }
+}
+
+async fn health_check_locker(state: &AppState) -> CustomResult<(), errors::HealthCheckError> {
+    let locker = &state.conf.locker;
+
+    if locker.mock_locker {
+        return match state
+            .store
+            .find_locker_by_card_id(MOCK_LOCKER_HEALTH_CHECK_CARD_ID)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(error) if error.current_context().is_db_not_found() => Ok(()),
+            Err(error) => Err(error.change_context(errors::HealthCheckError::LockerFailed)),
+        };
+    }
+
+    // Any response from the locker is sufficient to consider it reachable
+    let request = services::Request::new(services::Method::Get, &format!("{}/health", locker.host));
+    services::call_connector_api(state, request)
+        .await
+        .change_context(errors::HealthCheckError::LockerFailed)
+        .attach_printable("Failed to reach the card locker")?;
+
+    #[cfg(feature = "basilisk")]
+    {
+        let request = services::Request::new(
+            services::Method::Get,
+            &format!("{}/health", locker.basilisk_host),
+        );
+        services::call_connector_api(state, request)
+            .await
+            .change_context(errors::HealthCheckError::LockerFailed)
+            .attach_printable("Failed to reach the basilisk locker")?;
+    }
+
+    Ok(())
+}
+
+async fn health_check_process_tracker(
+    state: &AppState,
+    scheduler: &settings::SchedulerSettings,
+) -> CustomResult<(), errors::HealthCheckError> {
+    let now = date_time::now();
+    state
+        .store
+        .find_processes_by_time_status(
+            now.saturating_sub(time::Duration::minutes(1)),
+            now,
+            enums::ProcessTrackerStatus::New,
+            Some(1),
+        )
+        .await
+        .change_context(errors::HealthCheckError::ProcessTrackerFailed)?;
+
+    let redis_conn = state.store.get_redis_conn();
+    for stream_name in scheduler_utils::get_all_stream_names(scheduler) {
+        redis_conn
+            .stream_get_length(stream_name)
+            .await
+            .change_context(errors::HealthCheckError::ProcessTrackerFailed)
+            .attach_printable_lazy(|| {
+                format!("Failed to fetch the length of the scheduler stream {stream_name}")
+            })?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::db::StorageImpl;
+
+    #[tokio::test]
+    async fn deep_health_check_with_mock_storage_is_healthy() {
+        let (tx, _rx) = tokio::sync::oneshot::channel();
+        let state = AppState::with_storage(Default::default(), StorageImpl::Mock, tx).await;
+
+        let response = deep_health_check(&state).await;
+
+        assert_eq!(response.status, api::HealthStatus::Healthy);
+        assert_eq!(
+            response.components[&api::HealthCheckComponent::Locker].status,
+            api::HealthStatus::Healthy
+        );
+        assert!(!response
+            .components
+            .contains_key(&api::HealthCheckComponent::ProcessTracker));
+    }
+
+    #[tokio::test]
+    async fn check_component_reports_failures() {
+        let component = check_component(HEALTH_CHECK_TIMEOUT, || async {
+            Err(errors::HealthCheckError::RedisFailed).into_report()
+        })
+        .await;
+
+        assert_eq!(component.status, api::HealthStatus::Unhealthy);
+        assert_eq!(
+            component.error.as_deref(),
+            Some("Failed to perform round trip to redis")
+        );
+    }
+
+    #[tokio::test]
+    async fn check_component_times_out_slow_checks() {
+        let component = check_component(Duration::from_millis(10), || async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Ok(())
+        })
+        .await;
+
+        assert_eq!(component.status, api::HealthStatus::Unhealthy);
+        assert_eq!(
+            component.error.as_deref(),
+            Some("Timed out waiting for the health check to complete")
+        );
+        assert!(component.latency_ms < 5000);
+    }
+}
@@ -4,16 +4,19 @@ use futures::future;
 use router_env::{instrument, logger, tracing};
 use storage_models::enums as storage_enums;
 
-use super::payments::helpers;
+use super::payments::{self, helpers};
 use crate::{
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
     db::StorageInterface,
     routes::{metrics, AppState},
     services,
     types::{
         self,
         api::{
-            customers,
+            self, customers,
             mandates::{self, MandateResponseExt},
         },
         domain, storage,
@@ -63,6 +66,70 @@ pub async fn revoke_mandate(
     ))
 }
 
+/// Revokes the mandate at the connector it was set up with. Connectors which do not support
+/// revoking mandates are not called, in which case the mandate is only revoked locally.
+#[instrument(skip_all)]
+pub async fn revoke_mandate_at_connector(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    mandate: &storage::Mandate,
+) -> RouterResult<()> {
+    if mandate.connector_mandate_ids.is_none() && mandate.connector_mandate_id.is_none() {
+        return Ok(());
+    }
+
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &mandate.connector,
+        api::GetToken::Connector,
+    )?;
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > = connector_data.connector.get_connector_integration();
+    let router_data = core_utils::construct_mandate_revoke_router_data(
+        state,
+        merchant_account,
+        key_store,
+        mandate,
+    )
+    .await?;
+
+    let is_supported = connector_integration
+        .build_request(&router_data, &state.conf.connectors)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while building the mandate revoke request")?
+        .is_some();
+    if !is_supported {
+        logger::info!(connector = %mandate.connector, "Connector does not support revoking mandates");
+        return Ok(());
+    }
+
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while calling mandate revoke connector api")?;
+    response
+        .response
+        .map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: mandate.connector.clone(),
+            status_code: err.status_code,
+            reason: err.reason,
+        })?;
+    Ok(())
+}
+
 #[instrument(skip(db))]
 pub async fn update_connector_mandate_id(
     db: &dyn StorageInterface,
@@ -31,3 +31,7 @@ counter_metric!(
     ATTACH_EVIDENCE_DISPUTE_STATUS_VALIDATION_FAILURE_METRIC,
     GLOBAL_METER
 );
+counter_metric!(DISPUTE_LIFECYCLE_TASKS_CREATED, GLOBAL_METER); // No. of dispute lifecycle tasks scheduled
+counter_metric!(DISPUTE_EVIDENCE_REMINDERS_SENT, GLOBAL_METER); // No. of evidence deadline reminders sent to merchants
+counter_metric!(DISPUTES_AUTO_ACCEPTED, GLOBAL_METER); // No. of disputes accepted according to the auto accept rules of merchants
+counter_metric!(DISPUTES_EXPIRED, GLOBAL_METER); // No. of disputes expired after their evidence deadline
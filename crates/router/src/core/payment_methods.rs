@@ -1,3 +1,7 @@
+pub mod account_updater;
+pub mod builtin_vault;
 pub mod cards;
+pub mod installments;
+pub mod network_tokenization;
 pub mod transformers;
 pub mod vault;
@@ -0,0 +1,437 @@
+use common_utils::date_time;
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings,
+    core::{
+        errors::{self, CustomResult, RouterResult},
+        payment_methods::{cards, network_tokenization},
+        webhooks,
+    },
+    logger,
+    routes::AppState,
+    types::{
+        api, domain,
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+};
+
+/// Card which the mock account updater reports as closed by the issuer
+#[cfg(test)]
+const MOCK_CLOSED_ACCOUNT_CARD_NUMBER: &str = "4000000000000069";
+/// Card which the mock account updater reports as reissued with a new card number
+#[cfg(test)]
+const MOCK_REISSUED_CARD_NUMBER: &str = "4000000000000077";
+/// Number of years by which the mock account updater extends the expiry of a card
+#[cfg(test)]
+const MOCK_EXPIRY_EXTENSION_YEARS: u32 = 3;
+
+/// Update of a card reported by the account updater
+#[derive(Debug, Clone)]
+pub enum CardAccountUpdate {
+    /// No update is available for the card
+    NoUpdate,
+    /// The card has been renewed with a new expiry
+    ExpiryUpdated {
+        card_exp_month: Secret<String>,
+        card_exp_year: Secret<String>,
+    },
+    /// The card has been reissued with a new card number
+    CardNumberUpdated {
+        card_number: ::cards::CardNumber,
+        card_exp_month: Secret<String>,
+        card_exp_year: Secret<String>,
+    },
+    /// The card account has been closed by the issuer
+    AccountClosed,
+}
+
+impl CardAccountUpdate {
+    pub fn get_update_type(&self) -> &'static str {
+        match self {
+            Self::NoUpdate => "no_update",
+            Self::ExpiryUpdated { .. } => "expiry_updated",
+            Self::CardNumberUpdated { .. } => "card_number_updated",
+            Self::AccountClosed => "account_closed",
+        }
+    }
+}
+
+/// An account updater, which reports the updates made by the issuers to the cards they issued,
+/// on behalf of the card networks
+#[async_trait::async_trait]
+pub trait AccountUpdaterProvider: Send + Sync {
+    async fn get_card_update(
+        &self,
+        state: &AppState,
+        card: &api::CardDetail,
+    ) -> CustomResult<CardAccountUpdate, errors::AccountUpdaterError>;
+}
+
+/// Obtains the configured account updater, if any
+pub fn get_account_updater_provider(
+    config: &settings::CardAccountUpdater,
+) -> Option<Box<dyn AccountUpdaterProvider>> {
+    config
+        .provider
+        .as_ref()
+        .map(|provider| -> Box<dyn AccountUpdaterProvider> {
+            match *provider {
+                #[cfg(test)]
+                settings::AccountUpdaterProviderKind::Mock => Box::new(MockAccountUpdaterProvider),
+            }
+        })
+}
+
+/// Account updater emulated locally, available only in tests since it reports made up updates.
+/// Dedicated test cards are reported as closed or reissued, every other card is reported as
+/// renewed with a later expiry.
+#[cfg(test)]
+pub struct MockAccountUpdaterProvider;
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl AccountUpdaterProvider for MockAccountUpdaterProvider {
+    async fn get_card_update(
+        &self,
+        _state: &AppState,
+        card: &api::CardDetail,
+    ) -> CustomResult<CardAccountUpdate, errors::AccountUpdaterError> {
+        let card_exp_year = extend_expiry_year(card.card_exp_year.peek())
+            .ok_or(errors::AccountUpdaterError::FetchCardUpdateFailed)
+            .into_report()
+            .attach_printable("Card expiry year is not valid")?;
+
+        match card.card_number.peek().as_str() {
+            MOCK_CLOSED_ACCOUNT_CARD_NUMBER => Ok(CardAccountUpdate::AccountClosed),
+            MOCK_REISSUED_CARD_NUMBER => {
+                let card_number =
+                    network_tokenization::generate_mock_token_number(card.card_number.peek())
+                        .and_then(|card_number| card_number.parse().ok())
+                        .ok_or(errors::AccountUpdaterError::FetchCardUpdateFailed)
+                        .into_report()
+                        .attach_printable("Failed to generate reissued card number")?;
+
+                Ok(CardAccountUpdate::CardNumberUpdated {
+                    card_number,
+                    card_exp_month: card.card_exp_month.clone(),
+                    card_exp_year: card_exp_year.into(),
+                })
+            }
+            _ => Ok(CardAccountUpdate::ExpiryUpdated {
+                card_exp_month: card.card_exp_month.clone(),
+                card_exp_year: card_exp_year.into(),
+            }),
+        }
+    }
+}
+
+/// Extends the expiry year of a card, keeping the number of digits it is written with
+#[cfg(test)]
+fn extend_expiry_year(card_exp_year: &str) -> Option<String> {
+    let year: u32 = card_exp_year.trim().parse().ok()?;
+    match card_exp_year.trim().len() {
+        2 => Some(format!("{:02}", (year + MOCK_EXPIRY_EXTENSION_YEARS) % 100)),
+        4 => Some((year + MOCK_EXPIRY_EXTENSION_YEARS).to_string()),
+        _ => None,
+    }
+}
+
+/// Computes the time at which a card expires, the start of the month following its expiry month.
+/// Expiry years are accepted with either two or four digits.
+pub fn get_card_expires_at(card: &api::CardDetail) -> Option<time::PrimitiveDateTime> {
+    let month: u8 = card.card_exp_month.peek().trim().parse().ok()?;
+    let month = time::Month::try_from(month).ok()?;
+    let year: i32 = card.card_exp_year.peek().trim().parse().ok()?;
+    let year = if year < 100 { 2000 + year } else { year };
+    let year = if month == time::Month::December {
+        year + 1
+    } else {
+        year
+    };
+    let date = time::Date::from_calendar_date(year, month.next(), 1).ok()?;
+    Some(time::PrimitiveDateTime::new(date, time::Time::MIDNIGHT))
+}
+
+/// Checks a saved card with the account updater, applying the reported update to the card in the
+/// locker and to the payment method. The merchant is notified of the updated payment method through
+/// a `payment_method_updated` webhook, unless no update was reported.
+#[instrument(skip_all)]
+pub async fn update_saved_card(
+    state: &AppState,
+    payment_method: storage::PaymentMethod,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<CardAccountUpdate> {
+    let db = &*state.store;
+    let stored_card = cards::get_card_from_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        payment_method.get_card_reference(),
+        merchant_account.locker_id.clone(),
+    )
+    .await?;
+    let card = api::CardDetail {
+        card_number: stored_card.card_number,
+        card_exp_month: stored_card.card_exp_month,
+        card_exp_year: stored_card.card_exp_year,
+        card_holder_name: stored_card.name_on_card,
+    };
+
+    let card_update = get_account_updater_provider(&state.conf.card_account_updater)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("No account updater has been configured")?
+        .get_card_update(state, &card)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let payment_method = match card_update.clone() {
+        CardAccountUpdate::NoUpdate => {
+            db.update_payment_method(
+                payment_method,
+                storage::PaymentMethodUpdate::AccountUpdaterUpdate {
+                    card_account_status: Some(enums::CardAccountStatus::Active),
+                    account_updater_checked_at: date_time::now(),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update payment method in db")?;
+            return Ok(card_update);
+        }
+        CardAccountUpdate::ExpiryUpdated {
+            card_exp_month,
+            card_exp_year,
+        } => {
+            let updated_card = api::CardDetail {
+                card_exp_month,
+                card_exp_year,
+                ..card
+            };
+            replace_saved_card(
+                state,
+                payment_method,
+                updated_card,
+                merchant_account,
+                key_store,
+            )
+            .await?
+        }
+        CardAccountUpdate::CardNumberUpdated {
+            card_number,
+            card_exp_month,
+            card_exp_year,
+        } => {
+            let updated_card = api::CardDetail {
+                card_number,
+                card_exp_month,
+                card_exp_year,
+                ..card
+            };
+            replace_saved_card(
+                state,
+                payment_method,
+                updated_card,
+                merchant_account,
+                key_store,
+            )
+            .await?
+        }
+        CardAccountUpdate::AccountClosed => {
+            // The network token of a closed card cannot be used for payments either
+            let payment_method = remove_network_token(state, payment_method).await?;
+            db.update_payment_method(
+                payment_method,
+                storage::PaymentMethodUpdate::AccountUpdaterUpdate {
+                    card_account_status: Some(enums::CardAccountStatus::Closed),
+                    account_updater_checked_at: date_time::now(),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update payment method in db")?
+        }
+    };
+
+    let payment_method_id = payment_method.payment_method_id.clone();
+    let payment_method_response = cards::get_payment_method_response(
+        state,
+        payment_method,
+        merchant_account.locker_id.clone(),
+    )
+    .await?;
+    webhooks::create_event_and_trigger_outgoing_webhook::<api::OutgoingWebhook>(
+        state.clone(),
+        merchant_account.clone(),
+        enums::EventType::PaymentMethodUpdated,
+        enums::EventClass::PaymentMethods,
+        None,
+        payment_method_id,
+        enums::EventObjectType::PaymentMethodDetails,
+        api::OutgoingWebhookContent::PaymentMethodDetails(Box::new(payment_method_response)),
+    )
+    .await?;
+
+    Ok(card_update)
+}
+
+/// Replaces the card of a saved payment method with the updated card in the locker. The payment
+/// method keeps its ID, so the mandates set up with the replaced card continue to use it.
+async fn replace_saved_card(
+    state: &AppState,
+    payment_method: storage::PaymentMethod,
+    card: api::CardDetail,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<storage::PaymentMethod> {
+    let db = &*state.store;
+    let req = api::PaymentMethodCreate {
+        payment_method: payment_method.payment_method.foreign_into(),
+        payment_method_type: payment_method
+            .payment_method_type
+            .map(ForeignInto::foreign_into),
+        payment_method_issuer: payment_method.payment_method_issuer.clone(),
+        payment_method_issuer_code: None,
+        card: Some(card.clone()),
+        metadata: None,
+        customer_id: Some(payment_method.customer_id.clone()),
+        card_network: payment_method.scheme.clone(),
+        wallet: None,
+        bank_transfer: None,
+        bank_debit: None,
+    };
+    let (payment_method_response, _) = cards::add_card_to_locker(
+        state,
+        req,
+        card.clone(),
+        payment_method.customer_id.clone(),
+        merchant_account,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Add Card Failed")?;
+
+    let card_reference = payment_method_response.payment_method_id;
+    let is_card_replaced = card_reference != payment_method.get_card_reference();
+    if is_card_replaced {
+        // Failing to delete the replaced card must not fail updating the card
+        cards::delete_card_from_locker(
+            state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            payment_method.get_card_reference(),
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to delete replaced card"))
+        .ok();
+    }
+
+    // The network token of the replaced card is not valid for the updated card
+    let payment_method = remove_network_token(state, payment_method).await?;
+    let payment_method = db
+        .update_payment_method(
+            payment_method,
+            storage::PaymentMethodUpdate::CardUpdate {
+                card_reference: is_card_replaced.then_some(card_reference),
+                card_fingerprint: Some(cards::generate_card_fingerprint(&card, key_store)?),
+                card_expires_at: get_card_expires_at(&card),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payment method in db")?;
+
+    let payment_method =
+        network_tokenization::provision_network_token(state, &card, payment_method, key_store)
+            .await;
+    db.update_payment_method(
+        payment_method,
+        storage::PaymentMethodUpdate::AccountUpdaterUpdate {
+            card_account_status: Some(enums::CardAccountStatus::Active),
+            account_updater_checked_at: date_time::now(),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update payment method in db")
+}
+
+async fn remove_network_token(
+    state: &AppState,
+    payment_method: storage::PaymentMethod,
+) -> RouterResult<storage::PaymentMethod> {
+    if payment_method.network_token_reference.is_none() {
+        return Ok(payment_method);
+    }
+
+    network_tokenization::delete_network_token(state, &payment_method).await;
+    state
+        .store
+        .update_payment_method(
+            payment_method,
+            storage::PaymentMethodUpdate::NetworkTokenUpdate {
+                network_token_reference: None,
+                network_token_status: enums::NetworkTokenStatus::Deleted,
+                network_token_data: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update network token of payment method")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn card(card_exp_month: &str, card_exp_year: &str) -> api::CardDetail {
+        api::CardDetail {
+            card_number: ::cards::CardNumber::from_str("4242424242424242").unwrap(),
+            card_exp_month: card_exp_month.to_string().into(),
+            card_exp_year: card_exp_year.to_string().into(),
+            card_holder_name: None,
+        }
+    }
+
+    #[test]
+    fn test_card_expires_at_start_of_following_month() {
+        assert_eq!(
+            get_card_expires_at(&card("10", "25")),
+            Some(time::macros::datetime!(2025-11-01 00:00))
+        );
+        assert_eq!(
+            get_card_expires_at(&card("12", "2025")),
+            Some(time::macros::datetime!(2026-01-01 00:00))
+        );
+        assert_eq!(get_card_expires_at(&card("13", "25")), None);
+    }
+
+    #[test]
+    fn test_extend_expiry_year_keeps_digits() {
+        assert_eq!(extend_expiry_year("25").unwrap(), "28");
+        assert_eq!(extend_expiry_year("98").unwrap(), "01");
+        assert_eq!(extend_expiry_year("2025").unwrap(), "2028");
+        assert_eq!(extend_expiry_year("202"), None);
+    }
+
+    #[test]
+    fn test_account_updater_provider_is_required() {
+        let config = settings::CardAccountUpdater::default();
+        assert!(config.provider.is_none());
+        assert!(get_account_updater_provider(&config).is_none());
+
+        let config = settings::CardAccountUpdater {
+            provider: Some(settings::AccountUpdaterProviderKind::Mock),
+            ..config
+        };
+        assert!(get_account_updater_provider(&config).is_some());
+    }
+}
@@ -0,0 +1,750 @@
+use common_utils::{
+    crypto::{self, DecodeMessage, EncodeMessage, SignMessage},
+    date_time,
+    ext_traits::StringExt,
+    generate_id,
+};
+use error_stack::{report, IntoReport, ResultExt};
+use josekit::jwe;
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+use serde::Deserialize;
+
+use crate::{
+    configs::settings,
+    consts,
+    core::{
+        errors::{self, CustomResult},
+        payment_methods::transformers as payment_methods,
+    },
+    logger,
+    routes::AppState,
+    services::{self, encryption},
+    types::{self, storage},
+    utils,
+};
+
+/// Status reported in the responses of the built-in vault
+const SUCCESS_STATUS: &str = "Ok";
+
+/// Request to store a card, as sent by the router to the basilisk locker
+#[derive(Debug, Deserialize)]
+struct StoreCardRequest {
+    merchant_id: String,
+    merchant_customer_id: String,
+    card: payment_methods::Card,
+}
+
+/// Request to retrieve or delete a stored card, as sent by the router to the basilisk locker
+#[derive(Debug, Deserialize)]
+struct CardRequest {
+    merchant_id: String,
+    merchant_customer_id: String,
+    card_reference: String,
+}
+
+/// Data encrypted under a data key of its own, which is itself encrypted (wrapped) under one of the
+/// key encryption keys of the vault
+#[derive(Debug)]
+pub struct EnvelopeEncryptedData {
+    pub encrypted_data: Vec<u8>,
+    pub encrypted_data_key: Vec<u8>,
+    pub key_id: String,
+}
+
+/// Whether the built-in vault is the configured locker. The mock locker takes precedence over it,
+/// as it does over the other lockers.
+pub fn is_enabled(locker: &settings::Locker) -> bool {
+    !locker.mock_locker && locker.locker_setup == settings::LockerSetup::BuiltinVault
+}
+
+/// The configuration of the built-in vault, with its keys decrypted when they are KMS encrypted
+fn get_vault_config(state: &AppState) -> &settings::BuiltinVault {
+    #[cfg(feature = "kms")]
+    if let Some(config) = state.kms_secrets.builtin_vault.as_ref() {
+        return config.peek();
+    }
+
+    &state.conf.builtin_vault
+}
+
+fn get_key_encryption_key(
+    config: &settings::BuiltinVault,
+    key_id: &str,
+) -> CustomResult<Vec<u8>, errors::BuiltinVaultError> {
+    let key_not_found = || errors::BuiltinVaultError::KeyNotFound {
+        key_id: key_id.to_owned(),
+    };
+    let key = config.keys.get(key_id).ok_or_else(key_not_found)?;
+
+    hex::decode(key)
+        .into_report()
+        .change_context_lazy(key_not_found)
+        .attach_printable("Key encryption key is not hex encoded")
+}
+
+fn decrypt_data_key(
+    config: &settings::BuiltinVault,
+    encrypted_data_key: Vec<u8>,
+    key_id: &str,
+) -> CustomResult<Vec<u8>, errors::BuiltinVaultError> {
+    let key_encryption_key = get_key_encryption_key(config, key_id)?;
+
+    crypto::GcmAes256
+        .decode_message(&key_encryption_key, encrypted_data_key.into())
+        .change_context(errors::BuiltinVaultError::DecryptionFailed)
+        .attach_printable("Failed to decrypt data key")
+}
+
+/// Encrypts the data under a new data key, wrapped under the active key encryption key
+pub fn encrypt_with_envelope(
+    config: &settings::BuiltinVault,
+    data: &[u8],
+) -> CustomResult<EnvelopeEncryptedData, errors::BuiltinVaultError> {
+    let data_key = services::generate_aes256_key()
+        .change_context(errors::BuiltinVaultError::EncryptionFailed)
+        .attach_printable("Failed to generate data key")?;
+    let encrypted_data = crypto::GcmAes256
+        .encode_message(&data_key, data)
+        .change_context(errors::BuiltinVaultError::EncryptionFailed)?;
+
+    let key_encryption_key = get_key_encryption_key(config, &config.active_key_id)?;
+    let encrypted_data_key = crypto::GcmAes256
+        .encode_message(&key_encryption_key, &data_key)
+        .change_context(errors::BuiltinVaultError::EncryptionFailed)
+        .attach_printable("Failed to encrypt data key")?;
+
+    Ok(EnvelopeEncryptedData {
+        encrypted_data,
+        encrypted_data_key,
+        key_id: config.active_key_id.clone(),
+    })
+}
+
+pub fn decrypt_with_envelope(
+    config: &settings::BuiltinVault,
+    envelope: EnvelopeEncryptedData,
+) -> CustomResult<Vec<u8>, errors::BuiltinVaultError> {
+    let data_key = decrypt_data_key(config, envelope.encrypted_data_key, &envelope.key_id)?;
+
+    crypto::GcmAes256
+        .decode_message(&data_key, envelope.encrypted_data.into())
+        .change_context(errors::BuiltinVaultError::DecryptionFailed)
+}
+
+/// Wraps a data key again under the active key encryption key, leaving the data encrypted under it
+/// untouched
+pub fn rewrap_data_key(
+    config: &settings::BuiltinVault,
+    encrypted_data_key: Vec<u8>,
+    key_id: &str,
+) -> CustomResult<Vec<u8>, errors::BuiltinVaultError> {
+    let data_key = decrypt_data_key(config, encrypted_data_key, key_id)?;
+    let key_encryption_key = get_key_encryption_key(config, &config.active_key_id)?;
+
+    crypto::GcmAes256
+        .encode_message(&key_encryption_key, &data_key)
+        .change_context(errors::BuiltinVaultError::EncryptionFailed)
+        .attach_printable("Failed to encrypt data key")
+}
+
+fn generate_card_fingerprint(
+    config: &settings::BuiltinVault,
+    card_number: &str,
+) -> CustomResult<String, errors::BuiltinVaultError> {
+    let fingerprint_key = hex::decode(&config.fingerprint_key)
+        .into_report()
+        .change_context(errors::BuiltinVaultError::EncryptionFailed)
+        .attach_printable("Fingerprint key is not hex encoded")?;
+    let fingerprint = crypto::HmacSha256
+        .sign_message(&fingerprint_key, card_number.as_bytes())
+        .change_context(errors::BuiltinVaultError::EncryptionFailed)
+        .attach_printable("Failed to generate card fingerprint")?;
+
+    Ok(hex::encode(fingerprint))
+}
+
+/// Serves a request of the router to the basilisk locker with the built-in vault, in place of
+/// sending it over the network. Both the request and the response are the JWE bodies exchanged
+/// with the basilisk locker, so that the card flows are the same for both lockers.
+#[instrument(skip_all)]
+pub async fn call_builtin_vault(
+    state: &AppState,
+    request: services::Request,
+) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
+    match serve_request(state, &request).await {
+        Ok(response) => Ok(Ok(types::Response {
+            headers: None,
+            response: response.into(),
+            status_code: 200,
+        })),
+        Err(error) => {
+            logger::error!(?error, url = %request.url, "Built-in vault failed to serve request");
+            let status_code = match error.current_context() {
+                errors::BuiltinVaultError::RequestDecryptionFailed => 401,
+                errors::BuiltinVaultError::CardNotFound
+                | errors::BuiltinVaultError::UnknownEndpoint(_) => 404,
+                _ => 500,
+            };
+            Ok(Err(types::Response {
+                headers: None,
+                response: error.current_context().to_string().into(),
+                status_code,
+            }))
+        }
+    }
+}
+
+async fn serve_request(
+    state: &AppState,
+    request: &services::Request,
+) -> CustomResult<String, errors::BuiltinVaultError> {
+    let config = get_vault_config(state);
+    let jwe_body: encryption::JweBody = request
+        .payload
+        .as_ref()
+        .ok_or(errors::BuiltinVaultError::RequestDecryptionFailed)
+        .into_report()
+        .attach_printable("Missing request body")?
+        .peek()
+        .parse_struct("JweBody")
+        .change_context(errors::BuiltinVaultError::RequestDecryptionFailed)?;
+    let payload = decrypt_request(config, jwe_body).await?;
+
+    let response = if request.url.ends_with("/cards/add") {
+        let store_card_request = payload
+            .parse_struct("StoreCardRequest")
+            .change_context(errors::BuiltinVaultError::RequestDecryptionFailed)?;
+        let response = store_card(state, store_card_request).await?;
+        utils::Encode::<payment_methods::StoreCardResp>::encode_to_vec(&response)
+    } else if request.url.ends_with("/cards/retrieve") {
+        let card_request = payload
+            .parse_struct("CardRequest")
+            .change_context(errors::BuiltinVaultError::RequestDecryptionFailed)?;
+        let response = retrieve_card(state, card_request).await?;
+        utils::Encode::<payment_methods::RetrieveCardResp>::encode_to_vec(&response)
+    } else if request.url.ends_with("/cards/delete") {
+        let card_request = payload
+            .parse_struct("CardRequest")
+            .change_context(errors::BuiltinVaultError::RequestDecryptionFailed)?;
+        let response = delete_card(state, card_request).await?;
+        utils::Encode::<payment_methods::DeleteCardResp>::encode_to_vec(&response)
+    } else {
+        return Err(report!(errors::BuiltinVaultError::UnknownEndpoint(
+            request.url.clone()
+        )));
+    }
+    .change_context(errors::BuiltinVaultError::ResponseEncryptionFailed)?;
+
+    encrypt_response(state, config, &response).await
+}
+
+/// Decrypts the request with the private key of the vault, and verifies its signature with the
+/// public key of the router
+async fn decrypt_request(
+    config: &settings::BuiltinVault,
+    jwe_body: encryption::JweBody,
+) -> CustomResult<String, errors::BuiltinVaultError> {
+    let jws = encryption::decrypt_jwe(
+        &payment_methods::get_dotted_jwe(jwe_body),
+        encryption::KeyIdCheck::SkipKeyIdCheck,
+        config.vault_private_key.clone(),
+        jwe::RSA_OAEP_256,
+    )
+    .await
+    .change_context(errors::BuiltinVaultError::RequestDecryptionFailed)?
+    .parse_struct("JwsBody")
+    .change_context(errors::BuiltinVaultError::RequestDecryptionFailed)?;
+
+    encryption::verify_sign(
+        payment_methods::get_dotted_jws(jws),
+        &config.router_public_key,
+    )
+    .change_context(errors::BuiltinVaultError::RequestDecryptionFailed)
+    .attach_printable("Failed to verify signature of request")
+}
+
+/// Signs the response with the private key of the vault, and encrypts it with the public key of
+/// the router, the way the router expects the responses of the basilisk locker
+async fn encrypt_response(
+    state: &AppState,
+    config: &settings::BuiltinVault,
+    response: &[u8],
+) -> CustomResult<String, errors::BuiltinVaultError> {
+    let jws = encryption::jws_sign_payload(
+        response,
+        &state.conf.locker.locker_signing_key_id,
+        config.vault_private_key.clone(),
+    )
+    .await
+    .change_context(errors::BuiltinVaultError::ResponseEncryptionFailed)?;
+    let jws_body = split_jws(&jws)
+        .ok_or(errors::BuiltinVaultError::ResponseEncryptionFailed)
+        .into_report()
+        .attach_printable("Malformed JWS")?;
+    let jws_payload = utils::Encode::<encryption::JwsBody>::encode_to_vec(&jws_body)
+        .change_context(errors::BuiltinVaultError::ResponseEncryptionFailed)?;
+
+    let jwe = encryption::encrypt_jwe_with_algorithm(
+        &jws_payload,
+        config.router_public_key.clone(),
+        jwe::RSA_OAEP,
+    )
+    .await
+    .change_context(errors::BuiltinVaultError::ResponseEncryptionFailed)?;
+    let jwe_body = split_jwe(&jwe)
+        .ok_or(errors::BuiltinVaultError::ResponseEncryptionFailed)
+        .into_report()
+        .attach_printable("Malformed JWE")?;
+
+    utils::Encode::<encryption::JweBody>::encode_to_string_of_json(&jwe_body)
+        .change_context(errors::BuiltinVaultError::ResponseEncryptionFailed)
+}
+
+fn split_jws(jws: &str) -> Option<encryption::JwsBody> {
+    let mut parts = jws.split('.');
+    Some(encryption::JwsBody {
+        header: parts.next()?.to_string(),
+        payload: parts.next()?.to_string(),
+        signature: parts.next()?.to_string(),
+    })
+}
+
+fn split_jwe(jwe: &str) -> Option<encryption::JweBody> {
+    let mut parts = jwe.split('.');
+    Some(encryption::JweBody {
+        header: parts.next()?.to_string(),
+        encrypted_key: parts.next()?.to_string(),
+        iv: parts.next()?.to_string(),
+        encrypted_payload: parts.next()?.to_string(),
+        tag: parts.next()?.to_string(),
+    })
+}
+
+/// Stores a card of a customer. A card which was stored before for the customer, identified by its
+/// fingerprint, is not stored again: the stored card is updated with the details in the request,
+/// and its reference is returned, marked as a duplicate.
+#[instrument(skip_all)]
+async fn store_card(
+    state: &AppState,
+    request: StoreCardRequest,
+) -> CustomResult<payment_methods::StoreCardResp, errors::BuiltinVaultError> {
+    let db = &*state.store;
+    let config = get_vault_config(state);
+    let card_fingerprint = generate_card_fingerprint(config, request.card.card_number.peek())?;
+
+    let stored_card = db
+        .find_vault_card_by_merchant_id_customer_id_card_fingerprint(
+            &request.merchant_id,
+            &request.merchant_customer_id,
+            &card_fingerprint,
+        )
+        .await
+        .change_context(errors::BuiltinVaultError::DatabaseError)?;
+
+    let card = utils::Encode::<payment_methods::Card>::encode_to_vec(&request.card)
+        .change_context(errors::BuiltinVaultError::EncryptionFailed)?;
+    let envelope = encrypt_with_envelope(config, &card)?;
+
+    // The stored card is replaced with the card in the request, which may have a different expiry
+    // or cardholder name than the stored one
+    let (card_reference, duplicate) = match stored_card {
+        Some(stored_card) => {
+            let card_reference = stored_card.card_reference.clone();
+            db.update_vault_card(
+                stored_card,
+                storage::VaultCardUpdate::CardUpdate {
+                    encrypted_card: envelope.encrypted_data,
+                    encrypted_data_key: envelope.encrypted_data_key,
+                    key_id: envelope.key_id,
+                },
+            )
+            .await
+            .change_context(errors::BuiltinVaultError::DatabaseError)?;
+
+            (card_reference, true)
+        }
+        None => {
+            let card_reference = generate_id(consts::ID_LENGTH, "card");
+
+            db.insert_vault_card(storage::VaultCardNew {
+                card_reference: card_reference.clone(),
+                merchant_id: request.merchant_id,
+                customer_id: request.merchant_customer_id,
+                card_fingerprint,
+                encrypted_card: envelope.encrypted_data,
+                encrypted_data_key: envelope.encrypted_data_key,
+                key_id: envelope.key_id,
+            })
+            .await
+            .change_context(errors::BuiltinVaultError::DatabaseError)?;
+
+            (card_reference, false)
+        }
+    };
+
+    Ok(payment_methods::StoreCardResp {
+        status: SUCCESS_STATUS.to_string(),
+        error_message: None,
+        error_code: None,
+        payload: Some(payment_methods::StoreCardRespPayload {
+            card_reference,
+            duplicate: Some(duplicate),
+        }),
+    })
+}
+
+#[instrument(skip_all)]
+async fn retrieve_card(
+    state: &AppState,
+    request: CardRequest,
+) -> CustomResult<payment_methods::RetrieveCardResp, errors::BuiltinVaultError> {
+    let vault_card = state
+        .store
+        .find_vault_card_by_merchant_id_customer_id_card_reference(
+            &request.merchant_id,
+            &request.merchant_customer_id,
+            &request.card_reference,
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::BuiltinVaultError::CardNotFound)
+            } else {
+                error.change_context(errors::BuiltinVaultError::DatabaseError)
+            }
+        })?;
+
+    let card = decrypt_with_envelope(
+        get_vault_config(state),
+        EnvelopeEncryptedData {
+            encrypted_data: vault_card.encrypted_card,
+            encrypted_data_key: vault_card.encrypted_data_key,
+            key_id: vault_card.key_id,
+        },
+    )?;
+    let card = String::from_utf8(card)
+        .into_report()
+        .change_context(errors::BuiltinVaultError::DecryptionFailed)?
+        .parse_struct("Card")
+        .change_context(errors::BuiltinVaultError::DecryptionFailed)?;
+
+    Ok(payment_methods::RetrieveCardResp {
+        status: SUCCESS_STATUS.to_string(),
+        error_message: None,
+        error_code: None,
+        payload: Some(payment_methods::RetrieveCardRespPayload {
+            card: Some(card),
+            enc_card_data: None,
+        }),
+    })
+}
+
+#[instrument(skip_all)]
+async fn delete_card(
+    state: &AppState,
+    request: CardRequest,
+) -> CustomResult<payment_methods::DeleteCardResp, errors::BuiltinVaultError> {
+    state
+        .store
+        .delete_vault_card_by_merchant_id_customer_id_card_reference(
+            &request.merchant_id,
+            &request.merchant_customer_id,
+            &request.card_reference,
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::BuiltinVaultError::CardNotFound)
+            } else {
+                error.change_context(errors::BuiltinVaultError::DatabaseError)
+            }
+        })?;
+
+    Ok(payment_methods::DeleteCardResp {
+        status: SUCCESS_STATUS.to_string(),
+        error_message: None,
+        error_code: None,
+    })
+}
+
+/// Stores the value of a temporary token, replacing the value stored before under the same token.
+/// The token expires after the configured TTL.
+#[instrument(skip_all)]
+pub async fn store_token(
+    state: &AppState,
+    token_id: &str,
+    value: &str,
+) -> CustomResult<(), errors::BuiltinVaultError> {
+    let db = &*state.store;
+    let config = get_vault_config(state);
+    let envelope = encrypt_with_envelope(config, value.as_bytes())?;
+
+    if db.find_vault_token_by_token_id(token_id).await.is_ok() {
+        db.delete_vault_token_by_token_id(token_id)
+            .await
+            .change_context(errors::BuiltinVaultError::DatabaseError)?;
+    }
+
+    db.insert_vault_token(storage::VaultTokenNew {
+        token_id: token_id.to_owned(),
+        encrypted_value: envelope.encrypted_data,
+        encrypted_data_key: envelope.encrypted_data_key,
+        key_id: envelope.key_id,
+        expires_at: date_time::now() + time::Duration::seconds(config.token_ttl),
+    })
+    .await
+    .change_context(errors::BuiltinVaultError::DatabaseError)?;
+
+    Ok(())
+}
+
+/// Retrieves the value of a temporary token. An expired token is deleted, and not returned.
+#[instrument(skip_all)]
+pub async fn retrieve_token(
+    state: &AppState,
+    token_id: &str,
+) -> CustomResult<String, errors::BuiltinVaultError> {
+    let db = &*state.store;
+    let vault_token = db
+        .find_vault_token_by_token_id(token_id)
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::BuiltinVaultError::TokenNotFound)
+            } else {
+                error.change_context(errors::BuiltinVaultError::DatabaseError)
+            }
+        })?;
+
+    if vault_token.expires_at < date_time::now() {
+        db.delete_vault_token_by_token_id(token_id)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to delete expired vault token"))
+            .ok();
+        Err(errors::BuiltinVaultError::TokenExpired)?
+    }
+
+    let value = decrypt_with_envelope(
+        get_vault_config(state),
+        EnvelopeEncryptedData {
+            encrypted_data: vault_token.encrypted_value,
+            encrypted_data_key: vault_token.encrypted_data_key,
+            key_id: vault_token.key_id,
+        },
+    )?;
+
+    String::from_utf8(value)
+        .into_report()
+        .change_context(errors::BuiltinVaultError::DecryptionFailed)
+}
+
+#[instrument(skip_all)]
+pub async fn delete_token(
+    state: &AppState,
+    token_id: &str,
+) -> CustomResult<(), errors::BuiltinVaultError> {
+    state
+        .store
+        .delete_vault_token_by_token_id(token_id)
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::BuiltinVaultError::TokenNotFound)
+            } else {
+                error.change_context(errors::BuiltinVaultError::DatabaseError)
+            }
+        })?;
+
+    Ok(())
+}
+
+/// Wraps the data keys of the stored cards which are wrapped under a retired key encryption key
+/// again under the active one, and returns the number of cards whose data key was rotated. Cards
+/// which fail to be rotated are logged and skipped.
+#[instrument(skip_all)]
+pub async fn rotate_card_data_keys(
+    state: &AppState,
+) -> CustomResult<usize, errors::BuiltinVaultError> {
+    let db = &*state.store;
+    let config = get_vault_config(state);
+    let vault_cards = db
+        .find_vault_cards_not_under_key_id(&config.active_key_id, config.key_rotation_batch_size)
+        .await
+        .change_context(errors::BuiltinVaultError::DatabaseError)?;
+
+    let mut rotated_cards = 0;
+    for vault_card in vault_cards {
+        let card_reference = vault_card.card_reference.clone();
+        let rotation = match rewrap_data_key(
+            config,
+            vault_card.encrypted_data_key.clone(),
+            &vault_card.key_id,
+        ) {
+            Ok(encrypted_data_key) => db
+                .update_vault_card(
+                    vault_card,
+                    storage::VaultCardUpdate::KeyRotationUpdate {
+                        encrypted_data_key,
+                        key_id: config.active_key_id.clone(),
+                    },
+                )
+                .await
+                .change_context(errors::BuiltinVaultError::DatabaseError),
+            Err(error) => Err(error),
+        };
+
+        match rotation {
+            Ok(_) => rotated_cards += 1,
+            Err(error) => {
+                logger::error!(?error, %card_reference, "Failed to rotate data key of vault card")
+            }
+        }
+    }
+
+    Ok(rotated_cards)
+}
+
+/// Deletes the temporary tokens which have expired, and returns whether any token was deleted
+#[instrument(skip_all)]
+pub async fn purge_expired_tokens(
+    state: &AppState,
+) -> CustomResult<bool, errors::BuiltinVaultError> {
+    state
+        .store
+        .delete_expired_vault_tokens(date_time::now())
+        .await
+        .change_context(errors::BuiltinVaultError::DatabaseError)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::{collections::HashMap, str::FromStr};
+
+    use super::*;
+    use crate::db::StorageImpl;
+
+    fn get_config(active_key_id: &str) -> settings::BuiltinVault {
+        settings::BuiltinVault {
+            active_key_id: active_key_id.to_string(),
+            keys: HashMap::from([
+                ("1".to_string(), hex::encode([1_u8; 32])),
+                ("2".to_string(), hex::encode([2_u8; 32])),
+            ]),
+            fingerprint_key: hex::encode([3_u8; 32]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_envelope_encryption() {
+        let config = get_config("1");
+        let envelope = encrypt_with_envelope(&config, b"4242424242424242").unwrap();
+        assert_eq!(envelope.key_id, "1");
+        assert_ne!(envelope.encrypted_data, b"4242424242424242".to_vec());
+
+        let data = decrypt_with_envelope(&config, envelope).unwrap();
+        assert_eq!(data, b"4242424242424242".to_vec());
+    }
+
+    #[test]
+    fn test_data_key_rotation() {
+        let envelope = encrypt_with_envelope(&get_config("1"), b"4242424242424242").unwrap();
+
+        let config = get_config("2");
+        let encrypted_data_key =
+            rewrap_data_key(&config, envelope.encrypted_data_key, &envelope.key_id).unwrap();
+        let data = decrypt_with_envelope(
+            &config,
+            EnvelopeEncryptedData {
+                encrypted_data: envelope.encrypted_data,
+                encrypted_data_key,
+                key_id: "2".to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(data, b"4242424242424242".to_vec());
+    }
+
+    #[test]
+    fn test_missing_key_encryption_key() {
+        let envelope = encrypt_with_envelope(&get_config("1"), b"4242424242424242").unwrap();
+
+        let mut config = get_config("2");
+        config.keys.remove("1");
+        assert!(decrypt_with_envelope(&config, envelope).is_err());
+    }
+
+    #[test]
+    fn test_card_fingerprint() {
+        let config = get_config("1");
+        let fingerprint = generate_card_fingerprint(&config, "4242424242424242").unwrap();
+        assert_eq!(
+            fingerprint,
+            generate_card_fingerprint(&config, "4242424242424242").unwrap()
+        );
+        assert_ne!(
+            fingerprint,
+            generate_card_fingerprint(&config, "4000056655665556").unwrap()
+        );
+    }
+
+    fn store_card_request(card_exp_year: &str) -> StoreCardRequest {
+        StoreCardRequest {
+            merchant_id: "merchant_1".to_string(),
+            merchant_customer_id: "cus_1".to_string(),
+            card: payment_methods::Card {
+                card_number: ::cards::CardNumber::from_str("4242424242424242").unwrap(),
+                name_on_card: None,
+                card_exp_month: "10".to_string().into(),
+                card_exp_year: card_exp_year.to_string().into(),
+                card_brand: None,
+                card_isin: None,
+                nick_name: None,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_card_replaces_stored_card() {
+        let conf = settings::Settings {
+            builtin_vault: get_config("1"),
+            ..Default::default()
+        };
+        let (tx, _rx) = tokio::sync::oneshot::channel();
+        let state = AppState::with_storage(conf, StorageImpl::Mock, tx).await;
+
+        let stored_card = store_card(&state, store_card_request("25"))
+            .await
+            .unwrap()
+            .payload
+            .unwrap();
+        assert_eq!(stored_card.duplicate, Some(false));
+
+        let duplicate_card = store_card(&state, store_card_request("28"))
+            .await
+            .unwrap()
+            .payload
+            .unwrap();
+        assert_eq!(duplicate_card.duplicate, Some(true));
+        assert_eq!(duplicate_card.card_reference, stored_card.card_reference);
+
+        let card = retrieve_card(
+            &state,
+            CardRequest {
+                merchant_id: "merchant_1".to_string(),
+                merchant_customer_id: "cus_1".to_string(),
+                card_reference: stored_card.card_reference,
+            },
+        )
+        .await
+        .unwrap()
+        .payload
+        .unwrap()
+        .card
+        .unwrap();
+        assert_eq!(card.card_exp_year.peek(), "28");
+    }
+}
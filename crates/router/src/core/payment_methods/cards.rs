@@ -4,7 +4,7 @@ use api_models::{
     admin::{self, PaymentMethodsEnabled},
     enums::{self as api_enums},
     payment_methods::{
-        CardNetworkTypes, PaymentExperienceTypes, RequestPaymentMethodTypes,
+        CardNetworkTypes, InstallmentPlanTypes, PaymentExperienceTypes, RequestPaymentMethodTypes,
         ResponsePaymentMethodIntermediate, ResponsePaymentMethodTypes,
         ResponsePaymentMethodsEnabled,
     },
@@ -12,12 +12,13 @@ use api_models::{
 };
 use common_utils::{
     consts,
+    crypto::{self, SignMessage},
     ext_traits::{AsyncExt, BytesExt, StringExt, ValueExt},
     generate_id,
 };
 use error_stack::{report, IntoReport, ResultExt};
 use router_env::{instrument, tracing};
-use storage_models::{enums as storage_enums, payment_method};
+use storage_models::{encryption::Encryption, enums as storage_enums, payment_method};
 
 #[cfg(feature = "basilisk")]
 use crate::scheduler::metrics as scheduler_metrics;
@@ -26,6 +27,7 @@ use crate::{
     core::{
         errors::{self, StorageErrorExt},
         payment_methods::{
+            account_updater, builtin_vault, installments, network_tokenization,
             transformers::{self as payment_methods},
             vault,
         },
@@ -39,6 +41,7 @@ use crate::{
     },
     services,
     types::{
+        self,
         api::{self, PaymentMethodCreateExt},
         domain::{self},
         storage::{self, enums},
@@ -48,6 +51,7 @@ use crate::{
 };
 
 #[instrument(skip_all)]
+#[allow(clippy::too_many_arguments)]
 pub async fn create_payment_method(
     db: &dyn db::StorageInterface,
     req: &api::PaymentMethodCreate,
@@ -55,6 +59,8 @@ pub async fn create_payment_method(
     payment_method_id: &str,
     merchant_id: &str,
     pm_metadata: Option<serde_json::Value>,
+    payment_method_data: Option<Encryption>,
+    card_fingerprint: Option<String>,
 ) -> errors::CustomResult<storage::PaymentMethod, errors::StorageError> {
     let response = db
         .insert_payment_method(storage::PaymentMethodNew {
@@ -66,6 +72,12 @@ pub async fn create_payment_method(
             payment_method_issuer: req.payment_method_issuer.clone(),
             scheme: req.card_network.clone(),
             metadata: pm_metadata.map(masking::Secret::new),
+            payment_method_data,
+            card_fingerprint,
+            card_expires_at: req
+                .card
+                .as_ref()
+                .and_then(account_updater::get_card_expires_at),
             ..storage::PaymentMethodNew::default()
         })
         .await?;
@@ -78,40 +90,180 @@ pub async fn add_payment_method(
     state: &routes::AppState,
     req: api::PaymentMethodCreate,
     merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
 ) -> errors::RouterResponse<api::PaymentMethodResponse> {
     req.validate()?;
     let merchant_id = &merchant_account.merchant_id;
     let customer_id = req.customer_id.clone().get_required_value("customer_id")?;
     let response = match req.card.clone() {
-        Some(card) => add_card_to_locker(state, req, card, customer_id, merchant_account)
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Add Card Failed"),
-        None => {
-            let pm_id = generate_id(consts::ID_LENGTH, "pm");
-            let payment_method_response = api::PaymentMethodResponse {
+        Some(card) => add_card_payment_method(
+            state,
+            req,
+            card,
+            customer_id,
+            merchant_account,
+            key_store,
+            None,
+        )
+        .await
+        .map(|(payment_method_response, _, _)| payment_method_response),
+        None => match get_payment_method_data_to_store(&req) {
+            Some(payment_method_data) => {
+                add_payment_method_data(
+                    state,
+                    req,
+                    payment_method_data,
+                    &customer_id,
+                    merchant_id,
+                    key_store,
+                    None,
+                )
+                .await
+            }
+            // Payment methods without any details to store, such as pay later payment methods,
+            // are not saved
+            None => Ok(api::PaymentMethodResponse {
                 merchant_id: merchant_id.to_string(),
                 customer_id: Some(customer_id),
-                payment_method_id: pm_id,
+                payment_method_id: generate_id(consts::ID_LENGTH, "pm"),
                 payment_method: req.payment_method,
                 payment_method_type: req.payment_method_type,
                 card: None,
                 metadata: req.metadata,
                 created: Some(common_utils::date_time::now()),
-                recurring_enabled: false,           //[#219]
-                installment_payment_enabled: false, //[#219]
+                network_token_status: None,
+                card_account_status: None,
+                recurring_enabled: false, //[#219]
+                installment_payment_enabled: installments::is_installment_payment_method_type(
+                    &state.conf.installments,
+                    req.payment_method_type,
+                ),
                 payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]), //[#219]
-            };
-            Ok((payment_method_response, false))
-        }
+            }),
+        },
     };
-    Ok(response?.0).map(services::ApplicationResponse::Json)
+    response.map(services::ApplicationResponse::Json)
+}
+
+/// Obtains the details of a payment method other than a card which can be stored, if they were
+/// provided for the payment method of the request. Apple Pay and Google Pay payment data is not
+/// stored, as it can only be used for a single payment.
+pub fn get_payment_method_data_to_store(
+    req: &api::PaymentMethodCreate,
+) -> Option<api::PaymentMethodData> {
+    match req.payment_method {
+        api_enums::PaymentMethod::Wallet => req
+            .wallet
+            .clone()
+            .filter(|wallet| {
+                !matches!(
+                    wallet,
+                    api_models::payments::WalletData::ApplePay(_)
+                        | api_models::payments::WalletData::GooglePay(_)
+                )
+            })
+            .map(api::PaymentMethodData::Wallet),
+        api_enums::PaymentMethod::BankTransfer => req
+            .bank_transfer
+            .clone()
+            .map(|bank_transfer| api::PaymentMethodData::BankTransfer(Box::new(bank_transfer))),
+        api_enums::PaymentMethod::BankDebit => req
+            .bank_debit
+            .clone()
+            .map(api::PaymentMethodData::BankDebit),
+        _ => None,
+    }
+}
+
+/// Stores a payment method other than a card, whose details are encrypted with the key of the
+/// merchant and stored along with the payment method, instead of in the card locker.
+#[instrument(skip_all)]
+pub async fn add_payment_method_data(
+    state: &routes::AppState,
+    req: api::PaymentMethodCreate,
+    payment_method_data: api::PaymentMethodData,
+    customer_id: &str,
+    merchant_id: &str,
+    key_store: &domain::MerchantKeyStore,
+    pm_metadata: Option<serde_json::Value>,
+) -> errors::RouterResult<api::PaymentMethodResponse> {
+    let payment_method_data =
+        utils::Encode::<api::PaymentMethodData>::encode_to_value(&payment_method_data)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode payment method data")?;
+    let payment_method_data = domain::types::encrypt(
+        masking::Secret::<serde_json::Value>::new(payment_method_data),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt payment method data")?;
+
+    let payment_method_id = generate_id(consts::ID_LENGTH, "pm");
+    let payment_method = create_payment_method(
+        &*state.store,
+        &req,
+        customer_id,
+        &payment_method_id,
+        merchant_id,
+        pm_metadata,
+        Some(payment_method_data.into()),
+        None,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to add payment method in db")?;
+
+    Ok(api::PaymentMethodResponse {
+        merchant_id: payment_method.merchant_id,
+        customer_id: Some(payment_method.customer_id),
+        payment_method_id: payment_method.payment_method_id,
+        payment_method: req.payment_method,
+        payment_method_type: req.payment_method_type,
+        card: None,
+        metadata: req.metadata,
+        created: Some(payment_method.created_at),
+        network_token_status: None,
+        card_account_status: None,
+        recurring_enabled: false, //[#219]
+        installment_payment_enabled: installments::is_installment_payment_method_type(
+            &state.conf.installments,
+            req.payment_method_type,
+        ),
+        payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]), //[#219]
+    })
+}
+
+/// Decrypts the details of a payment method other than a card, if they were stored along with the
+/// payment method.
+pub async fn decrypt_payment_method_data(
+    pm: &storage::PaymentMethod,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<Option<api::PaymentMethodData>> {
+    let payment_method_data = domain::types::decrypt::<serde_json::Value, masking::WithType>(
+        pm.payment_method_data.clone(),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt payment method data")?;
+
+    payment_method_data
+        .map(|payment_method_data| {
+            payment_method_data
+                .into_inner()
+                .expose()
+                .parse_value("PaymentMethodData")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        })
+        .transpose()
 }
 
 #[instrument(skip_all)]
 pub async fn update_customer_payment_method(
     state: &routes::AppState,
     merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
     req: api::PaymentMethodUpdate,
     payment_method_id: &str,
 ) -> errors::RouterResponse<api::PaymentMethodResponse> {
@@ -128,24 +280,53 @@ pub async fn update_customer_payment_method(
             state,
             &pm.customer_id,
             &pm.merchant_id,
-            &pm.payment_method_id,
+            pm.get_card_reference(),
         )
         .await?;
+        network_tokenization::delete_network_token(state, &pm).await;
     };
+    let (wallet, bank_transfer, bank_debit) =
+        match decrypt_payment_method_data(&pm, &key_store).await? {
+            Some(api::PaymentMethodData::Wallet(wallet)) => (Some(wallet), None, None),
+            Some(api::PaymentMethodData::BankTransfer(bank_transfer)) => {
+                (None, Some(*bank_transfer), None)
+            }
+            Some(api::PaymentMethodData::BankDebit(bank_debit)) => (None, None, Some(bank_debit)),
+            _ => (None, None, None),
+        };
     let new_pm = api::PaymentMethodCreate {
         payment_method: pm.payment_method.foreign_into(),
         payment_method_type: pm.payment_method_type.map(|x| x.foreign_into()),
-        payment_method_issuer: pm.payment_method_issuer,
+        payment_method_issuer: pm.payment_method_issuer.clone(),
         payment_method_issuer_code: pm.payment_method_issuer_code.map(|x| x.foreign_into()),
         card: req.card,
         metadata: req.metadata,
-        customer_id: Some(pm.customer_id),
+        customer_id: Some(pm.customer_id.clone()),
         card_network: req
             .card_network
             .as_ref()
             .map(|card_network| card_network.to_string()),
+        wallet,
+        bank_transfer,
+        bank_debit,
     };
-    add_payment_method(state, new_pm, &merchant_account).await
+    let response = add_payment_method(state, new_pm, &merchant_account, &key_store).await?;
+
+    // The updated payment method is saved under a new ID, which the customer keeps as their
+    // default payment method in case the replaced payment method was their default
+    if let services::ApplicationResponse::Json(payment_method_response) = &response {
+        replace_default_payment_method(
+            state,
+            &pm,
+            Some(payment_method_response.payment_method_id.clone()),
+            &key_store,
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to replace default payment method"))
+        .ok();
+    }
+
+    Ok(response)
 }
 
 // Wrapper function to switch lockers
@@ -162,7 +343,7 @@ pub async fn add_card_to_locker(
     request::record_operation_time(
         async {
             match state.conf.locker.locker_setup {
-                settings::LockerSetup::BasiliskLocker => {
+                settings::LockerSetup::BasiliskLocker | settings::LockerSetup::BuiltinVault => {
                     add_card_hs(state, req, card, customer_id, merchant_account).await
                 }
                 settings::LockerSetup::LegacyLocker => {
@@ -179,6 +360,270 @@ pub async fn add_card_to_locker(
     .await
 }
 
+/// The context from which the key used to fingerprint cards is derived from the key of the merchant
+const CARD_FINGERPRINT_KEY_CONTEXT: &[u8] = b"card_fingerprint";
+
+/// Computes the fingerprint of a card, a keyed hash of the card number using a key derived from
+/// the key of the merchant, which identifies the card across the customers of the merchant without
+/// revealing it.
+pub fn generate_card_fingerprint(
+    card: &api::CardDetail,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<String> {
+    let fingerprint_key = crypto::HmacSha256
+        .sign_message(
+            key_store.key.get_inner().peek(),
+            CARD_FINGERPRINT_KEY_CONTEXT,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to derive card fingerprint key")?;
+    sign_card_number(&fingerprint_key, card)
+}
+
+/// Computes the fingerprint of a card the way it was computed before the fingerprint key was
+/// derived from the key of the merchant, to find the cards saved before then
+fn generate_legacy_card_fingerprint(
+    card: &api::CardDetail,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<String> {
+    sign_card_number(key_store.key.get_inner().peek(), card)
+}
+
+fn sign_card_number(key: &[u8], card: &api::CardDetail) -> errors::RouterResult<String> {
+    let fingerprint = crypto::HmacSha256
+        .sign_message(key, card.card_number.peek().as_bytes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate card fingerprint")?;
+    Ok(hex::encode(fingerprint))
+}
+
+/// Saves a card of the customer in the locker and as a payment method. A card which was saved
+/// before for the customer, identified by its fingerprint, is not saved again: the existing payment
+/// method is returned instead, with its card replaced in the locker if the expiry has changed.
+///
+/// The response will be the tuple of PaymentMethodResponse, the payment method and whether the card
+/// was saved before.
+#[instrument(skip_all)]
+pub async fn add_card_payment_method(
+    state: &routes::AppState,
+    req: api::PaymentMethodCreate,
+    card: api::CardDetail,
+    customer_id: String,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    pm_metadata: Option<serde_json::Value>,
+) -> errors::RouterResult<(api::PaymentMethodResponse, storage::PaymentMethod, bool)> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let card_fingerprint = generate_card_fingerprint(&card, key_store)?;
+
+    let mut existing_pm = None;
+    for fingerprint in [
+        card_fingerprint.clone(),
+        generate_legacy_card_fingerprint(&card, key_store)?,
+    ] {
+        existing_pm = db
+            .find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+                &customer_id,
+                merchant_id,
+                &fingerprint,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find payment method by card fingerprint")?;
+        if existing_pm.is_some() {
+            break;
+        }
+    }
+
+    let (pm, is_duplicate) = match existing_pm {
+        Some(pm) => {
+            let stored_card = get_card_from_locker(
+                state,
+                &customer_id,
+                merchant_id,
+                pm.get_card_reference(),
+                merchant_account.locker_id.clone(),
+            )
+            .await?;
+
+            let pm = if stored_card.card_exp_month.peek() == card.card_exp_month.peek()
+                && stored_card.card_exp_year.peek() == card.card_exp_year.peek()
+            {
+                // Cards saved before their expiry was tracked are picked up by the account updater
+                // once saved again, and cards fingerprinted with the legacy key are fingerprinted
+                // again
+                if pm.card_expires_at.is_none()
+                    || pm.card_fingerprint.as_deref() != Some(card_fingerprint.as_str())
+                {
+                    db.update_payment_method(
+                        pm.clone(),
+                        storage::PaymentMethodUpdate::CardUpdate {
+                            card_reference: None,
+                            card_fingerprint: Some(card_fingerprint.clone()),
+                            card_expires_at: account_updater::get_card_expires_at(&card),
+                        },
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to update payment method in db")?
+                } else {
+                    pm
+                }
+            } else {
+                let (payment_method_response, _) = add_card_to_locker(
+                    state,
+                    req.clone(),
+                    card.clone(),
+                    customer_id.clone(),
+                    merchant_account,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Add Card Failed")?;
+
+                // Lockers which identify duplicate cards by their number return the reference of
+                // the replaced card, which must not be deleted then. Failing to delete the
+                // replaced card must not fail saving the card.
+                let card_reference = payment_method_response.payment_method_id;
+                let is_card_replaced = card_reference != pm.get_card_reference();
+                if is_card_replaced {
+                    delete_card_from_locker(
+                        state,
+                        &customer_id,
+                        merchant_id,
+                        pm.get_card_reference(),
+                    )
+                    .await
+                    .map_err(|error| logger::error!(?error, "Failed to delete replaced card"))
+                    .ok();
+                }
+
+                // The network token of the replaced card is not valid for the new card
+                let pm = if pm.network_token_reference.is_some() {
+                    network_tokenization::delete_network_token(state, &pm).await;
+                    db.update_payment_method(
+                        pm,
+                        storage::PaymentMethodUpdate::NetworkTokenUpdate {
+                            network_token_reference: None,
+                            network_token_status: enums::NetworkTokenStatus::Deleted,
+                            network_token_data: None,
+                        },
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to update payment method in db")?
+                } else {
+                    pm
+                };
+
+                db.update_payment_method(
+                    pm,
+                    storage::PaymentMethodUpdate::CardUpdate {
+                        card_reference: is_card_replaced.then_some(card_reference),
+                        card_fingerprint: Some(card_fingerprint.clone()),
+                        card_expires_at: account_updater::get_card_expires_at(&card),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update payment method in db")?
+            };
+            (pm, true)
+        }
+        None => {
+            let (payment_method_response, is_duplicate) = add_card_to_locker(
+                state,
+                req.clone(),
+                card.clone(),
+                customer_id.clone(),
+                merchant_account,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Add Card Failed")?;
+
+            // The locker identifies duplicate cards as well, returning the reference of the card
+            // saved before, which may not have been fingerprinted yet
+            let existing_pm = if is_duplicate {
+                match db
+                    .find_payment_method(&payment_method_response.payment_method_id)
+                    .await
+                {
+                    Ok(pm) => Some(pm),
+                    Err(error) if error.current_context().is_db_not_found() => None,
+                    Err(error) => Err(error)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to find payment method")?,
+                }
+            } else {
+                None
+            };
+
+            match existing_pm {
+                Some(pm) => {
+                    let card_reference = payment_method_response.payment_method_id;
+                    let is_card_replaced = card_reference != pm.get_card_reference();
+                    let pm = db
+                        .update_payment_method(
+                            pm,
+                            storage::PaymentMethodUpdate::CardUpdate {
+                                card_reference: is_card_replaced.then_some(card_reference),
+                                card_fingerprint: Some(card_fingerprint.clone()),
+                                card_expires_at: account_updater::get_card_expires_at(&card),
+                            },
+                        )
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to update payment method in db")?;
+                    (pm, true)
+                }
+                None => {
+                    let pm = create_payment_method(
+                        db,
+                        &req,
+                        &customer_id,
+                        &payment_method_response.payment_method_id,
+                        merchant_id,
+                        pm_metadata,
+                        None,
+                        Some(card_fingerprint.clone()),
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to add payment method in db")?;
+                    (pm, false)
+                }
+            }
+        }
+    };
+
+    // Cards saved before network tokenization was enabled, or whose network token has been
+    // deleted, are provisioned a new network token
+    let pm = match pm.network_token_status {
+        None | Some(enums::NetworkTokenStatus::Deleted) => {
+            network_tokenization::provision_network_token(state, &card, pm, key_store).await
+        }
+        Some(_) => pm,
+    };
+
+    let mut payment_method_response = payment_methods::mk_add_card_response_hs(
+        card,
+        pm.payment_method_id.clone(),
+        req,
+        merchant_id,
+    );
+    if let Some(card_detail) = payment_method_response.card.as_mut() {
+        card_detail.scheme = pm.scheme.clone();
+        card_detail.card_fingerprint = Some(card_fingerprint.into());
+    }
+    payment_method_response.created = Some(pm.created_at);
+    payment_method_response.network_token_status = pm.network_token_status;
+    payment_method_response.card_account_status = pm.card_account_status;
+
+    Ok((payment_method_response, pm, is_duplicate))
+}
+
 pub async fn get_card_from_locker(
     state: &routes::AppState,
     customer_id: &str,
@@ -199,7 +644,7 @@ pub async fn get_card_from_locker(
                     )
                     .await
                 }
-                settings::LockerSetup::BasiliskLocker => {
+                settings::LockerSetup::BasiliskLocker | settings::LockerSetup::BuiltinVault => {
                     get_card_from_hs_locker(state, customer_id, merchant_id, card_reference)
                         .await
                         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -230,7 +675,7 @@ pub async fn delete_card_from_locker(
                 settings::LockerSetup::LegacyLocker => {
                     delete_card(state, merchant_id, card_reference).await
                 }
-                settings::LockerSetup::BasiliskLocker => {
+                settings::LockerSetup::BasiliskLocker | settings::LockerSetup::BuiltinVault => {
                     delete_card_from_hs_locker(state, customer_id, merchant_id, card_reference)
                         .await
                 }
@@ -245,6 +690,22 @@ pub async fn delete_card_from_locker(
     .await
 }
 
+/// Sends a request to the basilisk locker, or serves it with the built-in vault when it is the
+/// configured locker
+async fn call_hs_locker_api(
+    state: &routes::AppState,
+    request: services::Request,
+) -> errors::CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
+    match state.conf.locker.locker_setup {
+        settings::LockerSetup::BuiltinVault => {
+            builtin_vault::call_builtin_vault(state, request).await
+        }
+        settings::LockerSetup::BasiliskLocker | settings::LockerSetup::LegacyLocker => {
+            services::call_connector_api(state, request).await
+        }
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn add_card_hs(
     state: &routes::AppState,
@@ -267,7 +728,7 @@ pub async fn add_card_hs(
             .await?;
 
     let stored_card_response = if !locker.mock_locker {
-        let response = services::call_connector_api(state, request)
+        let response = call_hs_locker_api(state, request)
             .await
             .change_context(errors::VaultError::SaveCardFailed);
 
@@ -396,7 +857,7 @@ pub async fn get_card_from_hs_locker<'a>(
     .change_context(errors::VaultError::FetchCardFailed)
     .attach_printable("Making get card request failed")?;
     if !locker.mock_locker {
-        let response = services::call_connector_api(state, request)
+        let response = call_hs_locker_api(state, request)
             .await
             .change_context(errors::VaultError::FetchCardFailed)
             .attach_printable("Failed while executing call_connector_api for get_card");
@@ -479,7 +940,7 @@ pub async fn delete_card_from_hs_locker<'a>(
     .attach_printable("Making delete card request failed")?;
 
     if !locker.mock_locker {
-        let response = services::call_connector_api(state, request)
+        let response = call_hs_locker_api(state, request)
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed while executing call_connector_api for delete card");
@@ -833,6 +1294,7 @@ pub async fn list_payment_methods(
             address.as_ref(),
             mca.connector_name,
             pm_config_mapping,
+            &state.conf.installments,
         )
         .await?;
     }
@@ -858,6 +1320,9 @@ pub async fn list_payment_methods(
     let mut bank_transfer_consolidated_hm =
         HashMap::<api_enums::PaymentMethodType, Vec<String>>::new();
 
+    let mut installment_plans_consolidated_hm =
+        HashMap::<api_enums::PaymentMethodType, HashMap<u8, Vec<String>>>::new();
+
     for element in response.clone() {
         let payment_method = element.payment_method;
         let payment_method_type = element.payment_method_type;
@@ -970,8 +1435,48 @@ pub async fn list_payment_methods(
                 bank_transfer_consolidated_hm.insert(element.payment_method_type, vec![connector]);
             }
         }
+
+        for installment_count in element.installment_counts {
+            let connector = element.connector.clone();
+            installment_plans_consolidated_hm
+                .entry(element.payment_method_type)
+                .or_default()
+                .entry(installment_count)
+                .or_default()
+                .push(connector);
+        }
     }
 
+    let payment_amount = payment_attempt
+        .as_ref()
+        .map(|payment_attempt| payment_attempt.amount)
+        .unwrap_or_default();
+    let get_installment_plans = |payment_method_type: &api_enums::PaymentMethodType| {
+        installment_plans_consolidated_hm
+            .get(payment_method_type)
+            .map(|installment_plans_hm| {
+                let mut installment_plans = installment_plans_hm
+                    .iter()
+                    .map(|(installment_count, connectors)| {
+                        let (installment_amount, first_installment_amount) =
+                            installments::get_installment_amounts(
+                                payment_amount,
+                                *installment_count,
+                            );
+                        InstallmentPlanTypes {
+                            installment_count: *installment_count,
+                            installment_amount,
+                            first_installment_amount,
+                            eligible_connectors: connectors.clone(),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                installment_plans
+                    .sort_by_key(|installment_plan| installment_plan.installment_count);
+                installment_plans
+            })
+    };
+
     let mut payment_method_responses: Vec<ResponsePaymentMethodsEnabled> = vec![];
     for key in payment_experiences_consolidated_hm.iter() {
         let mut payment_method_types = vec![];
@@ -991,6 +1496,7 @@ pub async fn list_payment_methods(
                 bank_names: None,
                 bank_debits: None,
                 bank_transfers: None,
+                installment_plans: get_installment_plans(payment_method_types_hm.0),
             })
         }
 
@@ -1018,6 +1524,7 @@ pub async fn list_payment_methods(
                 bank_names: None,
                 bank_debits: None,
                 bank_transfers: None,
+                installment_plans: get_installment_plans(payment_method_types_hm.0),
             })
         }
 
@@ -1041,6 +1548,7 @@ pub async fn list_payment_methods(
                 card_networks: None,
                 bank_debits: None,
                 bank_transfers: None,
+                installment_plans: None,
             }
         })
     }
@@ -1067,6 +1575,7 @@ pub async fn list_payment_methods(
                     eligible_connectors: connectors.clone(),
                 }),
                 bank_transfers: None,
+                installment_plans: None,
             }
         })
     }
@@ -1093,6 +1602,7 @@ pub async fn list_payment_methods(
                 bank_transfers: Some(api_models::payment_methods::BankTransferTypes {
                     eligible_connectors: connectors,
                 }),
+                installment_plans: None,
             }
         })
     }
@@ -1128,6 +1638,7 @@ pub async fn filter_payment_methods(
     address: Option<&domain::Address>,
     connector: String,
     config: &settings::ConnectorFilters,
+    installments_config: &settings::ConnectorInstallments,
 ) -> errors::CustomResult<(), errors::ApiErrorResponse> {
     for payment_method in payment_methods.into_iter() {
         let parse_result = serde_json::from_value::<PaymentMethodsEnabled>(payment_method);
@@ -1218,12 +1729,27 @@ pub async fn filter_payment_methods(
                         &payment_method_object.payment_method_type,
                     );
 
+                    let installment_counts = payment_attempt
+                        .and_then(|payment_attempt| {
+                            payment_attempt.currency.map(|currency| {
+                                installments::get_offered_installment_counts(
+                                    installments_config,
+                                    &connector,
+                                    &payment_method_object,
+                                    currency.foreign_into(),
+                                    payment_attempt.amount,
+                                )
+                            })
+                        })
+                        .unwrap_or_default();
+
                     let connector = connector.clone();
 
                     let response_pm_type = ResponsePaymentMethodIntermediate::new(
                         payment_method_object,
                         connector,
                         payment_method,
+                        installment_counts,
                     );
 
                     if filter && filter2 && filter3 && filter4 && filter5 && filter6 {
@@ -1566,21 +2092,31 @@ pub async fn list_customer_payment_method(
 ) -> errors::RouterResponse<api::CustomerPaymentMethodsListResponse> {
     let db = &*state.store;
 
-    db.find_customer_by_customer_id_merchant_id(
-        customer_id,
-        &merchant_account.merchant_id,
-        &key_store,
-    )
-    .await
-    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            customer_id,
+            &merchant_account.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
 
-    let resp = db
+    let mut resp = db
         .find_payment_method_by_customer_id_merchant_id_list(
             customer_id,
             &merchant_account.merchant_id,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    // The default payment method of the customer is listed first, followed by the remaining
+    // payment methods in the order of their most recent usage
+    let default_payment_method_id = customer.default_payment_method_id;
+    resp.sort_by_key(|pm| {
+        (
+            Some(&pm.payment_method_id) != default_payment_method_id.as_ref(),
+            std::cmp::Reverse(pm.last_used_at),
+        )
+    });
     //let mca = query::find_mca_by_merchant_id(conn, &merchant_account.merchant_id)?;
     let mut customer_pms = Vec::new();
     for pm in resp.into_iter() {
@@ -1595,9 +2131,18 @@ pub async fn list_customer_payment_method(
         } else {
             None
         };
+        // Payment methods other than cards are only decrypted once their token is used for a
+        // payment, rather than every time they are listed
+        let saved_payment_method_id = pm
+            .payment_method_data
+            .is_some()
+            .then(|| pm.payment_method_id.clone());
+        let default_payment_method_set =
+            Some(&pm.payment_method_id) == default_payment_method_id.as_ref();
         //Need validation for enabled payment method ,querying MCA
         let pma = api::CustomerPaymentMethod {
             payment_token: parent_payment_method_token.to_owned(),
+            payment_method_id: pm.payment_method_id,
             customer_id: pm.customer_id,
             payment_method: pm.payment_method.foreign_into(),
             payment_method_type: pm.payment_method_type.map(ForeignInto::foreign_into),
@@ -1611,6 +2156,7 @@ pub async fn list_customer_payment_method(
             installment_payment_enabled: false,
             payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
             created: Some(pm.created_at),
+            default_payment_method_set,
         };
         customer_pms.push(pma.to_owned());
 
@@ -1634,6 +2180,26 @@ pub async fn list_customer_payment_method(
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to add data in redis")?;
 
+        if let Some(saved_payment_method_id) = saved_payment_method_id {
+            redis_conn
+                .set_key_with_expiry(
+                    &helpers::get_saved_payment_method_token_key(
+                        &parent_payment_method_token,
+                        pma.payment_method,
+                    ),
+                    saved_payment_method_id,
+                    consts::TOKEN_TTL,
+                )
+                .await
+                .map_err(|error| {
+                    logger::error!(saved_payment_method_kv_error=?error);
+                    errors::StorageError::KVError
+                })
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to add data in redis")?;
+        }
+
         if let Some(metadata) = pma.metadata {
             let pm_metadata_vec: payment_methods::PaymentMethodMetadata = metadata
                 .parse_value("PaymentMethodMetadata")
@@ -1668,6 +2234,168 @@ pub async fn list_customer_payment_method(
     Ok(services::ApplicationResponse::Json(response))
 }
 
+pub async fn set_default_payment_method(
+    state: &routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    customer_id: &str,
+    payment_method_id: &str,
+) -> errors::RouterResponse<api_models::customers::CustomerDefaultPaymentMethodResponse> {
+    let db = &*state.store;
+
+    db.find_customer_by_customer_id_merchant_id(
+        customer_id,
+        &merchant_account.merchant_id,
+        &key_store,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let pm = db
+        .find_payment_method(payment_method_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    utils::when(
+        pm.merchant_id != merchant_account.merchant_id || pm.customer_id != customer_id,
+        || Err(report!(errors::ApiErrorResponse::PaymentMethodNotFound)),
+    )?;
+    utils::when(
+        pm.card_account_status == Some(enums::CardAccountStatus::Closed),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The card account of this payment method has been closed".to_string(),
+            }))
+        },
+    )?;
+
+    let customer = db
+        .update_customer_by_customer_id_merchant_id(
+            customer_id.to_owned(),
+            merchant_account.merchant_id,
+            domain::CustomerUpdate::DefaultPaymentMethod {
+                default_payment_method_id: Some(pm.payment_method_id),
+            },
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the default payment method of the customer")?;
+
+    Ok(services::ApplicationResponse::Json(
+        api_models::customers::CustomerDefaultPaymentMethodResponse {
+            customer_id: customer.customer_id,
+            default_payment_method_id: customer.default_payment_method_id,
+        },
+    ))
+}
+
+/// Replaces the default payment method of the customer, in case it was the deleted or replaced
+/// payment method, with the replacement payment method if any, or otherwise with the most recently
+/// used payment method of the customer whose card account has not been closed
+async fn replace_default_payment_method(
+    state: &routes::AppState,
+    replaced_pm: &storage::PaymentMethod,
+    replacement_payment_method_id: Option<String>,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<()> {
+    let db = &*state.store;
+    let customer = match db
+        .find_customer_by_customer_id_merchant_id(
+            &replaced_pm.customer_id,
+            &replaced_pm.merchant_id,
+            key_store,
+        )
+        .await
+    {
+        Ok(customer) => customer,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(()),
+        Err(error) => {
+            return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+        }
+    };
+
+    if customer.default_payment_method_id.as_ref() != Some(&replaced_pm.payment_method_id) {
+        return Ok(());
+    }
+
+    let default_payment_method_id = match replacement_payment_method_id {
+        Some(payment_method_id) => Some(payment_method_id),
+        None => match db
+            .find_payment_method_by_customer_id_merchant_id_list(
+                &replaced_pm.customer_id,
+                &replaced_pm.merchant_id,
+            )
+            .await
+        {
+            Ok(payment_methods) => payment_methods
+                .into_iter()
+                .filter(|pm| pm.card_account_status != Some(enums::CardAccountStatus::Closed))
+                .max_by_key(|pm| (pm.last_used_at, pm.created_at))
+                .map(|pm| pm.payment_method_id),
+            Err(error) if error.current_context().is_db_not_found() => None,
+            Err(error) => Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to list the payment methods of the customer")?,
+        },
+    };
+
+    db.update_customer_by_customer_id_merchant_id(
+        customer.customer_id,
+        customer.merchant_id,
+        domain::CustomerUpdate::DefaultPaymentMethod {
+            default_payment_method_id,
+        },
+        key_store,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the default payment method of the customer")?;
+
+    Ok(())
+}
+
+/// Finds the default payment method of a customer. A default payment method which no longer
+/// exists, or whose card account has been closed, is not used.
+pub async fn find_default_payment_method(
+    db: &dyn db::StorageInterface,
+    default_payment_method_id: &str,
+) -> errors::RouterResult<Option<storage::PaymentMethod>> {
+    match db.find_payment_method(default_payment_method_id).await {
+        Ok(pm) if pm.card_account_status == Some(enums::CardAccountStatus::Closed) => Ok(None),
+        Ok(pm) => Ok(Some(pm)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find the default payment method"),
+    }
+}
+
+/// Records the usage of a saved payment method, used for ordering the payment methods of a
+/// customer
+pub async fn update_payment_method_last_used(
+    db: &dyn db::StorageInterface,
+    payment_method_id: &str,
+) {
+    let result = match db.find_payment_method(payment_method_id).await {
+        Ok(pm) => db
+            .update_payment_method(
+                pm,
+                storage::PaymentMethodUpdate::LastUsedUpdate {
+                    last_used_at: common_utils::date_time::now(),
+                },
+            )
+            .await
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => Ok(()),
+        Err(error) => Err(error),
+    };
+
+    if let Err(error) = result {
+        logger::error!(?error, %payment_method_id, "Failed to update the last usage of the payment method");
+    }
+}
+
 pub async fn get_lookup_key_from_locker(
     state: &routes::AppState,
     payment_token: &str,
@@ -1678,7 +2406,7 @@ pub async fn get_lookup_key_from_locker(
         state,
         &pm.customer_id,
         &pm.merchant_id,
-        &pm.payment_method_id,
+        pm.get_card_reference(),
         Some(locker_id.to_string()),
     )
     .await
@@ -1871,13 +2599,25 @@ pub async fn retrieve_payment_method(
         .find_payment_method(&pm.payment_method_id)
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    let payment_method_response =
+        get_payment_method_response(state, pm, merchant_account.locker_id).await?;
+    Ok(services::ApplicationResponse::Json(payment_method_response))
+}
+
+/// Builds the response for a saved payment method, along with the card details from the locker
+/// for saved cards
+pub async fn get_payment_method_response(
+    state: &routes::AppState,
+    pm: storage::PaymentMethod,
+    locker_id: Option<String>,
+) -> errors::RouterResult<api::PaymentMethodResponse> {
     let card = if pm.payment_method == enums::PaymentMethod::Card {
         let card = get_card_from_locker(
             state,
             &pm.customer_id,
             &pm.merchant_id,
-            &pm.payment_method_id,
-            merchant_account.locker_id,
+            pm.get_card_reference(),
+            locker_id,
         )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -1889,21 +2629,24 @@ pub async fn retrieve_payment_method(
     } else {
         None
     };
-    Ok(services::ApplicationResponse::Json(
-        api::PaymentMethodResponse {
-            merchant_id: pm.merchant_id,
-            customer_id: Some(pm.customer_id),
-            payment_method_id: pm.payment_method_id,
-            payment_method: pm.payment_method.foreign_into(),
-            payment_method_type: pm.payment_method_type.map(ForeignInto::foreign_into),
-            card,
-            metadata: pm.metadata,
-            created: Some(pm.created_at),
-            recurring_enabled: false,           //[#219]
-            installment_payment_enabled: false, //[#219]
-            payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]), //[#219],
-        },
-    ))
+    Ok(api::PaymentMethodResponse {
+        merchant_id: pm.merchant_id,
+        customer_id: Some(pm.customer_id),
+        payment_method_id: pm.payment_method_id,
+        payment_method: pm.payment_method.foreign_into(),
+        payment_method_type: pm.payment_method_type.map(ForeignInto::foreign_into),
+        card,
+        metadata: pm.metadata,
+        created: Some(pm.created_at),
+        recurring_enabled: false, //[#219]
+        installment_payment_enabled: installments::is_installment_payment_method_type(
+            &state.conf.installments,
+            pm.payment_method_type.map(ForeignInto::foreign_into),
+        ),
+        payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]), //[#219],
+        network_token_status: pm.network_token_status,
+        card_account_status: pm.card_account_status,
+    })
 }
 
 #[instrument(skip_all)]
@@ -1911,6 +2654,7 @@ pub async fn delete_payment_method(
     state: &routes::AppState,
     merchant_account: domain::MerchantAccount,
     pm: api::PaymentMethodId,
+    key_store: domain::MerchantKeyStore,
 ) -> errors::RouterResponse<api::PaymentMethodDeleteResponse> {
     let (_, supplementary_data) =
         vault::Vault::get_payment_method_data_from_locker(state, &pm.payment_method_id).await?;
@@ -1927,16 +2671,28 @@ pub async fn delete_payment_method(
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
 
     if pm.payment_method == enums::PaymentMethod::Card {
-        let response =
-            delete_card_from_locker(state, &pm.customer_id, &pm.merchant_id, &payment_method_id)
-                .await?;
+        let response = delete_card_from_locker(
+            state,
+            &pm.customer_id,
+            &pm.merchant_id,
+            pm.get_card_reference(),
+        )
+        .await?;
         if response.status == "SUCCESS" {
             print!("Card From locker deleted Successfully")
         } else {
             print!("Error: Deleting Card From Locker")
         }
+        network_tokenization::delete_network_token(state, &pm).await;
     };
 
+    // The payment method has been deleted already, which failing to update the default payment
+    // method of the customer must not fail
+    replace_default_payment_method(state, &pm, None, &key_store)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to replace default payment method"))
+        .ok();
+
     Ok(services::ApplicationResponse::Json(
         api::PaymentMethodDeleteResponse {
             payment_method_id: pm.payment_method_id,
@@ -1944,3 +2700,268 @@ pub async fn delete_payment_method(
         },
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::{db::payment_method::PaymentMethodInterface, utils::test_utils};
+
+    fn card(card_number: &str) -> api::CardDetail {
+        api::CardDetail {
+            card_number: ::cards::CardNumber::from_str(card_number).unwrap(),
+            card_exp_month: "10".to_string().into(),
+            card_exp_year: "25".to_string().into(),
+            card_holder_name: None,
+        }
+    }
+
+    #[test]
+    fn test_card_fingerprint_uses_derived_key() {
+        let merchant_key_store = test_utils::merchant_key_store(test_utils::MERCHANT_KEY);
+        let fingerprint =
+            generate_card_fingerprint(&card("4242424242424242"), &merchant_key_store).unwrap();
+
+        assert_eq!(
+            fingerprint,
+            generate_card_fingerprint(&card("4242424242424242"), &merchant_key_store).unwrap()
+        );
+        assert_ne!(
+            fingerprint,
+            generate_card_fingerprint(&card("4000056655665556"), &merchant_key_store).unwrap()
+        );
+        assert_ne!(
+            fingerprint,
+            generate_card_fingerprint(
+                &card("4242424242424242"),
+                &test_utils::merchant_key_store([2; 32])
+            )
+            .unwrap()
+        );
+        assert_ne!(
+            fingerprint,
+            generate_legacy_card_fingerprint(&card("4242424242424242"), &merchant_key_store)
+                .unwrap()
+        );
+    }
+
+    fn payment_method(
+        payment_method_id: &str,
+        last_used_at: Option<time::PrimitiveDateTime>,
+        card_account_status: Option<enums::CardAccountStatus>,
+    ) -> storage::PaymentMethodNew {
+        storage::PaymentMethodNew {
+            customer_id: "cus_1".into(),
+            merchant_id: "merchant_1".into(),
+            payment_method_id: payment_method_id.into(),
+            card_account_status,
+            last_used_at,
+            ..Default::default()
+        }
+    }
+
+    async fn get_default_payment_method_id(
+        state: &routes::AppState,
+        key_store: &domain::MerchantKeyStore,
+    ) -> Option<String> {
+        state
+            .store
+            .find_customer_by_customer_id_merchant_id("cus_1", "merchant_1", key_store)
+            .await
+            .unwrap()
+            .default_payment_method_id
+    }
+
+    #[tokio::test]
+    async fn test_find_default_payment_method() {
+        let mockdb = db::MockDb::new(&Default::default()).await.unwrap();
+        for pm in [
+            payment_method("pm_active", None, Some(enums::CardAccountStatus::Active)),
+            payment_method("pm_closed", None, Some(enums::CardAccountStatus::Closed)),
+        ] {
+            mockdb.insert_payment_method(pm).await.unwrap();
+        }
+
+        assert!(find_default_payment_method(&mockdb, "pm_active")
+            .await
+            .unwrap()
+            .is_some());
+        assert!(find_default_payment_method(&mockdb, "pm_closed")
+            .await
+            .unwrap()
+            .is_none());
+        assert!(find_default_payment_method(&mockdb, "pm_deleted")
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_replace_default_payment_method() {
+        let (tx, _rx) = tokio::sync::oneshot::channel();
+        let state =
+            routes::AppState::with_storage(Default::default(), db::StorageImpl::Mock, tx).await;
+        let key_store = test_utils::merchant_key_store(test_utils::MERCHANT_KEY);
+        let now = common_utils::date_time::now();
+        state
+            .store
+            .insert_customer(
+                domain::Customer {
+                    id: Some(1),
+                    customer_id: "cus_1".into(),
+                    merchant_id: "merchant_1".into(),
+                    name: None,
+                    email: None,
+                    phone: None,
+                    phone_country_code: None,
+                    description: None,
+                    created_at: now,
+                    metadata: None,
+                    modified_at: now,
+                    connector_customer: None,
+                    default_payment_method_id: Some("pm_default".into()),
+                    email_blind_index: None,
+                    phone_blind_index: None,
+                    default_billing_address_id: None,
+                    default_shipping_address_id: None,
+                },
+                &key_store,
+            )
+            .await
+            .unwrap();
+        let mut payment_methods = Vec::new();
+        for pm in [
+            payment_method("pm_default", None, None),
+            payment_method("pm_old", Some(datetime!(2023-06-01 0:00)), None),
+            payment_method("pm_recent", Some(datetime!(2023-07-01 0:00)), None),
+            payment_method(
+                "pm_closed",
+                Some(datetime!(2023-07-02 0:00)),
+                Some(enums::CardAccountStatus::Closed),
+            ),
+        ] {
+            payment_methods.push(state.store.insert_payment_method(pm).await.unwrap());
+        }
+
+        // Payment methods other than the default one do not change the default payment method
+        replace_default_payment_method(&state, &payment_methods[1], None, &key_store)
+            .await
+            .unwrap();
+        assert_eq!(
+            get_default_payment_method_id(&state, &key_store).await,
+            Some("pm_default".into())
+        );
+
+        // The most recently used payment method whose card account is not closed becomes the
+        // default payment method when the default payment method is deleted
+        let deleted_pm = state
+            .store
+            .delete_payment_method_by_merchant_id_payment_method_id("merchant_1", "pm_default")
+            .await
+            .unwrap();
+        replace_default_payment_method(&state, &deleted_pm, None, &key_store)
+            .await
+            .unwrap();
+        assert_eq!(
+            get_default_payment_method_id(&state, &key_store).await,
+            Some("pm_recent".into())
+        );
+
+        // A replacement payment method becomes the default payment method
+        replace_default_payment_method(
+            &state,
+            &payment_methods[2],
+            Some("pm_updated".into()),
+            &key_store,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            get_default_payment_method_id(&state, &key_store).await,
+            Some("pm_updated".into())
+        );
+    }
+
+    fn payment_method_create(
+        payment_method: api_enums::PaymentMethod,
+        wallet: Option<api_models::payments::WalletData>,
+    ) -> api::PaymentMethodCreate {
+        api::PaymentMethodCreate {
+            payment_method,
+            payment_method_type: None,
+            payment_method_issuer: None,
+            payment_method_issuer_code: None,
+            card: None,
+            metadata: None,
+            customer_id: None,
+            card_network: None,
+            wallet,
+            bank_transfer: None,
+            bank_debit: None,
+        }
+    }
+
+    #[test]
+    fn test_payment_method_data_to_store() {
+        let paypal = payment_method_create(
+            api_enums::PaymentMethod::Wallet,
+            Some(api_models::payments::WalletData::PaypalRedirect(
+                api_models::payments::PaypalRedirection {},
+            )),
+        );
+        assert!(matches!(
+            get_payment_method_data_to_store(&paypal),
+            Some(api::PaymentMethodData::Wallet(
+                api_models::payments::WalletData::PaypalRedirect(_)
+            ))
+        ));
+
+        let pay_later = payment_method_create(api_enums::PaymentMethod::PayLater, None);
+        assert!(get_payment_method_data_to_store(&pay_later).is_none());
+
+        let missing_wallet = payment_method_create(api_enums::PaymentMethod::Wallet, None);
+        assert!(get_payment_method_data_to_store(&missing_wallet).is_none());
+    }
+
+    #[test]
+    fn test_single_use_wallet_data_is_not_stored() {
+        let google_pay = payment_method_create(
+            api_enums::PaymentMethod::Wallet,
+            Some(api_models::payments::WalletData::GooglePay(
+                api_models::payments::GooglePayWalletData {
+                    pm_type: "CARD".to_string(),
+                    description: "Visa 1111".to_string(),
+                    info: api_models::payments::GooglePayPaymentMethodInfo {
+                        card_network: "VISA".to_string(),
+                        card_details: "1111".to_string(),
+                    },
+                    tokenization_data: api_models::payments::GpayTokenizationData {
+                        token_type: "PAYMENT_GATEWAY".to_string(),
+                        token: "gpay_token".to_string(),
+                    },
+                },
+            )),
+        );
+        assert!(get_payment_method_data_to_store(&google_pay).is_none());
+
+        let apple_pay = payment_method_create(
+            api_enums::PaymentMethod::Wallet,
+            Some(api_models::payments::WalletData::ApplePay(
+                api_models::payments::ApplePayWalletData {
+                    payment_data: "apple_pay_data".to_string(),
+                    payment_method: api_models::payments::ApplepayPaymentMethod {
+                        display_name: "Visa 1111".to_string(),
+                        network: "Visa".to_string(),
+                        pm_type: "debit".to_string(),
+                    },
+                    transaction_identifier: "transaction_1".to_string(),
+                },
+            )),
+        );
+        assert!(get_payment_method_data_to_store(&apple_pay).is_none());
+    }
+}
@@ -0,0 +1,296 @@
+use api_models::{admin::PaymentMethodsEnabled, enums as api_enums};
+
+use crate::{
+    configs::settings,
+    core::errors::{self, RouterResult, StorageErrorExt},
+    logger,
+    routes::AppState,
+    types::{domain, storage::enums as storage_enums, transformers::ForeignInto},
+};
+
+/// The smallest number of installments a payment can be split into
+pub const MIN_INSTALLMENT_COUNT: u8 = 2;
+
+/// Returns the installment counts offered for a payment method type enabled on a merchant
+/// connector account, which are the counts in the merchant's installment plans for the payment
+/// currency and amount that are also supported by the connector
+pub fn get_offered_installment_counts(
+    config: &settings::ConnectorInstallments,
+    connector: &str,
+    payment_method_type: &api_models::payment_methods::RequestPaymentMethodTypes,
+    currency: api_enums::Currency,
+    amount: i64,
+) -> Vec<u8> {
+    if !payment_method_type.installment_payment_enabled {
+        return Vec::new();
+    }
+
+    let Some(connector_filter) = config
+        .0
+        .get(connector)
+        .and_then(|filters| filters.get(&payment_method_type.payment_method_type))
+    else {
+        return Vec::new();
+    };
+
+    let is_currency_supported = connector_filter
+        .currency
+        .as_ref()
+        .map_or(true, |currencies| currencies.contains(&currency));
+    if !is_currency_supported {
+        return Vec::new();
+    }
+
+    let mut installment_counts = payment_method_type
+        .installment_plans
+        .iter()
+        .flatten()
+        .filter(|plan| {
+            plan.currency == currency
+                && plan
+                    .minimum_amount
+                    .map_or(true, |minimum_amount| amount >= minimum_amount)
+        })
+        .flat_map(|plan| plan.installment_counts.iter().copied())
+        .filter(|count| {
+            *count >= MIN_INSTALLMENT_COUNT && connector_filter.installment_counts.contains(count)
+        })
+        .collect::<Vec<_>>();
+    installment_counts.sort_unstable();
+    installment_counts.dedup();
+    installment_counts
+}
+
+/// Checks whether payments made with the payment method type can be paid in installments with any
+/// of the connectors
+pub fn is_installment_payment_method_type(
+    config: &settings::ConnectorInstallments,
+    payment_method_type: Option<api_enums::PaymentMethodType>,
+) -> bool {
+    payment_method_type.map_or(false, |payment_method_type| {
+        config.0.values().any(|filters| {
+            filters
+                .get(&payment_method_type)
+                .map_or(false, |filter| !filter.installment_counts.is_empty())
+        })
+    })
+}
+
+/// Splits the amount into installments, returning the amount of each installment and the amount
+/// of the first installment, which also carries the remainder of the split
+pub fn get_installment_amounts(amount: i64, installment_count: u8) -> (i64, i64) {
+    let installment_count = i64::from(installment_count.max(1));
+    let installment_amount = amount / installment_count;
+    (
+        installment_amount,
+        installment_amount + amount % installment_count,
+    )
+}
+
+/// Validates that the installment count selected for a payment is offered by at least one of the
+/// merchant's connector accounts for the payment method type, currency and amount of the payment.
+/// Once the payment has been routed, only the accounts of the connector it is routed to are
+/// considered, since the other connectors would not receive the installment count.
+#[allow(clippy::too_many_arguments)]
+pub async fn validate_installment_count_is_offered(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    connector_name: Option<&str>,
+    installment_count: u8,
+    payment_method_type: Option<storage_enums::PaymentMethodType>,
+    currency: storage_enums::Currency,
+    amount: i64,
+) -> RouterResult<()> {
+    let payment_method_type: api_enums::PaymentMethodType = payment_method_type
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "`payment_method_type` is required to pay in installments".to_string(),
+        })?
+        .foreign_into();
+    let currency: api_enums::Currency = currency.foreign_into();
+
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &merchant_account.merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let is_offered = merchant_connector_accounts
+        .iter()
+        .filter(|mca| {
+            connector_name.map_or(true, |connector_name| mca.connector_name == connector_name)
+        })
+        .any(|mca| {
+            mca.payment_methods_enabled
+                .iter()
+                .flatten()
+                .filter_map(|payment_methods_enabled| {
+                    serde_json::from_value::<PaymentMethodsEnabled>(
+                        payment_methods_enabled.clone(),
+                    )
+                    .map_err(|error| {
+                        logger::error!(%error, "Failed to deserialize payment methods enabled")
+                    })
+                    .ok()
+                })
+                .flat_map(|payment_methods_enabled| {
+                    payment_methods_enabled
+                        .payment_method_types
+                        .unwrap_or_default()
+                })
+                .filter(|request_payment_method_type| {
+                    request_payment_method_type.payment_method_type == payment_method_type
+                })
+                .any(|request_payment_method_type| {
+                    get_offered_installment_counts(
+                        &state.conf.installments,
+                        &mca.connector_name,
+                        &request_payment_method_type,
+                        currency,
+                        amount,
+                    )
+                    .contains(&installment_count)
+                })
+        });
+
+    if is_offered {
+        Ok(())
+    } else {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: match connector_name {
+                Some(connector_name) => format!(
+                    "An installment plan of {installment_count} installments is not offered by {connector_name} for this payment"
+                ),
+                None => format!(
+                    "An installment plan of {installment_count} installments is not offered for this payment"
+                ),
+            },
+        }
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use api_models::payment_methods::{InstallmentPlanConfig, RequestPaymentMethodTypes};
+
+    use super::*;
+
+    fn get_config(currency: Option<api_enums::Currency>) -> settings::ConnectorInstallments {
+        settings::ConnectorInstallments(HashMap::from([(
+            "stripe".to_string(),
+            HashMap::from([(
+                api_enums::PaymentMethodType::Credit,
+                settings::InstallmentFilter {
+                    currency: currency.map(|currency| HashSet::from([currency])),
+                    installment_counts: HashSet::from([3, 6, 9, 12]),
+                },
+            )]),
+        )]))
+    }
+
+    fn get_payment_method_type(
+        installment_payment_enabled: bool,
+        installment_plans: Vec<InstallmentPlanConfig>,
+    ) -> RequestPaymentMethodTypes {
+        RequestPaymentMethodTypes {
+            payment_method_type: api_enums::PaymentMethodType::Credit,
+            payment_experience: None,
+            card_networks: None,
+            accepted_currencies: None,
+            accepted_countries: None,
+            minimum_amount: None,
+            maximum_amount: None,
+            recurring_enabled: true,
+            installment_payment_enabled,
+            installment_plans: Some(installment_plans),
+        }
+    }
+
+    fn get_plan(installment_counts: Vec<u8>, minimum_amount: Option<i64>) -> InstallmentPlanConfig {
+        InstallmentPlanConfig {
+            currency: api_enums::Currency::MXN,
+            installment_counts,
+            minimum_amount,
+        }
+    }
+
+    #[test]
+    fn test_offered_installment_counts_are_supported_by_connector() {
+        let config = get_config(Some(api_enums::Currency::MXN));
+        let payment_method_type = get_payment_method_type(
+            true,
+            vec![
+                get_plan(vec![12, 3, 1, 24], None),
+                get_plan(vec![6, 3], Some(50000)),
+                get_plan(vec![9], Some(200000)),
+            ],
+        );
+
+        let installment_counts = get_offered_installment_counts(
+            &config,
+            "stripe",
+            &payment_method_type,
+            api_enums::Currency::MXN,
+            100000,
+        );
+
+        assert_eq!(installment_counts, vec![3, 6, 12]);
+    }
+
+    #[test]
+    fn test_no_installment_counts_offered() {
+        let config = get_config(Some(api_enums::Currency::MXN));
+        let plans = vec![get_plan(vec![3, 6], None)];
+
+        let disabled = get_payment_method_type(false, plans.clone());
+        assert!(get_offered_installment_counts(
+            &config,
+            "stripe",
+            &disabled,
+            api_enums::Currency::MXN,
+            100000
+        )
+        .is_empty());
+
+        let enabled = get_payment_method_type(true, plans);
+        assert!(get_offered_installment_counts(
+            &config,
+            "adyen",
+            &enabled,
+            api_enums::Currency::MXN,
+            100000
+        )
+        .is_empty());
+        assert!(get_offered_installment_counts(
+            &config,
+            "stripe",
+            &enabled,
+            api_enums::Currency::USD,
+            100000
+        )
+        .is_empty());
+
+        let usd_config = get_config(Some(api_enums::Currency::USD));
+        assert!(get_offered_installment_counts(
+            &usd_config,
+            "stripe",
+            &enabled,
+            api_enums::Currency::MXN,
+            100000
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_installment_amounts() {
+        assert_eq!(get_installment_amounts(10000, 6), (1666, 1670));
+        assert_eq!(get_installment_amounts(12000, 3), (4000, 4000));
+    }
+}
@@ -0,0 +1,462 @@
+use std::str::FromStr;
+
+use common_utils::{ext_traits::ValueExt, generate_id};
+use error_stack::{report, IntoReport, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use rand::Rng;
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings,
+    consts,
+    core::errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+    logger,
+    routes::{metrics, AppState},
+    services,
+    types::{
+        self, api, domain,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+/// Network token provisioned for a card, stored encrypted along with the payment method
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct NetworkToken {
+    pub token_number: Secret<String>,
+    pub token_exp_month: Secret<String>,
+    pub token_exp_year: Secret<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProvisionedNetworkToken {
+    /// Reference with which the token is identified by the token service provider
+    pub token_reference: String,
+    pub token: NetworkToken,
+}
+
+#[derive(Debug, Clone)]
+pub struct NetworkTokenCryptogram {
+    pub cryptogram: Secret<String>,
+    pub eci: Option<String>,
+}
+
+/// A token service provider, which provisions network tokens for cards on behalf of the card
+/// networks and manages their lifecycle
+#[async_trait::async_trait]
+pub trait TokenServiceProvider: Send + Sync {
+    async fn provision_token(
+        &self,
+        state: &AppState,
+        card: &api::CardDetail,
+    ) -> CustomResult<ProvisionedNetworkToken, errors::NetworkTokenizationError>;
+
+    /// Generates the cryptogram with which a single payment is made using the network token
+    async fn get_cryptogram(
+        &self,
+        state: &AppState,
+        token_reference: &str,
+    ) -> CustomResult<NetworkTokenCryptogram, errors::NetworkTokenizationError>;
+
+    async fn suspend_token(
+        &self,
+        state: &AppState,
+        token_reference: &str,
+    ) -> CustomResult<(), errors::NetworkTokenizationError>;
+
+    async fn resume_token(
+        &self,
+        state: &AppState,
+        token_reference: &str,
+    ) -> CustomResult<(), errors::NetworkTokenizationError>;
+
+    async fn delete_token(
+        &self,
+        state: &AppState,
+        token_reference: &str,
+    ) -> CustomResult<(), errors::NetworkTokenizationError>;
+}
+
+pub fn get_token_service_provider(
+    config: &settings::NetworkTokenization,
+) -> Box<dyn TokenServiceProvider> {
+    match config.provider {
+        settings::TokenServiceProviderKind::Mock => Box::new(MockTokenServiceProvider),
+    }
+}
+
+/// Token service provider emulated locally. Tokens keep the BIN and length of the card, so that
+/// the card network can still be identified from them.
+pub struct MockTokenServiceProvider;
+
+#[async_trait::async_trait]
+impl TokenServiceProvider for MockTokenServiceProvider {
+    async fn provision_token(
+        &self,
+        _state: &AppState,
+        card: &api::CardDetail,
+    ) -> CustomResult<ProvisionedNetworkToken, errors::NetworkTokenizationError> {
+        let token_number = generate_mock_token_number(card.card_number.peek())
+            .ok_or(errors::NetworkTokenizationError::ProvisionTokenFailed)
+            .into_report()
+            .attach_printable("Card number is not valid for provisioning a network token")?;
+
+        Ok(ProvisionedNetworkToken {
+            token_reference: generate_id(consts::ID_LENGTH, "ntr"),
+            token: NetworkToken {
+                token_number: token_number.into(),
+                token_exp_month: card.card_exp_month.clone(),
+                token_exp_year: card.card_exp_year.clone(),
+            },
+        })
+    }
+
+    async fn get_cryptogram(
+        &self,
+        _state: &AppState,
+        _token_reference: &str,
+    ) -> CustomResult<NetworkTokenCryptogram, errors::NetworkTokenizationError> {
+        let cryptogram: [u8; 20] = rand::random();
+        Ok(NetworkTokenCryptogram {
+            cryptogram: base64::Engine::encode(&consts::BASE64_ENGINE, cryptogram).into(),
+            eci: Some("07".to_string()),
+        })
+    }
+
+    async fn suspend_token(
+        &self,
+        _state: &AppState,
+        _token_reference: &str,
+    ) -> CustomResult<(), errors::NetworkTokenizationError> {
+        Ok(())
+    }
+
+    async fn resume_token(
+        &self,
+        _state: &AppState,
+        _token_reference: &str,
+    ) -> CustomResult<(), errors::NetworkTokenizationError> {
+        Ok(())
+    }
+
+    async fn delete_token(
+        &self,
+        _state: &AppState,
+        _token_reference: &str,
+    ) -> CustomResult<(), errors::NetworkTokenizationError> {
+        Ok(())
+    }
+}
+
+/// Generates a Luhn valid number of the same BIN and length as the card number
+pub(super) fn generate_mock_token_number(card_number: &str) -> Option<String> {
+    let bin = card_number.get(..6)?;
+    let mut rng = rand::thread_rng();
+    let mut token_number = bin.to_string();
+    for _ in bin.len()..card_number.len() - 1 {
+        token_number.push(char::from_digit(rng.gen_range(0..10), 10)?);
+    }
+    let check_digit = luhn_check_digit(&token_number)?;
+    token_number.push(char::from_digit(check_digit, 10)?);
+    Some(token_number)
+}
+
+fn luhn_check_digit(payload: &str) -> Option<u32> {
+    let sum = payload
+        .chars()
+        .rev()
+        .enumerate()
+        .try_fold(0, |sum, (index, character)| {
+            let digit = character.to_digit(10)?;
+            let digit = if index % 2 == 0 {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                digit
+            };
+            Some(sum + digit)
+        })?;
+    Some((10 - sum % 10) % 10)
+}
+
+/// Provisions a network token for the card of a saved payment method, storing the token along
+/// with the payment method. Failing to provision the token does not fail saving the card, the card
+/// number keeps being used for payments instead.
+#[instrument(skip_all)]
+pub async fn provision_network_token(
+    state: &AppState,
+    card: &api::CardDetail,
+    payment_method: storage::PaymentMethod,
+    key_store: &domain::MerchantKeyStore,
+) -> storage::PaymentMethod {
+    if !state.conf.network_tokenization.enabled {
+        return payment_method;
+    }
+
+    match store_network_token(state, card, payment_method.clone(), key_store).await {
+        Ok(payment_method) => {
+            metrics::NETWORK_TOKENS_PROVISIONED.add(&metrics::CONTEXT, 1, &[]);
+            payment_method
+        }
+        Err(error) => {
+            metrics::NETWORK_TOKEN_FAILURES.add(&metrics::CONTEXT, 1, &[]);
+            logger::error!(?error, "Failed to provision network token");
+            payment_method
+        }
+    }
+}
+
+async fn store_network_token(
+    state: &AppState,
+    card: &api::CardDetail,
+    payment_method: storage::PaymentMethod,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<storage::PaymentMethod> {
+    let provisioned_token = get_token_service_provider(&state.conf.network_tokenization)
+        .provision_token(state, card)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let network_token_data =
+        utils::Encode::<NetworkToken>::encode_to_value(&provisioned_token.token)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode network token")?;
+    let network_token_data = domain::types::encrypt(
+        Secret::<serde_json::Value>::new(network_token_data),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt network token")?;
+
+    state
+        .store
+        .update_payment_method(
+            payment_method,
+            storage::PaymentMethodUpdate::NetworkTokenUpdate {
+                network_token_reference: Some(provisioned_token.token_reference),
+                network_token_status: enums::NetworkTokenStatus::Active,
+                network_token_data: Some(network_token_data.into()),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update network token of payment method")
+}
+
+/// Fetches the network token of the saved card used for a payment, along with a cryptogram for the
+/// payment, if the connector accepts network tokens. The card number is used for the payment when
+/// no active network token is available or the token service provider fails.
+#[instrument(skip_all)]
+pub async fn get_network_token_for_payment(
+    state: &AppState,
+    connector_name: &str,
+    key_store: &domain::MerchantKeyStore,
+    payment_method_id: Option<&str>,
+) -> Option<types::NetworkTokenData> {
+    let config = &state.conf.network_tokenization;
+    let is_connector_supported = api_models::enums::Connector::from_str(connector_name)
+        .map(|connector| config.connector_list.contains(&connector))
+        .unwrap_or(false);
+    if !config.enabled || !is_connector_supported {
+        return None;
+    }
+
+    let payment_method_id = payment_method_id?;
+    match fetch_network_token_data(state, key_store, payment_method_id).await {
+        Ok(network_token) => {
+            if network_token.is_some() {
+                metrics::NETWORK_TOKEN_PAYMENTS.add(&metrics::CONTEXT, 1, &[]);
+            }
+            network_token
+        }
+        Err(error) => {
+            metrics::NETWORK_TOKEN_FAILURES.add(&metrics::CONTEXT, 1, &[]);
+            logger::error!(
+                ?error,
+                "Failed to fetch network token, falling back to card number"
+            );
+            None
+        }
+    }
+}
+
+async fn fetch_network_token_data(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    payment_method_id: &str,
+) -> RouterResult<Option<types::NetworkTokenData>> {
+    let payment_method = state
+        .store
+        .find_payment_method(payment_method_id)
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    let Some(token_reference) = payment_method
+        .network_token_reference
+        .as_ref()
+        .filter(|_| payment_method.network_token_status == Some(enums::NetworkTokenStatus::Active))
+    else {
+        return Ok(None);
+    };
+
+    let network_token = domain::types::decrypt::<serde_json::Value, masking::WithType>(
+        payment_method.network_token_data.clone(),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt network token")?;
+    let Some(network_token) = network_token else {
+        return Ok(None);
+    };
+    let network_token: NetworkToken = network_token
+        .into_inner()
+        .expose()
+        .parse_value("NetworkToken")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let cryptogram = get_token_service_provider(&state.conf.network_tokenization)
+        .get_cryptogram(state, token_reference)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let token_number = cards::CardNumber::from_str(network_token.token_number.peek())
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Network token is not a valid card number")?;
+
+    Ok(Some(types::NetworkTokenData {
+        token_number,
+        token_exp_month: network_token.token_exp_month,
+        token_exp_year: network_token.token_exp_year,
+        token_cryptogram: cryptogram.cryptogram,
+        eci: cryptogram.eci,
+    }))
+}
+
+/// Deletes the network token of a payment method being deleted with the token service provider.
+/// Failing to delete the token does not fail deleting the payment method.
+#[instrument(skip_all)]
+pub async fn delete_network_token(state: &AppState, payment_method: &storage::PaymentMethod) {
+    let Some(token_reference) = payment_method.network_token_reference.as_ref().filter(|_| {
+        payment_method.network_token_status != Some(enums::NetworkTokenStatus::Deleted)
+    }) else {
+        return;
+    };
+
+    get_token_service_provider(&state.conf.network_tokenization)
+        .delete_token(state, token_reference)
+        .await
+        .map_err(|error| {
+            metrics::NETWORK_TOKEN_FAILURES.add(&metrics::CONTEXT, 1, &[]);
+            logger::error!(?error, "Failed to delete network token");
+        })
+        .ok();
+}
+
+/// Moves the network token of a payment method to the requested lifecycle status, with the token
+/// service provider as well as on the payment method
+#[instrument(skip_all)]
+pub async fn update_network_token(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    payment_method_id: &str,
+    req: api::NetworkTokenUpdateRequest,
+) -> RouterResponse<api::NetworkTokenResponse> {
+    let db = &*state.store;
+    let payment_method = db
+        .find_payment_method(payment_method_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    utils::when(
+        payment_method.merchant_id != merchant_account.merchant_id,
+        || Err(report!(errors::ApiErrorResponse::PaymentMethodNotFound)),
+    )?;
+
+    let (Some(token_reference), Some(current_status)) = (
+        payment_method.network_token_reference.clone(),
+        payment_method.network_token_status,
+    ) else {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "no network token has been provisioned for the payment method".to_string(),
+        }));
+    };
+
+    if current_status != req.status {
+        let token_service_provider = get_token_service_provider(&state.conf.network_tokenization);
+        let result = match (current_status, req.status) {
+            (enums::NetworkTokenStatus::Active, enums::NetworkTokenStatus::Suspended) => {
+                token_service_provider
+                    .suspend_token(state, &token_reference)
+                    .await
+            }
+            (enums::NetworkTokenStatus::Suspended, enums::NetworkTokenStatus::Active) => {
+                token_service_provider
+                    .resume_token(state, &token_reference)
+                    .await
+            }
+            (_, enums::NetworkTokenStatus::Deleted) => {
+                token_service_provider
+                    .delete_token(state, &token_reference)
+                    .await
+            }
+            (current_status, status) => {
+                return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: format!(
+                        "network token cannot be moved from {current_status} to {status} status"
+                    ),
+                }))
+            }
+        };
+        result
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update network token with the token service provider")?;
+
+        db.update_payment_method(
+            payment_method,
+            storage::PaymentMethodUpdate::NetworkTokenUpdate {
+                network_token_reference: None,
+                network_token_status: req.status,
+                network_token_data: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update network token status of payment method")?;
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        api::NetworkTokenResponse {
+            payment_method_id: payment_method_id.to_string(),
+            status: req.status,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_luhn_check_digit() {
+        assert_eq!(luhn_check_digit("424242424242424"), Some(2));
+        assert_eq!(luhn_check_digit("555555555555444"), Some(4));
+        assert_eq!(luhn_check_digit("42424242424242a"), None);
+    }
+
+    #[test]
+    fn test_mock_token_number_keeps_bin_and_length() {
+        let card_number = "4242424242424242";
+        let token_number = generate_mock_token_number(card_number).unwrap();
+
+        assert_eq!(token_number.len(), card_number.len());
+        assert_eq!(&token_number[..6], &card_number[..6]);
+        assert!(cards::CardNumber::from_str(&token_number).is_ok());
+    }
+}
@@ -308,6 +308,8 @@ pub fn mk_add_card_response_hs(
         card: Some(card),
         metadata: req.metadata,
         created: Some(common_utils::date_time::now()),
+        network_token_status: None,
+        card_account_status: None,
         recurring_enabled: false,           // [#256]
         installment_payment_enabled: false, // #[#256]
         payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]), // [#256]
@@ -341,6 +343,8 @@ pub fn mk_add_card_response(
         card: Some(card),
         metadata: req.metadata,
         created: Some(common_utils::date_time::now()),
+        network_token_status: None,
+        card_account_status: None,
         recurring_enabled: false,           // [#256]
         installment_payment_enabled: false, // [#256] Pending on discussion, and not stored in the card locker
         payment_experience: None,           // [#256]
@@ -553,7 +557,7 @@ pub fn get_card_detail(
         expiry_month: Some(response.card_exp_month),
         expiry_year: Some(response.card_exp_year),
         card_token: None,
-        card_fingerprint: None,
+        card_fingerprint: pm.card_fingerprint.clone().map(Secret::new),
         card_holder_name: response.name_on_card,
     };
     Ok(card_detail)
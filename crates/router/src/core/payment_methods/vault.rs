@@ -11,7 +11,10 @@ use router_env::{instrument, tracing};
 use crate::routes::metrics;
 use crate::{
     configs::settings,
-    core::errors::{self, CustomResult, RouterResult},
+    core::{
+        errors::{self, CustomResult, RouterResult},
+        payment_methods::builtin_vault,
+    },
     logger, routes,
     types::{
         api,
@@ -161,6 +164,50 @@ impl Vaultable for api_models::payments::BankTransferData {
     }
 }
 
+impl Vaultable for api_models::payments::BankDebitData {
+    fn get_value1(&self, _customer_id: Option<String>) -> CustomResult<String, errors::VaultError> {
+        let value1 = api_models::payment_methods::TokenizedBankDebitValue1 {
+            data: self.to_owned(),
+        };
+
+        utils::Encode::<api_models::payment_methods::TokenizedBankDebitValue1>::encode_to_string_of_json(&value1)
+            .change_context(errors::VaultError::RequestEncodingFailed)
+            .attach_printable("Failed to encode bank debit data")
+    }
+
+    fn get_value2(&self, customer_id: Option<String>) -> CustomResult<String, errors::VaultError> {
+        let value2 = api_models::payment_methods::TokenizedBankDebitValue2 { customer_id };
+
+        utils::Encode::<api_models::payment_methods::TokenizedBankDebitValue2>::encode_to_string_of_json(&value2)
+            .change_context(errors::VaultError::RequestEncodingFailed)
+            .attach_printable("Failed to encode bank debit supplementary data")
+    }
+
+    fn from_values(
+        value1: String,
+        value2: String,
+    ) -> CustomResult<(Self, SupplementaryVaultData), errors::VaultError> {
+        let value1: api_models::payment_methods::TokenizedBankDebitValue1 = value1
+            .parse_struct("TokenizedBankDebitValue1")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+            .attach_printable("Could not deserialize into bank debit data")?;
+
+        let value2: api_models::payment_methods::TokenizedBankDebitValue2 = value2
+            .parse_struct("TokenizedBankDebitValue2")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+            .attach_printable("Could not deserialize into supplementary bank debit data")?;
+
+        let bank_debit_data = value1.data;
+
+        let supp_data = SupplementaryVaultData {
+            customer_id: value2.customer_id,
+            payment_method_id: None,
+        };
+
+        Ok((bank_debit_data, supp_data))
+    }
+}
+
 impl Vaultable for api::WalletData {
     fn get_value1(&self, _customer_id: Option<String>) -> CustomResult<String, errors::VaultError> {
         let value1 = api::TokenizedWalletValue1 {
@@ -211,6 +258,7 @@ pub enum VaultPaymentMethod {
     Card(String),
     Wallet(String),
     BankTransfer(String),
+    BankDebit(String),
 }
 
 impl Vaultable for api::PaymentMethodData {
@@ -221,6 +269,9 @@ impl Vaultable for api::PaymentMethodData {
             Self::BankTransfer(bank_transfer) => {
                 VaultPaymentMethod::BankTransfer(bank_transfer.get_value1(customer_id)?)
             }
+            Self::BankDebit(bank_debit) => {
+                VaultPaymentMethod::BankDebit(bank_debit.get_value1(customer_id)?)
+            }
             _ => Err(errors::VaultError::PaymentMethodNotSupported)
                 .into_report()
                 .attach_printable("Payment method not supported")?,
@@ -238,6 +289,9 @@ impl Vaultable for api::PaymentMethodData {
             Self::BankTransfer(bank_transfer) => {
                 VaultPaymentMethod::BankTransfer(bank_transfer.get_value2(customer_id)?)
             }
+            Self::BankDebit(bank_debit) => {
+                VaultPaymentMethod::BankDebit(bank_debit.get_value2(customer_id)?)
+            }
             _ => Err(errors::VaultError::PaymentMethodNotSupported)
                 .into_report()
                 .attach_printable("Payment method not supported")?,
@@ -279,6 +333,11 @@ impl Vaultable for api::PaymentMethodData {
                     api_models::payments::BankTransferData::from_values(mvalue1, mvalue2)?;
                 Ok((Self::BankTransfer(Box::new(bank_transfer)), supp_data))
             }
+            (VaultPaymentMethod::BankDebit(mvalue1), VaultPaymentMethod::BankDebit(mvalue2)) => {
+                let (bank_debit, supp_data) =
+                    api_models::payments::BankDebitData::from_values(mvalue1, mvalue2)?;
+                Ok((Self::BankDebit(bank_debit), supp_data))
+            }
             _ => Err(errors::VaultError::PaymentMethodNotSupported)
                 .into_report()
                 .attach_printable("Payment method not supported"),
@@ -301,15 +360,22 @@ impl Vault {
         state: &routes::AppState,
         lookup_key: &str,
     ) -> RouterResult<(Option<api::PaymentMethodData>, SupplementaryVaultData)> {
-        let config = state
-            .store
-            .find_config_by_key(lookup_key)
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Could not find payment method in vault")?;
+        let value = if builtin_vault::is_enabled(&state.conf.locker) {
+            builtin_vault::retrieve_token(state, lookup_key)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Could not find payment method in vault")?
+        } else {
+            state
+                .store
+                .find_config_by_key(lookup_key)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Could not find payment method in vault")?
+                .config
+        };
 
-        let tokenize_value: MockTokenizeDBValue = config
-            .config
+        let tokenize_value: MockTokenizeDBValue = value
             .parse_struct("MockTokenizeDBValue")
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Unable to deserialize Mock tokenize db value")?;
@@ -349,6 +415,15 @@ impl Vault {
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Failed to encode payment method as mock tokenize db value")?;
 
+        if builtin_vault::is_enabled(&state.conf.locker) {
+            builtin_vault::store_token(state, &lookup_key, &value_string)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to save payment method in built-in vault")?;
+
+            return Ok(lookup_key);
+        }
+
         let already_present = state.store.find_config_by_key(&lookup_key).await;
 
         if already_present.is_err() {
@@ -385,6 +460,16 @@ impl Vault {
     ) {
         let db = &*state.store;
         if let Some(id) = lookup_key {
+            if builtin_vault::is_enabled(&state.conf.locker) {
+                match builtin_vault::delete_token(state, id).await {
+                    Ok(_) => logger::info!("Card Deleted from built-in vault"),
+                    Err(err) => {
+                        logger::error!("Err: Card Delete from built-in vault Failed : {}", err)
+                    }
+                }
+                return;
+            }
+
             match db.delete_config_by_key(id).await {
                 Ok(_) => logger::info!("Card Deleted from locker mock up"),
                 Err(err) => logger::error!("Err: Card Delete from locker Failed : {}", err),
@@ -491,6 +576,10 @@ pub async fn create_tokenize(
     lookup_key: String,
 ) -> RouterResult<String> {
     metrics::CREATED_TOKENIZED_CARD.add(&metrics::CONTEXT, 1, &[]);
+    if builtin_vault::is_enabled(&state.conf.locker) {
+        return create_tokenize_in_builtin_vault(state, value1, value2, lookup_key).await;
+    }
+
     let payload_to_be_encrypted = api::TokenizePayloadRequest {
         value1,
         value2: value2.unwrap_or_default(),
@@ -571,6 +660,10 @@ pub async fn get_tokenized_data(
     should_get_value2: bool,
 ) -> RouterResult<api::TokenizePayloadRequest> {
     metrics::GET_TOKENIZED_CARD.add(&metrics::CONTEXT, 1, &[]);
+    if builtin_vault::is_enabled(&state.conf.locker) {
+        return get_tokenized_data_from_builtin_vault(state, lookup_key, should_get_value2).await;
+    }
+
     let payload_to_be_encrypted = api::GetTokenizePayloadRequest {
         lookup_key: lookup_key.to_string(),
         get_value2: should_get_value2,
@@ -649,6 +742,14 @@ pub async fn delete_tokenized_data(
     lookup_key: &str,
 ) -> RouterResult<String> {
     metrics::DELETED_TOKENIZED_CARD.add(&metrics::CONTEXT, 1, &[]);
+    if builtin_vault::is_enabled(&state.conf.locker) {
+        builtin_vault::delete_token(state, lookup_key)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete payment method from built-in vault")?;
+        return Ok("Ok".to_string());
+    }
+
     let payload_to_be_encrypted = api::DeleteTokenizeByTokenRequest {
         lookup_key: lookup_key.to_string(),
         service_name: VAULT_SERVICE_NAME.to_string(),
@@ -696,6 +797,64 @@ pub async fn delete_tokenized_data(
     }
 }
 
+/// Stores the tokenized data in the built-in vault, which serves as the temporary locker in place
+/// of basilisk when it is the configured locker
+#[cfg(feature = "basilisk")]
+async fn create_tokenize_in_builtin_vault(
+    state: &routes::AppState,
+    value1: String,
+    value2: Option<String>,
+    lookup_key: String,
+) -> RouterResult<String> {
+    let db_value = MockTokenizeDBValue {
+        value1,
+        value2: value2.unwrap_or_default(),
+    };
+    let value_string = utils::Encode::<MockTokenizeDBValue>::encode_to_string_of_json(&db_value)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode payment method as mock tokenize db value")?;
+
+    builtin_vault::store_token(state, &lookup_key, &value_string)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to save payment method in built-in vault")?;
+
+    Ok(lookup_key)
+}
+
+#[cfg(feature = "basilisk")]
+async fn get_tokenized_data_from_builtin_vault(
+    state: &routes::AppState,
+    lookup_key: &str,
+    should_get_value2: bool,
+) -> RouterResult<api::TokenizePayloadRequest> {
+    let value = builtin_vault::retrieve_token(state, lookup_key)
+        .await
+        .map_err(|error| match error.current_context() {
+            errors::BuiltinVaultError::TokenNotFound | errors::BuiltinVaultError::TokenExpired => {
+                error.change_context(errors::ApiErrorResponse::UnprocessableEntity {
+                    entity: "Token".to_string(),
+                })
+            }
+            _ => error.change_context(errors::ApiErrorResponse::InternalServerError),
+        })?;
+    let tokenize_value: MockTokenizeDBValue = value
+        .parse_struct("MockTokenizeDBValue")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to deserialize Mock tokenize db value")?;
+
+    Ok(api::TokenizePayloadRequest {
+        value1: tokenize_value.value1,
+        value2: if should_get_value2 {
+            tokenize_value.value2
+        } else {
+            String::new()
+        },
+        lookup_key: lookup_key.to_string(),
+        service_name: VAULT_SERVICE_NAME.to_string(),
+    })
+}
+
 // ********************************************** PROCESS TRACKER **********************************************
 #[cfg(feature = "basilisk")]
 pub async fn add_delete_tokenized_data_task(
@@ -792,19 +951,12 @@ pub async fn get_delete_tokenize_schedule_time(
     pm: &enums::PaymentMethod,
     retry_count: i32,
 ) -> Option<time::PrimitiveDateTime> {
-    let redis_mapping = db::get_and_deserialize_key(
+    let mapping: process_data::PaymentMethodsPTMapping = process_tracker_utils::get_pt_mapping(
         db,
-        &format!("pt_mapping_delete_{pm}_tokenize_data"),
+        &process_data::get_delete_tokenize_data_mapping_key(pm),
         "PaymentMethodsPTMapping",
     )
     .await;
-    let mapping = match redis_mapping {
-        Ok(x) => x,
-        Err(err) => {
-            logger::info!("Redis Mapping Error: {}", err);
-            process_data::PaymentMethodsPTMapping::default()
-        }
-    };
     let time_delta = process_tracker_utils::get_pm_schedule_time(mapping, pm, retry_count + 1);
 
     process_tracker_utils::get_time_from_delta(time_delta)
@@ -41,6 +41,7 @@ use crate::{
     types::{
         self, api, domain,
         storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
     },
     utils::{Encode, OptionExt, ValueExt},
 };
@@ -120,6 +121,19 @@ where
     )
     .await?;
 
+    if let Some(api::ConnectorCallType::Single(connector_data)) = &connector {
+        operation
+            .to_domain()?
+            .validate_routed_connector(
+                state,
+                &merchant_account,
+                &key_store,
+                &payment_data,
+                connector_data,
+            )
+            .await?;
+    }
+
     let (mut payment_data, tokenization_action) =
         get_connector_tokenization_action(state, &operation, payment_data, &validate_result)
             .await?;
@@ -143,6 +157,8 @@ where
                 .ok();
         }
 
+        let previous_intent_status = payment_data.payment_intent.status;
+
         payment_data = match connector_details {
             api::ConnectorCallType::Single(connector) => {
                 let router_data = call_connector_service(
@@ -187,6 +203,19 @@ where
             }
         };
 
+        if previous_intent_status != storage_enums::IntentStatus::RequiresCapture
+            && payment_data.payment_intent.status == storage_enums::IntentStatus::RequiresCapture
+        {
+            add_capture_schedule_tasks(
+                &*state.store,
+                &merchant_account,
+                &payment_data.payment_attempt,
+            )
+            .await
+            .map_err(|error| logger::error!(capture_schedule_task_error=?error))
+            .ok();
+        }
+
         if should_delete_pm_from_locker(payment_data.payment_intent.status) {
             vault::Vault::delete_locker_payment_method_by_lookup_key(state, &payment_data.token)
                 .await
@@ -1247,6 +1276,113 @@ pub async fn add_process_sync_task(
     Ok(())
 }
 
+/// Schedules the expiry of the payment intent, once the fulfillment time of the merchant has
+/// elapsed without the payment intent being confirmed.
+pub async fn add_payment_intent_expiry_task(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    merchant_intent_fulfillment_time: Option<i64>,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data = storage::PaymentIntentExpiryTrackingData {
+        payment_id: payment_intent.payment_id.clone(),
+        merchant_id: payment_intent.merchant_id.clone(),
+    };
+    let runner = "PAYMENT_INTENT_EXPIRY_WORKFLOW";
+    let task = "PAYMENT_INTENT_EXPIRY";
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &payment_intent.payment_id,
+        &payment_intent.merchant_id,
+    );
+    let schedule_time =
+        helpers::get_intent_fulfillment_deadline(payment_intent, merchant_intent_fulfillment_time);
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            task,
+            runner,
+            tracking_data,
+            schedule_time,
+        )?;
+
+    db.insert_process(process_tracker_entry).await?;
+    metrics::PAYMENT_INTENT_EXPIRY_TASKS_CREATED.add(&metrics::CONTEXT, 1, &[]);
+    Ok(())
+}
+
+/// Schedules the automatic capture and void of an authorized payment, as configured in the
+/// capture schedule of the merchant for the payment method used.
+pub async fn add_capture_schedule_tasks(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    payment_attempt: &storage::PaymentAttempt,
+) -> Result<(), errors::ProcessTrackerError> {
+    let Some(capture_schedule) = merchant_account.capture_schedule.clone() else {
+        return Ok(());
+    };
+    let capture_schedule: api::CaptureScheduleConfig =
+        capture_schedule.parse_value("CaptureScheduleConfig")?;
+    let Some(schedule) = capture_schedule.get_schedule(
+        payment_attempt
+            .payment_method
+            .map(ForeignInto::foreign_into),
+    ) else {
+        return Ok(());
+    };
+
+    let now = common_utils::date_time::now();
+    let tasks = [
+        (
+            "AUTO_CAPTURE_WORKFLOW",
+            "AUTO_CAPTURE",
+            schedule
+                .auto_capture_after_hours
+                .map(|hours| time::Duration::hours(hours.into())),
+        ),
+        (
+            "AUTO_VOID_WORKFLOW",
+            "AUTO_VOID",
+            schedule
+                .auto_void_after_days
+                .map(|days| time::Duration::days(days.into())),
+        ),
+    ];
+
+    for (runner, task, delay) in tasks {
+        let Some(delay) = delay else {
+            continue;
+        };
+        let tracking_data = storage::CaptureScheduleTrackingData {
+            payment_id: payment_attempt.payment_id.clone(),
+            merchant_id: payment_attempt.merchant_id.clone(),
+            attempt_id: payment_attempt.attempt_id.clone(),
+        };
+        let process_tracker_id = pt_utils::get_process_tracker_id(
+            runner,
+            task,
+            &payment_attempt.attempt_id,
+            &payment_attempt.merchant_id,
+        );
+        let process_tracker_entry =
+            <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+                process_tracker_id,
+                task,
+                runner,
+                tracking_data,
+                now.saturating_add(delay),
+            )?;
+
+        db.insert_process(process_tracker_entry).await?;
+        metrics::CAPTURE_SCHEDULE_TASKS_CREATED.add(
+            &metrics::CONTEXT,
+            1,
+            &[metrics::request::add_attributes("runner", runner)],
+        );
+    }
+    Ok(())
+}
+
 pub fn update_straight_through_routing<F>(
     payment_data: &mut PaymentData<F>,
     request_straight_through: serde_json::Value,
@@ -1466,3 +1602,190 @@ pub fn should_add_task_to_process_tracker<F: Clone>(payment_data: &PaymentData<F
         )
     )
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{
+        db::{
+            connector_response::ConnectorResponseInterface,
+            payment_attempt::PaymentAttemptInterface, payment_intent::PaymentIntentInterface,
+            StorageImpl,
+        },
+        routes,
+        utils::test_utils,
+    };
+
+    async fn payment_data(
+        state: &AppState,
+        installment_count: Option<i16>,
+    ) -> PaymentData<api::Authorize> {
+        let db = &*state.store;
+        let now = common_utils::date_time::now();
+        let storage_scheme = storage_enums::MerchantStorageScheme::PostgresOnly;
+        let payment_intent = db
+            .insert_payment_intent(
+                storage::PaymentIntentNew {
+                    payment_id: "pay_1".into(),
+                    merchant_id: "merchant_1".into(),
+                    status: storage_enums::IntentStatus::RequiresPaymentMethod,
+                    amount: 120000,
+                    currency: Some(storage_enums::Currency::MXN),
+                    created_at: Some(now),
+                    modified_at: Some(now),
+                    ..Default::default()
+                },
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        let payment_attempt = db
+            .insert_payment_attempt(
+                storage::PaymentAttemptNew {
+                    payment_id: "pay_1".into(),
+                    merchant_id: "merchant_1".into(),
+                    attempt_id: "pay_1_1".into(),
+                    amount: 120000,
+                    currency: Some(storage_enums::Currency::MXN),
+                    payment_method_type: Some(storage_enums::PaymentMethodType::Credit),
+                    installment_count,
+                    ..Default::default()
+                },
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        let connector_response = db
+            .insert_connector_response(
+                storage::ConnectorResponseNew {
+                    payment_id: "pay_1".into(),
+                    merchant_id: "merchant_1".into(),
+                    attempt_id: "pay_1_1".into(),
+                    created_at: now,
+                    modified_at: now,
+                    connector_name: None,
+                    connector_transaction_id: None,
+                    authentication_data: None,
+                    encoded_data: None,
+                },
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+
+        PaymentData {
+            flow: PhantomData,
+            payment_intent,
+            payment_attempt,
+            connector_response,
+            amount: api::Amount::from(120000),
+            mandate_id: None,
+            mandate_connector: None,
+            currency: storage_enums::Currency::MXN,
+            setup_mandate: None,
+            address: PaymentAddress::default(),
+            token: None,
+            confirm: Some(true),
+            force_sync: None,
+            payment_method_data: None,
+            refunds: Vec::new(),
+            disputes: Vec::new(),
+            sessions_token: Vec::new(),
+            card_cvc: None,
+            email: None,
+            creds_identifier: None,
+            pm_token: None,
+            connector_customer_id: None,
+            ephemeral_key: None,
+            redirect_response: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_with_confirm_validates_installments_against_routed_connector() {
+        let (tx, _rx) = tokio::sync::oneshot::channel();
+        let state = routes::AppState::with_storage(Default::default(), StorageImpl::Mock, tx).await;
+        let merchant_account = test_utils::merchant_account();
+        let key_store = test_utils::merchant_key_store(test_utils::MERCHANT_KEY);
+        let connector = api::ConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            "stripe",
+            api::GetToken::Connector,
+        )
+        .unwrap();
+        let payment_data = payment_data(&state, Some(3)).await;
+
+        // Creating the payment without confirming it does not call the connector
+        let operation = if_not_create_change_operation::<_, api::Authorize>(
+            storage_enums::IntentStatus::RequiresPaymentMethod,
+            Some(false),
+            &PaymentCreate,
+        );
+        operation
+            .to_domain()
+            .unwrap()
+            .validate_routed_connector(
+                &state,
+                &merchant_account,
+                &key_store,
+                &payment_data,
+                &connector,
+            )
+            .await
+            .unwrap();
+
+        // The merchant has no connector account offering the installment plan
+        let operation = if_not_create_change_operation::<_, api::Authorize>(
+            storage_enums::IntentStatus::RequiresPaymentMethod,
+            Some(true),
+            &PaymentCreate,
+        );
+        let error = operation
+            .to_domain()
+            .unwrap()
+            .validate_routed_connector(
+                &state,
+                &merchant_account,
+                &key_store,
+                &payment_data,
+                &connector,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { message }
+                if message.contains("stripe")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_confirm_without_installments_accepts_routed_connector() {
+        let (tx, _rx) = tokio::sync::oneshot::channel();
+        let state = routes::AppState::with_storage(Default::default(), StorageImpl::Mock, tx).await;
+        let connector = api::ConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            "stripe",
+            api::GetToken::Connector,
+        )
+        .unwrap();
+        let payment_data = payment_data(&state, None).await;
+
+        let operation: BoxedOperation<'_, api::Authorize, api::PaymentsRequest> =
+            Box::new(PaymentConfirm);
+        operation
+            .to_domain()
+            .unwrap()
+            .validate_routed_connector(
+                &state,
+                &test_utils::merchant_account(),
+                &test_utils::merchant_key_store(test_utils::MERCHANT_KEY),
+                &payment_data,
+                &connector,
+            )
+            .await
+            .unwrap();
+    }
+}
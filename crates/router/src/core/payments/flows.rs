@@ -610,6 +610,70 @@ default_imp_for_defend_dispute!(
     connector::Zen
 );
 
+macro_rules! default_imp_for_fetch_dispute{
+    ($($path:ident::$connector:ident),*)=> {
+        $(
+            impl api::FetchDispute for $path::$connector {}
+            impl
+                services::ConnectorIntegration<
+                api::Fetch,
+                types::FetchDisputeRequestData,
+                types::FetchDisputeResponse,
+            > for $path::$connector
+            {}
+    )*
+    };
+}
+
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::FetchDispute for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::Fetch,
+        types::FetchDisputeRequestData,
+        types::FetchDisputeResponse,
+    > for connector::DummyConnector<T>
+{
+}
+
+default_imp_for_fetch_dispute!(
+    connector::Aci,
+    connector::Adyen,
+    connector::Airwallex,
+    connector::Authorizedotnet,
+    connector::Bambora,
+    connector::Bitpay,
+    connector::Bluesnap,
+    connector::Braintree,
+    connector::Cashtocode,
+    connector::Cybersource,
+    connector::Coinbase,
+    connector::Dlocal,
+    connector::Fiserv,
+    connector::Forte,
+    connector::Globalpay,
+    connector::Iatapay,
+    connector::Klarna,
+    connector::Mollie,
+    connector::Multisafepay,
+    connector::Nexinets,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Payeezy,
+    connector::Paypal,
+    connector::Payu,
+    connector::Rapyd,
+    connector::Stripe,
+    connector::Shift4,
+    connector::Trustpay,
+    connector::Opennode,
+    connector::Worldline,
+    connector::Worldpay,
+    connector::Zen
+);
+
 macro_rules! default_imp_for_pre_processing_steps{
     ($($path:ident::$connector:ident),*)=> {
         $(
@@ -672,3 +736,67 @@ default_imp_for_pre_processing_steps!(
     connector::Worldpay,
     connector::Zen
 );
+
+macro_rules! default_imp_for_mandate_revoke{
+    ($($path:ident::$connector:ident),*)=> {
+        $(
+            impl api::mandates::ConnectorMandateRevoke for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+            api::mandates::MandateRevoke,
+            types::MandateRevokeRequestData,
+            types::MandateRevokeResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::mandates::ConnectorMandateRevoke for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for connector::DummyConnector<T>
+{
+}
+
+default_imp_for_mandate_revoke!(
+    connector::Aci,
+    connector::Adyen,
+    connector::Airwallex,
+    connector::Authorizedotnet,
+    connector::Bambora,
+    connector::Bitpay,
+    connector::Bluesnap,
+    connector::Braintree,
+    connector::Cashtocode,
+    connector::Checkout,
+    connector::Coinbase,
+    connector::Cybersource,
+    connector::Dlocal,
+    connector::Fiserv,
+    connector::Forte,
+    connector::Globalpay,
+    connector::Iatapay,
+    connector::Klarna,
+    connector::Mollie,
+    connector::Multisafepay,
+    connector::Nexinets,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opennode,
+    connector::Payeezy,
+    connector::Paypal,
+    connector::Payu,
+    connector::Rapyd,
+    connector::Shift4,
+    connector::Trustpay,
+    connector::Worldline,
+    connector::Worldpay,
+    connector::Zen
+);
@@ -6,6 +6,7 @@ use crate::{
     core::{
         errors::{self, ConnectorErrorExt, RouterResult},
         mandate,
+        payment_methods::network_tokenization,
         payments::{self, access_token, customers, tokenization, transformers, PaymentData},
     },
     logger,
@@ -36,7 +37,10 @@ impl
             types::PaymentsResponseData,
         >,
     > {
-        transformers::construct_payment_router_data::<api::Authorize, types::PaymentsAuthorizeData>(
+        let mut router_data = transformers::construct_payment_router_data::<
+            api::Authorize,
+            types::PaymentsAuthorizeData,
+        >(
             state,
             self.clone(),
             connector_id,
@@ -44,7 +48,19 @@ impl
             key_store,
             customer,
         )
-        .await
+        .await?;
+
+        if let api::PaymentMethodData::Card(_) = router_data.request.payment_method_data {
+            router_data.network_token = network_tokenization::get_network_token_for_payment(
+                state,
+                connector_id,
+                key_store,
+                router_data.payment_method_id.as_deref(),
+            )
+            .await;
+        }
+
+        Ok(router_data)
     }
 }
 #[async_trait]
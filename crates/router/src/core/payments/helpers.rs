@@ -11,7 +11,7 @@ use josekit::jwe;
 use masking::{ExposeInterface, PeekInterface};
 use router_env::{instrument, tracing};
 use storage_models::{enums, payment_intent};
-use time::Duration;
+use time::{Duration, PrimitiveDateTime};
 use uuid::Uuid;
 
 use super::{
@@ -22,8 +22,9 @@ use crate::{
     configs::settings::Server,
     consts,
     core::{
+        customers,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
-        payment_methods::{cards, vault},
+        payment_methods::{cards, installments, vault},
         payments,
     },
     db::StorageInterface,
@@ -230,6 +231,7 @@ pub async fn get_address_for_payment_request(
                                         .zip
                                         .async_lift(|inner| types::encrypt_optional(inner, key))
                                         .await?,
+                                    is_saved: false,
                                 })
                             }
                             .await
@@ -253,6 +255,64 @@ pub async fn get_address_for_payment_request(
     })
 }
 
+/// Kind of an address of a payment, determining the default address of the customer used for it
+#[derive(Clone, Copy, Debug)]
+pub enum PaymentAddressType {
+    Billing,
+    Shipping,
+}
+
+/// Gets an address saved in the address book of the customer, to be used as an address of the
+/// payment. When no saved address is specified and `use_default_address` is set, the default
+/// address of the customer for the kind of address is used, if any. The saved address is copied
+/// into a new address of the payment, so that later changes to the address book do not affect the
+/// payment.
+pub async fn get_saved_address_for_payment_request(
+    db: &dyn StorageInterface,
+    saved_address_id: Option<&str>,
+    address_type: PaymentAddressType,
+    use_default_address: bool,
+    merchant_id: &str,
+    customer_id: Option<&String>,
+    merchant_key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Option<api::Address>> {
+    let saved_address_id = match (saved_address_id, customer_id) {
+        (Some(saved_address_id), _) => saved_address_id.to_owned(),
+        (None, Some(customer_id)) if use_default_address => {
+            let default_address_id = db
+                .find_customer_optional_by_customer_id_merchant_id(
+                    customer_id,
+                    merchant_id,
+                    merchant_key_store,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while fetching the customer")?
+                .and_then(|customer| match address_type {
+                    PaymentAddressType::Billing => customer.default_billing_address_id,
+                    PaymentAddressType::Shipping => customer.default_shipping_address_id,
+                });
+            match default_address_id {
+                Some(default_address_id) => default_address_id,
+                None => return Ok(None),
+            }
+        }
+        (None, _) => return Ok(None),
+    };
+    let customer_id = customer_id.get_required_value("customer_id")?;
+
+    let address = customers::find_saved_address(
+        db,
+        merchant_id,
+        customer_id,
+        &saved_address_id,
+        merchant_key_store,
+    )
+    .await?;
+
+    Ok(Some(api::Address::from(&address)))
+}
+
 pub async fn get_address_by_id(
     db: &dyn StorageInterface,
     address_id: Option<String>,
@@ -269,6 +329,7 @@ pub async fn get_token_pm_type_mandate_details(
     request: &api::PaymentsRequest,
     mandate_type: Option<api::MandateTransactionType>,
     merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
 ) -> RouterResult<(
     Option<String>,
     Option<storage_enums::PaymentMethod>,
@@ -302,16 +363,118 @@ pub async fn get_token_pm_type_mandate_details(
                 mandate_connector,
             ))
         }
-        None => Ok((
-            request.payment_token.to_owned(),
-            request.payment_method.map(ForeignInto::foreign_into),
-            request.payment_method_type.map(ForeignInto::foreign_into),
-            request.mandate_data.clone(),
-            None,
-        )),
+        None => {
+            let default_payment_method =
+                get_token_for_default_payment_method(state, request, merchant_account, key_store)
+                    .await?;
+            match default_payment_method {
+                Some((token, payment_method, payment_method_type)) => Ok((
+                    Some(token),
+                    Some(payment_method),
+                    payment_method_type
+                        .or_else(|| request.payment_method_type.map(ForeignInto::foreign_into)),
+                    request.mandate_data.clone(),
+                    None,
+                )),
+                None => Ok((
+                    request.payment_token.to_owned(),
+                    request.payment_method.map(ForeignInto::foreign_into),
+                    request.payment_method_type.map(ForeignInto::foreign_into),
+                    request.mandate_data.clone(),
+                    None,
+                )),
+            }
+        }
     }
 }
 
+/// Off-session payments of a customer, which are made without a payment method, are made with
+/// the default payment method of the customer
+pub async fn get_token_for_default_payment_method(
+    state: &AppState,
+    req: &api::PaymentsRequest,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<
+    Option<(
+        String,
+        storage_enums::PaymentMethod,
+        Option<storage_enums::PaymentMethodType>,
+    )>,
+> {
+    let customer_id = match &req.customer_id {
+        Some(customer_id)
+            if req.off_session == Some(true)
+                && req.payment_token.is_none()
+                && req.payment_method_data.is_none() =>
+        {
+            customer_id
+        }
+        _ => return Ok(None),
+    };
+
+    let db = &*state.store;
+    let customer = db
+        .find_customer_optional_by_customer_id_merchant_id(
+            customer_id,
+            &merchant_account.merchant_id,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the customer")?;
+    let default_payment_method_id =
+        customer.and_then(|customer| customer.default_payment_method_id);
+    let Some(default_payment_method_id) = default_payment_method_id else {
+        return Ok(None);
+    };
+
+    let Some(payment_method) =
+        cards::find_default_payment_method(db, &default_payment_method_id).await?
+    else {
+        return Ok(None);
+    };
+
+    if let Some(payment_method_from_request) = req.payment_method {
+        let pm: storage_enums::PaymentMethod = payment_method_from_request.foreign_into();
+        if pm != payment_method.payment_method {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "payment method in request does not match the default payment method \
+                          of the customer"
+                    .into()
+            }))?
+        }
+    };
+
+    let token = Uuid::new_v4().to_string();
+    if let storage_models::enums::PaymentMethod::Card = payment_method.payment_method {
+        let locker_id = merchant_account
+            .locker_id
+            .to_owned()
+            .get_required_value("locker_id")?;
+        let _ =
+            cards::get_lookup_key_from_locker(state, &token, &payment_method, &locker_id).await?;
+    } else {
+        let payment_method_data = cards::decrypt_payment_method_data(&payment_method, key_store)
+            .await?
+            .get_required_value("payment_method_data")?;
+        vault::Vault::store_payment_method_data_in_locker(
+            state,
+            Some(token.clone()),
+            &payment_method_data,
+            Some(payment_method.customer_id.clone()),
+            payment_method.payment_method,
+        )
+        .await?;
+    }
+
+    Ok(Some((
+        token,
+        payment_method.payment_method,
+        payment_method.payment_method_type,
+    )))
+}
+
 pub async fn get_token_for_recurring_mandate(
     state: &AppState,
     req: &api::PaymentsRequest,
@@ -838,6 +1001,9 @@ pub(crate) async fn get_payment_method_create_request(
                             .card_network
                             .as_ref()
                             .map(|card_network| card_network.to_string()),
+                        wallet: None,
+                        bank_transfer: None,
+                        bank_debit: None,
                     };
                     Ok(payment_method_request)
                 }
@@ -851,6 +1017,22 @@ pub(crate) async fn get_payment_method_create_request(
                         metadata: None,
                         customer_id: Some(customer.customer_id.to_owned()),
                         card_network: None,
+                        wallet: match pm_data {
+                            api::PaymentMethodData::Wallet(wallet) => Some(wallet.clone()),
+                            _ => None,
+                        },
+                        bank_transfer: match pm_data {
+                            api::PaymentMethodData::BankTransfer(bank_transfer) => {
+                                Some(*bank_transfer.clone())
+                            }
+                            _ => None,
+                        },
+                        bank_debit: match pm_data {
+                            api::PaymentMethodData::BankDebit(bank_debit) => {
+                                Some(bank_debit.clone())
+                            }
+                            _ => None,
+                        },
                     };
                     Ok(payment_method_request)
                 }
@@ -956,6 +1138,55 @@ pub fn validate_customer_details_in_request(
     Ok(())
 }
 
+// Checks if an address is passed both in full and as the ID of a saved address
+// If so, raise an error
+pub fn validate_saved_addresses_in_request(
+    request: &api_models::payments::PaymentsRequest,
+) -> Result<(), errors::ApiErrorResponse> {
+    for (address_passed, saved_address_passed, field_name) in [
+        (
+            request.shipping.is_some(),
+            request.shipping_address_id.is_some(),
+            "shipping",
+        ),
+        (
+            request.billing.is_some(),
+            request.billing_address_id.is_some(),
+            "billing",
+        ),
+    ] {
+        fp_utils::when(address_passed && saved_address_passed, || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Only one of `{field_name}` and `{field_name}_address_id` can be passed"
+                ),
+            })
+        })?;
+    }
+
+    Ok(())
+}
+
+pub fn validate_installment_count_in_request(
+    request: &api_models::payments::PaymentsRequest,
+) -> Result<(), errors::ApiErrorResponse> {
+    request
+        .installment_count
+        .map_or(Ok(()), |installment_count| {
+            fp_utils::when(
+                installment_count < installments::MIN_INSTALLMENT_COUNT,
+                || {
+                    Err(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "`installment_count` must be at least {}",
+                            installments::MIN_INSTALLMENT_COUNT
+                        ),
+                    })
+                },
+            )
+        })
+}
+
 /// Get the customer details from customer field if present
 /// or from the individual fields in `PaymentsRequest`
 pub fn get_customer_details_from_request(
@@ -1071,6 +1302,16 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
                                 description: None,
                                 connector_customer: None,
                                 metadata: None,
+                                email_blind_index: request_customer_details
+                                    .email
+                                    .as_ref()
+                                    .map(|email| domain::get_email_blind_index(email.peek(), key))
+                                    .transpose()?,
+                                phone_blind_index: request_customer_details
+                                    .phone
+                                    .as_ref()
+                                    .map(|phone| domain::get_phone_blind_index(phone.peek(), key))
+                                    .transpose()?,
                             })
                         }
                         .await
@@ -1117,6 +1358,19 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
                             metadata: None,
                             modified_at: common_utils::date_time::now(),
                             connector_customer: None,
+                            default_payment_method_id: None,
+                            email_blind_index: request_customer_details
+                                .email
+                                .as_ref()
+                                .map(|email| domain::get_email_blind_index(email.peek(), key))
+                                .transpose()?,
+                            phone_blind_index: request_customer_details
+                                .phone
+                                .as_ref()
+                                .map(|phone| domain::get_phone_blind_index(phone.peek(), key))
+                                .transpose()?,
+                            default_billing_address_id: None,
+                            default_shipping_address_id: None,
                         })
                     }
                     .await
@@ -1160,6 +1414,50 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
     ))
 }
 
+/// Returns the redis key holding the ID of the saved payment method, other than a card, which a
+/// payment method token listed for a customer refers to
+pub fn get_saved_payment_method_token_key(
+    parent_payment_method_token: &str,
+    payment_method: impl std::fmt::Display,
+) -> String {
+    format!("pm_token_{parent_payment_method_token}_{payment_method}_payment_method_id")
+}
+
+/// Decrypts the details of a saved payment method other than a card, when its token is used for
+/// a payment
+async fn get_saved_payment_method_data(
+    state: &AppState,
+    merchant_id: &str,
+    payment_method_id: &str,
+) -> RouterResult<(
+    Option<api::PaymentMethodData>,
+    vault::SupplementaryVaultData,
+)> {
+    let db = &*state.store;
+    let payment_method = db
+        .find_payment_method(payment_method_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    utils::when(payment_method.merchant_id != merchant_id, || {
+        Err(errors::ApiErrorResponse::PaymentMethodNotFound)
+    })?;
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let payment_method_data =
+        cards::decrypt_payment_method_data(&payment_method, &key_store).await?;
+
+    Ok((
+        payment_method_data,
+        vault::SupplementaryVaultData {
+            customer_id: Some(payment_method.customer_id),
+            payment_method_id: Some(payment_method.payment_method_id),
+        },
+    ))
+}
+
 pub async fn make_pm_data<'a, F: Clone, R>(
     operation: BoxedOperation<'a, F, R>,
     state: &'a AppState,
@@ -1167,17 +1465,14 @@ pub async fn make_pm_data<'a, F: Clone, R>(
 ) -> RouterResult<(BoxedOperation<'a, F, R>, Option<api::PaymentMethodData>)> {
     let request = &payment_data.payment_method_data;
     let token = payment_data.token.clone();
-    let hyperswitch_token = if let Some(token) = token {
+    let (hyperswitch_token, saved_payment_method_id) = if let Some(token) = token {
         let redis_conn = state.store.get_redis_conn();
-        let key = format!(
-            "pm_token_{}_{}_hyperswitch",
-            token,
-            payment_data
-                .payment_attempt
-                .payment_method
-                .to_owned()
-                .get_required_value("payment_method")?,
-        );
+        let payment_method = payment_data
+            .payment_attempt
+            .payment_method
+            .to_owned()
+            .get_required_value("payment_method")?;
+        let key = format!("pm_token_{}_{}_hyperswitch", token, payment_method);
 
         let hyperswitch_token_option = redis_conn
             .get_key::<Option<String>>(&key)
@@ -1185,9 +1480,18 @@ pub async fn make_pm_data<'a, F: Clone, R>(
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to fetch the token from redis")?;
 
-        hyperswitch_token_option.or(Some(token))
+        let saved_payment_method_id = redis_conn
+            .get_key::<Option<String>>(&get_saved_payment_method_token_key(&token, payment_method))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the token from redis")?;
+
+        (
+            hyperswitch_token_option.or(Some(token)),
+            saved_payment_method_id,
+        )
     } else {
-        None
+        (None, None)
     };
 
     let card_cvc = payment_data.card_cvc.clone();
@@ -1195,14 +1499,24 @@ pub async fn make_pm_data<'a, F: Clone, R>(
     // TODO: Handle case where payment method and token both are present in request properly.
     let payment_method = match (request, hyperswitch_token) {
         (_, Some(hyperswitch_token)) => {
-            let (pm, supplementary_data) = vault::Vault::get_payment_method_data_from_locker(
-                state,
-                &hyperswitch_token,
-            )
-            .await
-            .attach_printable(
-                "Payment method for given token not found or there was a problem fetching it",
-            )?;
+            let (pm, supplementary_data) = match saved_payment_method_id {
+                Some(payment_method_id) => {
+                    get_saved_payment_method_data(
+                        state,
+                        &payment_data.payment_intent.merchant_id,
+                        &payment_method_id,
+                    )
+                    .await?
+                }
+                None => vault::Vault::get_payment_method_data_from_locker(
+                    state,
+                    &hyperswitch_token,
+                )
+                .await
+                .attach_printable(
+                    "Payment method for given token not found or there was a problem fetching it",
+                )?,
+            };
 
             utils::when(
                 supplementary_data
@@ -1217,6 +1531,11 @@ pub async fn make_pm_data<'a, F: Clone, R>(
                 Some(api::PaymentMethodData::Card(card)) => {
                     payment_data.payment_attempt.payment_method =
                         Some(storage_enums::PaymentMethod::Card);
+                    // The saved card is identified for the payment to use its network token
+                    if supplementary_data.payment_method_id.is_some() {
+                        payment_data.payment_attempt.payment_method_id =
+                            supplementary_data.payment_method_id.clone();
+                    }
                     if let Some(cvc) = card_cvc {
                         let mut updated_card = card;
                         updated_card.card_cvc = cvc;
@@ -1246,6 +1565,12 @@ pub async fn make_pm_data<'a, F: Clone, R>(
                         Some(storage_enums::PaymentMethod::BankTransfer);
                     pm
                 }
+
+                Some(api::PaymentMethodData::BankDebit(_)) => {
+                    payment_data.payment_attempt.payment_method =
+                        Some(storage_enums::PaymentMethod::BankDebit);
+                    pm
+                }
                 Some(_) => Err(errors::ApiErrorResponse::InternalServerError)
                     .into_report()
                     .attach_printable(
@@ -1685,11 +2010,10 @@ pub(crate) fn authenticate_client_secret(
                 Err(errors::ApiErrorResponse::ClientSecretInvalid)
             } else {
                 //This is done to check whether the merchant_account's intent fulfillment time has expired or not
-                let payment_intent_fulfillment_deadline =
-                    payment_intent.created_at.saturating_add(Duration::seconds(
-                        merchant_intent_fulfillment_time
-                            .unwrap_or(consts::DEFAULT_FULFILLMENT_TIME),
-                    ));
+                let payment_intent_fulfillment_deadline = get_intent_fulfillment_deadline(
+                    payment_intent,
+                    merchant_intent_fulfillment_time,
+                );
                 let current_timestamp = common_utils::date_time::now();
                 fp_utils::when(
                     current_timestamp > payment_intent_fulfillment_deadline,
@@ -1703,6 +2027,17 @@ pub(crate) fn authenticate_client_secret(
     }
 }
 
+/// Returns the time after which the client secret of the payment intent is no longer valid, and
+/// an unconfirmed payment intent is expired.
+pub(crate) fn get_intent_fulfillment_deadline(
+    payment_intent: &payment_intent::PaymentIntent,
+    merchant_intent_fulfillment_time: Option<i64>,
+) -> PrimitiveDateTime {
+    payment_intent.created_at.saturating_add(Duration::seconds(
+        merchant_intent_fulfillment_time.unwrap_or(consts::DEFAULT_FULFILLMENT_TIME),
+    ))
+}
+
 pub(crate) fn validate_payment_status_against_not_allowed_statuses(
     intent_status: &storage_enums::IntentStatus,
     not_allowed_statuses: &[storage_enums::IntentStatus],
@@ -1987,6 +2322,48 @@ mod tests {
         )
         .is_err())
     }
+
+    #[test]
+    fn test_intent_fulfillment_deadline() {
+        let created_at = common_utils::date_time::now();
+        let payment_intent = payment_intent::PaymentIntent {
+            id: 21,
+            payment_id: "23".to_string(),
+            merchant_id: "22".to_string(),
+            status: storage_enums::IntentStatus::RequiresPaymentMethod,
+            amount: 200,
+            currency: None,
+            amount_captured: None,
+            customer_id: None,
+            description: None,
+            return_url: None,
+            metadata: None,
+            connector_id: None,
+            shipping_address_id: None,
+            billing_address_id: None,
+            statement_descriptor_name: None,
+            statement_descriptor_suffix: None,
+            created_at,
+            modified_at: created_at,
+            last_synced: None,
+            setup_future_usage: None,
+            off_session: None,
+            client_secret: Some("1".to_string()),
+            active_attempt_id: "nopes".to_string(),
+            business_country: storage_enums::CountryAlpha2::AG,
+            business_label: "no".to_string(),
+            order_details: None,
+            udf: None,
+        };
+        assert_eq!(
+            get_intent_fulfillment_deadline(&payment_intent, None),
+            created_at.saturating_add(Duration::seconds(consts::DEFAULT_FULFILLMENT_TIME))
+        );
+        assert_eq!(
+            get_intent_fulfillment_deadline(&payment_intent, Some(10)),
+            created_at.saturating_add(Duration::seconds(10))
+        );
+    }
 }
 
 // This function will be removed after moving this functionality to server_wrap and using cache instead of config
@@ -2143,6 +2520,7 @@ pub fn router_data_type_conversion<F1, F2, Req1, Req2, Res1, Res2>(
         customer_id: router_data.customer_id,
         connector_customer: router_data.connector_customer,
         preprocessing_id: router_data.preprocessing_id,
+        network_token: router_data.network_token,
     }
 }
 
@@ -2199,6 +2577,7 @@ pub fn get_attempt_type(
             }
         }
         enums::IntentStatus::Cancelled
+        | enums::IntentStatus::Expired
         | enums::IntentStatus::RequiresCapture
         | enums::IntentStatus::Processing
         | enums::IntentStatus::Succeeded => {
@@ -2285,6 +2664,7 @@ impl AttemptType {
             mandate_details: old_payment_attempt.mandate_details,
             preprocessing_step_id: None,
             error_reason: None,
+            installment_count: None,
         }
     }
 
@@ -131,6 +131,19 @@ pub trait Domain<F: Clone, R>: Send + Sync {
         payment_intent: &storage::payment_intent::PaymentIntent,
         mechant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<api::ConnectorChoice, errors::ApiErrorResponse>;
+
+    /// Validates the payment against the connector it has been routed to, before the connector is
+    /// called
+    async fn validate_routed_connector<'a>(
+        &'a self,
+        _state: &AppState,
+        _merchant_account: &domain::MerchantAccount,
+        _merchant_key_store: &domain::MerchantKeyStore,
+        _payment_data: &PaymentData<F>,
+        _connector: &api::ConnectorData,
+    ) -> RouterResult<()> {
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -77,6 +77,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
                 request,
                 mandate_type.clone(),
                 merchant_account,
+                key_store,
             )
             .await?;
 
@@ -129,9 +130,33 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
                 .or_else(|| request.customer_id.clone()),
         )?;
 
+        let saved_shipping_address = helpers::get_saved_address_for_payment_request(
+            db,
+            request.shipping_address_id.as_deref(),
+            helpers::PaymentAddressType::Shipping,
+            request.shipping.is_none() && payment_intent.shipping_address_id.is_none(),
+            merchant_id,
+            payment_intent.customer_id.as_ref(),
+            key_store,
+        )
+        .await?;
+        let saved_billing_address = helpers::get_saved_address_for_payment_request(
+            db,
+            request.billing_address_id.as_deref(),
+            helpers::PaymentAddressType::Billing,
+            request.billing.is_none() && payment_intent.billing_address_id.is_none(),
+            merchant_id,
+            payment_intent.customer_id.as_ref(),
+            key_store,
+        )
+        .await?;
+
         let shipping_address = helpers::get_address_for_payment_request(
             db,
-            request.shipping.as_ref(),
+            request
+                .shipping
+                .as_ref()
+                .or(saved_shipping_address.as_ref()),
             payment_intent.shipping_address_id.as_deref(),
             merchant_id,
             payment_intent.customer_id.as_ref(),
@@ -140,7 +165,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
         .await?;
         let billing_address = helpers::get_address_for_payment_request(
             db,
-            request.billing.as_ref(),
+            request.billing.as_ref().or(saved_billing_address.as_ref()),
             payment_intent.billing_address_id.as_deref(),
             merchant_id,
             payment_intent.customer_id.as_ref(),
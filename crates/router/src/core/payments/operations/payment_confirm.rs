@@ -10,6 +10,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        payment_methods::installments,
         payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
         utils as core_utils,
     },
@@ -63,6 +64,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             &payment_intent.status,
             &[
                 storage_enums::IntentStatus::Cancelled,
+                storage_enums::IntentStatus::Expired,
                 storage_enums::IntentStatus::Succeeded,
                 storage_enums::IntentStatus::Processing,
                 storage_enums::IntentStatus::RequiresCapture,
@@ -111,6 +113,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 request,
                 mandate_type.clone(),
                 merchant_account,
+                key_store,
             )
             .await?;
 
@@ -156,6 +159,27 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         currency = payment_attempt.currency.get_required_value("currency")?;
         amount = payment_attempt.amount.into();
 
+        payment_attempt.installment_count = request
+            .installment_count
+            .map(i16::from)
+            .or(payment_attempt.installment_count);
+        if let Some(installment_count) = payment_attempt
+            .installment_count
+            .and_then(|installment_count| u8::try_from(installment_count).ok())
+        {
+            installments::validate_installment_count_is_offered(
+                state,
+                merchant_account,
+                key_store,
+                None,
+                installment_count,
+                payment_attempt.payment_method_type,
+                currency,
+                payment_attempt.amount,
+            )
+            .await?;
+        }
+
         helpers::validate_customer_id_mandatory_cases(
             request.shipping.is_some(),
             request.billing.is_some(),
@@ -166,9 +190,39 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 .or_else(|| customer_details.customer_id.clone()),
         )?;
 
+        let saved_shipping_address = helpers::get_saved_address_for_payment_request(
+            db,
+            request.shipping_address_id.as_deref(),
+            helpers::PaymentAddressType::Shipping,
+            request.shipping.is_none() && payment_intent.shipping_address_id.is_none(),
+            merchant_id,
+            payment_intent
+                .customer_id
+                .as_ref()
+                .or(customer_details.customer_id.as_ref()),
+            key_store,
+        )
+        .await?;
+        let saved_billing_address = helpers::get_saved_address_for_payment_request(
+            db,
+            request.billing_address_id.as_deref(),
+            helpers::PaymentAddressType::Billing,
+            request.billing.is_none() && payment_intent.billing_address_id.is_none(),
+            merchant_id,
+            payment_intent
+                .customer_id
+                .as_ref()
+                .or(customer_details.customer_id.as_ref()),
+            key_store,
+        )
+        .await?;
+
         let shipping_address = helpers::get_address_for_payment_request(
             db,
-            request.shipping.as_ref(),
+            request
+                .shipping
+                .as_ref()
+                .or(saved_shipping_address.as_ref()),
             payment_intent.shipping_address_id.as_deref(),
             merchant_id,
             payment_intent
@@ -180,7 +234,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         .await?;
         let billing_address = helpers::get_address_for_payment_request(
             db,
-            request.billing.as_ref(),
+            request.billing.as_ref().or(saved_billing_address.as_ref()),
             payment_intent.billing_address_id.as_deref(),
             merchant_id,
             payment_intent
@@ -341,6 +395,38 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentConfirm {
         // creating the payment or if none is passed then use the routing algorithm
         helpers::get_connector_default(state, request.routing.clone()).await
     }
+
+    /// The connector the payment is routed to must itself offer the installment plan, since the
+    /// installment count is only sent to that connector
+    async fn validate_routed_connector<'a>(
+        &'a self,
+        state: &AppState,
+        merchant_account: &domain::MerchantAccount,
+        merchant_key_store: &domain::MerchantKeyStore,
+        payment_data: &PaymentData<F>,
+        connector: &api::ConnectorData,
+    ) -> RouterResult<()> {
+        match payment_data
+            .payment_attempt
+            .installment_count
+            .and_then(|installment_count| u8::try_from(installment_count).ok())
+        {
+            Some(installment_count) => {
+                installments::validate_installment_count_is_offered(
+                    state,
+                    merchant_account,
+                    merchant_key_store,
+                    Some(&connector.connector_name.to_string()),
+                    installment_count,
+                    payment_data.payment_attempt.payment_method_type,
+                    payment_data.currency,
+                    payment_data.payment_attempt.amount,
+                )
+                .await
+            }
+            None => Ok(()),
+        }
+    }
 }
 
 #[async_trait]
@@ -386,6 +472,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
 
         let business_sub_label = payment_data.payment_attempt.business_sub_label.clone();
         let authentication_type = payment_data.payment_attempt.authentication_type;
+        let installment_count = payment_data.payment_attempt.installment_count;
         payment_data.payment_attempt = db
             .update_payment_attempt_with_attempt_id(
                 payment_data.payment_attempt,
@@ -403,6 +490,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     payment_experience,
                     business_sub_label,
                     straight_through_algorithm,
+                    installment_count,
                 },
                 storage_scheme,
             )
@@ -495,6 +583,8 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentConfir
         }
 
         helpers::validate_customer_details_in_request(request)?;
+        helpers::validate_saved_addresses_in_request(request)?;
+        helpers::validate_installment_count_in_request(request)?;
 
         let given_payment_id = match &request.payment_id {
             Some(id_type) => Some(
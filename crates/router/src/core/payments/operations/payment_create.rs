@@ -5,7 +5,7 @@ use async_trait::async_trait;
 use common_utils::ext_traits::{AsyncExt, Encode, ValueExt};
 use error_stack::{self, ResultExt};
 use router_derive::PaymentOperation;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 use storage_models::ephemeral_key;
 use uuid::Uuid;
 
@@ -14,6 +14,7 @@ use crate::{
     consts,
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        payment_methods::installments,
         payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
         utils as core_utils,
     },
@@ -72,14 +73,53 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 request,
                 mandate_type,
                 merchant_account,
+                merchant_key_store,
             )
             .await?;
 
+        if let Some(installment_count) = request.installment_count {
+            installments::validate_installment_count_is_offered(
+                state,
+                merchant_account,
+                merchant_key_store,
+                None,
+                installment_count,
+                payment_method_type,
+                currency,
+                amount.into(),
+            )
+            .await?;
+        }
+
         let customer_details = helpers::get_customer_details_from_request(request);
 
+        let saved_shipping_address = helpers::get_saved_address_for_payment_request(
+            db,
+            request.shipping_address_id.as_deref(),
+            helpers::PaymentAddressType::Shipping,
+            request.shipping.is_none(),
+            merchant_id,
+            customer_details.customer_id.as_ref(),
+            merchant_key_store,
+        )
+        .await?;
+        let saved_billing_address = helpers::get_saved_address_for_payment_request(
+            db,
+            request.billing_address_id.as_deref(),
+            helpers::PaymentAddressType::Billing,
+            request.billing.is_none(),
+            merchant_id,
+            customer_details.customer_id.as_ref(),
+            merchant_key_store,
+        )
+        .await?;
+
         let shipping_address = helpers::get_address_for_payment_request(
             db,
-            request.shipping.as_ref(),
+            request
+                .shipping
+                .as_ref()
+                .or(saved_shipping_address.as_ref()),
             None,
             merchant_id,
             customer_details.customer_id.as_ref(),
@@ -89,7 +129,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
 
         let billing_address = helpers::get_address_for_payment_request(
             db,
-            request.billing.as_ref(),
+            request.billing.as_ref().or(saved_billing_address.as_ref()),
             None,
             merchant_id,
             customer_details.customer_id.as_ref(),
@@ -143,6 +183,19 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
                 payment_id: payment_id.clone(),
             })?;
+
+        // Payment intents confirmed on creation are not left waiting for a confirmation
+        if !request.confirm.unwrap_or(false) {
+            payments::add_payment_intent_expiry_task(
+                db,
+                &payment_intent,
+                merchant_account.intent_fulfillment_time,
+            )
+            .await
+            .map_err(|error| logger::error!(payment_intent_expiry_task_error=?error))
+            .ok();
+        }
+
         connector_response = db
             .insert_connector_response(
                 Self::make_connector_response(&payment_attempt),
@@ -428,6 +481,8 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentCreate
         }
 
         helpers::validate_customer_details_in_request(request)?;
+        helpers::validate_saved_addresses_in_request(request)?;
+        helpers::validate_installment_count_in_request(request)?;
 
         let given_payment_id = match &request.payment_id {
             Some(id_type) => Some(
@@ -542,6 +597,7 @@ impl PaymentCreate {
             payment_token: request.payment_token.clone(),
             mandate_id: request.mandate_id.clone(),
             business_sub_label: request.business_sub_label.clone(),
+            installment_count: request.installment_count.map(i16::from),
             mandate_details: request
                 .mandate_data
                 .as_ref()
@@ -8,6 +8,7 @@ use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
         mandate,
+        payment_methods::cards,
         payments::PaymentData,
     },
     db::StorageInterface,
@@ -494,5 +495,14 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     )
     .await?;
 
+    if matches!(
+        payment_data.payment_attempt.status,
+        enums::AttemptStatus::Charged | enums::AttemptStatus::Authorized
+    ) {
+        if let Some(payment_method_id) = &payment_data.payment_attempt.payment_method_id {
+            cards::update_payment_method_last_used(db, payment_method_id).await;
+        }
+    }
+
     Ok(payment_data)
 }
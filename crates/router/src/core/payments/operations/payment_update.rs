@@ -10,6 +10,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        payment_methods::installments,
         payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
         utils as core_utils,
     },
@@ -72,6 +73,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 storage_enums::IntentStatus::Failed,
                 storage_enums::IntentStatus::Succeeded,
                 storage_enums::IntentStatus::RequiresCapture,
+                storage_enums::IntentStatus::Expired,
             ],
             "update",
         )?;
@@ -82,6 +84,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 request,
                 mandate_type.clone(),
                 merchant_account,
+                key_store,
             )
             .await?;
 
@@ -130,9 +133,39 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             )?;
         }
 
+        let saved_shipping_address = helpers::get_saved_address_for_payment_request(
+            db,
+            request.shipping_address_id.as_deref(),
+            helpers::PaymentAddressType::Shipping,
+            request.shipping.is_none() && payment_intent.shipping_address_id.is_none(),
+            merchant_id,
+            payment_intent
+                .customer_id
+                .as_ref()
+                .or(customer_details.customer_id.as_ref()),
+            key_store,
+        )
+        .await?;
+        let saved_billing_address = helpers::get_saved_address_for_payment_request(
+            db,
+            request.billing_address_id.as_deref(),
+            helpers::PaymentAddressType::Billing,
+            request.billing.is_none() && payment_intent.billing_address_id.is_none(),
+            merchant_id,
+            payment_intent
+                .customer_id
+                .as_ref()
+                .or(customer_details.customer_id.as_ref()),
+            key_store,
+        )
+        .await?;
+
         let shipping_address = helpers::get_address_for_payment_request(
             db,
-            request.shipping.as_ref(),
+            request
+                .shipping
+                .as_ref()
+                .or(saved_shipping_address.as_ref()),
             payment_intent.shipping_address_id.as_deref(),
             merchant_id,
             payment_intent
@@ -144,7 +177,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         .await?;
         let billing_address = helpers::get_address_for_payment_request(
             db,
-            request.billing.as_ref(),
+            request.billing.as_ref().or(saved_billing_address.as_ref()),
             payment_intent.billing_address_id.as_deref(),
             merchant_id,
             payment_intent
@@ -250,6 +283,20 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
 
         Self::populate_payment_attempt_with_request(&mut payment_attempt, request);
 
+        if let Some(installment_count) = request.installment_count {
+            installments::validate_installment_count_is_offered(
+                state,
+                merchant_account,
+                key_store,
+                None,
+                installment_count,
+                payment_attempt.payment_method_type,
+                currency,
+                amount.into(),
+            )
+            .await?;
+        }
+
         let creds_identifier = request
             .merchant_connector_details
             .as_ref()
@@ -420,6 +467,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
         let payment_experience = payment_data.payment_attempt.payment_experience.clone();
         let amount_to_capture = payment_data.payment_attempt.amount_to_capture;
         let capture_method = payment_data.payment_attempt.capture_method;
+        let installment_count = payment_data.payment_attempt.installment_count;
         payment_data.payment_attempt = db
             .update_payment_attempt_with_attempt_id(
                 payment_data.payment_attempt,
@@ -436,6 +484,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     business_sub_label,
                     amount_to_capture,
                     capture_method,
+                    installment_count,
                 },
                 storage_scheme,
             )
@@ -537,6 +586,8 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentUpdate
         }
 
         helpers::validate_customer_details_in_request(request)?;
+        helpers::validate_saved_addresses_in_request(request)?;
+        helpers::validate_installment_count_in_request(request)?;
 
         let given_payment_id = match &request.payment_id {
             Some(id_type) => Some(
@@ -605,6 +656,10 @@ impl PaymentUpdate {
         request
             .capture_method
             .map(|i| payment_attempt.capture_method.replace(i.foreign_into()));
+        payment_attempt.installment_count = request
+            .installment_count
+            .map(i16::from)
+            .or(payment_attempt.installment_count);
     }
     fn populate_payment_intent_with_request(
         payment_intent: &mut storage::PaymentIntent,
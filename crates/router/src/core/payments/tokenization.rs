@@ -1,11 +1,11 @@
 use common_utils::{ext_traits::ValueExt, pii};
-use error_stack::{report, ResultExt};
+use error_stack::ResultExt;
 use masking::ExposeInterface;
 
 use super::helpers;
 use crate::{
     core::{
-        errors::{self, ConnectorErrorExt, RouterResult},
+        errors::{self, ConnectorErrorExt, RouterResult, StorageErrorExt},
         mandate, payment_methods, payments,
     },
     logger,
@@ -60,68 +60,81 @@ where
         )
         .await?;
         let merchant_id = &merchant_account.merchant_id;
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
-        let locker_response = save_in_locker(
-            state,
-            merchant_account,
-            payment_method_create_request.to_owned(),
-        )
-        .await?;
-        let is_duplicate = locker_response.1;
+        if let Some(card) = payment_method_create_request.card.clone() {
+            payment_method_create_request.validate()?;
+            let pm_metadata = create_payment_method_metadata(None, connector_token.clone())?;
+            let (_, pm, is_duplicate) = payment_methods::cards::add_card_payment_method(
+                state,
+                payment_method_create_request,
+                card,
+                customer.customer_id.clone(),
+                merchant_account,
+                &key_store,
+                pm_metadata,
+            )
+            .await?;
+            let payment_method_id = pm.payment_method_id.clone();
 
-        if is_duplicate {
-            let existing_pm = db
-                .find_payment_method(&locker_response.0.payment_method_id)
-                .await;
-            match existing_pm {
-                Ok(pm) => {
-                    let pm_metadata =
-                        create_payment_method_metadata(pm.metadata.as_ref(), connector_token)?;
-                    if let Some(metadata) = pm_metadata {
-                        payment_methods::cards::update_payment_method(db, pm, metadata)
-                            .await
-                            .change_context(errors::ApiErrorResponse::InternalServerError)
-                            .attach_printable("Failed to add payment method in db")?;
-                    };
-                }
-                Err(error) => {
-                    match error.current_context() {
-                        errors::StorageError::DatabaseError(err) => match err.current_context() {
-                            storage_models::errors::DatabaseError::NotFound => {
-                                let pm_metadata =
-                                    create_payment_method_metadata(None, connector_token)?;
-                                payment_methods::cards::create_payment_method(
-                                    db,
-                                    &payment_method_create_request,
-                                    &customer.customer_id,
-                                    &locker_response.0.payment_method_id,
-                                    merchant_id,
-                                    pm_metadata,
-                                )
-                                .await
-                                .change_context(errors::ApiErrorResponse::InternalServerError)
-                                .attach_printable("Failed to add payment method in db")
-                            }
-                            _ => Err(report!(errors::ApiErrorResponse::InternalServerError)),
-                        },
-                        _ => Err(report!(errors::ApiErrorResponse::InternalServerError)),
-                    }?;
-                }
-            };
-        } else {
+            if is_duplicate {
+                let pm_metadata =
+                    create_payment_method_metadata(pm.metadata.as_ref(), connector_token)?;
+                if let Some(metadata) = pm_metadata {
+                    payment_methods::cards::update_payment_method(db, pm, metadata)
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to add payment method in db")?;
+                };
+            }
+            return Ok(Some(payment_method_id));
+        }
+
+        // Payment methods other than cards are stored encrypted along with the payment method,
+        // instead of in the card locker
+        if let Some(payment_method_data) =
+            payment_methods::cards::get_payment_method_data_to_store(&payment_method_create_request)
+        {
             let pm_metadata = create_payment_method_metadata(None, connector_token)?;
-            payment_methods::cards::create_payment_method(
-                db,
-                &payment_method_create_request,
+            let payment_method_response = payment_methods::cards::add_payment_method_data(
+                state,
+                payment_method_create_request,
+                payment_method_data,
                 &customer.customer_id,
-                &locker_response.0.payment_method_id,
                 merchant_id,
+                &key_store,
                 pm_metadata,
             )
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to add payment method in db")?;
-        };
+            .await?;
+            return Ok(Some(payment_method_response.payment_method_id));
+        }
+
+        let locker_response = save_in_locker(
+            state,
+            merchant_account,
+            payment_method_create_request.to_owned(),
+        )
+        .await?;
+        let pm_metadata = create_payment_method_metadata(None, connector_token)?;
+        payment_methods::cards::create_payment_method(
+            db,
+            &payment_method_create_request,
+            &customer.customer_id,
+            &locker_response.0.payment_method_id,
+            merchant_id,
+            pm_metadata,
+            None,
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add payment method in db")?;
         Some(locker_response.0.payment_method_id)
     } else {
         None
@@ -162,8 +175,14 @@ pub async fn save_in_locker(
                 card: None,
                 metadata: None,
                 created: Some(common_utils::date_time::now()),
-                recurring_enabled: false,           //[#219]
-                installment_payment_enabled: false, //[#219]
+                network_token_status: None,
+                card_account_status: None,
+                recurring_enabled: false, //[#219]
+                installment_payment_enabled:
+                    payment_methods::installments::is_installment_payment_method_type(
+                        &state.conf.installments,
+                        payment_method_request.payment_method_type,
+                    ),
                 payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]), //[#219]
             };
             Ok((payment_method_response, false))
@@ -124,6 +124,7 @@ where
         payment_method_token: payment_data.pm_token,
         connector_customer: payment_data.connector_customer_id,
         preprocessing_id: payment_data.payment_attempt.preprocessing_step_id,
+        network_token: None,
     };
 
     Ok(router_data)
@@ -453,6 +454,11 @@ where
                                 .payment_method_type
                                 .map(ForeignInto::foreign_into),
                         )
+                        .set_installment_count(
+                            payment_attempt
+                                .installment_count
+                                .and_then(|count| u8::try_from(count).ok()),
+                        )
                         .set_metadata(payment_intent.metadata)
                         .set_order_details(payment_intent.order_details)
                         .set_connector_label(connector_label)
@@ -511,6 +517,9 @@ where
             order_details: payment_intent.order_details,
             udf: payment_intent.udf,
             connector_transaction_id: payment_attempt.connector_transaction_id,
+            installment_count: payment_attempt
+                .installment_count
+                .and_then(|count| u8::try_from(count).ok()),
             ..Default::default()
         }),
     });
@@ -724,6 +733,10 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             webhook_url,
             complete_authorize_url,
             customer_id: None,
+            installment_count: payment_data
+                .payment_attempt
+                .installment_count
+                .and_then(|count| u8::try_from(count).ok()),
         })
     }
 }
@@ -0,0 +1,407 @@
+use api_models::process_tracker as process_tracker_api;
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use super::errors::{self, RouterResponse, StorageErrorExt};
+use crate::{
+    db::StorageInterface,
+    services,
+    types::{
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+};
+
+/// Business status set on tasks cancelled through the admin APIs.
+const CANCELLED_BY_ADMIN: &str = "CANCELLED_BY_ADMIN";
+
+#[derive(Clone, Copy, Debug, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+enum ProcessTrackerAdminAction {
+    Retry,
+    Reschedule,
+    Cancel,
+}
+
+/// Entry recorded in the audit trail of a task for every action performed on it through the admin
+/// APIs.
+#[derive(Debug, serde::Serialize)]
+struct ProcessTrackerAuditEntry {
+    action: ProcessTrackerAdminAction,
+    reason: Option<String>,
+    previous_status: storage_enums::ProcessTrackerStatus,
+    previous_business_status: String,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    previous_schedule_time: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    performed_at: PrimitiveDateTime,
+}
+
+#[instrument(skip(db))]
+pub async fn list_processes(
+    db: &dyn StorageInterface,
+    constraints: process_tracker_api::ProcessTrackerListConstraints,
+) -> RouterResponse<Vec<process_tracker_api::ProcessTrackerResponse>> {
+    common_utils::fp_utils::when(constraints.limit > 100 || constraints.limit < 1, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "limit should be in between 1 and 100".to_string(),
+        })
+        .into_report()
+    })?;
+
+    let processes = db
+        .find_processes_by_constraints(constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve process tracker tasks")?;
+
+    Ok(services::ApplicationResponse::Json(
+        processes
+            .into_iter()
+            .map(process_tracker_api::ProcessTrackerResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn retrieve_process(
+    db: &dyn StorageInterface,
+    process_id: &str,
+) -> RouterResponse<process_tracker_api::ProcessTrackerResponse> {
+    let process = find_process(db, process_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        process_tracker_api::ProcessTrackerResponse::foreign_from(process),
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn retry_process(
+    db: &dyn StorageInterface,
+    process_id: &str,
+    request: process_tracker_api::ProcessTrackerActionRequest,
+) -> RouterResponse<process_tracker_api::ProcessTrackerResponse> {
+    let process = find_process(db, process_id).await?;
+    validate_process_is_not_running(&process)?;
+
+    let process = update_process(
+        db,
+        process,
+        ProcessTrackerAdminAction::Retry,
+        request.reason,
+        storage_enums::ProcessTrackerStatus::Pending,
+        None,
+        Some(0),
+        Some(common_utils::date_time::now()),
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        process_tracker_api::ProcessTrackerResponse::foreign_from(process),
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn reschedule_process(
+    db: &dyn StorageInterface,
+    process_id: &str,
+    request: process_tracker_api::ProcessTrackerRescheduleRequest,
+) -> RouterResponse<process_tracker_api::ProcessTrackerResponse> {
+    let process = find_process(db, process_id).await?;
+    validate_process_is_not_running(&process)?;
+
+    let process = update_process(
+        db,
+        process,
+        ProcessTrackerAdminAction::Reschedule,
+        request.reason,
+        storage_enums::ProcessTrackerStatus::Pending,
+        None,
+        Some(0),
+        Some(request.schedule_time),
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        process_tracker_api::ProcessTrackerResponse::foreign_from(process),
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn cancel_process(
+    db: &dyn StorageInterface,
+    process_id: &str,
+    request: process_tracker_api::ProcessTrackerActionRequest,
+) -> RouterResponse<process_tracker_api::ProcessTrackerResponse> {
+    let process = find_process(db, process_id).await?;
+
+    common_utils::fp_utils::when(
+        process.status == storage_enums::ProcessTrackerStatus::Finish,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("Process tracker task {process_id} has already finished"),
+            })
+            .into_report()
+        },
+    )?;
+
+    let process = update_process(
+        db,
+        process,
+        ProcessTrackerAdminAction::Cancel,
+        request.reason,
+        storage_enums::ProcessTrackerStatus::Finish,
+        Some(CANCELLED_BY_ADMIN.to_string()),
+        None,
+        None,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        process_tracker_api::ProcessTrackerResponse::foreign_from(process),
+    ))
+}
+
+async fn find_process(
+    db: &dyn StorageInterface,
+    process_id: &str,
+) -> errors::RouterResult<storage::ProcessTracker> {
+    db.find_process_by_id(process_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch process tracker task")?
+        .ok_or(errors::ApiErrorResponse::ProcessTrackerTaskNotFound {
+            id: process_id.to_string(),
+        })
+        .into_report()
+}
+
+/// Tasks being executed by a consumer cannot be retried or rescheduled, since the consumer would
+/// overwrite the status and retry count of the task once it is done.
+/// Rejects updates to tasks which are being executed. A task whose lease has expired is no longer
+/// being executed, since the consumer executing it renews the lease until the task completes.
+fn validate_process_is_not_running(process: &storage::ProcessTracker) -> errors::RouterResult<()> {
+    let now = common_utils::date_time::now();
+    common_utils::fp_utils::when(
+        matches!(
+            process.status,
+            storage_enums::ProcessTrackerStatus::ProcessStarted
+                | storage_enums::ProcessTrackerStatus::Processing
+        ) && process
+            .lease_expires_at
+            .map_or(true, |expires_at| expires_at > now),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Process tracker task {} is being executed and cannot be updated",
+                    process.id
+                ),
+            })
+            .into_report()
+        },
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn update_process(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    action: ProcessTrackerAdminAction,
+    reason: Option<String>,
+    status: storage_enums::ProcessTrackerStatus,
+    business_status: Option<String>,
+    retry_count: Option<i32>,
+    schedule_time: Option<PrimitiveDateTime>,
+) -> errors::RouterResult<storage::ProcessTracker> {
+    let audit_entry = ProcessTrackerAuditEntry {
+        action,
+        reason,
+        previous_status: process.status,
+        previous_business_status: process.business_status.clone(),
+        previous_schedule_time: process.schedule_time,
+        performed_at: common_utils::date_time::now(),
+    };
+    logger::info!(
+        process_id = %process.id,
+        runner = ?process.runner,
+        ?audit_entry,
+        "Process tracker task updated through admin API"
+    );
+
+    let mut admin_audit_trail = process.admin_audit_trail.clone();
+    admin_audit_trail.push(
+        serde_json::to_value(&audit_entry)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize process tracker audit entry")?,
+    );
+
+    let process_id = process.id.clone();
+    db.update_process(
+        process,
+        storage::ProcessTrackerUpdate::AdminUpdate {
+            status,
+            business_status,
+            retry_count,
+            schedule_time,
+            admin_audit_trail,
+        },
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::ProcessTrackerTaskNotFound { id: process_id })
+    .attach_printable_lazy(|| format!("Failed to {action} process tracker task"))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::{
+        db::{process_tracker::ProcessTrackerInterface, MockDb},
+        types::storage::{ProcessTrackerExt, ProcessTrackerUpdate},
+    };
+
+    async fn insert_process(db: &MockDb, status: storage_enums::ProcessTrackerStatus) {
+        let process = <storage::ProcessTracker as ProcessTrackerExt>::make_process_tracker_new(
+            "process_1".to_string(),
+            "TASK",
+            "REFUND_WORKFLOW_ROUTER",
+            serde_json::json!({ "merchant_id": "merchant_1" }),
+            common_utils::date_time::now(),
+        )
+        .unwrap();
+        let process = db.insert_process(process).await.unwrap();
+        db.update_process(
+            process,
+            ProcessTrackerUpdate::StatusRetryUpdate {
+                status,
+                retry_count: 3,
+                schedule_time: common_utils::date_time::now(),
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn retry_resets_retry_count_and_records_audit_trail() {
+        let db = MockDb::new(&Default::default()).await.unwrap();
+        insert_process(&db, storage_enums::ProcessTrackerStatus::Finish).await;
+
+        let response = retry_process(
+            &db,
+            "process_1",
+            process_tracker_api::ProcessTrackerActionRequest {
+                reason: Some("connector outage".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+        let services::ApplicationResponse::Json(process) = response else {
+            panic!("Unexpected response")
+        };
+
+        assert_eq!(process.retry_count, 0);
+        assert_eq!(
+            process.status,
+            api_models::enums::ProcessTrackerStatus::Pending
+        );
+        assert!(process.event.is_empty());
+        assert_eq!(process.admin_audit_trail.len(), 1);
+        assert_eq!(process.admin_audit_trail[0]["action"], "retry");
+        assert_eq!(process.admin_audit_trail[0]["reason"], "connector outage");
+    }
+
+    #[tokio::test]
+    async fn running_process_cannot_be_retried_or_rescheduled() {
+        let db = MockDb::new(&Default::default()).await.unwrap();
+        insert_process(&db, storage_enums::ProcessTrackerStatus::ProcessStarted).await;
+
+        let error = retry_process(&db, "process_1", Default::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+
+        let error = reschedule_process(
+            &db,
+            "process_1",
+            process_tracker_api::ProcessTrackerRescheduleRequest {
+                schedule_time: common_utils::date_time::now(),
+                reason: None,
+            },
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn process_with_expired_lease_can_be_retried() {
+        let db = MockDb::new(&Default::default()).await.unwrap();
+        insert_process(&db, storage_enums::ProcessTrackerStatus::ProcessStarted).await;
+        db.acquire_process_lease(
+            "process_1",
+            "consumer_1",
+            common_utils::date_time::now() + time::Duration::minutes(5),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        let error = retry_process(&db, "process_1", Default::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+
+        // The consumer executing the task dies and its lease expires
+        db.renew_process_lease(
+            "process_1",
+            "consumer_1",
+            common_utils::date_time::now() - time::Duration::seconds(1),
+        )
+        .await
+        .unwrap();
+
+        let response = retry_process(&db, "process_1", Default::default())
+            .await
+            .unwrap();
+        let services::ApplicationResponse::Json(process) = response else {
+            panic!("Unexpected response")
+        };
+        assert_eq!(
+            process.status,
+            api_models::enums::ProcessTrackerStatus::Pending
+        );
+    }
+
+    #[tokio::test]
+    async fn list_limit_is_bounded() {
+        let db = MockDb::new(&Default::default()).await.unwrap();
+        let constraints = process_tracker_api::ProcessTrackerListConstraints {
+            limit: 1000,
+            runner: None,
+            status: None,
+            business_status: None,
+            merchant_id: None,
+            schedule_time_lte: None,
+            schedule_time_gte: None,
+        };
+
+        let error = list_processes(&db, constraints).await.unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        ));
+    }
+}
@@ -0,0 +1,986 @@
+pub mod parsers;
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use api_models::reconciliation::{
+    self as reconciliation_api, ReconciliationMismatch, ReconciliationMismatchType,
+    ReconciliationTransactionType,
+};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use self::parsers::{ReportRecord, ReportedOutcome};
+use super::errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt};
+use crate::{
+    consts,
+    core::files::helpers as file_helpers,
+    db::StorageInterface,
+    routes::{metrics, AppState},
+    services,
+    types::{
+        api::{self, enums as api_enums},
+        domain,
+        storage::{self, enums},
+        transformers::ForeignTryFrom,
+    },
+};
+
+/// Name of the directory to which reports are moved once they have been imported.
+const PROCESSED_REPORTS_DIRECTORY: &str = "processed";
+
+/// Name of the directory to which reports are moved when they cannot be reconciled.
+const FAILED_REPORTS_DIRECTORY: &str = "failed";
+
+/// Maximum number of reported transactions looked up in a single query.
+const RECORD_LOOKUP_BATCH_SIZE: usize = 500;
+
+/// A connector report to be reconciled against the records of a merchant.
+#[derive(Debug)]
+pub struct ConnectorReport {
+    pub connector: api_enums::Connector,
+    pub report_type: enums::ReconciliationReportType,
+    /// The file or path from which the report was imported
+    pub source: String,
+    pub content: String,
+    /// The period covered by the report, required to detect transactions missing from it
+    pub period: Option<(PrimitiveDateTime, PrimitiveDateTime)>,
+}
+
+#[derive(Debug, Default)]
+struct ReconciliationSummary {
+    total_records: i32,
+    matched_records: i32,
+    mismatches: Vec<ReconciliationMismatch>,
+}
+
+#[instrument(skip(state))]
+pub async fn create_reconciliation_run(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: reconciliation_api::ReconciliationRunRequest,
+) -> RouterResponse<reconciliation_api::ReconciliationRunResponse> {
+    let period = match (req.period_start, req.period_end) {
+        (Some(period_start), Some(period_end)) if period_start <= period_end => {
+            Some((period_start, period_end))
+        }
+        (None, None) => None,
+        _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`period_start` and `period_end` must either be both specified, with \
+                      `period_start` not after `period_end`, or both omitted"
+                .to_string(),
+        })?,
+    };
+
+    let (file_data, _) = file_helpers::retrieve_file_and_provider_file_id_from_file_id(
+        state,
+        Some(req.file_id.clone()),
+        &merchant_account,
+        &key_store,
+        api::FileDataRequired::Required,
+    )
+    .await?;
+    let content = file_data
+        .map(String::from_utf8)
+        .transpose()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The report must be a CSV file encoded in UTF-8".to_string(),
+        })?
+        .ok_or(errors::ApiErrorResponse::FileNotAvailable)
+        .into_report()
+        .attach_printable("File data not retrieved")?;
+
+    let run = reconcile_report(
+        &*state.store,
+        &merchant_account,
+        ConnectorReport {
+            connector: req.connector,
+            report_type: req.report_type,
+            source: req.file_id,
+            content,
+            period,
+        },
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        reconciliation_api::ReconciliationRunResponse::foreign_try_from(run)
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_reconciliation_run(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    run_id: String,
+) -> RouterResponse<reconciliation_api::ReconciliationRunResponse> {
+    let run = state
+        .store
+        .find_reconciliation_run_by_merchant_id_run_id(&merchant_account.merchant_id, &run_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ReconciliationRunNotFound { run_id })?;
+
+    Ok(services::ApplicationResponse::Json(
+        reconciliation_api::ReconciliationRunResponse::foreign_try_from(run)
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn list_reconciliation_runs(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    constraints: reconciliation_api::ReconciliationRunListConstraints,
+) -> RouterResponse<Vec<reconciliation_api::ReconciliationRunResponse>> {
+    let runs = state
+        .store
+        .find_reconciliation_runs_by_merchant_id(&merchant_account.merchant_id, constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve reconciliation runs")?;
+
+    let runs = runs
+        .into_iter()
+        .map(reconciliation_api::ReconciliationRunResponse::foreign_try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    Ok(services::ApplicationResponse::Json(runs))
+}
+
+/// Reconciles a connector report against the payment attempts and refunds of the merchant,
+/// recording the outcome as a reconciliation run. Failures to process the report are recorded in
+/// the run instead of being returned.
+pub async fn reconcile_report(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    report: ConnectorReport,
+) -> RouterResult<storage::ReconciliationRun> {
+    let run = db
+        .insert_reconciliation_run(storage::ReconciliationRunNew {
+            run_id: common_utils::generate_id(consts::ID_LENGTH, "recon"),
+            merchant_id: merchant_account.merchant_id.clone(),
+            connector: report.connector.to_string(),
+            report_type: report.report_type,
+            report_source: report.source.clone(),
+            status: enums::ReconciliationRunStatus::Processing,
+            period_start: report.period.map(|(period_start, _)| period_start),
+            period_end: report.period.map(|(_, period_end)| period_end),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert reconciliation run")?;
+
+    let run_update = match match_report(db, merchant_account, &report).await {
+        Ok(summary) => {
+            metrics::RECONCILIATION_RUNS_COMPLETED.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes(
+                    "connector",
+                    report.connector.to_string(),
+                )],
+            );
+            metrics::RECONCILIATION_MISMATCHES_FOUND.add(
+                &metrics::CONTEXT,
+                u64::try_from(summary.mismatches.len()).unwrap_or(u64::MAX),
+                &[metrics::request::add_attributes(
+                    "connector",
+                    report.connector.to_string(),
+                )],
+            );
+            storage::ReconciliationRunUpdate::Completed {
+                total_records: summary.total_records,
+                matched_records: summary.matched_records,
+                mismatches: serde_json::to_value(summary.mismatches)
+                    .into_report()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to serialize reconciliation mismatches")?,
+            }
+        }
+        Err(error) => {
+            logger::error!(?error, run_id = %run.run_id, "Failed to reconcile report");
+            metrics::RECONCILIATION_RUNS_FAILED.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes(
+                    "connector",
+                    report.connector.to_string(),
+                )],
+            );
+            storage::ReconciliationRunUpdate::Failed {
+                error_message: error.current_context().to_string(),
+            }
+        }
+    };
+
+    db.update_reconciliation_run(run, run_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update reconciliation run")
+}
+
+async fn match_report(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    report: &ConnectorReport,
+) -> CustomResult<ReconciliationSummary, errors::ReconciliationError> {
+    let records = parsers::get_report_parser(report.connector)?.parse_report(&report.content)?;
+    let connector = report.connector.to_string();
+
+    let mut summary = ReconciliationSummary {
+        total_records: i32::try_from(records.len()).unwrap_or(i32::MAX),
+        ..Default::default()
+    };
+    let reported_payments = get_reported_ids(&records, ReconciliationTransactionType::Payment);
+    let reported_refunds = get_reported_ids(&records, ReconciliationTransactionType::Refund);
+    let payment_attempts =
+        find_reported_payment_attempts(db, merchant_account, &connector, &reported_payments)
+            .await?;
+    let refunds =
+        find_reported_refunds(db, merchant_account, &connector, &reported_refunds).await?;
+
+    for record in records {
+        let mismatches = match record.transaction_type {
+            ReconciliationTransactionType::Payment => payment_attempts
+                .get(&record.connector_transaction_id)
+                .map(|payment_attempt| match_payment(&record, payment_attempt)),
+            ReconciliationTransactionType::Refund => refunds
+                .get(&record.connector_transaction_id)
+                .map(|refund| match_refund(&record, refund)),
+        }
+        .unwrap_or_else(|| vec![extra_transaction_mismatch(&record)]);
+
+        if mismatches.is_empty() {
+            summary.matched_records += 1;
+        }
+        summary.mismatches.extend(mismatches);
+    }
+
+    if let Some((period_start, period_end)) = report.period {
+        let charged_attempts = db
+            .find_payment_attempts_by_merchant_id_connector_status_created_between(
+                &merchant_account.merchant_id,
+                &connector,
+                enums::AttemptStatus::Charged,
+                period_start,
+                period_end,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ReconciliationError::RecordFetchingFailed)?;
+        summary
+            .mismatches
+            .extend(charged_attempts.into_iter().filter_map(|attempt| {
+                let connector_transaction_id = attempt.connector_transaction_id?;
+                (!reported_payments.contains(&connector_transaction_id)).then(|| {
+                    ReconciliationMismatch {
+                        mismatch_type: ReconciliationMismatchType::MissingTransaction,
+                        transaction_type: ReconciliationTransactionType::Payment,
+                        connector_transaction_id,
+                        payment_id: Some(attempt.payment_id),
+                        refund_id: None,
+                        expected: None,
+                        reported: None,
+                    }
+                })
+            }));
+
+        let successful_refunds = db
+            .find_refunds_by_merchant_id_connector_status_created_between(
+                &merchant_account.merchant_id,
+                &connector,
+                enums::RefundStatus::Success,
+                period_start,
+                period_end,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ReconciliationError::RecordFetchingFailed)?;
+        summary
+            .mismatches
+            .extend(successful_refunds.into_iter().filter_map(|refund| {
+                let connector_refund_id = refund.connector_refund_id?;
+                (!reported_refunds.contains(&connector_refund_id)).then(|| ReconciliationMismatch {
+                    mismatch_type: ReconciliationMismatchType::MissingTransaction,
+                    transaction_type: ReconciliationTransactionType::Refund,
+                    connector_transaction_id: connector_refund_id,
+                    payment_id: Some(refund.payment_id),
+                    refund_id: Some(refund.refund_id),
+                    expected: None,
+                    reported: None,
+                })
+            }));
+    }
+
+    Ok(summary)
+}
+
+fn get_reported_ids(
+    records: &[ReportRecord],
+    transaction_type: ReconciliationTransactionType,
+) -> HashSet<String> {
+    records
+        .iter()
+        .filter(|record| record.transaction_type == transaction_type)
+        .map(|record| record.connector_transaction_id.clone())
+        .collect()
+}
+
+/// Fetches the payment attempts of the reported payments in batches, keyed by their connector
+/// transaction ID.
+async fn find_reported_payment_attempts(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    connector: &str,
+    connector_txn_ids: &HashSet<String>,
+) -> CustomResult<HashMap<String, storage::PaymentAttempt>, errors::ReconciliationError> {
+    let connector_txn_ids = connector_txn_ids.iter().cloned().collect::<Vec<_>>();
+    let mut payment_attempts = HashMap::new();
+
+    for batch in connector_txn_ids.chunks(RECORD_LOOKUP_BATCH_SIZE) {
+        payment_attempts.extend(
+            db.find_payment_attempts_by_merchant_id_connector_txn_ids(
+                &merchant_account.merchant_id,
+                connector,
+                batch.to_vec(),
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ReconciliationError::RecordFetchingFailed)?
+            .into_iter()
+            .filter_map(|payment_attempt| {
+                Some((
+                    payment_attempt.connector_transaction_id.clone()?,
+                    payment_attempt,
+                ))
+            }),
+        );
+    }
+
+    // Attempts of merchants using the KV store may not have been written to the database yet
+    if merchant_account.storage_scheme == enums::MerchantStorageScheme::RedisKv {
+        let missing_txn_ids = connector_txn_ids
+            .into_iter()
+            .filter(|connector_txn_id| !payment_attempts.contains_key(connector_txn_id))
+            .collect::<Vec<_>>();
+        for connector_txn_id in missing_txn_ids {
+            match db
+                .find_payment_attempt_by_merchant_id_connector_txn_id(
+                    &merchant_account.merchant_id,
+                    &connector_txn_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+            {
+                Ok(payment_attempt) if payment_attempt.connector.as_deref() == Some(connector) => {
+                    payment_attempts.insert(connector_txn_id, payment_attempt);
+                }
+                Ok(_) => {}
+                Err(error) if is_not_found(error.current_context()) => {}
+                Err(error) => {
+                    Err(error.change_context(errors::ReconciliationError::RecordFetchingFailed))?
+                }
+            }
+        }
+    }
+
+    Ok(payment_attempts)
+}
+
+/// Fetches the refunds of the reported refunds in batches, keyed by their connector refund ID.
+async fn find_reported_refunds(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    connector: &str,
+    connector_refund_ids: &HashSet<String>,
+) -> CustomResult<HashMap<String, storage::Refund>, errors::ReconciliationError> {
+    let connector_refund_ids = connector_refund_ids.iter().cloned().collect::<Vec<_>>();
+    let mut refunds = HashMap::new();
+
+    for batch in connector_refund_ids.chunks(RECORD_LOOKUP_BATCH_SIZE) {
+        refunds.extend(
+            db.find_refunds_by_merchant_id_connector_refund_ids_connector(
+                &merchant_account.merchant_id,
+                batch.to_vec(),
+                connector,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ReconciliationError::RecordFetchingFailed)?
+            .into_iter()
+            .filter_map(|refund| Some((refund.connector_refund_id.clone()?, refund))),
+        );
+    }
+
+    // Refunds of merchants using the KV store may not have been written to the database yet
+    if merchant_account.storage_scheme == enums::MerchantStorageScheme::RedisKv {
+        let missing_refund_ids = connector_refund_ids
+            .into_iter()
+            .filter(|connector_refund_id| !refunds.contains_key(connector_refund_id))
+            .collect::<Vec<_>>();
+        for connector_refund_id in missing_refund_ids {
+            match db
+                .find_refund_by_merchant_id_connector_refund_id_connector(
+                    &merchant_account.merchant_id,
+                    &connector_refund_id,
+                    connector,
+                    merchant_account.storage_scheme,
+                )
+                .await
+            {
+                Ok(refund) => {
+                    refunds.insert(connector_refund_id, refund);
+                }
+                Err(error) if is_not_found(error.current_context()) => {}
+                Err(error) => {
+                    Err(error.change_context(errors::ReconciliationError::RecordFetchingFailed))?
+                }
+            }
+        }
+    }
+
+    Ok(refunds)
+}
+
+fn match_payment(
+    record: &ReportRecord,
+    payment_attempt: &storage::PaymentAttempt,
+) -> Vec<ReconciliationMismatch> {
+    let succeeded = matches!(
+        payment_attempt.status,
+        enums::AttemptStatus::Charged | enums::AttemptStatus::PartialCharged
+    );
+    compare_record(
+        record,
+        RecordedTransaction {
+            payment_id: payment_attempt.payment_id.clone(),
+            refund_id: None,
+            amount: payment_attempt
+                .amount_to_capture
+                .unwrap_or(payment_attempt.amount),
+            currency: payment_attempt.currency,
+            status: payment_attempt.status.to_string(),
+            succeeded,
+        },
+    )
+}
+
+fn match_refund(record: &ReportRecord, refund: &storage::Refund) -> Vec<ReconciliationMismatch> {
+    let succeeded = refund.refund_status == enums::RefundStatus::Success;
+    compare_record(
+        record,
+        RecordedTransaction {
+            payment_id: refund.payment_id.clone(),
+            refund_id: Some(refund.refund_id.clone()),
+            amount: refund.refund_amount,
+            currency: Some(refund.currency),
+            status: refund.refund_status.to_string(),
+            succeeded,
+        },
+    )
+}
+
+/// A payment or refund as recorded by hyperswitch.
+struct RecordedTransaction {
+    payment_id: String,
+    refund_id: Option<String>,
+    amount: i64,
+    currency: Option<enums::Currency>,
+    status: String,
+    succeeded: bool,
+}
+
+fn compare_record(
+    record: &ReportRecord,
+    transaction: RecordedTransaction,
+) -> Vec<ReconciliationMismatch> {
+    let mismatch = |mismatch_type, expected: String, reported: String| ReconciliationMismatch {
+        mismatch_type,
+        transaction_type: record.transaction_type,
+        connector_transaction_id: record.connector_transaction_id.clone(),
+        payment_id: Some(transaction.payment_id.clone()),
+        refund_id: transaction.refund_id.clone(),
+        expected: Some(expected),
+        reported: Some(reported),
+    };
+
+    let mut mismatches = Vec::new();
+    if transaction.amount != record.amount {
+        mismatches.push(mismatch(
+            ReconciliationMismatchType::AmountMismatch,
+            transaction.amount.to_string(),
+            record.amount.to_string(),
+        ));
+    }
+    if transaction.currency != Some(record.currency) {
+        mismatches.push(mismatch(
+            ReconciliationMismatchType::CurrencyMismatch,
+            transaction
+                .currency
+                .map(|currency| currency.to_string())
+                .unwrap_or_default(),
+            record.currency.to_string(),
+        ));
+    }
+    if transaction.succeeded != (record.outcome == ReportedOutcome::Succeeded) {
+        mismatches.push(mismatch(
+            ReconciliationMismatchType::StatusMismatch,
+            transaction.status.clone(),
+            record.outcome.to_string(),
+        ));
+    }
+    mismatches
+}
+
+fn extra_transaction_mismatch(record: &ReportRecord) -> ReconciliationMismatch {
+    ReconciliationMismatch {
+        mismatch_type: ReconciliationMismatchType::ExtraTransaction,
+        transaction_type: record.transaction_type,
+        connector_transaction_id: record.connector_transaction_id.clone(),
+        payment_id: None,
+        refund_id: None,
+        expected: None,
+        reported: Some(record.amount.to_string()),
+    }
+}
+
+fn is_not_found(error: &errors::StorageError) -> bool {
+    error.is_db_not_found() || matches!(error, errors::StorageError::ValueNotFound(_))
+}
+
+/// Imports the reports placed in the directory, which are expected at
+/// `<merchant_id>/<connector>/<report_type>/<report>.csv` relative to the directory. Imported
+/// reports are moved to a `processed` directory alongside them, so that they are imported once.
+/// Reports whose reconciliation fails, such as malformed ones, are moved to a `failed` directory
+/// alongside them instead, to be inspected and placed back once fixed. Reports which cannot be
+/// read, or whose run cannot be recorded, are left in place, to be retried on the next import.
+pub async fn import_reports_from_directory(
+    db: &dyn StorageInterface,
+    directory: &Path,
+) -> CustomResult<(), errors::ReconciliationError> {
+    for merchant_directory in read_subdirectories(directory).await? {
+        let merchant_id = get_file_name(&merchant_directory);
+        let merchant_account = match get_merchant_account(db, &merchant_id).await {
+            Ok(merchant_account) => merchant_account,
+            Err(error) => {
+                logger::error!(?error, %merchant_id, "Skipping reports of unknown merchant");
+                continue;
+            }
+        };
+
+        for connector_directory in read_subdirectories(&merchant_directory).await? {
+            let Ok(connector) =
+                api_enums::Connector::from_str(&get_file_name(&connector_directory))
+            else {
+                logger::warn!(path = ?connector_directory, "Skipping reports of unknown connector");
+                continue;
+            };
+
+            for report_type_directory in read_subdirectories(&connector_directory).await? {
+                let Ok(report_type) = enums::ReconciliationReportType::from_str(&get_file_name(
+                    &report_type_directory,
+                )) else {
+                    logger::warn!(path = ?report_type_directory, "Skipping reports of unknown type");
+                    continue;
+                };
+
+                for report_path in read_reports(&report_type_directory).await? {
+                    if let Err(error) =
+                        import_report(db, &merchant_account, connector, report_type, &report_path)
+                            .await
+                    {
+                        logger::error!(
+                            ?error,
+                            path = %report_path.display(),
+                            "Failed to import report"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn import_report(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    connector: api_enums::Connector,
+    report_type: enums::ReconciliationReportType,
+    report_path: &Path,
+) -> CustomResult<storage::ReconciliationRun, errors::ReconciliationError> {
+    let content = run_blocking_fs({
+        let report_path = report_path.to_owned();
+        move || fs::read_to_string(report_path)
+    })
+    .await
+    .attach_printable_lazy(|| format!("Failed to read report {}", report_path.display()))?;
+
+    // Reports placed in the directory do not state the period they cover, which is taken to span
+    // the transactions listed in them
+    let period = parsers::get_report_parser(connector)
+        .and_then(|parser| parser.parse_report(&content))
+        .ok()
+        .and_then(|records| get_records_period(&records));
+    let run = reconcile_report(
+        db,
+        merchant_account,
+        ConnectorReport {
+            connector,
+            report_type,
+            source: report_path.display().to_string(),
+            content,
+            period,
+        },
+    )
+    .await
+    .change_context(errors::ReconciliationError::RecordFetchingFailed)?;
+    logger::info!(
+        run_id = %run.run_id,
+        status = %run.status,
+        path = %report_path.display(),
+        "Imported report"
+    );
+
+    let destination_directory = report_path
+        .parent()
+        .map(|directory| {
+            directory.join(match run.status {
+                enums::ReconciliationRunStatus::Failed => FAILED_REPORTS_DIRECTORY,
+                _ => PROCESSED_REPORTS_DIRECTORY,
+            })
+        })
+        .ok_or(errors::ReconciliationError::ReportReadFailed)
+        .into_report()?;
+    run_blocking_fs({
+        let report_path = report_path.to_owned();
+        move || {
+            fs::create_dir_all(&destination_directory)?;
+            fs::rename(
+                &report_path,
+                destination_directory.join(get_file_name(&report_path)),
+            )
+        }
+    })
+    .await
+    .attach_printable_lazy(|| {
+        format!("Failed to move imported report {}", report_path.display())
+    })?;
+    Ok(run)
+}
+
+/// Returns the period from the creation of the earliest to that of the latest of the records,
+/// if any of them lists its creation time.
+fn get_records_period(records: &[ReportRecord]) -> Option<(PrimitiveDateTime, PrimitiveDateTime)> {
+    let created_at = records.iter().filter_map(|record| record.created_at);
+    Some((created_at.clone().min()?, created_at.max()?))
+}
+
+async fn get_merchant_account(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> CustomResult<domain::MerchantAccount, errors::StorageError> {
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await?;
+    db.find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+}
+
+async fn read_subdirectories(
+    directory: &Path,
+) -> CustomResult<Vec<PathBuf>, errors::ReconciliationError> {
+    read_directory(directory, |path| {
+        path.is_dir()
+            && ![PROCESSED_REPORTS_DIRECTORY, FAILED_REPORTS_DIRECTORY]
+                .contains(&get_file_name(path).as_str())
+    })
+    .await
+}
+
+async fn read_reports(directory: &Path) -> CustomResult<Vec<PathBuf>, errors::ReconciliationError> {
+    read_directory(directory, |path| {
+        path.is_file()
+            && path
+                .extension()
+                .map_or(false, |extension| extension.eq_ignore_ascii_case("csv"))
+    })
+    .await
+}
+
+async fn read_directory(
+    directory: &Path,
+    filter: fn(&Path) -> bool,
+) -> CustomResult<Vec<PathBuf>, errors::ReconciliationError> {
+    run_blocking_fs({
+        let directory = directory.to_owned();
+        move || {
+            let mut paths = fs::read_dir(directory)?
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| filter(path))
+                .collect::<Vec<_>>();
+            paths.sort();
+            Ok(paths)
+        }
+    })
+    .await
+    .attach_printable_lazy(|| format!("Failed to read directory {}", directory.display()))
+}
+
+/// Runs the filesystem operation on the blocking thread pool, so that slow disks do not stall the
+/// async runtime.
+async fn run_blocking_fs<T, F>(operation: F) -> CustomResult<T, errors::ReconciliationError>
+where
+    F: FnOnce() -> std::io::Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    tokio::task::spawn_blocking(operation)
+        .await
+        .into_report()
+        .change_context(errors::ReconciliationError::ReportReadFailed)?
+        .into_report()
+        .change_context(errors::ReconciliationError::ReportReadFailed)
+}
+
+fn get_file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|file_name| file_name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::{
+        db::{refund::RefundInterface, MockDb},
+        utils::test_utils,
+    };
+
+    fn report_record(outcome: ReportedOutcome) -> ReportRecord {
+        ReportRecord {
+            transaction_type: ReconciliationTransactionType::Payment,
+            connector_transaction_id: "pi_1".to_string(),
+            outcome,
+            amount: 1000,
+            currency: enums::Currency::USD,
+            created_at: None,
+        }
+    }
+
+    fn recorded_transaction(amount: i64, succeeded: bool) -> RecordedTransaction {
+        RecordedTransaction {
+            payment_id: "pay_1".to_string(),
+            refund_id: None,
+            amount,
+            currency: Some(enums::Currency::USD),
+            status: "charged".to_string(),
+            succeeded,
+        }
+    }
+
+    #[test]
+    fn test_matching_record_has_no_mismatches() {
+        let mismatches = compare_record(
+            &report_record(ReportedOutcome::Succeeded),
+            recorded_transaction(1000, true),
+        );
+        assert!(mismatches.is_empty());
+    }
+
+    #[test]
+    fn test_amount_and_status_mismatches() {
+        let mismatches = compare_record(
+            &report_record(ReportedOutcome::Failed),
+            recorded_transaction(1200, true),
+        );
+
+        assert_eq!(
+            mismatches
+                .iter()
+                .map(|mismatch| mismatch.mismatch_type)
+                .collect::<Vec<_>>(),
+            vec![
+                ReconciliationMismatchType::AmountMismatch,
+                ReconciliationMismatchType::StatusMismatch
+            ]
+        );
+        assert_eq!(mismatches[0].expected.as_deref(), Some("1200"));
+        assert_eq!(mismatches[0].reported.as_deref(), Some("1000"));
+        assert_eq!(mismatches[0].payment_id.as_deref(), Some("pay_1"));
+    }
+
+    fn refund_new(refund_id: &str, connector_refund_id: &str) -> storage::RefundNew {
+        storage::RefundNew {
+            refund_id: refund_id.to_string(),
+            payment_id: "pay_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            internal_reference_id: refund_id.to_string(),
+            external_reference_id: None,
+            connector_transaction_id: "pi_1".to_string(),
+            connector: "stripe".to_string(),
+            connector_refund_id: Some(connector_refund_id.to_string()),
+            refund_type: enums::RefundType::InstantRefund,
+            total_amount: 10000,
+            currency: enums::Currency::USD,
+            refund_amount: 2500,
+            refund_status: enums::RefundStatus::Success,
+            sent_to_gateway: true,
+            metadata: None,
+            refund_arn: None,
+            created_at: Some(datetime!(2023-06-01 10:00)),
+            modified_at: None,
+            description: None,
+            attempt_id: "pay_1_1".to_string(),
+            refund_reason: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_match_report_with_reported_and_missing_refunds() {
+        let mockdb = MockDb::new(&Default::default()).await.unwrap();
+        for (refund_id, connector_refund_id) in [("ref_1", "re_1"), ("ref_2", "re_2")] {
+            mockdb
+                .insert_refund(
+                    refund_new(refund_id, connector_refund_id),
+                    enums::MerchantStorageScheme::PostgresOnly,
+                )
+                .await
+                .unwrap();
+        }
+
+        let report = ConnectorReport {
+            connector: api_enums::Connector::Stripe,
+            report_type: enums::ReconciliationReportType::Settlement,
+            source: "report.csv".to_string(),
+            content: "\
+balance_transaction_id,created_utc,currency,gross,fee,net,reporting_category,source_id,payment_intent_id
+txn_1,2023-06-01 11:00:00,usd,-25.00,0.00,-25.00,refund,re_1,pi_1
+txn_2,2023-06-01 12:00:00,usd,-10.00,0.00,-10.00,refund,re_3,pi_1
+"
+            .to_string(),
+            period: Some((datetime!(2023-06-01 0:00), datetime!(2023-06-02 0:00))),
+        };
+        let summary = match_report(&mockdb, &test_utils::merchant_account(), &report)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.total_records, 2);
+        assert_eq!(summary.matched_records, 1);
+        assert_eq!(
+            summary
+                .mismatches
+                .iter()
+                .map(|mismatch| (
+                    mismatch.mismatch_type,
+                    mismatch.connector_transaction_id.as_str()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (ReconciliationMismatchType::ExtraTransaction, "re_3"),
+                (ReconciliationMismatchType::MissingTransaction, "re_2"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_reports_skips_other_files() {
+        let directory = std::env::temp_dir().join(common_utils::generate_id(
+            consts::ID_LENGTH,
+            "reconciliation_reports",
+        ));
+        fs::create_dir_all(directory.join(PROCESSED_REPORTS_DIRECTORY)).unwrap();
+        fs::create_dir_all(directory.join(FAILED_REPORTS_DIRECTORY)).unwrap();
+        fs::write(directory.join("b.CSV"), "").unwrap();
+        fs::write(directory.join("a.csv"), "").unwrap();
+        fs::write(directory.join("notes.txt"), "").unwrap();
+
+        let reports = read_reports(&directory).await.unwrap();
+        let subdirectories = read_subdirectories(&directory).await.unwrap();
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(
+            reports,
+            vec![directory.join("a.csv"), directory.join("b.CSV")]
+        );
+        assert!(subdirectories.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_import_report_moves_report_by_outcome() {
+        let mockdb = MockDb::new(&Default::default()).await.unwrap();
+        let merchant_account = test_utils::merchant_account();
+        let directory = std::env::temp_dir().join(common_utils::generate_id(
+            consts::ID_LENGTH,
+            "reconciliation_reports",
+        ));
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(
+            directory.join("valid.csv"),
+            "\
+balance_transaction_id,created_utc,currency,gross,fee,net,reporting_category,source_id,payment_intent_id
+txn_1,2023-06-02 09:00:00,usd,-25.00,0.00,-25.00,refund,re_1,pi_1
+txn_2,2023-06-01 11:00:00,usd,-10.00,0.00,-10.00,refund,re_2,pi_1
+",
+        )
+        .unwrap();
+        fs::write(
+            directory.join("malformed.csv"),
+            "reporting_category,currency,gross\ncharge,usd,10.00\n",
+        )
+        .unwrap();
+
+        let mut runs = Vec::new();
+        for report in ["valid.csv", "malformed.csv"] {
+            runs.push(
+                import_report(
+                    &mockdb,
+                    &merchant_account,
+                    api_enums::Connector::Stripe,
+                    enums::ReconciliationReportType::Settlement,
+                    &directory.join(report),
+                )
+                .await
+                .unwrap(),
+            );
+        }
+        let processed_reports = read_reports(&directory.join(PROCESSED_REPORTS_DIRECTORY))
+            .await
+            .unwrap();
+        let failed_reports = read_reports(&directory.join(FAILED_REPORTS_DIRECTORY))
+            .await
+            .unwrap();
+        let pending_reports = read_reports(&directory).await.unwrap();
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(runs[0].status, enums::ReconciliationRunStatus::Completed);
+        assert_eq!(runs[0].period_start, Some(datetime!(2023-06-01 11:00)));
+        assert_eq!(runs[0].period_end, Some(datetime!(2023-06-02 9:00)));
+        assert_eq!(runs[1].status, enums::ReconciliationRunStatus::Failed);
+        assert_eq!(
+            processed_reports,
+            vec![directory
+                .join(PROCESSED_REPORTS_DIRECTORY)
+                .join("valid.csv")]
+        );
+        assert_eq!(
+            failed_reports,
+            vec![directory
+                .join(FAILED_REPORTS_DIRECTORY)
+                .join("malformed.csv")]
+        );
+        assert!(pending_reports.is_empty());
+    }
+}
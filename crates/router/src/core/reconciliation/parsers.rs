@@ -0,0 +1,476 @@
+//! Parsers for the settlement and payout reports generated by connectors.
+//!
+//! The settlement and payout reports of a connector list transactions in the same format, hence
+//! each connector has a single parser for both kinds of reports.
+
+use std::{collections::HashMap, str::FromStr};
+
+use api_models::reconciliation::ReconciliationTransactionType;
+use error_stack::{report, IntoReport, ResultExt};
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::errors::{self, CustomResult},
+    types::{api::enums as api_enums, storage::enums},
+};
+
+/// Outcome of a transaction according to a connector report.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportedOutcome {
+    Succeeded,
+    Failed,
+}
+
+/// A transaction listed in a connector report.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReportRecord {
+    pub transaction_type: ReconciliationTransactionType,
+    /// The identifier of the payment or refund at the connector
+    pub connector_transaction_id: String,
+    pub outcome: ReportedOutcome,
+    /// The amount in the lowest denomination of the currency
+    pub amount: i64,
+    pub currency: enums::Currency,
+    /// When the transaction was created at the connector, if listed in the report
+    pub created_at: Option<PrimitiveDateTime>,
+}
+
+pub trait ReportParser {
+    /// Parses the transactions relevant to reconciliation from a report in the CSV format,
+    /// ignoring any other entries such as fees and payouts.
+    fn parse_report(
+        &self,
+        report: &str,
+    ) -> CustomResult<Vec<ReportRecord>, errors::ReconciliationError>;
+}
+
+pub fn get_report_parser(
+    connector: api_enums::Connector,
+) -> CustomResult<Box<dyn ReportParser + Send + Sync>, errors::ReconciliationError> {
+    match connector {
+        api_enums::Connector::Stripe => Ok(Box::new(StripeReportParser)),
+        api_enums::Connector::Adyen => Ok(Box::new(AdyenReportParser)),
+        _ => Err(report!(errors::ReconciliationError::ReportNotSupported {
+            connector: connector.to_string(),
+        })),
+    }
+}
+
+/// Parser for the itemized balance change and payout reconciliation reports of Stripe.
+pub struct StripeReportParser;
+
+impl ReportParser for StripeReportParser {
+    fn parse_report(
+        &self,
+        report: &str,
+    ) -> CustomResult<Vec<ReportRecord>, errors::ReconciliationError> {
+        parse_csv(report)?
+            .iter()
+            .filter_map(|row| {
+                let (transaction_type, outcome) = match row.get("reporting_category")?.as_str() {
+                    "charge" => (
+                        ReconciliationTransactionType::Payment,
+                        ReportedOutcome::Succeeded,
+                    ),
+                    "refund" => (
+                        ReconciliationTransactionType::Refund,
+                        ReportedOutcome::Succeeded,
+                    ),
+                    "refund_failure" => (
+                        ReconciliationTransactionType::Refund,
+                        ReportedOutcome::Failed,
+                    ),
+                    _ => return None,
+                };
+                Some(parse_stripe_record(row, transaction_type, outcome))
+            })
+            .collect()
+    }
+}
+
+fn parse_stripe_record(
+    row: &HashMap<String, String>,
+    transaction_type: ReconciliationTransactionType,
+    outcome: ReportedOutcome,
+) -> CustomResult<ReportRecord, errors::ReconciliationError> {
+    // Payments are identified by the payment intent, the source of charges being the charge
+    let connector_transaction_id = match transaction_type {
+        ReconciliationTransactionType::Payment => row
+            .get("payment_intent_id")
+            .filter(|payment_intent_id| !payment_intent_id.is_empty())
+            .map_or_else(|| get_column(row, "source_id"), Ok)?,
+        ReconciliationTransactionType::Refund => get_column(row, "source_id")?,
+    };
+    let currency = parse_currency(get_column(row, "currency")?)?;
+    let amount = to_minor_unit(get_column(row, "gross")?, currency)?.abs();
+
+    Ok(ReportRecord {
+        transaction_type,
+        connector_transaction_id: connector_transaction_id.to_owned(),
+        outcome,
+        amount,
+        currency,
+        created_at: parse_optional_datetime(row, "created_utc")?,
+    })
+}
+
+/// Parser for the settlement details reports of Adyen.
+pub struct AdyenReportParser;
+
+impl ReportParser for AdyenReportParser {
+    fn parse_report(
+        &self,
+        report: &str,
+    ) -> CustomResult<Vec<ReportRecord>, errors::ReconciliationError> {
+        parse_csv(report)?
+            .iter()
+            .filter_map(|row| {
+                // Refunds are identified by the reference of the modification, and payments by the
+                // PSP reference
+                let (transaction_type, outcome, id_column, amount_column) =
+                    match row.get("Type")?.as_str() {
+                        "Settled" => (
+                            ReconciliationTransactionType::Payment,
+                            ReportedOutcome::Succeeded,
+                            "Psp Reference",
+                            "Gross Credit (GC)",
+                        ),
+                        "Refunded" => (
+                            ReconciliationTransactionType::Refund,
+                            ReportedOutcome::Succeeded,
+                            "Modification Reference",
+                            "Gross Debit (GC)",
+                        ),
+                        "RefundedReversed" => (
+                            ReconciliationTransactionType::Refund,
+                            ReportedOutcome::Failed,
+                            "Modification Reference",
+                            "Gross Credit (GC)",
+                        ),
+                        _ => return None,
+                    };
+                Some(parse_adyen_record(
+                    row,
+                    transaction_type,
+                    outcome,
+                    id_column,
+                    amount_column,
+                ))
+            })
+            .collect()
+    }
+}
+
+fn parse_adyen_record(
+    row: &HashMap<String, String>,
+    transaction_type: ReconciliationTransactionType,
+    outcome: ReportedOutcome,
+    id_column: &str,
+    amount_column: &str,
+) -> CustomResult<ReportRecord, errors::ReconciliationError> {
+    let currency = parse_currency(get_column(row, "Gross Currency")?)?;
+    let amount = to_minor_unit(get_column(row, amount_column)?, currency)?;
+
+    Ok(ReportRecord {
+        transaction_type,
+        connector_transaction_id: get_column(row, id_column)?.to_owned(),
+        outcome,
+        amount,
+        currency,
+        created_at: parse_optional_datetime(row, "Creation Date")?,
+    })
+}
+
+fn get_column<'a>(
+    row: &'a HashMap<String, String>,
+    column: &str,
+) -> CustomResult<&'a String, errors::ReconciliationError> {
+    row.get(column)
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| {
+            report!(errors::ReconciliationError::ReportParsingFailed {
+                message: format!("missing value for column `{column}`"),
+            })
+        })
+}
+
+/// Parses the value of the column, if present, as a date and time such as `2023-06-01 10:00:00`.
+fn parse_optional_datetime(
+    row: &HashMap<String, String>,
+    column: &str,
+) -> CustomResult<Option<PrimitiveDateTime>, errors::ReconciliationError> {
+    row.get(column)
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            let invalid_datetime = || errors::ReconciliationError::ReportParsingFailed {
+                message: format!("invalid date `{value}` for column `{column}`"),
+            };
+            let format =
+                time::format_description::parse("[year]-[month]-[day] [hour]:[minute]:[second]")
+                    .into_report()
+                    .change_context_lazy(invalid_datetime)?;
+            PrimitiveDateTime::parse(value, &format)
+                .into_report()
+                .change_context_lazy(invalid_datetime)
+        })
+        .transpose()
+}
+
+fn parse_currency(currency: &str) -> CustomResult<enums::Currency, errors::ReconciliationError> {
+    enums::Currency::from_str(&currency.to_uppercase())
+        .into_report()
+        .change_context(errors::ReconciliationError::ReportParsingFailed {
+            message: format!("invalid currency `{currency}`"),
+        })
+}
+
+/// Converts an amount in the base denomination of the currency, such as `10.50`, to the lowest
+/// denomination of the currency.
+fn to_minor_unit(
+    amount: &str,
+    currency: enums::Currency,
+) -> CustomResult<i64, errors::ReconciliationError> {
+    let invalid_amount = || errors::ReconciliationError::ReportParsingFailed {
+        message: format!("invalid amount `{amount}` for currency {currency}"),
+    };
+    let decimal_places = match currency {
+        enums::Currency::JPY | enums::Currency::KRW => 0,
+        enums::Currency::BHD
+        | enums::Currency::JOD
+        | enums::Currency::KWD
+        | enums::Currency::OMR => 3,
+        _ => 2,
+    };
+
+    let (is_negative, unsigned_amount) = match amount.trim().strip_prefix('-') {
+        Some(unsigned_amount) => (true, unsigned_amount),
+        None => (false, amount.trim()),
+    };
+    let (integer_part, fractional_part) = unsigned_amount
+        .split_once('.')
+        .unwrap_or((unsigned_amount, ""));
+    if integer_part.is_empty()
+        || fractional_part.len() > decimal_places
+        || !integer_part
+            .chars()
+            .chain(fractional_part.chars())
+            .all(|character| character.is_ascii_digit())
+    {
+        return Err(report!(invalid_amount()));
+    }
+
+    let minor_unit_amount = format!("{integer_part}{fractional_part:0<decimal_places$}")
+        .parse::<i64>()
+        .into_report()
+        .change_context_lazy(invalid_amount)?;
+    Ok(if is_negative {
+        -minor_unit_amount
+    } else {
+        minor_unit_amount
+    })
+}
+
+/// Parses a CSV document with a header row into rows keyed by the column names.
+fn parse_csv(
+    document: &str,
+) -> CustomResult<Vec<HashMap<String, String>>, errors::ReconciliationError> {
+    let mut records = parse_csv_records(document)?.into_iter();
+    let header = records.next().ok_or_else(|| {
+        report!(errors::ReconciliationError::ReportParsingFailed {
+            message: "report is empty".to_string(),
+        })
+    })?;
+
+    records
+        .enumerate()
+        .map(|(index, record)| {
+            if record.len() != header.len() {
+                return Err(report!(errors::ReconciliationError::ReportParsingFailed {
+                    message: format!(
+                        "row {} has {} columns, expected {}",
+                        index + 1,
+                        record.len(),
+                        header.len()
+                    ),
+                }));
+            }
+            Ok(header.iter().cloned().zip(record).collect())
+        })
+        .collect()
+}
+
+/// Splits a CSV document into records, handling quoted fields as described in RFC 4180. Blank
+/// lines are skipped.
+fn parse_csv_records(
+    document: &str,
+) -> CustomResult<Vec<Vec<String>>, errors::ReconciliationError> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut characters = document.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(character) = characters.next() {
+        match (in_quotes, character) {
+            (true, '"') if characters.peek() == Some(&'"') => {
+                characters.next();
+                field.push('"');
+            }
+            (true, '"') => in_quotes = false,
+            (true, character) => field.push(character),
+            (false, '"') if field.is_empty() => in_quotes = true,
+            (false, ',') => record.push(std::mem::take(&mut field)),
+            (false, '\r') if characters.peek() == Some(&'\n') => {}
+            (false, '\n') => {
+                record.push(std::mem::take(&mut field));
+                if record.len() == 1 && record[0].is_empty() {
+                    record.clear();
+                } else {
+                    records.push(std::mem::take(&mut record));
+                }
+            }
+            (false, character) => field.push(character),
+        }
+    }
+
+    if in_quotes {
+        return Err(report!(errors::ReconciliationError::ReportParsingFailed {
+            message: "unterminated quoted field".to_string(),
+        }));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_with_quoted_fields() {
+        let document =
+            "id,description\r\n1,\"Refund, partial\"\r\n\r\n2,\"Said \"\"hi\"\"\nthere\"";
+        let records = parse_csv(document).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["description"], "Refund, partial");
+        assert_eq!(records[1]["id"], "2");
+        assert_eq!(records[1]["description"], "Said \"hi\"\nthere");
+    }
+
+    #[test]
+    fn test_parse_csv_rejects_malformed_documents() {
+        assert!(parse_csv("").is_err());
+        assert!(parse_csv("id,amount\n1").is_err());
+        assert!(parse_csv("id,amount\n1,\"10").is_err());
+    }
+
+    #[test]
+    fn test_to_minor_unit() {
+        assert_eq!(to_minor_unit("10.5", enums::Currency::USD).unwrap(), 1050);
+        assert_eq!(to_minor_unit("-0.99", enums::Currency::EUR).unwrap(), -99);
+        assert_eq!(to_minor_unit("1500", enums::Currency::JPY).unwrap(), 1500);
+        assert_eq!(to_minor_unit("1.234", enums::Currency::KWD).unwrap(), 1234);
+        assert!(to_minor_unit("1.234", enums::Currency::USD).is_err());
+        assert!(to_minor_unit("1,00", enums::Currency::USD).is_err());
+        assert!(to_minor_unit("", enums::Currency::USD).is_err());
+    }
+
+    #[test]
+    fn test_stripe_report_parser() {
+        let report = "\
+balance_transaction_id,created_utc,currency,gross,fee,net,reporting_category,source_id,payment_intent_id
+txn_1,2023-06-01 10:00:00,usd,100.00,-3.20,96.80,charge,ch_1,pi_1
+txn_2,2023-06-01 11:00:00,usd,-25.00,0.00,-25.00,refund,re_1,pi_1
+txn_3,2023-06-01 12:00:00,usd,-3.20,-3.20,0.00,fee,fee_1,
+txn_4,2023-06-02 10:00:00,eur,25.00,0.00,25.00,refund_failure,re_2,pi_2
+";
+        let records = StripeReportParser.parse_report(report).unwrap();
+
+        assert_eq!(
+            records,
+            vec![
+                ReportRecord {
+                    transaction_type: ReconciliationTransactionType::Payment,
+                    connector_transaction_id: "pi_1".to_string(),
+                    outcome: ReportedOutcome::Succeeded,
+                    amount: 10000,
+                    currency: enums::Currency::USD,
+                    created_at: Some(datetime!(2023-06-01 10:00)),
+                },
+                ReportRecord {
+                    transaction_type: ReconciliationTransactionType::Refund,
+                    connector_transaction_id: "re_1".to_string(),
+                    outcome: ReportedOutcome::Succeeded,
+                    amount: 2500,
+                    currency: enums::Currency::USD,
+                    created_at: Some(datetime!(2023-06-01 11:00)),
+                },
+                ReportRecord {
+                    transaction_type: ReconciliationTransactionType::Refund,
+                    connector_transaction_id: "re_2".to_string(),
+                    outcome: ReportedOutcome::Failed,
+                    amount: 2500,
+                    currency: enums::Currency::EUR,
+                    created_at: Some(datetime!(2023-06-02 10:00)),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_adyen_report_parser() {
+        let report = "\
+Company Account,Merchant Account,Psp Reference,Merchant Reference,Type,Modification Reference,Gross Currency,Gross Debit (GC),Gross Credit (GC)
+Company,Merchant,8815000000000001,pay_1,Settled,8815000000000001,EUR,,49.99
+Company,Merchant,8815000000000001,pay_1,Refunded,8815000000000002,EUR,10.00,
+Company,Merchant,,,MerchantPayout,,EUR,39.99,
+";
+        let records = AdyenReportParser.parse_report(report).unwrap();
+
+        assert_eq!(
+            records,
+            vec![
+                ReportRecord {
+                    transaction_type: ReconciliationTransactionType::Payment,
+                    connector_transaction_id: "8815000000000001".to_string(),
+                    outcome: ReportedOutcome::Succeeded,
+                    amount: 4999,
+                    currency: enums::Currency::EUR,
+                    created_at: None,
+                },
+                ReportRecord {
+                    transaction_type: ReconciliationTransactionType::Refund,
+                    connector_transaction_id: "8815000000000002".to_string(),
+                    outcome: ReportedOutcome::Succeeded,
+                    amount: 1000,
+                    currency: enums::Currency::EUR,
+                    created_at: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_report_with_invalid_date_fails() {
+        let report = "\
+reporting_category,currency,gross,source_id,created_utc
+charge,usd,10.00,ch_1,01/06/2023
+";
+        assert!(StripeReportParser.parse_report(report).is_err());
+    }
+
+    #[test]
+    fn test_report_with_missing_column_fails() {
+        let report = "reporting_category,currency,gross\ncharge,usd,10.00\n";
+        assert!(StripeReportParser.parse_report(report).is_err());
+    }
+}
@@ -1040,21 +1040,12 @@ pub async fn get_refund_sync_process_schedule_time(
     merchant_id: &str,
     retry_count: i32,
 ) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
-    let redis_mapping: errors::CustomResult<process_data::ConnectorPTMapping, errors::RedisError> =
-        db::get_and_deserialize_key(
-            db,
-            &format!("pt_mapping_refund_sync_{connector}"),
-            "ConnectorPTMapping",
-        )
-        .await;
-
-    let mapping = match redis_mapping {
-        Ok(x) => x,
-        Err(err) => {
-            logger::error!("Error: while getting connector mapping: {}", err);
-            process_data::ConnectorPTMapping::default()
-        }
-    };
+    let mapping: process_data::ConnectorPTMapping = process_tracker_utils::get_pt_mapping(
+        db,
+        &process_data::get_refund_sync_mapping_key(connector),
+        "ConnectorPTMapping",
+    )
+    .await;
 
     let time_delta =
         process_tracker_utils::get_schedule_time(mapping, merchant_id, retry_count + 1);
@@ -0,0 +1,554 @@
+use api_models::retry_schedules as retry_schedules_api;
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use super::errors::{self, RouterResponse, RouterResult};
+use crate::{
+    db::StorageInterface,
+    scheduler::{process_data, utils as process_tracker_utils},
+    services::{self, RedisConnInterface},
+    types::{
+        api::enums as api_enums,
+        storage::{self, enums as storage_enums},
+        transformers::{ForeignFrom, ForeignInto},
+    },
+    utils,
+};
+
+/// Upper bound on the number of attempts shown in a retry timeline, to keep previews of schedules
+/// with very large retry counts readable
+const MAX_TIMELINE_ATTEMPTS: i32 = 100;
+
+#[instrument(skip(db))]
+pub async fn retrieve_connector_retry_schedule(
+    db: &dyn StorageInterface,
+    connector: &str,
+    workflow: retry_schedules_api::ConnectorRetryWorkflow,
+) -> RouterResponse<retry_schedules_api::ConnectorRetryScheduleResponse> {
+    let key = get_connector_mapping_key(connector, workflow);
+    let (mapping, is_default) =
+        find_mapping::<process_data::ConnectorPTMapping>(db, &key, "ConnectorPTMapping").await?;
+
+    Ok(services::ApplicationResponse::Json(
+        retry_schedules_api::ConnectorRetryScheduleResponse {
+            connector: connector.to_string(),
+            workflow,
+            is_default,
+            schedule: retry_schedules_api::ConnectorRetrySchedule::foreign_from(mapping),
+        },
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn update_connector_retry_schedule(
+    db: &dyn StorageInterface,
+    connector: &str,
+    workflow: retry_schedules_api::ConnectorRetryWorkflow,
+    schedule: retry_schedules_api::ConnectorRetrySchedule,
+) -> RouterResponse<retry_schedules_api::ConnectorRetryScheduleResponse> {
+    validate_mapping(&schedule.default_mapping, "default_mapping")?;
+    for (merchant_id, mapping) in &schedule.custom_merchant_mapping {
+        validate_mapping(mapping, &format!("custom_merchant_mapping.{merchant_id}"))?;
+    }
+    validate_max_retries_count(schedule.max_retries_count)?;
+
+    let key = get_connector_mapping_key(connector, workflow);
+    let mapping = process_data::ConnectorPTMapping::foreign_from(schedule.clone());
+    upsert_mapping(db, &key, &mapping).await?;
+    logger::info!(%key, ?mapping, "Retry schedule updated");
+
+    Ok(services::ApplicationResponse::Json(
+        retry_schedules_api::ConnectorRetryScheduleResponse {
+            connector: connector.to_string(),
+            workflow,
+            is_default: false,
+            schedule,
+        },
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn delete_connector_retry_schedule(
+    db: &dyn StorageInterface,
+    connector: &str,
+    workflow: retry_schedules_api::ConnectorRetryWorkflow,
+) -> RouterResponse<retry_schedules_api::ConnectorRetryScheduleResponse> {
+    let key = get_connector_mapping_key(connector, workflow);
+    delete_mapping(db, &key).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        retry_schedules_api::ConnectorRetryScheduleResponse {
+            connector: connector.to_string(),
+            workflow,
+            is_default: true,
+            schedule: retry_schedules_api::ConnectorRetrySchedule::foreign_from(
+                process_data::ConnectorPTMapping::default(),
+            ),
+        },
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn preview_connector_retry_timeline(
+    db: &dyn StorageInterface,
+    connector: &str,
+    workflow: retry_schedules_api::ConnectorRetryWorkflow,
+    request: retry_schedules_api::ConnectorRetryTimelineRequest,
+) -> RouterResponse<retry_schedules_api::RetryTimelineResponse> {
+    let key = get_connector_mapping_key(connector, workflow);
+    let (mapping, _) =
+        find_mapping::<process_data::ConnectorPTMapping>(db, &key, "ConnectorPTMapping").await?;
+    // Merchant IDs are never empty, so this selects the default mapping if no merchant is specified
+    let merchant_id = request.merchant_id.unwrap_or_default();
+
+    Ok(services::ApplicationResponse::Json(get_retry_timeline(
+        |retry_count| {
+            process_tracker_utils::get_schedule_time(mapping.clone(), &merchant_id, retry_count)
+        },
+    )))
+}
+
+#[instrument(skip(db))]
+pub async fn retrieve_payment_method_retry_schedule(
+    db: &dyn StorageInterface,
+    payment_method: api_enums::PaymentMethod,
+) -> RouterResponse<retry_schedules_api::PaymentMethodRetryScheduleResponse> {
+    let key = process_data::get_delete_tokenize_data_mapping_key(&payment_method.foreign_into());
+    let (mapping, is_default) =
+        find_mapping::<process_data::PaymentMethodsPTMapping>(db, &key, "PaymentMethodsPTMapping")
+            .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        retry_schedules_api::PaymentMethodRetryScheduleResponse {
+            payment_method,
+            is_default,
+            schedule: retry_schedules_api::PaymentMethodRetrySchedule::foreign_from(mapping),
+        },
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn update_payment_method_retry_schedule(
+    db: &dyn StorageInterface,
+    payment_method: api_enums::PaymentMethod,
+    schedule: retry_schedules_api::PaymentMethodRetrySchedule,
+) -> RouterResponse<retry_schedules_api::PaymentMethodRetryScheduleResponse> {
+    validate_mapping(&schedule.default_mapping, "default_mapping")?;
+    for (custom_payment_method, mapping) in &schedule.custom_pm_mapping {
+        validate_mapping(
+            mapping,
+            &format!("custom_pm_mapping.{custom_payment_method}"),
+        )?;
+    }
+    validate_max_retries_count(schedule.max_retries_count)?;
+
+    let key = process_data::get_delete_tokenize_data_mapping_key(&payment_method.foreign_into());
+    let mapping = process_data::PaymentMethodsPTMapping::foreign_from(schedule.clone());
+    upsert_mapping(db, &key, &mapping).await?;
+    logger::info!(%key, ?mapping, "Retry schedule updated");
+
+    Ok(services::ApplicationResponse::Json(
+        retry_schedules_api::PaymentMethodRetryScheduleResponse {
+            payment_method,
+            is_default: false,
+            schedule,
+        },
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn delete_payment_method_retry_schedule(
+    db: &dyn StorageInterface,
+    payment_method: api_enums::PaymentMethod,
+) -> RouterResponse<retry_schedules_api::PaymentMethodRetryScheduleResponse> {
+    let key = process_data::get_delete_tokenize_data_mapping_key(&payment_method.foreign_into());
+    delete_mapping(db, &key).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        retry_schedules_api::PaymentMethodRetryScheduleResponse {
+            payment_method,
+            is_default: true,
+            schedule: retry_schedules_api::PaymentMethodRetrySchedule::foreign_from(
+                process_data::PaymentMethodsPTMapping::default(),
+            ),
+        },
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn preview_payment_method_retry_timeline(
+    db: &dyn StorageInterface,
+    payment_method: api_enums::PaymentMethod,
+) -> RouterResponse<retry_schedules_api::RetryTimelineResponse> {
+    let payment_method: storage_enums::PaymentMethod = payment_method.foreign_into();
+    let key = process_data::get_delete_tokenize_data_mapping_key(&payment_method);
+    let (mapping, _) =
+        find_mapping::<process_data::PaymentMethodsPTMapping>(db, &key, "PaymentMethodsPTMapping")
+            .await?;
+
+    Ok(services::ApplicationResponse::Json(get_retry_timeline(
+        |retry_count| {
+            process_tracker_utils::get_pm_schedule_time(
+                mapping.clone(),
+                &payment_method,
+                retry_count,
+            )
+        },
+    )))
+}
+
+fn get_connector_mapping_key(
+    connector: &str,
+    workflow: retry_schedules_api::ConnectorRetryWorkflow,
+) -> String {
+    match workflow {
+        retry_schedules_api::ConnectorRetryWorkflow::PaymentSync => {
+            process_data::get_payment_sync_mapping_key(connector)
+        }
+        retry_schedules_api::ConnectorRetryWorkflow::RefundSync => {
+            process_data::get_refund_sync_mapping_key(connector)
+        }
+    }
+}
+
+/// Obtains the retry schedule stored with the key, along with whether the default retry schedule
+/// is being used since none has been configured.
+async fn find_mapping<T>(
+    db: &dyn StorageInterface,
+    key: &str,
+    type_name: &'static str,
+) -> RouterResult<(T, bool)>
+where
+    T: serde::de::DeserializeOwned + Default,
+{
+    match db.find_config_by_key_cached(key).await {
+        Ok(config) => {
+            let mapping = serde_json::from_str::<T>(&config.config)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| format!("Failed to parse {type_name} stored in {key}"))?;
+            Ok((mapping, false))
+        }
+        Err(error)
+            if error.current_context().is_db_not_found()
+                || matches!(
+                    error.current_context(),
+                    errors::StorageError::ValueNotFound(_)
+                ) =>
+        {
+            Ok(
+                match process_tracker_utils::get_redis_pt_mapping(db, key, type_name).await {
+                    Some(mapping) => (mapping, false),
+                    None => (T::default(), true),
+                },
+            )
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("Failed to fetch {type_name} stored in {key}")),
+    }
+}
+
+async fn upsert_mapping<T>(db: &dyn StorageInterface, key: &str, mapping: &T) -> RouterResult<()>
+where
+    T: serde::Serialize + std::fmt::Debug,
+{
+    let config = serde_json::to_string(mapping)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize retry schedule")?;
+
+    let exists = db.find_config_by_key(key).await.is_ok();
+    if exists {
+        db.update_config_cached(
+            key,
+            storage::ConfigUpdate::Update {
+                config: Some(config),
+            },
+        )
+        .await
+        .map(|_| ())
+    } else {
+        db.insert_config(storage::ConfigNew {
+            key: key.to_string(),
+            config,
+        })
+        .await
+        .map(|_| ())
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| format!("Failed to store retry schedule in {key}"))
+}
+
+async fn delete_mapping(db: &dyn StorageInterface, key: &str) -> RouterResult<()> {
+    let is_config_deleted = match db.delete_config_by_key(key).await {
+        Ok(is_deleted) => is_deleted,
+        Err(error) if error.current_context().is_db_not_found() => false,
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("Failed to delete retry schedule stored in {key}"))?,
+    };
+
+    // Retry schedules stored in redis are used when there is no config, so they are deleted too
+    let is_redis_mapping_deleted = matches!(
+        db.get_redis_conn()
+            .delete_key(key)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("Failed to delete retry schedule stored in {key}"))?,
+        redis_interface::DelReply::KeyDeleted
+    );
+
+    utils::when(!is_config_deleted && !is_redis_mapping_deleted, || {
+        Err(errors::ApiErrorResponse::ConfigNotFound)
+    })?;
+    logger::info!(%key, "Retry schedule deleted, the default schedule will be used");
+
+    Ok(())
+}
+
+fn validate_mapping(
+    mapping: &retry_schedules_api::RetryMapping,
+    field_name: &str,
+) -> RouterResult<()> {
+    let error = |message: &str| {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("{field_name}: {message}"),
+        })
+        .into_report()
+    };
+
+    if mapping.start_after < 0 {
+        return error("`start_after` must not be negative");
+    }
+    if mapping.frequency.is_empty() {
+        return error("`frequency` must not be empty");
+    }
+    if mapping.frequency.len() != mapping.count.len() {
+        return error("`frequency` and `count` must have the same number of elements");
+    }
+    if mapping.frequency.iter().any(|frequency| *frequency <= 0) {
+        return error("`frequency` must only contain positive delays");
+    }
+    if mapping.count.iter().any(|count| *count <= 0) {
+        return error("`count` must only contain positive retry counts");
+    }
+
+    Ok(())
+}
+
+fn validate_max_retries_count(max_retries_count: i32) -> RouterResult<()> {
+    common_utils::fp_utils::when(max_retries_count < 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`max_retries_count` must not be negative".to_string(),
+        })
+        .into_report()
+    })
+}
+
+/// Builds the timeline of attempts from the delay before each attempt, as obtained by the
+/// workflows when scheduling the task.
+fn get_retry_timeline(
+    get_delay: impl Fn(i32) -> Option<i32>,
+) -> retry_schedules_api::RetryTimelineResponse {
+    let mut timeline = Vec::new();
+    let mut elapsed = 0;
+    for attempt in 0..MAX_TIMELINE_ATTEMPTS {
+        let delay = match get_delay(attempt) {
+            Some(delay) => delay,
+            None => break,
+        };
+        elapsed += i64::from(delay);
+        timeline.push(retry_schedules_api::RetryTimelineEntry {
+            attempt,
+            delay,
+            elapsed,
+        });
+    }
+
+    retry_schedules_api::RetryTimelineResponse { timeline }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn json<T>(response: services::ApplicationResponse<T>) -> Option<T> {
+        match response {
+            services::ApplicationResponse::Json(response) => Some(response),
+            _ => None,
+        }
+    }
+
+    fn mapping(
+        start_after: i32,
+        frequency: Vec<i32>,
+        count: Vec<i32>,
+    ) -> process_data::RetryMapping {
+        process_data::RetryMapping {
+            start_after,
+            frequency,
+            count,
+        }
+    }
+
+    #[test]
+    fn retry_timeline_follows_schedule() {
+        let mapping = process_data::ConnectorPTMapping {
+            default_mapping: mapping(60, vec![300, 600], vec![2, 1]),
+            custom_merchant_mapping: [("merchant_1".to_string(), mapping(10, vec![20], vec![1]))]
+                .into_iter()
+                .collect(),
+            max_retries_count: 3,
+        };
+        let get_delay = |merchant_id: &'static str| {
+            let mapping = &mapping;
+            move |retry_count| {
+                process_tracker_utils::get_schedule_time(mapping.clone(), merchant_id, retry_count)
+            }
+        };
+
+        let timeline = get_retry_timeline(get_delay(""))
+            .timeline
+            .into_iter()
+            .map(|entry| (entry.attempt, entry.delay, entry.elapsed))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            timeline,
+            vec![(0, 60, 60), (1, 300, 360), (2, 300, 660), (3, 600, 1260)]
+        );
+
+        let timeline = get_retry_timeline(get_delay("merchant_1"))
+            .timeline
+            .into_iter()
+            .map(|entry| (entry.attempt, entry.delay, entry.elapsed))
+            .collect::<Vec<_>>();
+        assert_eq!(timeline, vec![(0, 10, 10), (1, 20, 30)]);
+    }
+
+    #[test]
+    fn invalid_mappings_are_rejected() {
+        let valid = retry_schedules_api::RetryMapping {
+            start_after: 60,
+            frequency: vec![300],
+            count: vec![5],
+        };
+        assert!(validate_mapping(&valid, "default_mapping").is_ok());
+
+        let mismatched = retry_schedules_api::RetryMapping {
+            count: vec![5, 1],
+            ..valid.clone()
+        };
+        assert!(validate_mapping(&mismatched, "default_mapping").is_err());
+
+        let non_positive_frequency = retry_schedules_api::RetryMapping {
+            frequency: vec![0],
+            ..valid.clone()
+        };
+        assert!(validate_mapping(&non_positive_frequency, "default_mapping").is_err());
+
+        let negative_start = retry_schedules_api::RetryMapping {
+            start_after: -1,
+            ..valid
+        };
+        assert!(validate_mapping(&negative_start, "default_mapping").is_err());
+    }
+
+    #[tokio::test]
+    async fn update_and_retrieve_connector_retry_schedule() {
+        let db = crate::db::MockDb::new(&Default::default()).await.unwrap();
+        let workflow = retry_schedules_api::ConnectorRetryWorkflow::RefundSync;
+
+        let response = json(
+            retrieve_connector_retry_schedule(&db, "stripe", workflow)
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(response.is_default);
+
+        let schedule = retry_schedules_api::ConnectorRetrySchedule {
+            default_mapping: retry_schedules_api::RetryMapping {
+                start_after: 30,
+                frequency: vec![120],
+                count: vec![3],
+            },
+            custom_merchant_mapping: Default::default(),
+            max_retries_count: 3,
+        };
+        update_connector_retry_schedule(&db, "stripe", workflow, schedule.clone())
+            .await
+            .unwrap();
+
+        let response = json(
+            retrieve_connector_retry_schedule(&db, "stripe", workflow)
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(!response.is_default);
+        assert_eq!(response.schedule, schedule);
+
+        // The workflows read the stored schedule
+        let mapping: process_data::ConnectorPTMapping = process_tracker_utils::get_pt_mapping(
+            &db,
+            &process_data::get_refund_sync_mapping_key("stripe"),
+            "ConnectorPTMapping",
+        )
+        .await;
+        assert_eq!(mapping.default_mapping.start_after, 30);
+    }
+
+    #[tokio::test]
+    async fn retry_schedule_stored_in_redis_is_used_and_deleted() {
+        let db = crate::db::MockDb::new(&Default::default()).await.unwrap();
+        let key = process_data::get_payment_sync_mapping_key("stripe");
+        let mapping = process_data::ConnectorPTMapping {
+            default_mapping: mapping(45, vec![90], vec![2]),
+            custom_merchant_mapping: Default::default(),
+            max_retries_count: 2,
+        };
+        db.get_redis_conn()
+            .serialize_and_set_key(&key, &mapping)
+            .await
+            .unwrap();
+
+        let stored: process_data::ConnectorPTMapping =
+            process_tracker_utils::get_pt_mapping(&db, &key, "ConnectorPTMapping").await;
+        assert_eq!(stored.default_mapping.start_after, 45);
+
+        let workflow = retry_schedules_api::ConnectorRetryWorkflow::PaymentSync;
+        let response = json(
+            retrieve_connector_retry_schedule(&db, "stripe", workflow)
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(!response.is_default);
+
+        delete_connector_retry_schedule(&db, "stripe", workflow)
+            .await
+            .unwrap();
+        let stored: process_data::ConnectorPTMapping =
+            process_tracker_utils::get_pt_mapping(&db, &key, "ConnectorPTMapping").await;
+        assert_eq!(stored.default_mapping.start_after, 60);
+    }
+
+    #[tokio::test]
+    async fn deleting_missing_retry_schedule_is_not_found() {
+        let db = crate::db::MockDb::new(&Default::default()).await.unwrap();
+
+        let error = delete_connector_retry_schedule(
+            &db,
+            "stripe",
+            retry_schedules_api::ConnectorRetryWorkflow::PaymentSync,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::ConfigNotFound
+        ));
+    }
+}
@@ -3,12 +3,13 @@ use std::marker::PhantomData;
 use api_models::enums::{DisputeStage, DisputeStatus};
 use common_utils::errors::CustomResult;
 use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
 use router_env::{instrument, tracing};
 
 use super::payments::{helpers, PaymentAddress};
 use crate::{
     consts,
-    core::errors::{self, RouterResult},
+    core::errors::{self, RouterResult, StorageErrorExt},
     routes::AppState,
     types::{
         self, domain,
@@ -107,6 +108,7 @@ pub async fn construct_refund_router_data<'a, F>(
         payment_method_token: None,
         connector_customer: None,
         preprocessing_id: None,
+        network_token: None,
     };
 
     Ok(router_data)
@@ -294,6 +296,7 @@ pub async fn construct_accept_dispute_router_data<'a>(
         connector_customer: None,
         customer_id: None,
         preprocessing_id: None,
+        network_token: None,
     };
     Ok(router_data)
 }
@@ -355,6 +358,7 @@ pub async fn construct_submit_evidence_router_data<'a>(
         connector_customer: None,
         customer_id: None,
         preprocessing_id: None,
+        network_token: None,
     };
     Ok(router_data)
 }
@@ -417,6 +421,7 @@ pub async fn construct_upload_file_router_data<'a>(
         connector_customer: None,
         customer_id: None,
         preprocessing_id: None,
+        network_token: None,
     };
     Ok(router_data)
 }
@@ -481,6 +486,150 @@ pub async fn construct_defend_dispute_router_data<'a>(
         customer_id: None,
         connector_customer: None,
         preprocessing_id: None,
+        network_token: None,
+    };
+    Ok(router_data)
+}
+
+#[instrument(skip_all)]
+pub async fn construct_fetch_dispute_router_data<'a>(
+    state: &'a AppState,
+    payment_intent: &'a storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: &storage::Dispute,
+) -> RouterResult<types::FetchDisputeRouterData> {
+    let connector_id = &dispute.connector;
+    let connector_label = helpers::get_connector_label(
+        payment_intent.business_country,
+        &payment_intent.business_label,
+        payment_attempt.business_sub_label.as_ref(),
+        connector_id,
+    );
+    let merchant_connector_account = helpers::get_merchant_connector_account(
+        state,
+        merchant_account.merchant_id.as_str(),
+        &connector_label,
+        None,
+        key_store,
+    )
+    .await?;
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .get_connector_account_details()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let payment_method = payment_attempt
+        .payment_method
+        .get_required_value("payment_method_type")?;
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: connector_id.to_string(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        status: payment_attempt.status,
+        payment_method,
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: payment_intent.return_url.clone(),
+        payment_method_id: payment_attempt.payment_method_id.clone(),
+        address: PaymentAddress::default(),
+        auth_type: payment_attempt.authentication_type.unwrap_or_default(),
+        connector_meta_data: merchant_connector_account.get_metadata(),
+        amount_captured: payment_intent.amount_captured,
+        request: types::FetchDisputeRequestData {
+            dispute_id: dispute.dispute_id.clone(),
+            connector_dispute_id: dispute.connector_dispute_id.clone(),
+        },
+        response: Err(types::ErrorResponse::default()),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        connector_customer: None,
+        customer_id: None,
+        preprocessing_id: None,
+        network_token: None,
+    };
+    Ok(router_data)
+}
+
+#[instrument(skip_all)]
+pub async fn construct_mandate_revoke_router_data(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    mandate: &storage::Mandate,
+) -> RouterResult<types::MandateRevokeRouterData> {
+    // Mandates do not record the connector account they were set up with, so the first enabled
+    // account of the connector is used
+    let merchant_connector_account = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &merchant_account.merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?
+        .into_iter()
+        .find(|mca| mca.connector_name == mandate.connector)
+        .ok_or(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: mandate.connector.clone(),
+        })
+        .into_report()?;
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .peek()
+        .clone()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let connector_mandate_ids = mandate
+        .connector_mandate_ids
+        .clone()
+        .map(|connector_mandate_ids| {
+            connector_mandate_ids
+                .parse_value::<api_models::payments::ConnectorMandateReferenceId>(
+                    "ConnectorMandateId",
+                )
+                .change_context(errors::ApiErrorResponse::MandateDeserializationFailed)
+        })
+        .transpose()?;
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: mandate.connector.clone(),
+        customer_id: Some(mandate.customer_id.clone()),
+        connector_customer: None,
+        payment_id: "irrelevant_payment_id_in_mandate_revoke_flow".to_string(),
+        attempt_id: "irrelevant_attempt_id_in_mandate_revoke_flow".to_string(),
+        status: storage_models::enums::AttemptStatus::default(),
+        payment_method: storage_models::enums::PaymentMethod::default(),
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: None,
+        payment_method_id: Some(mandate.payment_method_id.clone()),
+        address: PaymentAddress::default(),
+        auth_type: storage_models::enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.metadata,
+        amount_captured: None,
+        request: types::MandateRevokeRequestData {
+            mandate_id: mandate.mandate_id.clone(),
+            connector_mandate_id: connector_mandate_ids
+                .as_ref()
+                .and_then(|ids| ids.connector_mandate_id.clone())
+                .or_else(|| mandate.connector_mandate_id.clone()),
+            connector_payment_method_id: connector_mandate_ids
+                .and_then(|ids| ids.payment_method_id),
+        },
+        response: Err(types::ErrorResponse::default()),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        preprocessing_id: None,
+        network_token: None,
     };
     Ok(router_data)
 }
@@ -543,6 +692,7 @@ pub async fn construct_retrieve_file_router_data<'a>(
         reference_id: None,
         payment_method_token: None,
         preprocessing_id: None,
+        network_token: None,
     };
     Ok(router_data)
 }
@@ -11,6 +11,7 @@ use super::{errors::StorageErrorExt, metrics};
 use crate::{
     consts,
     core::{
+        disputes,
         errors::{self, CustomResult, RouterResponse},
         payments, refunds,
     },
@@ -285,11 +286,26 @@ pub async fn get_or_update_dispute_object(
                 connector_updated_at: dispute_details.updated_at,
                 evidence: None,
             };
-            state
+            let dispute = state
                 .store
                 .insert_dispute(new_dispute.clone())
                 .await
-                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+            // Failing to schedule the lifecycle of the dispute must not fail the webhook
+            disputes::add_dispute_lifecycle_task(
+                db,
+                &dispute,
+                &state.conf.disputes.evidence_reminder_hours,
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to add dispute lifecycle task to process tracker"
+                )
+            })
+            .ok();
+            Ok(dispute)
         }
         Some(dispute) => {
             logger::info!("Dispute Already exists, Updating the dispute details");
@@ -491,12 +507,14 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: api::OutgoingWebhookTy
         .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
         .attach_printable("event insertion failure")?;
 
-    if state.conf.webhooks.outgoing_enabled {
-        let arbiter = actix::Arbiter::try_current()
-            .ok_or(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .into_report()
-            .attach_printable("arbiter retrieval failure")?;
+    #[cfg(feature = "email")]
+    if let Some((recipient, template)) =
+        services::email::get_event_notification(&merchant_account, event.event_type, &content)
+    {
+        services::email::trigger_email_notification(&state, recipient, template);
+    }
 
+    if state.conf.webhooks.outgoing_enabled {
         let outgoing_webhook = api::OutgoingWebhook {
             merchant_id: merchant_account.merchant_id.clone(),
             event_id: event.event_id,
@@ -525,7 +543,7 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: api::OutgoingWebhookTy
             .attach_printable("Failed to sign the message")?
             .map(hex::encode);
 
-        arbiter.spawn(async move {
+        let webhook_delivery = async move {
             let result = trigger_webhook_to_merchant::<W>(
                 merchant_account,
                 outgoing_webhook,
@@ -537,7 +555,18 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: api::OutgoingWebhookTy
             if let Err(e) = result {
                 logger::error!(?e);
             }
-        });
+        };
+
+        // Events raised outside the actix runtime (such as by scheduler workflows) do not have
+        // an arbiter available, deliver those webhooks on the tokio runtime instead.
+        match actix::Arbiter::try_current() {
+            Some(arbiter) => {
+                arbiter.spawn(webhook_delivery);
+            }
+            None => {
+                tokio::spawn(webhook_delivery);
+            }
+        }
     }
 
     Ok(())
@@ -4,11 +4,13 @@ pub mod cache;
 pub mod cards_info;
 pub mod configs;
 pub mod connector_response;
+pub mod customer_erasure;
 pub mod customers;
 pub mod dispute;
 pub mod ephemeral_key;
 pub mod events;
 pub mod file;
+pub mod health_check;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -19,14 +21,18 @@ pub mod payment_intent;
 pub mod payment_method;
 pub mod process_tracker;
 pub mod queue;
+pub mod reconciliation;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod vault_card;
+pub mod vault_token;
 
 use std::sync::Arc;
 
 use futures::lock::Mutex;
 
 use crate::{
+    core::errors::{self, CustomResult},
     services::{self, Store},
     types::storage,
 };
@@ -47,11 +53,13 @@ pub trait StorageInterface:
     + api_keys::ApiKeyInterface
     + configs::ConfigInterface
     + connector_response::ConnectorResponseInterface
+    + customer_erasure::CustomerErasureInterface
     + customers::CustomerInterface
     + dispute::DisputeInterface
     + ephemeral_key::EphemeralKeyInterface
     + events::EventInterface
     + file::FileMetadataInterface
+    + health_check::HealthCheckInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
@@ -62,8 +70,11 @@ pub trait StorageInterface:
     + payment_method::PaymentMethodInterface
     + process_tracker::ProcessTrackerInterface
     + queue::QueueInterface
+    + reconciliation::ReconciliationRunInterface
     + refund::RefundInterface
     + reverse_lookup::ReverseLookupInterface
+    + vault_card::VaultCardInterface
+    + vault_token::VaultTokenInterface
     + cards_info::CardsInfoInterface
     + merchant_key_store::MerchantKeyStoreInterface
     + MasterKeyInterface
@@ -115,11 +126,33 @@ pub struct MockDb {
     disputes: Arc<Mutex<Vec<storage::Dispute>>>,
     lockers: Arc<Mutex<Vec<storage::LockerMockUp>>>,
     mandates: Arc<Mutex<Vec<storage::Mandate>>>,
+    configs: Arc<Mutex<Vec<storage::Config>>>,
+    files: Arc<Mutex<Vec<storage::FileMetadata>>>,
+    reconciliation_runs: Arc<Mutex<Vec<storage::ReconciliationRun>>>,
+    customer_erasures: Arc<Mutex<Vec<storage::CustomerErasure>>>,
+    merchant_key_store: Arc<Mutex<Vec<storage_models::merchant_key_store::MerchantKeyStore>>>,
+    reverse_lookups: Arc<Mutex<Vec<storage::ReverseLookup>>>,
+    vault_cards: Arc<Mutex<Vec<storage::VaultCard>>>,
+    vault_tokens: Arc<Mutex<Vec<storage::VaultToken>>>,
 }
 
 impl MockDb {
-    pub async fn new(redis: &crate::configs::settings::Settings) -> Self {
-        Self {
+    /// Creates an empty mock database. Redis is kept in memory when built for tests or with the
+    /// `mocks` feature, which the integration tests enable, and is otherwise connected to as
+    /// configured.
+    pub async fn new(
+        conf: &crate::configs::settings::Settings,
+    ) -> CustomResult<Self, errors::StorageError> {
+        #[cfg(any(test, feature = "mocks"))]
+        let redis = redis_interface::RedisConnectionPool::new_in_memory(&conf.redis)
+            .await
+            .map_err(Into::<errors::StorageError>::into)?;
+        #[cfg(not(any(test, feature = "mocks")))]
+        let redis = redis_interface::RedisConnectionPool::new(&conf.redis)
+            .await
+            .map_err(Into::<errors::StorageError>::into)?;
+
+        Ok(Self {
             addresses: Default::default(),
             merchant_accounts: Default::default(),
             merchant_connector_accounts: Default::default(),
@@ -130,7 +163,7 @@ impl MockDb {
             refunds: Default::default(),
             processes: Default::default(),
             connector_response: Default::default(),
-            redis: Arc::new(crate::connection::redis_connection(redis).await),
+            redis: Arc::new(redis),
             api_keys: Default::default(),
             ephemeral_keys: Default::default(),
             cards_info: Default::default(),
@@ -138,7 +171,15 @@ impl MockDb {
             disputes: Default::default(),
             lockers: Default::default(),
             mandates: Default::default(),
-        }
+            configs: Default::default(),
+            files: Default::default(),
+            reconciliation_runs: Default::default(),
+            customer_erasures: Default::default(),
+            merchant_key_store: Default::default(),
+            reverse_lookups: Default::default(),
+            vault_cards: Default::default(),
+            vault_tokens: Default::default(),
+        })
     }
 }
 
@@ -46,6 +46,33 @@ where
         address: storage::AddressUpdate,
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Address>, errors::StorageError>;
+
+    async fn update_unsaved_addresses_by_merchant_id_customer_id(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        address: storage::AddressUpdate,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError>;
+
+    async fn find_addresses_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError>;
+
+    async fn find_saved_addresses_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError>;
+
+    async fn delete_address_by_address_id(
+        &self,
+        address_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -143,6 +170,101 @@ impl AddressInterface for Store {
         })
         .await
     }
+
+    async fn update_unsaved_addresses_by_merchant_id_customer_id(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        address: storage::AddressUpdate,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Address::update_unsaved_by_merchant_id_customer_id(
+            &conn,
+            customer_id,
+            merchant_id,
+            address.into(),
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+        .async_and_then(|addresses| async {
+            let mut output = Vec::with_capacity(addresses.len());
+            for address in addresses.into_iter() {
+                output.push(
+                    address
+                        .convert(key_store.key.get_inner())
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                )
+            }
+            Ok(output)
+        })
+        .await
+    }
+
+    async fn find_addresses_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Address::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+            .async_and_then(|addresses| async {
+                let mut output = Vec::with_capacity(addresses.len());
+                for address in addresses.into_iter() {
+                    output.push(
+                        address
+                            .convert(key_store.key.get_inner())
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)?,
+                    )
+                }
+                Ok(output)
+            })
+            .await
+    }
+
+    async fn find_saved_addresses_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Address::find_saved_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+            .async_and_then(|addresses| async {
+                let mut output = Vec::with_capacity(addresses.len());
+                for address in addresses.into_iter() {
+                    output.push(
+                        address
+                            .convert(key_store.key.get_inner())
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)?,
+                    )
+                }
+                Ok(output)
+            })
+            .await
+    }
+
+    async fn delete_address_by_address_id(
+        &self,
+        address_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Address::delete_by_address_id(&conn, address_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -253,4 +375,220 @@ impl AddressInterface for MockDb {
             }
         }
     }
+
+    async fn update_unsaved_addresses_by_merchant_id_customer_id(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        address_update: storage::AddressUpdate,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+        let address_update = AddressUpdateInternal::from(address_update);
+        let updated_addresses = self
+            .addresses
+            .lock()
+            .await
+            .iter_mut()
+            .filter(|address| {
+                address.customer_id == customer_id
+                    && address.merchant_id == merchant_id
+                    && !address.is_saved
+            })
+            .map(|a| {
+                let address_updated = address_update.clone().create_address(a.clone());
+                *a = address_updated.clone();
+                address_updated
+            })
+            .collect::<Vec<_>>();
+
+        let mut output = Vec::with_capacity(updated_addresses.len());
+        for address in updated_addresses.into_iter() {
+            output.push(
+                address
+                    .convert(key_store.key.get_inner())
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            )
+        }
+        Ok(output)
+    }
+
+    async fn find_addresses_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+        let addresses = self
+            .addresses
+            .lock()
+            .await
+            .iter()
+            .filter(|address| {
+                address.merchant_id == merchant_id && address.customer_id == customer_id
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut output = Vec::with_capacity(addresses.len());
+        for address in addresses.into_iter() {
+            output.push(
+                address
+                    .convert(key_store.key.get_inner())
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            )
+        }
+        Ok(output)
+    }
+
+    async fn find_saved_addresses_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+        let addresses = self
+            .addresses
+            .lock()
+            .await
+            .iter()
+            .filter(|address| {
+                address.merchant_id == merchant_id
+                    && address.customer_id == customer_id
+                    && address.is_saved
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut output = Vec::with_capacity(addresses.len());
+        for address in addresses.into_iter() {
+            output.push(
+                address
+                    .convert(key_store.key.get_inner())
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            )
+        }
+        Ok(output)
+    }
+
+    async fn delete_address_by_address_id(
+        &self,
+        address_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut addresses = self.addresses.lock().await;
+        match addresses
+            .iter()
+            .position(|address| address.address_id == address_id)
+        {
+            Some(index) => {
+                addresses.remove(index);
+                Ok(true)
+            }
+            None => Err(errors::StorageError::ValueNotFound(
+                "cannot find address to delete".to_string(),
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use crate::{
+        db::{address::AddressInterface, MockDb},
+        types::storage,
+        utils::test_utils,
+    };
+
+    fn address(address_id: &str, is_saved: bool) -> storage::Address {
+        storage::Address {
+            id: 1,
+            address_id: address_id.into(),
+            city: Some("Bangalore".into()),
+            country: None,
+            line1: None,
+            line2: None,
+            line3: None,
+            state: None,
+            zip: None,
+            first_name: None,
+            last_name: None,
+            phone_number: None,
+            country_code: None,
+            created_at: datetime!(2023-07-01 0:00),
+            modified_at: datetime!(2023-07-01 0:00),
+            customer_id: "cus_1".into(),
+            merchant_id: "merchant_1".into(),
+            is_saved,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_unsaved_addresses_skips_saved_addresses() {
+        let mockdb = MockDb::new(&Default::default()).await.unwrap();
+        mockdb.addresses.lock().await.extend([
+            address("add_payment_1", false),
+            address("add_saved", true),
+            address("add_payment_2", false),
+        ]);
+
+        let updated = mockdb
+            .update_unsaved_addresses_by_merchant_id_customer_id(
+                "cus_1",
+                "merchant_1",
+                storage::AddressUpdate::Update {
+                    city: Some("Mumbai".into()),
+                    country: None,
+                    line1: None,
+                    line2: None,
+                    line3: None,
+                    state: None,
+                    zip: None,
+                    first_name: None,
+                    last_name: None,
+                    phone_number: None,
+                    country_code: None,
+                },
+                &test_utils::merchant_key_store(test_utils::MERCHANT_KEY),
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.len(), 2);
+
+        let saved = mockdb
+            .find_saved_addresses_by_merchant_id_customer_id(
+                "merchant_1",
+                "cus_1",
+                &test_utils::merchant_key_store(test_utils::MERCHANT_KEY),
+            )
+            .await
+            .unwrap();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].address_id, "add_saved");
+        assert_eq!(saved[0].city.as_deref(), Some("Bangalore"));
+    }
+
+    #[tokio::test]
+    async fn test_delete_address_by_address_id() {
+        let mockdb = MockDb::new(&Default::default()).await.unwrap();
+        mockdb
+            .addresses
+            .lock()
+            .await
+            .push(address("add_saved", true));
+
+        assert!(mockdb
+            .delete_address_by_address_id("add_saved")
+            .await
+            .unwrap());
+        assert!(mockdb
+            .delete_address_by_address_id("add_saved")
+            .await
+            .is_err());
+    }
 }
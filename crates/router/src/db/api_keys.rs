@@ -44,6 +44,12 @@ pub trait ApiKeyInterface {
         limit: Option<i64>,
         offset: Option<i64>,
     ) -> CustomResult<Vec<storage::ApiKey>, errors::StorageError>;
+
+    async fn list_unused_api_keys_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        unused_since: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::ApiKey>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -120,6 +126,18 @@ impl ApiKeyInterface for Store {
             .map_err(Into::into)
             .into_report()
     }
+
+    async fn list_unused_api_keys_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        unused_since: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::ApiKey>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ApiKey::find_unused_by_merchant_id(&conn, merchant_id, unused_since)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -281,6 +299,23 @@ impl ApiKeyInterface for MockDb {
 
         Ok(keys_for_merchant_id)
     }
+
+    async fn list_unused_api_keys_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        unused_since: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::ApiKey>, errors::StorageError> {
+        Ok(self
+            .api_keys
+            .lock()
+            .await
+            .iter()
+            .filter(|k| {
+                k.merchant_id == merchant_id && k.last_used.unwrap_or(k.created_at) < unused_since
+            })
+            .cloned()
+            .collect())
+    }
 }
 
 #[cfg(test)]
@@ -295,7 +330,7 @@ mod tests {
     #[allow(clippy::unwrap_used)]
     #[tokio::test]
     async fn test_mockdb_api_key_interface() {
-        let mockdb = MockDb::new(&Default::default()).await;
+        let mockdb = MockDb::new(&Default::default()).await.unwrap();
 
         let key1 = mockdb
             .insert_api_key(storage::ApiKeyNew {
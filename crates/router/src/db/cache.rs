@@ -108,3 +108,20 @@ where
     publish_into_redact_channel(store, key).await?;
     Ok(data)
 }
+
+pub async fn publish_and_redact_multiple<'a, T, F, Fut, K>(
+    store: &dyn StorageInterface,
+    keys: K,
+    fun: F,
+) -> CustomResult<T, errors::StorageError>
+where
+    F: FnOnce() -> Fut + Send,
+    Fut: futures::Future<Output = CustomResult<T, errors::StorageError>> + Send,
+    K: IntoIterator<Item = cache::CacheKind<'a>> + Send,
+{
+    let data = fun().await?;
+    for key in keys {
+        publish_into_redact_channel(store, key).await?;
+    }
+    Ok(data)
+}
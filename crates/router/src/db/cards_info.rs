@@ -1,6 +1,7 @@
 use error_stack::IntoReport;
 
 use crate::{
+    cache::CARD_INFO_CACHE,
     connection,
     core::errors::{self, CustomResult},
     db::MockDb,
@@ -22,11 +23,21 @@ impl CardsInfoInterface for Store {
         &self,
         card_iin: &str,
     ) -> CustomResult<Option<CardInfo>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        CardInfo::find_by_iin(&conn, card_iin)
-            .await
-            .map_err(Into::into)
-            .into_report()
+        let find_call = || async {
+            let conn = connection::pg_connection_read(self).await?;
+            CardInfo::find_by_iin(&conn, card_iin)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        };
+
+        super::cache::get_or_populate_in_memory(
+            self,
+            &format!("card_info_{card_iin}"),
+            find_call,
+            &CARD_INFO_CACHE,
+        )
+        .await
     }
 }
 
@@ -113,47 +113,86 @@ impl ConfigInterface for Store {
 impl ConfigInterface for MockDb {
     async fn insert_config(
         &self,
-        _config: storage::ConfigNew,
+        config: storage::ConfigNew,
     ) -> CustomResult<storage::Config, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut configs = self.configs.lock().await;
+        if configs.iter().any(|existing| existing.key == config.key) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "config",
+                key: Some(config.key.clone()),
+            })?
+        }
+
+        let config = storage::Config {
+            #[allow(clippy::as_conversions)]
+            id: configs.len() as i32,
+            key: config.key,
+            config: config.config,
+        };
+        configs.push(config.clone());
+        Ok(config)
     }
 
     async fn find_config_by_key(
         &self,
-        _key: &str,
+        key: &str,
     ) -> CustomResult<storage::Config, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.configs
+            .lock()
+            .await
+            .iter()
+            .find(|config| config.key == key)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!("cannot find config with key {key}"))
+                    .into()
+            })
     }
 
     async fn update_config_by_key(
         &self,
-        _key: &str,
-        _config_update: storage::ConfigUpdate,
+        key: &str,
+        config_update: storage::ConfigUpdate,
     ) -> CustomResult<storage::Config, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut configs = self.configs.lock().await;
+        let config = configs
+            .iter_mut()
+            .find(|config| config.key == key)
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!("cannot find config with key {key}"))
+            })?;
+
+        match config_update {
+            storage::ConfigUpdate::Update {
+                config: updated_config,
+            } => {
+                if let Some(updated_config) = updated_config {
+                    config.config = updated_config;
+                }
+            }
+        }
+        Ok(config.clone())
     }
+
     async fn update_config_cached(
         &self,
-        _key: &str,
-        _config_update: storage::ConfigUpdate,
+        key: &str,
+        config_update: storage::ConfigUpdate,
     ) -> CustomResult<storage::Config, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.update_config_by_key(key, config_update).await
     }
 
-    async fn delete_config_by_key(&self, _key: &str) -> CustomResult<bool, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+    async fn delete_config_by_key(&self, key: &str) -> CustomResult<bool, errors::StorageError> {
+        let mut configs = self.configs.lock().await;
+        let initial_length = configs.len();
+        configs.retain(|config| config.key != key);
+        Ok(configs.len() != initial_length)
     }
 
     async fn find_config_by_key_cached(
         &self,
-        _key: &str,
+        key: &str,
     ) -> CustomResult<storage::Config, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.find_config_by_key(key).await
     }
 }
@@ -0,0 +1,139 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CustomerErasureInterface {
+    async fn insert_customer_erasure(
+        &self,
+        customer_erasure: storage::CustomerErasureNew,
+    ) -> CustomResult<storage::CustomerErasure, errors::StorageError>;
+
+    async fn find_customer_erasure_by_merchant_id_erasure_id(
+        &self,
+        merchant_id: &str,
+        erasure_id: &str,
+    ) -> CustomResult<storage::CustomerErasure, errors::StorageError>;
+
+    async fn update_customer_erasure(
+        &self,
+        this: storage::CustomerErasure,
+        customer_erasure: storage::CustomerErasureUpdate,
+    ) -> CustomResult<storage::CustomerErasure, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CustomerErasureInterface for Store {
+    async fn insert_customer_erasure(
+        &self,
+        customer_erasure: storage::CustomerErasureNew,
+    ) -> CustomResult<storage::CustomerErasure, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        customer_erasure
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_customer_erasure_by_merchant_id_erasure_id(
+        &self,
+        merchant_id: &str,
+        erasure_id: &str,
+    ) -> CustomResult<storage::CustomerErasure, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomerErasure::find_by_merchant_id_erasure_id(&conn, merchant_id, erasure_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_customer_erasure(
+        &self,
+        this: storage::CustomerErasure,
+        customer_erasure: storage::CustomerErasureUpdate,
+    ) -> CustomResult<storage::CustomerErasure, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, customer_erasure)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerErasureInterface for MockDb {
+    async fn insert_customer_erasure(
+        &self,
+        customer_erasure: storage::CustomerErasureNew,
+    ) -> CustomResult<storage::CustomerErasure, errors::StorageError> {
+        let mut customer_erasures = self.customer_erasures.lock().await;
+        if customer_erasures.iter().any(|erasure| {
+            erasure.merchant_id == customer_erasure.merchant_id
+                && erasure.erasure_id == customer_erasure.erasure_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "customer_erasure",
+                key: Some(customer_erasure.erasure_id.clone()),
+            })?
+        }
+
+        let now = common_utils::date_time::now();
+        let customer_erasure = storage::CustomerErasure {
+            #[allow(clippy::as_conversions)]
+            id: customer_erasures.len() as i32,
+            erasure_id: customer_erasure.erasure_id,
+            merchant_id: customer_erasure.merchant_id,
+            customer_id: customer_erasure.customer_id,
+            status: customer_erasure.status,
+            erased_records: serde_json::Value::Object(serde_json::Map::new()),
+            error_message: None,
+            created_at: now,
+            modified_at: now,
+            completed_at: None,
+        };
+        customer_erasures.push(customer_erasure.clone());
+        Ok(customer_erasure)
+    }
+
+    async fn find_customer_erasure_by_merchant_id_erasure_id(
+        &self,
+        merchant_id: &str,
+        erasure_id: &str,
+    ) -> CustomResult<storage::CustomerErasure, errors::StorageError> {
+        self.customer_erasures
+            .lock()
+            .await
+            .iter()
+            .find(|erasure| erasure.merchant_id == merchant_id && erasure.erasure_id == erasure_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find customer erasure for merchant_id = {merchant_id} and erasure_id = {erasure_id}"
+                ))
+                .into()
+            })
+    }
+
+    async fn update_customer_erasure(
+        &self,
+        this: storage::CustomerErasure,
+        customer_erasure: storage::CustomerErasureUpdate,
+    ) -> CustomResult<storage::CustomerErasure, errors::StorageError> {
+        let mut customer_erasures = self.customer_erasures.lock().await;
+        let erasure = customer_erasures
+            .iter_mut()
+            .find(|erasure| {
+                erasure.merchant_id == this.merchant_id && erasure.erasure_id == this.erasure_id
+            })
+            .ok_or(errors::StorageError::MockDbError)?;
+        *erasure =
+            storage::CustomerErasureUpdateInternal::from(customer_erasure).apply_changeset(this);
+        Ok(erasure.clone())
+    }
+}
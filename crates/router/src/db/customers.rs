@@ -14,7 +14,7 @@ use crate::{
             self,
             behaviour::{Conversion, ReverseConversion},
         },
-        storage,
+        storage::{self, CustomerDbExt},
     },
 };
 
@@ -56,6 +56,20 @@ where
         customer_data: domain::Customer,
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::Customer, errors::StorageError>;
+
+    async fn list_customers_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: &storage::CustomerListConstraints,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError>;
+}
+
+/// Customers which have been deleted are redacted rather than removed, and are not listed. This
+/// checks the same unencrypted fields as the database query.
+fn is_redacted(customer: &storage::Customer) -> bool {
+    customer.description.as_deref() == Some(REDACTED)
+        && customer.phone_country_code.as_deref() == Some(REDACTED)
 }
 
 #[async_trait::async_trait]
@@ -168,6 +182,29 @@ impl CustomerInterface for Store {
             .await
     }
 
+    async fn list_customers_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: &storage::CustomerListConstraints,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        let customers = storage::Customer::filter_by_constraints(&conn, merchant_id, constraints)
+            .await
+            .map_err(Into::into)
+            .into_report()?;
+        let mut result = Vec::with_capacity(customers.len());
+        for customer in customers {
+            result.push(
+                customer
+                    .convert(key_store.key.get_inner())
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            );
+        }
+        Ok(result)
+    }
+
     async fn delete_customer_by_customer_id_merchant_id(
         &self,
         customer_id: &str,
@@ -209,23 +246,49 @@ impl CustomerInterface for MockDb {
 
     async fn update_customer_by_customer_id_merchant_id(
         &self,
-        _customer_id: String,
-        _merchant_id: String,
-        _customer: storage::CustomerUpdate,
-        _key_store: &domain::MerchantKeyStore,
+        customer_id: String,
+        merchant_id: String,
+        customer: storage::CustomerUpdate,
+        key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::Customer, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut customers = self.customers.lock().await;
+        let existing_customer = customers
+            .iter_mut()
+            .find(|existing_customer| {
+                existing_customer.customer_id == customer_id
+                    && existing_customer.merchant_id == merchant_id
+            })
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find customer for customer_id = {customer_id} and merchant_id = {merchant_id}"
+                ))
+            })?;
+
+        *existing_customer = storage::CustomerUpdateInternal::from(customer)
+            .apply_changeset(existing_customer.clone());
+
+        existing_customer
+            .clone()
+            .convert(key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
 
     async fn find_customer_by_customer_id_merchant_id(
         &self,
-        _customer_id: &str,
-        _merchant_id: &str,
-        _key_store: &domain::MerchantKeyStore,
+        customer_id: &str,
+        merchant_id: &str,
+        key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::Customer, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let customer = self
+            .find_customer_optional_by_customer_id_merchant_id(customer_id, merchant_id, key_store)
+            .await?;
+        customer.ok_or_else(|| {
+            errors::StorageError::ValueNotFound(format!(
+                "cannot find customer for customer_id = {customer_id} and merchant_id = {merchant_id}"
+            ))
+            .into()
+        })
     }
 
     #[allow(clippy::panic)]
@@ -248,12 +311,252 @@ impl CustomerInterface for MockDb {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    async fn list_customers_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: &storage::CustomerListConstraints,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+        let customers = self.customers.lock().await;
+        let find_id = |customer_id: &str| {
+            customers
+                .iter()
+                .find(|customer| {
+                    customer.customer_id == customer_id && customer.merchant_id == merchant_id
+                })
+                .map(|customer| customer.id)
+                .ok_or_else(|| {
+                    errors::StorageError::ValueNotFound(format!(
+                        "cannot find customer for customer_id = {customer_id} and merchant_id = {merchant_id}"
+                    ))
+                })
+        };
+        let starting_after = constraints
+            .starting_after
+            .as_deref()
+            .map(find_id)
+            .transpose()?;
+        let ending_before = constraints
+            .ending_before
+            .as_deref()
+            .map(find_id)
+            .transpose()?;
+
+        let mut filtered_customers = customers
+            .iter()
+            .filter(|customer| {
+                customer.merchant_id == merchant_id
+                    && !is_redacted(customer)
+                    && constraints
+                        .email_blind_index
+                        .as_ref()
+                        .map_or(true, |index| {
+                            customer.email_blind_index.as_ref() == Some(index)
+                        })
+                    && constraints
+                        .phone_blind_index
+                        .as_ref()
+                        .map_or(true, |index| {
+                            customer.phone_blind_index.as_ref() == Some(index)
+                        })
+                    && constraints
+                        .created
+                        .map_or(true, |created| customer.created_at == created)
+                    && constraints
+                        .created_lt
+                        .map_or(true, |created_lt| customer.created_at < created_lt)
+                    && constraints
+                        .created_gt
+                        .map_or(true, |created_gt| customer.created_at > created_gt)
+                    && constraints
+                        .created_lte
+                        .map_or(true, |created_lte| customer.created_at <= created_lte)
+                    && constraints
+                        .created_gte
+                        .map_or(true, |created_gte| customer.created_at >= created_gte)
+                    && starting_after.map_or(true, |id| customer.id < id)
+                    && ending_before.map_or(true, |id| customer.id > id)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // Same ordering as the database query, from the most recently created customer
+        filtered_customers.sort_by_key(|customer| std::cmp::Reverse(customer.id));
+        let limit = usize::try_from(constraints.limit).unwrap_or(usize::MAX);
+        let filtered_customers = if ending_before.is_some() {
+            let skip = filtered_customers.len().saturating_sub(limit);
+            filtered_customers
+                .into_iter()
+                .skip(skip)
+                .collect::<Vec<_>>()
+        } else {
+            filtered_customers.into_iter().take(limit).collect()
+        };
+
+        let mut result = Vec::with_capacity(filtered_customers.len());
+        for customer in filtered_customers {
+            result.push(
+                customer
+                    .convert(key_store.key.get_inner())
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            );
+        }
+        Ok(result)
+    }
+
     async fn delete_customer_by_customer_id_merchant_id(
         &self,
-        _customer_id: &str,
-        _merchant_id: &str,
+        customer_id: &str,
+        merchant_id: &str,
     ) -> CustomResult<bool, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut customers = self.customers.lock().await;
+        let initial_length = customers.len();
+        customers.retain(|customer| {
+            !(customer.customer_id == customer_id && customer.merchant_id == merchant_id)
+        });
+        Ok(customers.len() != initial_length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use crate::{
+        db::{customers::CustomerInterface, MockDb},
+        types::{domain, storage},
+        utils::test_utils,
+    };
+
+    fn customer(id: i32, email: &str) -> storage::Customer {
+        storage::Customer {
+            id,
+            customer_id: format!("cus_{id}"),
+            merchant_id: "merchant_1".into(),
+            name: None,
+            email: None,
+            phone: None,
+            phone_country_code: None,
+            description: None,
+            created_at: datetime!(2023-06-01 0:00),
+            metadata: None,
+            connector_customer: None,
+            modified_at: datetime!(2023-06-01 0:00),
+            default_payment_method_id: None,
+            email_blind_index: Some(
+                domain::get_email_blind_index(email, &test_utils::MERCHANT_KEY).unwrap(),
+            ),
+            phone_blind_index: None,
+            default_billing_address_id: None,
+            default_shipping_address_id: None,
+        }
+    }
+
+    fn customer_ids(customers: Vec<domain::Customer>) -> Vec<String> {
+        customers
+            .into_iter()
+            .map(|customer| customer.customer_id)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_list_customers_by_email_blind_index() {
+        let mockdb = MockDb::new(&Default::default()).await.unwrap();
+        mockdb.customers.lock().await.extend([
+            customer(1, "JonTest@test.com"),
+            customer(2, "other@test.com"),
+            customer(3, " jontest@TEST.com"),
+        ]);
+
+        let customers = mockdb
+            .list_customers_by_merchant_id(
+                "merchant_1",
+                &storage::CustomerListConstraints {
+                    email_blind_index: Some(
+                        domain::get_email_blind_index(
+                            "jontest@test.com",
+                            &test_utils::MERCHANT_KEY,
+                        )
+                        .unwrap(),
+                    ),
+                    limit: 10,
+                    ..Default::default()
+                },
+                &test_utils::merchant_key_store(test_utils::MERCHANT_KEY),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(customer_ids(customers), vec!["cus_3", "cus_1"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_customers_pagination() {
+        let mockdb = MockDb::new(&Default::default()).await.unwrap();
+        mockdb
+            .customers
+            .lock()
+            .await
+            .extend((1..=4).map(|id| customer(id, "jontest@test.com")));
+
+        let customers = mockdb
+            .list_customers_by_merchant_id(
+                "merchant_1",
+                &storage::CustomerListConstraints {
+                    starting_after: Some("cus_4".into()),
+                    limit: 2,
+                    ..Default::default()
+                },
+                &test_utils::merchant_key_store(test_utils::MERCHANT_KEY),
+            )
+            .await
+            .unwrap();
+        assert_eq!(customer_ids(customers), vec!["cus_3", "cus_2"]);
+
+        let customers = mockdb
+            .list_customers_by_merchant_id(
+                "merchant_1",
+                &storage::CustomerListConstraints {
+                    ending_before: Some("cus_1".into()),
+                    limit: 2,
+                    ..Default::default()
+                },
+                &test_utils::merchant_key_store(test_utils::MERCHANT_KEY),
+            )
+            .await
+            .unwrap();
+        assert_eq!(customer_ids(customers), vec!["cus_3", "cus_2"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_customers_excludes_redacted_customers_before_limit() {
+        let mockdb = MockDb::new(&Default::default()).await.unwrap();
+        let redacted = |id| storage::Customer {
+            description: Some(super::REDACTED.to_string()),
+            phone_country_code: Some(super::REDACTED.to_string()),
+            ..customer(id, "jontest@test.com")
+        };
+        mockdb.customers.lock().await.extend([
+            customer(1, "jontest@test.com"),
+            customer(2, "jontest@test.com"),
+            redacted(3),
+            redacted(4),
+        ]);
+
+        let customers = mockdb
+            .list_customers_by_merchant_id(
+                "merchant_1",
+                &storage::CustomerListConstraints {
+                    limit: 2,
+                    ..Default::default()
+                },
+                &test_utils::merchant_key_store(test_utils::MERCHANT_KEY),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(customer_ids(customers), vec!["cus_2", "cus_1"]);
     }
 }
@@ -43,6 +43,22 @@ pub trait DisputeInterface {
         payment_id: &str,
     ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
 
+    async fn find_disputes_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &str,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
+
+    /// Lists the disputes which were last updated within the time range, least recently updated
+    /// first
+    async fn find_disputes_by_statuses_modified_between(
+        &self,
+        dispute_statuses: Vec<storage::enums::DisputeStatus>,
+        modified_after: time::PrimitiveDateTime,
+        modified_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
+
     async fn update_dispute(
         &self,
         this: storage::Dispute,
@@ -118,6 +134,38 @@ impl DisputeInterface for Store {
             .into_report()
     }
 
+    async fn find_disputes_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &str,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Dispute::find_by_merchant_id_payment_ids(&conn, merchant_id, payment_ids)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_disputes_by_statuses_modified_between(
+        &self,
+        dispute_statuses: Vec<storage::enums::DisputeStatus>,
+        modified_after: time::PrimitiveDateTime,
+        modified_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Dispute::find_by_statuses_modified_between(
+            &conn,
+            dispute_statuses,
+            modified_after,
+            modified_before,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn update_dispute(
         &self,
         this: storage::Dispute,
@@ -297,6 +345,43 @@ impl DisputeInterface for MockDb {
             .collect())
     }
 
+    async fn find_disputes_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &str,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        let locked_disputes = self.disputes.lock().await;
+
+        Ok(locked_disputes
+            .iter()
+            .filter(|d| d.merchant_id == merchant_id && payment_ids.contains(&d.payment_id))
+            .cloned()
+            .collect())
+    }
+
+    async fn find_disputes_by_statuses_modified_between(
+        &self,
+        dispute_statuses: Vec<storage::enums::DisputeStatus>,
+        modified_after: time::PrimitiveDateTime,
+        modified_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        let locked_disputes = self.disputes.lock().await;
+
+        let mut stale_disputes = locked_disputes
+            .iter()
+            .filter(|d| {
+                dispute_statuses.contains(&d.dispute_status)
+                    && d.modified_at > modified_after
+                    && d.modified_at < modified_before
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        stale_disputes.sort_by_key(|d| d.modified_at);
+        stale_disputes.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+        Ok(stale_disputes)
+    }
+
     async fn update_dispute(
         &self,
         this: storage::Dispute,
@@ -408,7 +493,7 @@ mod tests {
 
         #[tokio::test]
         async fn test_insert_dispute() {
-            let mockdb = MockDb::new(&Default::default()).await;
+            let mockdb = MockDb::new(&Default::default()).await.unwrap();
 
             let created_dispute = mockdb
                 .insert_dispute(create_dispute_new(DisputeNewIds {
@@ -436,7 +521,7 @@ mod tests {
 
         #[tokio::test]
         async fn test_find_by_merchant_id_payment_id_connector_dispute_id() {
-            let mockdb = MockDb::new(&Default::default()).await;
+            let mockdb = MockDb::new(&Default::default()).await.unwrap();
 
             let created_dispute = mockdb
                 .insert_dispute(create_dispute_new(DisputeNewIds {
@@ -476,7 +561,7 @@ mod tests {
 
         #[tokio::test]
         async fn test_find_dispute_by_merchant_id_dispute_id() {
-            let mockdb = MockDb::new(&Default::default()).await;
+            let mockdb = MockDb::new(&Default::default()).await.unwrap();
 
             let created_dispute = mockdb
                 .insert_dispute(create_dispute_new(DisputeNewIds {
@@ -510,7 +595,7 @@ mod tests {
 
         #[tokio::test]
         async fn test_find_disputes_by_merchant_id() {
-            let mockdb = MockDb::new(&Default::default()).await;
+            let mockdb = MockDb::new(&Default::default()).await.unwrap();
 
             let created_dispute = mockdb
                 .insert_dispute(create_dispute_new(DisputeNewIds {
@@ -560,7 +645,7 @@ mod tests {
 
         #[tokio::test]
         async fn test_find_disputes_by_merchant_id_payment_id() {
-            let mockdb = MockDb::new(&Default::default()).await;
+            let mockdb = MockDb::new(&Default::default()).await.unwrap();
 
             let created_dispute = mockdb
                 .insert_dispute(create_dispute_new(DisputeNewIds {
@@ -594,6 +679,91 @@ mod tests {
             assert_eq!(created_dispute, found_disputes.get(0).unwrap().clone());
         }
 
+        #[tokio::test]
+        async fn test_find_disputes_by_merchant_id_payment_ids() {
+            let mockdb = MockDb::new(&Default::default()).await.unwrap();
+
+            for (dispute_id, merchant_id, payment_id) in [
+                ("dispute_1", "merchant_1", "payment_1"),
+                ("dispute_2", "merchant_1", "payment_2"),
+                ("dispute_3", "merchant_1", "payment_3"),
+                ("dispute_4", "merchant_2", "payment_1"),
+            ] {
+                mockdb
+                    .insert_dispute(create_dispute_new(DisputeNewIds {
+                        dispute_id: dispute_id.into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.into(),
+                        payment_id: payment_id.into(),
+                        connector_dispute_id: dispute_id.into(),
+                    }))
+                    .await
+                    .unwrap();
+            }
+
+            let mut found_dispute_ids = mockdb
+                .find_disputes_by_merchant_id_payment_ids(
+                    "merchant_1",
+                    vec!["payment_1".into(), "payment_2".into()],
+                )
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|dispute| dispute.dispute_id)
+                .collect::<Vec<_>>();
+            found_dispute_ids.sort();
+
+            assert_eq!(found_dispute_ids, vec!["dispute_1", "dispute_2"]);
+        }
+
+        #[tokio::test]
+        async fn test_find_disputes_by_statuses_modified_between() {
+            let mockdb = MockDb::new(&Default::default()).await.unwrap();
+            let now = common_utils::date_time::now();
+
+            for dispute_id in ["too_old", "stale", "recent", "won"] {
+                mockdb
+                    .insert_dispute(create_dispute_new(DisputeNewIds {
+                        dispute_id: dispute_id.into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_1".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: dispute_id.into(),
+                    }))
+                    .await
+                    .unwrap();
+            }
+
+            for dispute in mockdb.disputes.lock().await.iter_mut() {
+                dispute.modified_at = match dispute.dispute_id.as_str() {
+                    "too_old" => now - time::Duration::days(40),
+                    "recent" => now - time::Duration::hours(1),
+                    _ => now - time::Duration::days(10),
+                };
+                if dispute.dispute_id == "won" {
+                    dispute.dispute_status = DisputeStatus::DisputeWon;
+                }
+            }
+
+            let found_dispute_ids = mockdb
+                .find_disputes_by_statuses_modified_between(
+                    vec![
+                        DisputeStatus::DisputeOpened,
+                        DisputeStatus::DisputeChallenged,
+                    ],
+                    now - time::Duration::days(30),
+                    now - time::Duration::days(7),
+                    10,
+                )
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|dispute| dispute.dispute_id)
+                .collect::<Vec<_>>();
+
+            assert_eq!(found_dispute_ids, vec!["stale"]);
+        }
+
         mod update_dispute {
             use masking::Secret;
             use serde_json::Value;
@@ -613,7 +783,7 @@ mod tests {
 
             #[tokio::test]
             async fn test_update_dispute_update() {
-                let mockdb = MockDb::new(&Default::default()).await;
+                let mockdb = MockDb::new(&Default::default()).await.unwrap();
 
                 let created_dispute = mockdb
                     .insert_dispute(create_dispute_new(DisputeNewIds {
@@ -690,7 +860,7 @@ mod tests {
 
             #[tokio::test]
             async fn test_update_dispute_update_status() {
-                let mockdb = MockDb::new(&Default::default()).await;
+                let mockdb = MockDb::new(&Default::default()).await.unwrap();
 
                 let created_dispute = mockdb
                     .insert_dispute(create_dispute_new(DisputeNewIds {
@@ -762,7 +932,7 @@ mod tests {
 
             #[tokio::test]
             async fn test_update_dispute_update_evidence() {
-                let mockdb = MockDb::new(&Default::default()).await;
+                let mockdb = MockDb::new(&Default::default()).await.unwrap();
 
                 let created_dispute = mockdb
                     .insert_dispute(create_dispute_new(DisputeNewIds {
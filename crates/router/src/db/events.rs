@@ -105,7 +105,7 @@ mod tests {
     #[allow(clippy::unwrap_used)]
     #[tokio::test]
     async fn test_mockdb_event_interface() {
-        let mockdb = MockDb::new(&Default::default()).await;
+        let mockdb = MockDb::new(&Default::default()).await.unwrap();
 
         let event1 = mockdb
             .insert_event(storage::EventNew {
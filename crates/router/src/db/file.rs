@@ -84,36 +84,98 @@ impl FileMetadataInterface for Store {
 impl FileMetadataInterface for MockDb {
     async fn insert_file_metadata(
         &self,
-        _file: storage::FileMetadataNew,
+        file: storage::FileMetadataNew,
     ) -> CustomResult<storage::FileMetadata, errors::StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut files = self.files.lock().await;
+        if files
+            .iter()
+            .any(|f| f.file_id == file.file_id && f.merchant_id == file.merchant_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "file_metadata",
+                key: Some(file.file_id.clone()),
+            })?
+        }
+
+        let file = storage::FileMetadata {
+            file_id: file.file_id,
+            merchant_id: file.merchant_id,
+            file_name: file.file_name,
+            file_size: file.file_size,
+            file_type: file.file_type,
+            provider_file_id: file.provider_file_id,
+            file_upload_provider: file.file_upload_provider,
+            available: file.available,
+            created_at: common_utils::date_time::now(),
+            connector_label: file.connector_label,
+        };
+        files.push(file.clone());
+        Ok(file)
     }
 
     async fn find_file_metadata_by_merchant_id_file_id(
         &self,
-        _merchant_id: &str,
-        _file_id: &str,
+        merchant_id: &str,
+        file_id: &str,
     ) -> CustomResult<storage::FileMetadata, errors::StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.files
+            .lock()
+            .await
+            .iter()
+            .find(|f| f.merchant_id == merchant_id && f.file_id == file_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find file metadata for merchant_id = {merchant_id} and file_id = {file_id}"
+                ))
+                .into()
+            })
     }
 
     async fn delete_file_metadata_by_merchant_id_file_id(
         &self,
-        _merchant_id: &str,
-        _file_id: &str,
+        merchant_id: &str,
+        file_id: &str,
     ) -> CustomResult<bool, errors::StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut files = self.files.lock().await;
+        let initial_length = files.len();
+        files.retain(|f| !(f.merchant_id == merchant_id && f.file_id == file_id));
+        Ok(files.len() != initial_length)
     }
 
     async fn update_file_metadata(
         &self,
-        _this: storage::FileMetadata,
-        _file_metadata: storage::FileMetadataUpdate,
+        this: storage::FileMetadata,
+        file_metadata: storage::FileMetadataUpdate,
     ) -> CustomResult<storage::FileMetadata, errors::StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut files = self.files.lock().await;
+        let file_to_update = files
+            .iter_mut()
+            .find(|f| f.merchant_id == this.merchant_id && f.file_id == this.file_id)
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find file metadata for merchant_id = {} and file_id = {}",
+                    this.merchant_id, this.file_id
+                ))
+            })?;
+
+        match file_metadata {
+            storage::FileMetadataUpdate::Update {
+                provider_file_id,
+                file_upload_provider,
+                available,
+                connector_label,
+            } => {
+                file_to_update.provider_file_id =
+                    provider_file_id.or(file_to_update.provider_file_id.take());
+                file_to_update.file_upload_provider =
+                    file_upload_provider.or(file_to_update.file_upload_provider);
+                file_to_update.available = available;
+                file_to_update.connector_label =
+                    connector_label.or(file_to_update.connector_label.take());
+            }
+        }
+
+        Ok(file_to_update.clone())
     }
 }
@@ -0,0 +1,89 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use error_stack::{IntoReport, ResultExt};
+use once_cell::sync::Lazy;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+};
+
+/// Key used for the Redis round trip performed as part of the health check, unique to this
+/// instance so that instances checking concurrently do not delete each other's keys
+static REDIS_HEALTH_CHECK_KEY: Lazy<String> =
+    Lazy::new(|| common_utils::generate_id_with_default_len("health_check"));
+
+#[async_trait::async_trait]
+pub trait HealthCheckInterface {
+    async fn health_check_master_db(&self) -> CustomResult<(), errors::StorageError>;
+
+    async fn health_check_replica_db(&self) -> CustomResult<(), errors::StorageError>;
+
+    async fn health_check_redis(&self) -> CustomResult<(), errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl HealthCheckInterface for Store {
+    async fn health_check_master_db(&self) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        diesel::sql_query("SELECT 1")
+            .execute_async(&conn)
+            .await
+            .into_report()
+            .change_context(errors::StorageError::DatabaseConnectionError)
+            .attach_printable("Failed to run query on the master database")?;
+        Ok(())
+    }
+
+    async fn health_check_replica_db(&self) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        diesel::sql_query("SELECT 1")
+            .execute_async(&conn)
+            .await
+            .into_report()
+            .change_context(errors::StorageError::DatabaseConnectionError)
+            .attach_printable("Failed to run query on the replica database")?;
+        Ok(())
+    }
+
+    async fn health_check_redis(&self) -> CustomResult<(), errors::StorageError> {
+        let redis_conn = self
+            .redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+
+        redis_conn
+            .set_key_with_expiry(&REDIS_HEALTH_CHECK_KEY, "true", 30)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to set key in redis")?;
+
+        redis_conn
+            .get_key::<String>(&REDIS_HEALTH_CHECK_KEY)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to get key from redis")?;
+
+        redis_conn
+            .delete_key(&REDIS_HEALTH_CHECK_KEY)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to delete key from redis")?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheckInterface for MockDb {
+    async fn health_check_master_db(&self) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+
+    async fn health_check_replica_db(&self) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+
+    async fn health_check_redis(&self) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+}
@@ -70,7 +70,12 @@ impl LockerMockUpInterface for MockDb {
             .iter()
             .find(|l| l.card_id == card_id)
             .cloned()
-            .ok_or(errors::StorageError::MockDbError.into())
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find locker entry for card_id = {card_id}"
+                ))
+                .into(),
+            )
     }
 
     async fn insert_locker_mock_up(
@@ -157,7 +162,7 @@ mod tests {
 
         #[tokio::test]
         async fn find_locker_by_card_id() {
-            let mockdb = MockDb::new(&Default::default()).await;
+            let mockdb = MockDb::new(&Default::default()).await.unwrap();
 
             let created_locker = mockdb
                 .insert_locker_mock_up(create_locker_mock_up_new(LockerMockUpIds {
@@ -183,9 +188,18 @@ mod tests {
             assert_eq!(created_locker, found_locker)
         }
 
+        #[tokio::test]
+        async fn find_locker_by_card_id_not_found() {
+            let mockdb = MockDb::new(&Default::default()).await.unwrap();
+
+            let error = mockdb.find_locker_by_card_id("card_1").await.unwrap_err();
+
+            assert!(error.current_context().is_db_not_found());
+        }
+
         #[tokio::test]
         async fn insert_locker_mock_up() {
-            let mockdb = MockDb::new(&Default::default()).await;
+            let mockdb = MockDb::new(&Default::default()).await.unwrap();
 
             let created_locker = mockdb
                 .insert_locker_mock_up(create_locker_mock_up_new(LockerMockUpIds {
@@ -212,7 +226,7 @@ mod tests {
 
         #[tokio::test]
         async fn delete_locker_mock_up() {
-            let mockdb = MockDb::new(&Default::default()).await;
+            let mockdb = MockDb::new(&Default::default()).await.unwrap();
 
             let created_locker = mockdb
                 .insert_locker_mock_up(create_locker_mock_up_new(LockerMockUpIds {
@@ -165,6 +165,12 @@ impl MandateInterface for MockDb {
                     } => {
                         mandate.connector_mandate_ids = connector_mandate_ids;
                     }
+                    storage::MandateUpdate::RedactionUpdate => {
+                        mandate.customer_ip_address =
+                            Some(masking::Secret::new(common_utils::pii::REDACTED.to_string()));
+                        mandate.customer_user_agent = Some(common_utils::pii::REDACTED.to_string());
+                        mandate.metadata = Some(serde_json::json!({}).into());
+                    }
                 }
                 Ok(mandate.clone())
             }
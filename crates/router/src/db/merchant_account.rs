@@ -287,46 +287,94 @@ impl MerchantAccountInterface for MockDb {
             .await
             .transpose()?;
 
-        match account {
-            Some(account) => Ok(account),
-            // [#172]: Implement function for `MockDb`
-            None => Err(errors::StorageError::MockDbError)?,
-        }
+        account.ok_or_else(|| {
+            errors::StorageError::ValueNotFound(format!(
+                "cannot find merchant account for merchant_id = {merchant_id}"
+            ))
+            .into()
+        })
     }
 
     async fn update_merchant(
         &self,
-        _this: domain::MerchantAccount,
-        _merchant_account: storage::MerchantAccountUpdate,
-        _merchant_key_store: &domain::MerchantKeyStore,
+        this: domain::MerchantAccount,
+        merchant_account: storage::MerchantAccountUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::MerchantAccount, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.update_specific_fields_in_merchant(
+            &this.merchant_id,
+            merchant_account,
+            merchant_key_store,
+        )
+        .await
     }
 
     async fn update_specific_fields_in_merchant(
         &self,
-        _merchant_id: &str,
-        _merchant_account: storage::MerchantAccountUpdate,
-        _merchant_key_store: &domain::MerchantKeyStore,
+        merchant_id: &str,
+        merchant_account: storage::MerchantAccountUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::MerchantAccount, errors::StorageError> {
-        // [#TODO]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut accounts = self.merchant_accounts.lock().await;
+        let account = accounts
+            .iter_mut()
+            .find(|account| account.merchant_id == merchant_id)
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find merchant account for merchant_id = {merchant_id}"
+                ))
+            })?;
+
+        *account =
+            storage_models::merchant_account::MerchantAccountUpdateInternal::from(merchant_account)
+                .apply_changeset(account.clone());
+
+        account
+            .clone()
+            .convert(merchant_key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
 
     async fn find_merchant_account_by_publishable_key(
         &self,
-        _publishable_key: &str,
+        publishable_key: &str,
     ) -> CustomResult<authentication::AuthenticationData, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let merchant = self
+            .merchant_accounts
+            .lock()
+            .await
+            .iter()
+            .find(|account| account.publishable_key.as_deref() == Some(publishable_key))
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find merchant account for publishable_key = {publishable_key}"
+                ))
+            })?;
+        let key_store = self
+            .get_merchant_key_store_by_merchant_id(
+                &merchant.merchant_id,
+                &self.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        Ok(authentication::AuthenticationData {
+            merchant_account: merchant
+                .convert(key_store.key.get_inner())
+                .await
+                .change_context(errors::StorageError::DecryptionError)?,
+            key_store,
+        })
     }
 
     async fn delete_merchant_account_by_merchant_id(
         &self,
-        _merchant_id: &str,
+        merchant_id: &str,
     ) -> CustomResult<bool, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut accounts = self.merchant_accounts.lock().await;
+        let initial_length = accounts.len();
+        accounts.retain(|account| account.merchant_id != merchant_id);
+        Ok(accounts.len() != initial_length)
     }
 }
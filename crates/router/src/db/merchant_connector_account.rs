@@ -4,6 +4,8 @@ use error_stack::{IntoReport, ResultExt};
 #[cfg(feature = "accounts_cache")]
 use super::cache;
 use super::{MockDb, Store};
+#[cfg(feature = "accounts_cache")]
+use crate::cache::{CacheKind, MERCHANT_CONNECTOR_ACCOUNTS_CACHE};
 use crate::{
     connection,
     core::errors::{self, CustomResult},
@@ -153,6 +155,12 @@ where
     ) -> CustomResult<bool, errors::StorageError>;
 }
 
+/// Key used to cache a merchant connector account looked up using its connector label
+#[cfg(feature = "accounts_cache")]
+fn get_connector_label_cache_key(merchant_id: &str, connector_label: &str) -> String {
+    format!("mca_{merchant_id}_{connector_label}")
+}
+
 #[async_trait::async_trait]
 impl MerchantConnectorAccountInterface for Store {
     async fn find_merchant_connector_account_by_merchant_id_connector_label(
@@ -161,21 +169,40 @@ impl MerchantConnectorAccountInterface for Store {
         connector_label: &str,
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::MerchantConnectorAccount, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::MerchantConnectorAccount::find_by_merchant_id_connector(
-            &conn,
-            merchant_id,
-            connector_label,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
-        .async_and_then(|item| async {
-            item.convert(key_store.key.get_inner())
+        let find_call = || async {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::MerchantConnectorAccount::find_by_merchant_id_connector(
+                &conn,
+                merchant_id,
+                connector_label,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            find_call()
+                .await?
+                .convert(key_store.key.get_inner())
                 .await
                 .change_context(errors::StorageError::DecryptionError)
-        })
-        .await
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            cache::get_or_populate_in_memory(
+                self,
+                &get_connector_label_cache_key(merchant_id, connector_label),
+                find_call,
+                &MERCHANT_CONNECTOR_ACCOUNTS_CACHE,
+            )
+            .await?
+            .convert(key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+        }
     }
 
     async fn find_by_merchant_connector_account_merchant_id_merchant_connector_id(
@@ -207,11 +234,16 @@ impl MerchantConnectorAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            cache::get_or_populate_redis(self, merchant_connector_id, find_call)
-                .await?
-                .convert(key_store.key.get_inner())
-                .await
-                .change_context(errors::StorageError::DeserializationFailed)
+            cache::get_or_populate_in_memory(
+                self,
+                merchant_connector_id,
+                find_call,
+                &MERCHANT_CONNECTOR_ACCOUNTS_CACHE,
+            )
+            .await?
+            .convert(key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DeserializationFailed)
         }
     }
 
@@ -268,6 +300,9 @@ impl MerchantConnectorAccountInterface for Store {
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::MerchantConnectorAccount, errors::StorageError> {
         let _merchant_connector_id = this.merchant_connector_id.clone();
+        #[cfg(feature = "accounts_cache")]
+        let connector_label_cache_key =
+            get_connector_label_cache_key(&this.merchant_id, &this.connector_label);
         let update_call = || async {
             let conn = connection::pg_connection_write(self).await?;
             Conversion::convert(this)
@@ -287,7 +322,15 @@ impl MerchantConnectorAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            cache::redact_cache(self, &_merchant_connector_id, update_call, None).await
+            cache::publish_and_redact_multiple(
+                self,
+                [
+                    CacheKind::MerchantConnectorAccounts(_merchant_connector_id.into()),
+                    CacheKind::MerchantConnectorAccounts(connector_label_cache_key.into()),
+                ],
+                update_call,
+            )
+            .await
         }
 
         #[cfg(not(feature = "accounts_cache"))]
@@ -301,15 +344,53 @@ impl MerchantConnectorAccountInterface for Store {
         merchant_id: &str,
         merchant_connector_id: &str,
     ) -> CustomResult<bool, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::MerchantConnectorAccount::delete_by_merchant_id_merchant_connector_id(
-            &conn,
-            merchant_id,
-            merchant_connector_id,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
+        let delete_call = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::MerchantConnectorAccount::delete_by_merchant_id_merchant_connector_id(
+                &conn,
+                merchant_id,
+                merchant_connector_id,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        };
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            // The connector label is needed to invalidate the entry cached by connector label
+            let conn = connection::pg_connection_read(self).await?;
+            let merchant_connector_account =
+                storage::MerchantConnectorAccount::find_by_merchant_id_merchant_connector_id(
+                    &conn,
+                    merchant_id,
+                    merchant_connector_id,
+                )
+                .await
+                .map_err(Into::<errors::StorageError>::into)
+                .into_report()?;
+
+            cache::publish_and_redact_multiple(
+                self,
+                [
+                    CacheKind::MerchantConnectorAccounts(merchant_connector_id.into()),
+                    CacheKind::MerchantConnectorAccounts(
+                        get_connector_label_cache_key(
+                            merchant_id,
+                            &merchant_connector_account.connector_label,
+                        )
+                        .into(),
+                    ),
+                ],
+                delete_call,
+            )
+            .await
+        }
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            delete_call().await
+        }
     }
 }
 
@@ -95,18 +95,50 @@ impl MerchantKeyStoreInterface for Store {
 impl MerchantKeyStoreInterface for MockDb {
     async fn insert_merchant_key_store(
         &self,
-        _merchant_key_store: domain::MerchantKeyStore,
-        _key: &Secret<Vec<u8>>,
+        merchant_key_store: domain::MerchantKeyStore,
+        key: &Secret<Vec<u8>>,
     ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError.into())
+        let mut locked_merchant_key_store = self.merchant_key_store.lock().await;
+
+        if locked_merchant_key_store
+            .iter()
+            .any(|merchant_key| merchant_key.merchant_id == merchant_key_store.merchant_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "merchant_key_store",
+                key: Some(merchant_key_store.merchant_id.clone()),
+            })?;
+        }
+
+        let merchant_key = Conversion::convert(merchant_key_store)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        locked_merchant_key_store.push(merchant_key.clone());
+
+        merchant_key
+            .convert(key)
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
+
     async fn get_merchant_key_store_by_merchant_id(
         &self,
-        _merchant_id: &str,
-        _key: &Secret<Vec<u8>>,
+        merchant_id: &str,
+        key: &Secret<Vec<u8>>,
     ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError.into())
+        self.merchant_key_store
+            .lock()
+            .await
+            .iter()
+            .find(|merchant_key| merchant_key.merchant_id == merchant_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find merchant key store for merchant_id = {merchant_id}"
+                ))
+            })?
+            .convert(key)
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
 }
@@ -62,6 +62,51 @@ pub trait PaymentAttemptInterface {
         merchant_id: &str,
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::PaymentAttempt, errors::StorageError>;
+
+    async fn find_payment_attempts_by_merchant_id_connector_status_created_between(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        status: enums::AttemptStatus,
+        start_time: time::PrimitiveDateTime,
+        end_time: time::PrimitiveDateTime,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError>;
+
+    /// Lists the payment attempts routed to the connector with any of the connector transaction
+    /// IDs. The attempts are read from the database.
+    async fn find_payment_attempts_by_merchant_id_connector_txn_ids(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        connector_txn_ids: Vec<String>,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError>;
+
+    /// Lists the payment attempts routed to a connector which were last updated within the time
+    /// range, least recently updated first. The attempts are read from the database.
+    async fn find_payment_attempts_by_statuses_modified_between(
+        &self,
+        statuses: Vec<enums::AttemptStatus>,
+        modified_after: time::PrimitiveDateTime,
+        modified_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError>;
+
+    /// Lists all the attempts of a payment. The attempts are read from the database, the callers
+    /// are expected to refetch an attempt with the merchant storage scheme before updating it.
+    async fn find_payment_attempts_by_payment_id_merchant_id(
+        &self,
+        payment_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError>;
+
+    /// Finds the attempts with the given IDs. The attempts are read from the database.
+    async fn find_payment_attempts_by_merchant_id_attempt_ids(
+        &self,
+        merchant_id: &str,
+        attempt_ids: Vec<String>,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError>;
 }
 
 #[cfg(not(feature = "kv_store"))]
@@ -208,6 +253,92 @@ mod storage {
                 .map_err(Into::into)
                 .into_report()
         }
+
+        async fn find_payment_attempts_by_merchant_id_connector_status_created_between(
+            &self,
+            merchant_id: &str,
+            connector: &str,
+            status: enums::AttemptStatus,
+            start_time: time::PrimitiveDateTime,
+            end_time: time::PrimitiveDateTime,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentAttempt::find_by_merchant_id_connector_status_created_between(
+                &conn,
+                merchant_id,
+                connector,
+                status,
+                start_time,
+                end_time,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        async fn find_payment_attempts_by_merchant_id_connector_txn_ids(
+            &self,
+            merchant_id: &str,
+            connector: &str,
+            connector_txn_ids: Vec<String>,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentAttempt::find_by_merchant_id_connector_connector_txn_ids(
+                &conn,
+                merchant_id,
+                connector,
+                connector_txn_ids,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        async fn find_payment_attempts_by_statuses_modified_between(
+            &self,
+            statuses: Vec<enums::AttemptStatus>,
+            modified_after: time::PrimitiveDateTime,
+            modified_before: time::PrimitiveDateTime,
+            limit: i64,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentAttempt::find_by_statuses_modified_between(
+                &conn,
+                statuses,
+                modified_after,
+                modified_before,
+                limit,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        async fn find_payment_attempts_by_payment_id_merchant_id(
+            &self,
+            payment_id: &str,
+            merchant_id: &str,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentAttempt::find_by_payment_id_merchant_id(&conn, payment_id, merchant_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn find_payment_attempts_by_merchant_id_attempt_ids(
+            &self,
+            merchant_id: &str,
+            attempt_ids: Vec<String>,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentAttempt::find_by_merchant_id_attempt_ids(&conn, merchant_id, attempt_ids)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
     }
 }
 
@@ -215,43 +346,95 @@ mod storage {
 impl PaymentAttemptInterface for MockDb {
     async fn find_payment_attempt_by_payment_id_merchant_id_attempt_id(
         &self,
-        _payment_id: &str,
-        _merchant_id: &str,
-        _attempt_id: &str,
+        payment_id: &str,
+        merchant_id: &str,
+        attempt_id: &str,
         _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::PaymentAttempt, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.payment_attempts
+            .lock()
+            .await
+            .iter()
+            .find(|payment_attempt| {
+                payment_attempt.payment_id == payment_id
+                    && payment_attempt.merchant_id == merchant_id
+                    && payment_attempt.attempt_id == attempt_id
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find payment attempt for payment_id = {payment_id}, merchant_id = {merchant_id} and attempt_id = {attempt_id}"
+                ))
+                .into()
+            })
     }
 
     async fn find_payment_attempt_by_attempt_id_merchant_id(
         &self,
-        _attempt_id: &str,
-        _merchant_id: &str,
+        attempt_id: &str,
+        merchant_id: &str,
         _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::PaymentAttempt, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.payment_attempts
+            .lock()
+            .await
+            .iter()
+            .find(|payment_attempt| {
+                payment_attempt.attempt_id == attempt_id && payment_attempt.merchant_id == merchant_id
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find payment attempt for attempt_id = {attempt_id} and merchant_id = {merchant_id}"
+                ))
+                .into()
+            })
     }
 
     async fn find_payment_attempt_by_preprocessing_id_merchant_id(
         &self,
-        _preprocessing_id: &str,
-        _merchant_id: &str,
+        preprocessing_id: &str,
+        merchant_id: &str,
         _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::PaymentAttempt, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.payment_attempts
+            .lock()
+            .await
+            .iter()
+            .find(|payment_attempt| {
+                payment_attempt.preprocessing_step_id.as_deref() == Some(preprocessing_id)
+                    && payment_attempt.merchant_id == merchant_id
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find payment attempt for preprocessing_id = {preprocessing_id} and merchant_id = {merchant_id}"
+                ))
+                .into()
+            })
     }
 
     async fn find_payment_attempt_by_merchant_id_connector_txn_id(
         &self,
-        _merchant_id: &str,
-        _connector_txn_id: &str,
+        merchant_id: &str,
+        connector_txn_id: &str,
         _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::PaymentAttempt, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.payment_attempts
+            .lock()
+            .await
+            .iter()
+            .find(|payment_attempt| {
+                payment_attempt.merchant_id == merchant_id
+                    && payment_attempt.connector_transaction_id.as_deref() == Some(connector_txn_id)
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find payment attempt for merchant_id = {merchant_id} and connector_txn_id = {connector_txn_id}"
+                ))
+                .into()
+            })
     }
 
     #[allow(clippy::panic)]
@@ -304,6 +487,7 @@ impl PaymentAttemptInterface for MockDb {
             mandate_details: payment_attempt.mandate_details,
             preprocessing_step_id: payment_attempt.preprocessing_step_id,
             error_reason: payment_attempt.error_reason,
+            installment_count: payment_attempt.installment_count,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -331,13 +515,27 @@ impl PaymentAttemptInterface for MockDb {
 
     async fn find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
         &self,
-        _connector_transaction_id: &str,
-        _payment_id: &str,
-        _merchant_id: &str,
+        connector_transaction_id: &str,
+        payment_id: &str,
+        merchant_id: &str,
         _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::PaymentAttempt, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.payment_attempts
+            .lock()
+            .await
+            .iter()
+            .find(|payment_attempt| {
+                payment_attempt.connector_transaction_id.as_deref() == Some(connector_transaction_id)
+                    && payment_attempt.payment_id == payment_id
+                    && payment_attempt.merchant_id == merchant_id
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find payment attempt for connector_transaction_id = {connector_transaction_id}, payment_id = {payment_id} and merchant_id = {merchant_id}"
+                ))
+                .into()
+            })
     }
 
     // safety: only used for testing
@@ -359,6 +557,115 @@ impl PaymentAttemptInterface for MockDb {
             .cloned()
             .unwrap())
     }
+
+    async fn find_payment_attempts_by_merchant_id_connector_status_created_between(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        status: enums::AttemptStatus,
+        start_time: time::PrimitiveDateTime,
+        end_time: time::PrimitiveDateTime,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError> {
+        Ok(self
+            .payment_attempts
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.merchant_id == merchant_id
+                    && payment_attempt.connector.as_deref() == Some(connector)
+                    && payment_attempt.status == status
+                    && (start_time..=end_time).contains(&payment_attempt.created_at)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn find_payment_attempts_by_merchant_id_connector_txn_ids(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        connector_txn_ids: Vec<String>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError> {
+        Ok(self
+            .payment_attempts
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.merchant_id == merchant_id
+                    && payment_attempt.connector.as_deref() == Some(connector)
+                    && payment_attempt
+                        .connector_transaction_id
+                        .as_ref()
+                        .map_or(false, |connector_txn_id| {
+                            connector_txn_ids.contains(connector_txn_id)
+                        })
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn find_payment_attempts_by_statuses_modified_between(
+        &self,
+        statuses: Vec<enums::AttemptStatus>,
+        modified_after: time::PrimitiveDateTime,
+        modified_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError> {
+        let mut payment_attempts = self
+            .payment_attempts
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_attempt| {
+                statuses.contains(&payment_attempt.status)
+                    && payment_attempt.connector.is_some()
+                    && payment_attempt.modified_at > modified_after
+                    && payment_attempt.modified_at < modified_before
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        payment_attempts.sort_by_key(|payment_attempt| payment_attempt.modified_at);
+        payment_attempts.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+        Ok(payment_attempts)
+    }
+
+    async fn find_payment_attempts_by_payment_id_merchant_id(
+        &self,
+        payment_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.payment_id == payment_id
+                    && payment_attempt.merchant_id == merchant_id
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn find_payment_attempts_by_merchant_id_attempt_ids(
+        &self,
+        merchant_id: &str,
+        attempt_ids: Vec<String>,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.merchant_id == merchant_id
+                    && attempt_ids.contains(&payment_attempt.attempt_id)
+            })
+            .cloned()
+            .collect())
+    }
 }
 
 #[cfg(feature = "kv_store")]
@@ -442,6 +749,7 @@ mod storage {
                         mandate_details: payment_attempt.mandate_details.clone(),
                         preprocessing_step_id: payment_attempt.preprocessing_step_id.clone(),
                         error_reason: payment_attempt.error_reason.clone(),
+                        installment_count: payment_attempt.installment_count,
                     };
 
                     let field = format!("pa_{}", created_attempt.attempt_id);
@@ -660,6 +968,92 @@ mod storage {
             .into_report()
         }
 
+        async fn find_payment_attempts_by_merchant_id_connector_status_created_between(
+            &self,
+            merchant_id: &str,
+            connector: &str,
+            status: enums::AttemptStatus,
+            start_time: time::PrimitiveDateTime,
+            end_time: time::PrimitiveDateTime,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentAttempt::find_by_merchant_id_connector_status_created_between(
+                &conn,
+                merchant_id,
+                connector,
+                status,
+                start_time,
+                end_time,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        async fn find_payment_attempts_by_merchant_id_connector_txn_ids(
+            &self,
+            merchant_id: &str,
+            connector: &str,
+            connector_txn_ids: Vec<String>,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentAttempt::find_by_merchant_id_connector_connector_txn_ids(
+                &conn,
+                merchant_id,
+                connector,
+                connector_txn_ids,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        async fn find_payment_attempts_by_statuses_modified_between(
+            &self,
+            statuses: Vec<enums::AttemptStatus>,
+            modified_after: time::PrimitiveDateTime,
+            modified_before: time::PrimitiveDateTime,
+            limit: i64,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentAttempt::find_by_statuses_modified_between(
+                &conn,
+                statuses,
+                modified_after,
+                modified_before,
+                limit,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        async fn find_payment_attempts_by_payment_id_merchant_id(
+            &self,
+            payment_id: &str,
+            merchant_id: &str,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentAttempt::find_by_payment_id_merchant_id(&conn, payment_id, merchant_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn find_payment_attempts_by_merchant_id_attempt_ids(
+            &self,
+            merchant_id: &str,
+            attempt_ids: Vec<String>,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentAttempt::find_by_merchant_id_attempt_ids(&conn, merchant_id, attempt_ids)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
         async fn find_payment_attempt_by_merchant_id_connector_txn_id(
             &self,
             merchant_id: &str,
@@ -15,6 +15,16 @@ pub trait PaymentIntentInterface {
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::PaymentIntent, errors::StorageError>;
 
+    /// Updates the payment intent only if it is still in one of the specified statuses, returning
+    /// `None` if it has moved to another status since it was read.
+    async fn update_payment_intent_if_status_in(
+        &self,
+        this: types::PaymentIntent,
+        payment_intent: types::PaymentIntentUpdate,
+        statuses: Vec<enums::IntentStatus>,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Option<types::PaymentIntent>, errors::StorageError>;
+
     async fn insert_payment_intent(
         &self,
         new: types::PaymentIntentNew,
@@ -35,6 +45,17 @@ pub trait PaymentIntentInterface {
         pc: &api::PaymentListConstraints,
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError>;
+
+    /// Lists the payment intents of a customer, most recent first. The intents are read from the
+    /// database, the callers are expected to refetch an intent with the merchant storage scheme
+    /// before updating it.
+    async fn find_payment_intents_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError>;
 }
 
 #[cfg(feature = "kv_store")]
@@ -51,9 +72,13 @@ mod storage {
         core::errors::{self, CustomResult},
         services::Store,
         types::storage::{enums, kv, payment_intent::*},
-        utils::{self, db_utils, storage_partitioning},
+        utils::{self, db_utils, storage_partitioning, StringExt},
     };
 
+    /// Number of times a conditional update of a payment intent is attempted when the intent is
+    /// modified concurrently.
+    const CONDITIONAL_UPDATE_ATTEMPTS: usize = 3;
+
     #[async_trait::async_trait]
     impl PaymentIntentInterface for Store {
         async fn insert_payment_intent(
@@ -189,6 +214,113 @@ mod storage {
             }
         }
 
+        async fn update_payment_intent_if_status_in(
+            &self,
+            this: PaymentIntent,
+            payment_intent: PaymentIntentUpdate,
+            statuses: Vec<enums::IntentStatus>,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Option<PaymentIntent>, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    this.update_if_status_in(&conn, payment_intent, statuses)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+
+                // The intent is updated only if it has not been modified since it was read, the
+                // read being retried when it has been modified concurrently
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{}_{}", this.merchant_id, this.payment_id);
+                    let redis_conn = self
+                        .redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?;
+
+                    for _ in 0..CONDITIONAL_UPDATE_ATTEMPTS {
+                        let stored_value = redis_conn
+                            .get_hash_field::<Option<String>>(&key, "pi")
+                            .await
+                            .change_context(errors::StorageError::KVError)?;
+                        let current_intent = match &stored_value {
+                            Some(stored_value) => stored_value
+                                .parse_struct("PaymentIntent")
+                                .change_context(errors::StorageError::DeserializationFailed)?,
+                            None => {
+                                let conn = connection::pg_connection_read(self).await?;
+                                PaymentIntent::find_by_payment_id_merchant_id(
+                                    &conn,
+                                    &this.payment_id,
+                                    &this.merchant_id,
+                                )
+                                .await
+                                .map_err(Into::into)
+                                .into_report()?
+                            }
+                        };
+                        if !statuses.contains(&current_intent.status) {
+                            return Ok(None);
+                        }
+
+                        let updated_intent = payment_intent
+                            .clone()
+                            .apply_changeset(current_intent.clone());
+                        let redis_value = utils::Encode::<PaymentIntent>::encode_to_string_of_json(
+                            &updated_intent,
+                        )
+                        .change_context(errors::StorageError::SerializationFailed)?;
+                        let is_updated = match &stored_value {
+                            Some(stored_value) => redis_conn
+                                .set_hash_field_if_equal(&key, "pi", stored_value, &redis_value)
+                                .await
+                                .change_context(errors::StorageError::KVError)?,
+                            None => matches!(
+                                redis_conn
+                                    .set_hash_field_if_not_exist(&key, "pi", redis_value.as_str())
+                                    .await
+                                    .change_context(errors::StorageError::KVError)?,
+                                HsetnxReply::KeySet
+                            ),
+                        };
+                        if !is_updated {
+                            continue;
+                        }
+
+                        let redis_entry = kv::TypedSql {
+                            op: kv::DBOperation::Update {
+                                updatable: kv::Updateable::PaymentIntentUpdate(
+                                    kv::PaymentIntentUpdateMems {
+                                        orig: current_intent,
+                                        update_data: payment_intent,
+                                    },
+                                ),
+                            },
+                        };
+                        self.push_to_drainer_stream::<PaymentIntent>(
+                            redis_entry,
+                            storage_partitioning::PartitionKey::MerchantIdPaymentId {
+                                merchant_id: &updated_intent.merchant_id,
+                                payment_id: &updated_intent.payment_id,
+                            },
+                        )
+                        .await?;
+                        return Ok(Some(updated_intent));
+                    }
+
+                    Err(errors::StorageError::KVError)
+                        .into_report()
+                        .attach_printable_lazy(|| {
+                            format!(
+                                "Payment intent {} was modified concurrently on every attempt to \
+                                 update it",
+                                this.payment_id
+                            )
+                        })
+                }
+            }
+        }
+
         async fn find_payment_intent_by_payment_id_merchant_id(
             &self,
             payment_id: &str,
@@ -237,6 +369,26 @@ mod storage {
                 enums::MerchantStorageScheme::RedisKv => Err(errors::StorageError::KVError.into()),
             }
         }
+
+        async fn find_payment_intents_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+            limit: Option<i64>,
+            offset: Option<i64>,
+        ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentIntent::find_by_merchant_id_customer_id(
+                &conn,
+                merchant_id,
+                customer_id,
+                limit,
+                offset,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
     }
 }
 
@@ -278,6 +430,20 @@ mod storage {
                 .into_report()
         }
 
+        async fn update_payment_intent_if_status_in(
+            &self,
+            this: PaymentIntent,
+            payment_intent: PaymentIntentUpdate,
+            statuses: Vec<enums::IntentStatus>,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Option<PaymentIntent>, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            this.update_if_status_in(&conn, payment_intent, statuses)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
         async fn find_payment_intent_by_payment_id_merchant_id(
             &self,
             payment_id: &str,
@@ -304,6 +470,26 @@ mod storage {
                 .map_err(Into::into)
                 .into_report()
         }
+
+        async fn find_payment_intents_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+            limit: Option<i64>,
+            offset: Option<i64>,
+        ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentIntent::find_by_merchant_id_customer_id(
+                &conn,
+                merchant_id,
+                customer_id,
+                limit,
+                offset,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
     }
 }
 
@@ -312,12 +498,60 @@ impl PaymentIntentInterface for MockDb {
     #[cfg(feature = "olap")]
     async fn filter_payment_intent_by_constraints(
         &self,
-        _merchant_id: &str,
-        _pc: &api::PaymentListConstraints,
+        merchant_id: &str,
+        pc: &api::PaymentListConstraints,
         _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let payment_intents = self.payment_intents.lock().await;
+        let find_id = |payment_id: &str| {
+            payment_intents
+                .iter()
+                .find(|payment_intent| {
+                    payment_intent.payment_id == payment_id
+                        && payment_intent.merchant_id == merchant_id
+                })
+                .map(|payment_intent| payment_intent.id)
+                .ok_or_else(|| {
+                    errors::StorageError::ValueNotFound(format!(
+                        "cannot find payment intent for payment_id = {payment_id}"
+                    ))
+                })
+        };
+        let starting_after = pc.starting_after.as_deref().map(find_id).transpose()?;
+        let ending_before = pc.ending_before.as_deref().map(find_id).transpose()?;
+
+        let mut filtered_payment_intents = payment_intents
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == merchant_id
+                    && pc.customer_id.as_ref().map_or(true, |customer_id| {
+                        payment_intent.customer_id.as_ref() == Some(customer_id)
+                    })
+                    && pc
+                        .created
+                        .map_or(true, |created| payment_intent.created_at == created)
+                    && pc
+                        .created_lt
+                        .map_or(true, |created_lt| payment_intent.created_at < created_lt)
+                    && pc
+                        .created_gt
+                        .map_or(true, |created_gt| payment_intent.created_at > created_gt)
+                    && pc
+                        .created_lte
+                        .map_or(true, |created_lte| payment_intent.created_at <= created_lte)
+                    && pc
+                        .created_gte
+                        .map_or(true, |created_gte| payment_intent.created_at >= created_gte)
+                    && starting_after.map_or(true, |id| payment_intent.id > id)
+                    && ending_before.map_or(true, |id| payment_intent.id < id)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        filtered_payment_intents.sort_by(|a, b| b.modified_at.cmp(&a.modified_at));
+        filtered_payment_intents.truncate(usize::try_from(pc.limit).unwrap_or(usize::MAX));
+
+        Ok(filtered_payment_intents)
     }
 
     #[allow(clippy::panic)]
@@ -379,6 +613,23 @@ impl PaymentIntentInterface for MockDb {
         Ok(payment_intent.clone())
     }
 
+    async fn update_payment_intent_if_status_in(
+        &self,
+        this: types::PaymentIntent,
+        update: types::PaymentIntentUpdate,
+        statuses: Vec<enums::IntentStatus>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Option<types::PaymentIntent>, errors::StorageError> {
+        let mut payment_intents = self.payment_intents.lock().await;
+        Ok(payment_intents
+            .iter_mut()
+            .find(|item| item.id == this.id && statuses.contains(&item.status))
+            .map(|payment_intent| {
+                *payment_intent = update.apply_changeset(payment_intent.clone());
+                payment_intent.clone()
+            }))
+    }
+
     // safety: only used for testing
     #[allow(clippy::unwrap_used)]
     async fn find_payment_intent_by_payment_id_merchant_id(
@@ -397,4 +648,115 @@ impl PaymentIntentInterface for MockDb {
             .cloned()
             .unwrap())
     }
+
+    async fn find_payment_intents_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError> {
+        let mut payment_intents = self
+            .payment_intents
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == merchant_id
+                    && payment_intent.customer_id.as_deref() == Some(customer_id)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        payment_intents.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(payment_intents
+            .into_iter()
+            .skip(
+                offset
+                    .and_then(|offset| usize::try_from(offset).ok())
+                    .unwrap_or(0),
+            )
+            .take(
+                limit
+                    .and_then(|limit| usize::try_from(limit).ok())
+                    .unwrap_or(usize::MAX),
+            )
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[tokio::test]
+    async fn test_update_payment_intent_if_status_in() {
+        let mockdb = MockDb::new(&Default::default()).await.unwrap();
+        let storage_scheme = enums::MerchantStorageScheme::PostgresOnly;
+        let expirable_statuses = vec![
+            enums::IntentStatus::RequiresPaymentMethod,
+            enums::IntentStatus::RequiresConfirmation,
+        ];
+        let expire = || types::PaymentIntentUpdate::PGStatusUpdate {
+            status: enums::IntentStatus::Expired,
+        };
+
+        let payment_intent = mockdb
+            .insert_payment_intent(
+                types::PaymentIntentNew {
+                    payment_id: "pay_1".into(),
+                    merchant_id: "merchant_1".into(),
+                    status: enums::IntentStatus::RequiresPaymentMethod,
+                    ..Default::default()
+                },
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+
+        // The payment intent is confirmed after it was read
+        mockdb
+            .update_payment_intent(
+                payment_intent.clone(),
+                types::PaymentIntentUpdate::PGStatusUpdate {
+                    status: enums::IntentStatus::Processing,
+                },
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        let updated = mockdb
+            .update_payment_intent_if_status_in(
+                payment_intent.clone(),
+                expire(),
+                expirable_statuses.clone(),
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        assert!(updated.is_none());
+
+        mockdb
+            .update_payment_intent(
+                payment_intent.clone(),
+                types::PaymentIntentUpdate::PGStatusUpdate {
+                    status: enums::IntentStatus::RequiresConfirmation,
+                },
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        let updated = mockdb
+            .update_payment_intent_if_status_in(
+                payment_intent,
+                expire(),
+                expirable_statuses,
+                storage_scheme,
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(updated.status, enums::IntentStatus::Expired);
+    }
 }
@@ -21,6 +21,20 @@ pub trait PaymentMethodInterface {
         merchant_id: &str,
     ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
 
+    async fn find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<Option<storage::PaymentMethod>, errors::StorageError>;
+
+    async fn find_cards_expiring_before(
+        &self,
+        expires_before: time::PrimitiveDateTime,
+        checked_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
+
     async fn insert_payment_method(
         &self,
         payment_method_new: storage::PaymentMethodNew,
@@ -89,6 +103,42 @@ impl PaymentMethodInterface for Store {
             .into_report()
     }
 
+    async fn find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<Option<storage::PaymentMethod>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethod::find_by_customer_id_merchant_id_card_fingerprint(
+            &conn,
+            customer_id,
+            merchant_id,
+            card_fingerprint,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_cards_expiring_before(
+        &self,
+        expires_before: time::PrimitiveDateTime,
+        checked_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethod::find_cards_expiring_before(
+            &conn,
+            expires_before,
+            checked_before,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn delete_payment_method_by_merchant_id_payment_method_id(
         &self,
         merchant_id: &str,
@@ -156,6 +206,16 @@ impl PaymentMethodInterface for MockDb {
             payment_method_issuer: payment_method_new.payment_method_issuer,
             payment_method_issuer_code: payment_method_new.payment_method_issuer_code,
             metadata: payment_method_new.metadata,
+            payment_method_data: payment_method_new.payment_method_data,
+            card_fingerprint: payment_method_new.card_fingerprint,
+            network_token_reference: payment_method_new.network_token_reference,
+            network_token_status: payment_method_new.network_token_status,
+            network_token_data: payment_method_new.network_token_data,
+            card_expires_at: payment_method_new.card_expires_at,
+            card_account_status: payment_method_new.card_account_status,
+            account_updater_checked_at: payment_method_new.account_updater_checked_at,
+            last_used_at: payment_method_new.last_used_at,
+            card_reference: payment_method_new.card_reference,
         };
         payment_methods.push(payment_method.clone());
         Ok(payment_method)
@@ -183,6 +243,47 @@ impl PaymentMethodInterface for MockDb {
         }
     }
 
+    async fn find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<Option<storage::PaymentMethod>, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        Ok(payment_methods
+            .iter()
+            .find(|pm| {
+                pm.customer_id == customer_id
+                    && pm.merchant_id == merchant_id
+                    && pm.card_fingerprint.as_deref() == Some(card_fingerprint)
+            })
+            .cloned())
+    }
+
+    async fn find_cards_expiring_before(
+        &self,
+        expires_before: time::PrimitiveDateTime,
+        checked_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        let mut expiring_cards: Vec<storage::PaymentMethod> = payment_methods
+            .iter()
+            .filter(|pm| {
+                pm.card_expires_at
+                    .map_or(false, |expires_at| expires_at < expires_before)
+                    && pm.card_account_status != Some(storage::enums::CardAccountStatus::Closed)
+                    && pm
+                        .account_updater_checked_at
+                        .map_or(true, |checked_at| checked_at < checked_before)
+            })
+            .cloned()
+            .collect();
+        expiring_cards.sort_by_key(|pm| pm.card_expires_at);
+        expiring_cards.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+        Ok(expiring_cards)
+    }
+
     async fn delete_payment_method_by_merchant_id_payment_method_id(
         &self,
         merchant_id: &str,
@@ -229,3 +330,63 @@ impl PaymentMethodInterface for MockDb {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use crate::{
+        db::{payment_method::PaymentMethodInterface, MockDb},
+        types::storage,
+    };
+
+    #[tokio::test]
+    async fn test_card_update_keeps_payment_method_id() {
+        let mockdb = MockDb::new(&Default::default()).await.unwrap();
+        let payment_method = mockdb
+            .insert_payment_method(storage::PaymentMethodNew {
+                customer_id: "cus_1".into(),
+                merchant_id: "merchant_1".into(),
+                payment_method_id: "pm_1".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(payment_method.get_card_reference(), "pm_1");
+
+        let payment_method = mockdb
+            .update_payment_method(
+                payment_method,
+                storage::PaymentMethodUpdate::CardUpdate {
+                    card_reference: Some("card_2".into()),
+                    card_fingerprint: Some("fingerprint_2".into()),
+                    card_expires_at: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(payment_method.payment_method_id, "pm_1");
+        assert_eq!(payment_method.get_card_reference(), "card_2");
+
+        let payment_method = mockdb
+            .update_payment_method(
+                payment_method,
+                storage::PaymentMethodUpdate::CardUpdate {
+                    card_reference: None,
+                    card_fingerprint: Some("fingerprint_3".into()),
+                    card_expires_at: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(payment_method.get_card_reference(), "card_2");
+        assert_eq!(
+            mockdb
+                .find_payment_method("pm_1")
+                .await
+                .unwrap()
+                .card_fingerprint
+                .as_deref(),
+            Some("fingerprint_3")
+        );
+    }
+}
@@ -5,7 +5,10 @@ use super::{MockDb, Store};
 use crate::{
     connection,
     core::errors::{self, CustomResult},
-    types::storage::{self, enums},
+    types::{
+        storage::{self, enums, ProcessTrackerDbExt},
+        transformers::ForeignFrom,
+    },
 };
 
 #[async_trait::async_trait]
@@ -50,6 +53,31 @@ pub trait ProcessTrackerInterface {
         status: enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn find_processes_by_constraints(
+        &self,
+        constraints: api_models::process_tracker::ProcessTrackerListConstraints,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn acquire_process_lease(
+        &self,
+        id: &str,
+        lease_owner: &str,
+        lease_expires_at: PrimitiveDateTime,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn renew_process_lease(
+        &self,
+        id: &str,
+        lease_owner: &str,
+        lease_expires_at: PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn release_process_lease(
+        &self,
+        id: &str,
+        lease_owner: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -97,6 +125,17 @@ impl ProcessTrackerInterface for Store {
         .into_report()
     }
 
+    async fn find_processes_by_constraints(
+        &self,
+        constraints: api_models::process_tracker::ProcessTrackerListConstraints,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::filter_by_constraints(&conn, constraints)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -143,6 +182,50 @@ impl ProcessTrackerInterface for Store {
             .map_err(Into::into)
             .into_report()
     }
+
+    async fn acquire_process_lease(
+        &self,
+        id: &str,
+        lease_owner: &str,
+        lease_expires_at: PrimitiveDateTime,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ProcessTracker::acquire_lease(
+            &conn,
+            id,
+            lease_owner,
+            lease_expires_at,
+            common_utils::date_time::now(),
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn renew_process_lease(
+        &self,
+        id: &str,
+        lease_owner: &str,
+        lease_expires_at: PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ProcessTracker::renew_lease(&conn, id, lease_owner, lease_expires_at)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn release_process_lease(
+        &self,
+        id: &str,
+        lease_owner: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ProcessTracker::release_lease(&conn, id, lease_owner)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -164,22 +247,102 @@ impl ProcessTrackerInterface for MockDb {
 
     async fn reinitialize_limbo_processes(
         &self,
-        _ids: Vec<String>,
-        _schedule_time: PrimitiveDateTime,
+        ids: Vec<String>,
+        schedule_time: PrimitiveDateTime,
     ) -> CustomResult<usize, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+        let mut count = 0;
+        for process in processes.iter_mut().filter(|process| {
+            process.status == enums::ProcessTrackerStatus::ProcessStarted
+                && ids.contains(&process.id)
+        }) {
+            process.status = enums::ProcessTrackerStatus::Processing;
+            process.schedule_time = Some(schedule_time);
+            count += 1;
+        }
+        Ok(count)
     }
 
     async fn find_processes_by_time_status(
         &self,
-        _time_lower_limit: PrimitiveDateTime,
-        _time_upper_limit: PrimitiveDateTime,
-        _status: enums::ProcessTrackerStatus,
-        _limit: Option<i64>,
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        status: enums::ProcessTrackerStatus,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let processes = self.processes.lock().await;
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+        Ok(processes
+            .iter()
+            .filter(|process| {
+                process.status == status
+                    && process.schedule_time.map_or(false, |schedule_time| {
+                        time_lower_limit <= schedule_time && schedule_time <= time_upper_limit
+                    })
+            })
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_processes_by_constraints(
+        &self,
+        constraints: api_models::process_tracker::ProcessTrackerListConstraints,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let processes = self.processes.lock().await;
+        let mut filtered_processes = processes
+            .iter()
+            .filter(|process| {
+                constraints
+                    .runner
+                    .as_ref()
+                    .map(|runner| process.runner.as_ref() == Some(runner))
+                    .unwrap_or(true)
+                    && constraints
+                        .status
+                        .map(|status| {
+                            enums::ProcessTrackerStatus::foreign_from(status) == process.status
+                        })
+                        .unwrap_or(true)
+                    && constraints
+                        .business_status
+                        .as_ref()
+                        .map(|business_status| business_status == &process.business_status)
+                        .unwrap_or(true)
+                    && constraints
+                        .merchant_id
+                        .as_ref()
+                        .map(|merchant_id| {
+                            process.tracking_data.get("merchant_id")
+                                == Some(&serde_json::Value::String(merchant_id.to_owned()))
+                        })
+                        .unwrap_or(true)
+                    && constraints
+                        .schedule_time_lte
+                        .map(|schedule_time_lte| {
+                            process
+                                .schedule_time
+                                .map_or(false, |schedule_time| schedule_time <= schedule_time_lte)
+                        })
+                        .unwrap_or(true)
+                    && constraints
+                        .schedule_time_gte
+                        .map(|schedule_time_gte| {
+                            process
+                                .schedule_time
+                                .map_or(false, |schedule_time| schedule_time >= schedule_time_gte)
+                        })
+                        .unwrap_or(true)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        filtered_processes.sort_by(|a, b| b.schedule_time.cmp(&a.schedule_time));
+        filtered_processes.truncate(usize::try_from(constraints.limit).unwrap_or(usize::MAX));
+
+        Ok(filtered_processes)
     }
 
     async fn insert_process(
@@ -201,6 +364,9 @@ impl ProcessTrackerInterface for MockDb {
             event: new.event,
             created_at: new.created_at,
             updated_at: new.updated_at,
+            lease_owner: None,
+            lease_expires_at: None,
+            admin_audit_trail: Vec::new(),
         };
         processes.push(process.clone());
         Ok(process)
@@ -208,28 +374,307 @@ impl ProcessTrackerInterface for MockDb {
 
     async fn update_process(
         &self,
-        _this: storage::ProcessTracker,
-        _process: storage::ProcessTrackerUpdate,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
     ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+        let process_to_update = processes
+            .iter_mut()
+            .find(|item| item.id == this.id)
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find process tracker entry for id = {}",
+                    this.id
+                ))
+            })?;
+
+        *process_to_update =
+            storage_models::process_tracker::ProcessTrackerUpdateInternal::from(process)
+                .apply_changeset(process_to_update.clone());
+
+        Ok(process_to_update.clone())
     }
 
     async fn update_process_tracker(
         &self,
-        _this: storage::ProcessTracker,
-        _process: storage::ProcessTrackerUpdate,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
     ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.update_process(this, process).await
     }
 
     async fn process_tracker_update_process_status_by_ids(
         &self,
-        _task_ids: Vec<String>,
-        _task_update: storage::ProcessTrackerUpdate,
+        task_ids: Vec<String>,
+        task_update: storage::ProcessTrackerUpdate,
     ) -> CustomResult<usize, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let task_update =
+            storage_models::process_tracker::ProcessTrackerUpdateInternal::from(task_update);
+        let mut processes = self.processes.lock().await;
+        let mut count = 0;
+        for process in processes
+            .iter_mut()
+            .filter(|process| task_ids.contains(&process.id))
+        {
+            *process = task_update.clone().apply_changeset(process.clone());
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    async fn acquire_process_lease(
+        &self,
+        id: &str,
+        lease_owner: &str,
+        lease_expires_at: PrimitiveDateTime,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        let now = common_utils::date_time::now();
+        let mut processes = self.processes.lock().await;
+        let leased = processes
+            .iter_mut()
+            .find(|process| {
+                process.id == id
+                    && matches!(
+                        process.status,
+                        enums::ProcessTrackerStatus::Processing
+                            | enums::ProcessTrackerStatus::ProcessStarted
+                    )
+                    && process
+                        .lease_expires_at
+                        .map_or(true, |expires_at| expires_at < now)
+            })
+            .map(|process| {
+                process.status = enums::ProcessTrackerStatus::ProcessStarted;
+                process.lease_owner = Some(lease_owner.to_owned());
+                process.lease_expires_at = Some(lease_expires_at);
+                process.updated_at = now;
+                process.clone()
+            });
+        Ok(leased)
+    }
+
+    async fn renew_process_lease(
+        &self,
+        id: &str,
+        lease_owner: &str,
+        lease_expires_at: PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut processes = self.processes.lock().await;
+        let process = processes.iter_mut().find(|process| {
+            process.id == id && process.lease_owner.as_deref() == Some(lease_owner)
+        });
+        Ok(process
+            .map(|process| process.lease_expires_at = Some(lease_expires_at))
+            .is_some())
+    }
+
+    async fn release_process_lease(
+        &self,
+        id: &str,
+        lease_owner: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut processes = self.processes.lock().await;
+        let process = processes.iter_mut().find(|process| {
+            process.id == id && process.lease_owner.as_deref() == Some(lease_owner)
+        });
+        Ok(process
+            .map(|process| {
+                process.lease_owner = None;
+                process.lease_expires_at = None;
+            })
+            .is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(clippy::unwrap_used)]
+    mod mockdb_process_tracker_interface {
+        use api_models::process_tracker::ProcessTrackerListConstraints;
+
+        use crate::{
+            db::{process_tracker::ProcessTrackerInterface, MockDb},
+            types::storage::{self, enums, ProcessTrackerExt},
+        };
+
+        fn create_process(id: &str, runner: &str, merchant_id: &str) -> storage::ProcessTrackerNew {
+            <storage::ProcessTracker as ProcessTrackerExt>::make_process_tracker_new(
+                id.to_string(),
+                "TASK",
+                runner,
+                serde_json::json!({ "merchant_id": merchant_id }),
+                common_utils::date_time::now(),
+            )
+            .unwrap()
+        }
+
+        fn constraints() -> ProcessTrackerListConstraints {
+            ProcessTrackerListConstraints {
+                limit: 10,
+                runner: None,
+                status: None,
+                business_status: None,
+                merchant_id: None,
+                schedule_time_lte: None,
+                schedule_time_gte: None,
+            }
+        }
+
+        #[tokio::test]
+        async fn find_processes_by_constraints() {
+            let mockdb = MockDb::new(&Default::default()).await.unwrap();
+
+            mockdb
+                .insert_process(create_process(
+                    "process_1",
+                    "REFUND_WORKFLOW_ROUTER",
+                    "merchant_1",
+                ))
+                .await
+                .unwrap();
+            mockdb
+                .insert_process(create_process(
+                    "process_2",
+                    "PAYMENTS_SYNC_WORKFLOW",
+                    "merchant_1",
+                ))
+                .await
+                .unwrap();
+            let process_3 = mockdb
+                .insert_process(create_process(
+                    "process_3",
+                    "REFUND_WORKFLOW_ROUTER",
+                    "merchant_2",
+                ))
+                .await
+                .unwrap();
+
+            let processes = mockdb
+                .find_processes_by_constraints(ProcessTrackerListConstraints {
+                    runner: Some("REFUND_WORKFLOW_ROUTER".to_string()),
+                    merchant_id: Some("merchant_2".to_string()),
+                    ..constraints()
+                })
+                .await
+                .unwrap();
+            assert_eq!(processes, vec![process_3.clone()]);
+
+            mockdb
+                .update_process(
+                    process_3,
+                    storage::ProcessTrackerUpdate::AdminUpdate {
+                        status: enums::ProcessTrackerStatus::Finish,
+                        business_status: Some("CANCELLED_BY_ADMIN".to_string()),
+                        retry_count: None,
+                        schedule_time: None,
+                        admin_audit_trail: vec![serde_json::json!({ "action": "cancel" })],
+                    },
+                )
+                .await
+                .unwrap();
+
+            let processes = mockdb
+                .find_processes_by_constraints(ProcessTrackerListConstraints {
+                    status: Some(api_models::enums::ProcessTrackerStatus::New),
+                    ..constraints()
+                })
+                .await
+                .unwrap();
+            assert_eq!(processes.len(), 2);
+
+            let cancelled_process = mockdb
+                .find_process_by_id("process_3")
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(cancelled_process.business_status, "CANCELLED_BY_ADMIN");
+            assert_eq!(
+                cancelled_process.admin_audit_trail,
+                vec![serde_json::json!({ "action": "cancel" })]
+            );
+            assert!(cancelled_process.event.is_empty());
+        }
+
+        #[tokio::test]
+        async fn process_lease_is_held_until_expiry() {
+            let mockdb = MockDb::new(&Default::default()).await.unwrap();
+            let now = common_utils::date_time::now();
+            let later = now + time::Duration::minutes(5);
+
+            let process = mockdb
+                .insert_process(create_process(
+                    "process_1",
+                    "REFUND_WORKFLOW_ROUTER",
+                    "merchant_1",
+                ))
+                .await
+                .unwrap();
+
+            // Tasks which have not been picked up by the producer cannot be leased
+            assert!(mockdb
+                .acquire_process_lease(&process.id, "consumer_1", later)
+                .await
+                .unwrap()
+                .is_none());
+
+            mockdb
+                .process_tracker_update_process_status_by_ids(
+                    vec![process.id.clone()],
+                    storage::ProcessTrackerUpdate::StatusUpdate {
+                        status: enums::ProcessTrackerStatus::Processing,
+                        business_status: None,
+                    },
+                )
+                .await
+                .unwrap();
+
+            let leased = mockdb
+                .acquire_process_lease(&process.id, "consumer_1", later)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(leased.status, enums::ProcessTrackerStatus::ProcessStarted);
+            assert_eq!(leased.lease_owner.as_deref(), Some("consumer_1"));
+            assert_eq!(leased.retry_count, process.retry_count);
+
+            assert!(mockdb
+                .acquire_process_lease(&process.id, "consumer_2", later)
+                .await
+                .unwrap()
+                .is_none());
+            assert!(!mockdb
+                .renew_process_lease(&process.id, "consumer_2", later)
+                .await
+                .unwrap());
+
+            // The lease of a consumer which stopped renewing it can be taken over
+            assert!(mockdb
+                .renew_process_lease(&process.id, "consumer_1", now - time::Duration::seconds(1))
+                .await
+                .unwrap());
+            let reclaimed = mockdb
+                .acquire_process_lease(&process.id, "consumer_2", later)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(reclaimed.lease_owner.as_deref(), Some("consumer_2"));
+            assert_eq!(reclaimed.retry_count, process.retry_count);
+
+            assert!(!mockdb
+                .release_process_lease(&process.id, "consumer_1")
+                .await
+                .unwrap());
+            assert!(mockdb
+                .release_process_lease(&process.id, "consumer_2")
+                .await
+                .unwrap());
+            let released = mockdb
+                .find_process_by_id(&process.id)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(released.lease_owner, None);
+            assert_eq!(released.lease_expires_at, None);
+        }
     }
 }
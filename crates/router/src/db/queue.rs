@@ -1,9 +1,10 @@
-use redis_interface::{errors::RedisError, RedisEntryId, SetnxReply};
+use redis_interface::{errors::RedisError, RedisConnectionPool, RedisEntryId, SetnxReply};
 use router_env::logger;
 
 use super::{MockDb, Store};
 use crate::{
     core::errors::{CustomResult, ProcessTrackerError},
+    scheduler::StalledBatch,
     types::storage,
 };
 
@@ -14,7 +15,22 @@ pub trait QueueInterface {
         stream_name: &str,
         group_name: &str,
         consumer_name: &str,
-    ) -> CustomResult<Vec<storage::ProcessTracker>, ProcessTrackerError>;
+    ) -> CustomResult<(Vec<storage::ProcessTracker>, Vec<String>), ProcessTrackerError>;
+
+    async fn claim_stalled_consumer_tasks(
+        &self,
+        stream_name: &str,
+        group_name: &str,
+        consumer_name: &str,
+        min_idle_time: u64,
+    ) -> CustomResult<Vec<StalledBatch>, ProcessTrackerError>;
+
+    async fn acknowledge_consumer_tasks(
+        &self,
+        stream_name: &str,
+        group_name: &str,
+        entry_ids: Vec<String>,
+    ) -> CustomResult<(), ProcessTrackerError>;
 
     async fn consumer_group_create(
         &self,
@@ -50,8 +66,27 @@ impl QueueInterface for Store {
         stream_name: &str,
         group_name: &str,
         consumer_name: &str,
-    ) -> CustomResult<Vec<storage::ProcessTracker>, ProcessTrackerError> {
+    ) -> CustomResult<(Vec<storage::ProcessTracker>, Vec<String>), ProcessTrackerError> {
         crate::scheduler::consumer::fetch_consumer_tasks(
+            &self
+                .redis_conn()
+                .map_err(ProcessTrackerError::ERedisError)?
+                .clone(),
+            stream_name,
+            group_name,
+            consumer_name,
+        )
+        .await
+    }
+
+    async fn claim_stalled_consumer_tasks(
+        &self,
+        stream_name: &str,
+        group_name: &str,
+        consumer_name: &str,
+        min_idle_time: u64,
+    ) -> CustomResult<Vec<StalledBatch>, ProcessTrackerError> {
+        crate::scheduler::consumer::claim_stalled_tasks(
             self,
             &self
                 .redis_conn()
@@ -60,6 +95,25 @@ impl QueueInterface for Store {
             stream_name,
             group_name,
             consumer_name,
+            min_idle_time,
+        )
+        .await
+    }
+
+    async fn acknowledge_consumer_tasks(
+        &self,
+        stream_name: &str,
+        group_name: &str,
+        entry_ids: Vec<String>,
+    ) -> CustomResult<(), ProcessTrackerError> {
+        crate::scheduler::utils::acknowledge_batches(
+            &self
+                .redis_conn()
+                .map_err(ProcessTrackerError::ERedisError)?
+                .clone(),
+            stream_name,
+            group_name,
+            entry_ids,
         )
         .await
     }
@@ -82,39 +136,11 @@ impl QueueInterface for Store {
         lock_val: &str,
         ttl: i64,
     ) -> CustomResult<bool, RedisError> {
-        let conn = self.redis_conn()?.clone();
-        let is_lock_acquired = conn.set_key_if_not_exist(lock_key, lock_val).await;
-        Ok(match is_lock_acquired {
-            Ok(SetnxReply::KeySet) => match conn.set_expiry(lock_key, ttl).await {
-                Ok(()) => true,
-
-                #[allow(unused_must_use)]
-                Err(error) => {
-                    logger::error!(error=?error.current_context());
-                    conn.delete_key(lock_key).await;
-                    false
-                }
-            },
-            Ok(SetnxReply::KeyNotSet) => {
-                logger::error!(%tag, "Lock not acquired, previous fetch still in progress");
-                false
-            }
-            Err(error) => {
-                logger::error!(error=%error.current_context(), %tag, "Error while locking");
-                false
-            }
-        })
+        acquire_lock(&self.redis_conn()?, tag, lock_key, lock_val, ttl).await
     }
 
     async fn release_pt_lock(&self, tag: &str, lock_key: &str) -> CustomResult<bool, RedisError> {
-        let is_lock_released = self.redis_conn()?.delete_key(lock_key).await;
-        Ok(match is_lock_released {
-            Ok(_del_reply) => true,
-            Err(error) => {
-                logger::error!(error=%error.current_context(), %tag, "Error while releasing lock");
-                false
-            }
-        })
+        release_lock(&self.redis_conn()?, tag, lock_key).await
     }
 
     async fn stream_append_entry(
@@ -137,53 +163,283 @@ impl QueueInterface for Store {
 impl QueueInterface for MockDb {
     async fn fetch_consumer_tasks(
         &self,
-        _stream_name: &str,
-        _group_name: &str,
-        _consumer_name: &str,
-    ) -> CustomResult<Vec<storage::ProcessTracker>, ProcessTrackerError> {
-        // [#172]: Implement function for `MockDb`
-        Err(ProcessTrackerError::ResourceFetchingFailed {
-            resource_name: "consumer_tasks",
-        })?
+        stream_name: &str,
+        group_name: &str,
+        consumer_name: &str,
+    ) -> CustomResult<(Vec<storage::ProcessTracker>, Vec<String>), ProcessTrackerError> {
+        crate::scheduler::consumer::fetch_consumer_tasks(
+            &self.redis,
+            stream_name,
+            group_name,
+            consumer_name,
+        )
+        .await
+    }
+
+    async fn claim_stalled_consumer_tasks(
+        &self,
+        stream_name: &str,
+        group_name: &str,
+        consumer_name: &str,
+        min_idle_time: u64,
+    ) -> CustomResult<Vec<StalledBatch>, ProcessTrackerError> {
+        crate::scheduler::consumer::claim_stalled_tasks(
+            self,
+            &self.redis,
+            stream_name,
+            group_name,
+            consumer_name,
+            min_idle_time,
+        )
+        .await
+    }
+
+    async fn acknowledge_consumer_tasks(
+        &self,
+        stream_name: &str,
+        group_name: &str,
+        entry_ids: Vec<String>,
+    ) -> CustomResult<(), ProcessTrackerError> {
+        crate::scheduler::utils::acknowledge_batches(
+            &self.redis,
+            stream_name,
+            group_name,
+            entry_ids,
+        )
+        .await
     }
 
     async fn consumer_group_create(
         &self,
-        _stream: &str,
-        _group: &str,
-        _id: &RedisEntryId,
+        stream: &str,
+        group: &str,
+        id: &RedisEntryId,
     ) -> CustomResult<(), RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Err(RedisError::ConsumerGroupCreateFailed)?
+        self.redis.consumer_group_create(stream, group, id).await
     }
 
     async fn acquire_pt_lock(
         &self,
-        _tag: &str,
-        _lock_key: &str,
-        _lock_val: &str,
-        _ttl: i64,
+        tag: &str,
+        lock_key: &str,
+        lock_val: &str,
+        ttl: i64,
     ) -> CustomResult<bool, RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Ok(false)
+        acquire_lock(&self.redis, tag, lock_key, lock_val, ttl).await
     }
 
-    async fn release_pt_lock(&self, _tag: &str, _lock_key: &str) -> CustomResult<bool, RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Ok(false)
+    async fn release_pt_lock(&self, tag: &str, lock_key: &str) -> CustomResult<bool, RedisError> {
+        release_lock(&self.redis, tag, lock_key).await
     }
 
     async fn stream_append_entry(
         &self,
-        _stream: &str,
-        _entry_id: &RedisEntryId,
-        _fields: Vec<(&str, String)>,
+        stream: &str,
+        entry_id: &RedisEntryId,
+        fields: Vec<(&str, String)>,
     ) -> CustomResult<(), RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Err(RedisError::StreamAppendFailed)?
+        self.redis
+            .stream_append_entry(stream, entry_id, fields)
+            .await
     }
 
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError> {
         self.redis.get_key(key).await
     }
 }
+
+async fn acquire_lock(
+    conn: &RedisConnectionPool,
+    tag: &str,
+    lock_key: &str,
+    lock_val: &str,
+    ttl: i64,
+) -> CustomResult<bool, RedisError> {
+    let is_lock_acquired = conn.set_key_if_not_exist(lock_key, lock_val).await;
+    Ok(match is_lock_acquired {
+        Ok(SetnxReply::KeySet) => match conn.set_expiry(lock_key, ttl).await {
+            Ok(()) => true,
+
+            #[allow(unused_must_use)]
+            Err(error) => {
+                logger::error!(error=?error.current_context());
+                conn.delete_key(lock_key).await;
+                false
+            }
+        },
+        Ok(SetnxReply::KeyNotSet) => {
+            logger::error!(%tag, "Lock not acquired, previous fetch still in progress");
+            false
+        }
+        Err(error) => {
+            logger::error!(error=%error.current_context(), %tag, "Error while locking");
+            false
+        }
+    })
+}
+
+async fn release_lock(
+    conn: &RedisConnectionPool,
+    tag: &str,
+    lock_key: &str,
+) -> CustomResult<bool, RedisError> {
+    let is_lock_released = conn.delete_key(lock_key).await;
+    Ok(match is_lock_released {
+        Ok(_del_reply) => true,
+        Err(error) => {
+            logger::error!(error=%error.current_context(), %tag, "Error while releasing lock");
+            false
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(clippy::unwrap_used)]
+    mod mockdb_queue_interface {
+        use redis_interface::RedisEntryId;
+
+        use crate::{
+            db::{process_tracker::ProcessTrackerInterface, queue::QueueInterface, MockDb},
+            scheduler::ProcessTrackerBatch,
+            types::storage::{self, enums, ProcessTrackerExt},
+        };
+
+        #[tokio::test]
+        async fn acquire_and_release_pt_lock() {
+            let mockdb = MockDb::new(&Default::default()).await.unwrap();
+
+            assert!(mockdb
+                .acquire_pt_lock("tag", "lock_key", "lock_val", 60)
+                .await
+                .unwrap());
+            assert!(!mockdb
+                .acquire_pt_lock("tag", "lock_key", "lock_val", 60)
+                .await
+                .unwrap());
+
+            assert!(mockdb.release_pt_lock("tag", "lock_key").await.unwrap());
+            assert!(mockdb
+                .acquire_pt_lock("tag", "lock_key", "lock_val", 60)
+                .await
+                .unwrap());
+        }
+
+        #[tokio::test]
+        async fn claim_and_acknowledge_stalled_tasks() {
+            let mockdb = MockDb::new(&Default::default()).await.unwrap();
+            let (stream, group) = ("SCHEDULER_STREAM", "SCHEDULER_GROUP");
+
+            let process = mockdb
+                .insert_process(
+                    <storage::ProcessTracker as ProcessTrackerExt>::make_process_tracker_new(
+                        "process_1".to_string(),
+                        "TASK",
+                        "PAYMENTS_SYNC_WORKFLOW",
+                        serde_json::json!({}),
+                        common_utils::date_time::now(),
+                    )
+                    .unwrap(),
+                )
+                .await
+                .unwrap();
+            let batch = ProcessTrackerBatch {
+                id: "batch_1".to_string(),
+                group_name: group.to_string(),
+                stream_name: stream.to_string(),
+                connection_name: String::new(),
+                created_time: common_utils::date_time::now(),
+                rule: String::new(),
+                trackers: vec![process],
+            };
+
+            mockdb
+                .consumer_group_create(stream, group, &RedisEntryId::AfterLastID)
+                .await
+                .unwrap();
+            mockdb
+                .stream_append_entry(
+                    stream,
+                    &RedisEntryId::AutoGeneratedID,
+                    batch.to_redis_field_value_pairs().unwrap(),
+                )
+                .await
+                .unwrap();
+
+            let (tasks, _) = mockdb
+                .fetch_consumer_tasks(stream, group, "consumer_1")
+                .await
+                .unwrap();
+            assert_eq!(tasks.len(), 1);
+            mockdb
+                .process_tracker_update_process_status_by_ids(
+                    vec![tasks[0].id.clone()],
+                    storage::ProcessTrackerUpdate::StatusUpdate {
+                        status: enums::ProcessTrackerStatus::Processing,
+                        business_status: None,
+                    },
+                )
+                .await
+                .unwrap();
+            let lease_expires_at = common_utils::date_time::now() + time::Duration::minutes(5);
+            mockdb
+                .acquire_process_lease(&tasks[0].id, "consumer_1", lease_expires_at)
+                .await
+                .unwrap()
+                .unwrap();
+
+            // The task is not executed again while the consumer which read the batch holds its
+            // lease, even though the batch has not been acknowledged
+            let batches = mockdb
+                .claim_stalled_consumer_tasks(stream, group, "consumer_2", 0)
+                .await
+                .unwrap();
+            assert_eq!(batches.len(), 1);
+            assert!(batches[0].tasks.is_empty());
+            assert!(batches[0].has_leased_tasks);
+
+            // The consumer dies and its lease expires
+            mockdb
+                .renew_process_lease(
+                    "process_1",
+                    "consumer_1",
+                    common_utils::date_time::now() - time::Duration::seconds(1),
+                )
+                .await
+                .unwrap();
+            let batches = mockdb
+                .claim_stalled_consumer_tasks(stream, group, "consumer_2", 0)
+                .await
+                .unwrap();
+            assert_eq!(batches.len(), 1);
+            assert!(!batches[0].has_leased_tasks);
+            let tasks = &batches[0].tasks;
+            assert_eq!(tasks.len(), 1);
+            assert_eq!(tasks[0].retry_count, 1);
+            assert_eq!(tasks[0].status, enums::ProcessTrackerStatus::ProcessStarted);
+            assert_eq!(
+                mockdb
+                    .find_process_by_id("process_1")
+                    .await
+                    .unwrap()
+                    .unwrap()
+                    .retry_count,
+                1
+            );
+
+            mockdb
+                .acknowledge_consumer_tasks(
+                    stream,
+                    group,
+                    batches.into_iter().map(|batch| batch.entry_id).collect(),
+                )
+                .await
+                .unwrap();
+            let batches = mockdb
+                .claim_stalled_consumer_tasks(stream, group, "consumer_2", 0)
+                .await
+                .unwrap();
+            assert!(batches.is_empty());
+        }
+    }
+}
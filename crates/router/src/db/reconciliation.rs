@@ -0,0 +1,209 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, ReconciliationRunDbExt},
+};
+
+#[async_trait::async_trait]
+pub trait ReconciliationRunInterface {
+    async fn insert_reconciliation_run(
+        &self,
+        reconciliation_run: storage::ReconciliationRunNew,
+    ) -> CustomResult<storage::ReconciliationRun, errors::StorageError>;
+
+    async fn find_reconciliation_run_by_merchant_id_run_id(
+        &self,
+        merchant_id: &str,
+        run_id: &str,
+    ) -> CustomResult<storage::ReconciliationRun, errors::StorageError>;
+
+    async fn find_reconciliation_runs_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: api_models::reconciliation::ReconciliationRunListConstraints,
+    ) -> CustomResult<Vec<storage::ReconciliationRun>, errors::StorageError>;
+
+    async fn update_reconciliation_run(
+        &self,
+        this: storage::ReconciliationRun,
+        reconciliation_run: storage::ReconciliationRunUpdate,
+    ) -> CustomResult<storage::ReconciliationRun, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ReconciliationRunInterface for Store {
+    async fn insert_reconciliation_run(
+        &self,
+        reconciliation_run: storage::ReconciliationRunNew,
+    ) -> CustomResult<storage::ReconciliationRun, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        reconciliation_run
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_reconciliation_run_by_merchant_id_run_id(
+        &self,
+        merchant_id: &str,
+        run_id: &str,
+    ) -> CustomResult<storage::ReconciliationRun, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReconciliationRun::find_by_merchant_id_run_id(&conn, merchant_id, run_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_reconciliation_runs_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: api_models::reconciliation::ReconciliationRunListConstraints,
+    ) -> CustomResult<Vec<storage::ReconciliationRun>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReconciliationRun::filter_by_constraints(&conn, merchant_id, constraints)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_reconciliation_run(
+        &self,
+        this: storage::ReconciliationRun,
+        reconciliation_run: storage::ReconciliationRunUpdate,
+    ) -> CustomResult<storage::ReconciliationRun, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, reconciliation_run)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl ReconciliationRunInterface for MockDb {
+    async fn insert_reconciliation_run(
+        &self,
+        reconciliation_run: storage::ReconciliationRunNew,
+    ) -> CustomResult<storage::ReconciliationRun, errors::StorageError> {
+        let mut reconciliation_runs = self.reconciliation_runs.lock().await;
+        if reconciliation_runs.iter().any(|run| {
+            run.merchant_id == reconciliation_run.merchant_id
+                && run.run_id == reconciliation_run.run_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "reconciliation_run",
+                key: Some(reconciliation_run.run_id.clone()),
+            })?
+        }
+
+        let now = common_utils::date_time::now();
+        let reconciliation_run = storage::ReconciliationRun {
+            #[allow(clippy::as_conversions)]
+            id: reconciliation_runs.len() as i32,
+            run_id: reconciliation_run.run_id,
+            merchant_id: reconciliation_run.merchant_id,
+            connector: reconciliation_run.connector,
+            report_type: reconciliation_run.report_type,
+            report_source: reconciliation_run.report_source,
+            status: reconciliation_run.status,
+            period_start: reconciliation_run.period_start,
+            period_end: reconciliation_run.period_end,
+            total_records: 0,
+            matched_records: 0,
+            mismatches: serde_json::Value::Array(Vec::new()),
+            error_message: None,
+            created_at: now,
+            modified_at: now,
+        };
+        reconciliation_runs.push(reconciliation_run.clone());
+        Ok(reconciliation_run)
+    }
+
+    async fn find_reconciliation_run_by_merchant_id_run_id(
+        &self,
+        merchant_id: &str,
+        run_id: &str,
+    ) -> CustomResult<storage::ReconciliationRun, errors::StorageError> {
+        self.reconciliation_runs
+            .lock()
+            .await
+            .iter()
+            .find(|run| run.merchant_id == merchant_id && run.run_id == run_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find reconciliation run for merchant_id = {merchant_id} and run_id = {run_id}"
+                ))
+                .into()
+            })
+    }
+
+    async fn find_reconciliation_runs_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: api_models::reconciliation::ReconciliationRunListConstraints,
+    ) -> CustomResult<Vec<storage::ReconciliationRun>, errors::StorageError> {
+        let reconciliation_runs = self.reconciliation_runs.lock().await;
+        let mut runs = reconciliation_runs
+            .iter()
+            .filter(|run| {
+                run.merchant_id == merchant_id
+                    && constraints
+                        .connector
+                        .map_or(true, |connector| run.connector == connector.to_string())
+                    && constraints
+                        .status
+                        .map_or(true, |status| run.status == status)
+                    && constraints
+                        .created_lte
+                        .map_or(true, |created_lte| run.created_at <= created_lte)
+                    && constraints
+                        .created_gte
+                        .map_or(true, |created_gte| run.created_at >= created_gte)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        runs.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        if let Some(limit) = constraints.limit {
+            runs.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+        }
+        Ok(runs)
+    }
+
+    async fn update_reconciliation_run(
+        &self,
+        this: storage::ReconciliationRun,
+        reconciliation_run: storage::ReconciliationRunUpdate,
+    ) -> CustomResult<storage::ReconciliationRun, errors::StorageError> {
+        let mut reconciliation_runs = self.reconciliation_runs.lock().await;
+        let run = reconciliation_runs
+            .iter_mut()
+            .find(|run| run.merchant_id == this.merchant_id && run.run_id == this.run_id)
+            .ok_or(errors::StorageError::MockDbError)?;
+
+        match reconciliation_run {
+            storage::ReconciliationRunUpdate::Completed {
+                total_records,
+                matched_records,
+                mismatches,
+            } => {
+                run.status = storage::enums::ReconciliationRunStatus::Completed;
+                run.total_records = total_records;
+                run.matched_records = matched_records;
+                run.mismatches = mismatches;
+            }
+            storage::ReconciliationRunUpdate::Failed { error_message } => {
+                run.status = storage::enums::ReconciliationRunStatus::Failed;
+                run.error_message = Some(error_message);
+            }
+        }
+        run.modified_at = common_utils::date_time::now();
+
+        Ok(run.clone())
+    }
+}
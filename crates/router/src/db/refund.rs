@@ -37,6 +37,43 @@ pub trait RefundInterface {
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage_types::Refund, errors::StorageError>;
 
+    async fn find_refunds_by_merchant_id_connector_status_created_between(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        refund_status: enums::RefundStatus,
+        start_time: time::PrimitiveDateTime,
+        end_time: time::PrimitiveDateTime,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError>;
+
+    /// Lists the refunds processed by the connector with any of the connector refund IDs. The
+    /// refunds are read from the database.
+    async fn find_refunds_by_merchant_id_connector_refund_ids_connector(
+        &self,
+        merchant_id: &str,
+        connector_refund_ids: Vec<String>,
+        connector: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError>;
+
+    /// Lists the refunds sent to the connector which were last updated within the time range,
+    /// least recently updated first. The refunds are read from the database.
+    async fn find_refunds_by_statuses_modified_between(
+        &self,
+        refund_statuses: Vec<enums::RefundStatus>,
+        modified_after: time::PrimitiveDateTime,
+        modified_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError>;
+
+    /// Lists the refunds of the given payments. The refunds are read from the database.
+    async fn find_refunds_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &str,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError>;
+
     async fn update_refund(
         &self,
         this: storage_types::Refund,
@@ -169,6 +206,80 @@ mod storage {
             .into_report()
         }
 
+        async fn find_refunds_by_merchant_id_connector_status_created_between(
+            &self,
+            merchant_id: &str,
+            connector: &str,
+            refund_status: enums::RefundStatus,
+            start_time: time::PrimitiveDateTime,
+            end_time: time::PrimitiveDateTime,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Refund::find_by_merchant_id_connector_status_created_between(
+                &conn,
+                merchant_id,
+                connector,
+                refund_status,
+                start_time,
+                end_time,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        async fn find_refunds_by_merchant_id_connector_refund_ids_connector(
+            &self,
+            merchant_id: &str,
+            connector_refund_ids: Vec<String>,
+            connector: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Refund::find_by_merchant_id_connector_refund_ids_connector(
+                &conn,
+                merchant_id,
+                connector_refund_ids,
+                connector,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        async fn find_refunds_by_statuses_modified_between(
+            &self,
+            refund_statuses: Vec<enums::RefundStatus>,
+            modified_after: time::PrimitiveDateTime,
+            modified_before: time::PrimitiveDateTime,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Refund::find_by_statuses_modified_between(
+                &conn,
+                refund_statuses,
+                modified_after,
+                modified_before,
+                limit,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        async fn find_refunds_by_merchant_id_payment_ids(
+            &self,
+            merchant_id: &str,
+            payment_ids: Vec<String>,
+        ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Refund::find_by_merchant_id_payment_ids(&conn, merchant_id, payment_ids)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
         async fn find_refund_by_payment_id_merchant_id(
             &self,
             payment_id: &str,
@@ -544,6 +655,80 @@ mod storage {
             }
         }
 
+        async fn find_refunds_by_merchant_id_connector_status_created_between(
+            &self,
+            merchant_id: &str,
+            connector: &str,
+            refund_status: enums::RefundStatus,
+            start_time: time::PrimitiveDateTime,
+            end_time: time::PrimitiveDateTime,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Refund::find_by_merchant_id_connector_status_created_between(
+                &conn,
+                merchant_id,
+                connector,
+                refund_status,
+                start_time,
+                end_time,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        async fn find_refunds_by_merchant_id_connector_refund_ids_connector(
+            &self,
+            merchant_id: &str,
+            connector_refund_ids: Vec<String>,
+            connector: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Refund::find_by_merchant_id_connector_refund_ids_connector(
+                &conn,
+                merchant_id,
+                connector_refund_ids,
+                connector,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        async fn find_refunds_by_statuses_modified_between(
+            &self,
+            refund_statuses: Vec<enums::RefundStatus>,
+            modified_after: time::PrimitiveDateTime,
+            modified_before: time::PrimitiveDateTime,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Refund::find_by_statuses_modified_between(
+                &conn,
+                refund_statuses,
+                modified_after,
+                modified_before,
+                limit,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        async fn find_refunds_by_merchant_id_payment_ids(
+            &self,
+            merchant_id: &str,
+            payment_ids: Vec<String>,
+        ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Refund::find_by_merchant_id_payment_ids(&conn, merchant_id, payment_ids)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
         async fn find_refund_by_payment_id_merchant_id(
             &self,
             payment_id: &str,
@@ -738,6 +923,94 @@ impl RefundInterface for MockDb {
             })
     }
 
+    async fn find_refunds_by_merchant_id_connector_status_created_between(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        refund_status: enums::RefundStatus,
+        start_time: time::PrimitiveDateTime,
+        end_time: time::PrimitiveDateTime,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
+        let refunds = self.refunds.lock().await;
+
+        Ok(refunds
+            .iter()
+            .filter(|refund| {
+                refund.merchant_id == merchant_id
+                    && refund.connector == connector
+                    && refund.refund_status == refund_status
+                    && (start_time..=end_time).contains(&refund.created_at)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn find_refunds_by_merchant_id_connector_refund_ids_connector(
+        &self,
+        merchant_id: &str,
+        connector_refund_ids: Vec<String>,
+        connector: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
+        let refunds = self.refunds.lock().await;
+
+        Ok(refunds
+            .iter()
+            .filter(|refund| {
+                refund.merchant_id == merchant_id
+                    && refund.connector == connector
+                    && refund
+                        .connector_refund_id
+                        .as_ref()
+                        .map_or(false, |connector_refund_id| {
+                            connector_refund_ids.contains(connector_refund_id)
+                        })
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn find_refunds_by_statuses_modified_between(
+        &self,
+        refund_statuses: Vec<enums::RefundStatus>,
+        modified_after: time::PrimitiveDateTime,
+        modified_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
+        let refunds = self.refunds.lock().await;
+
+        let mut stale_refunds = refunds
+            .iter()
+            .filter(|refund| {
+                refund_statuses.contains(&refund.refund_status)
+                    && refund.sent_to_gateway
+                    && refund.updated_at > modified_after
+                    && refund.updated_at < modified_before
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        stale_refunds.sort_by_key(|refund| refund.updated_at);
+        stale_refunds.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+        Ok(stale_refunds)
+    }
+
+    async fn find_refunds_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &str,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
+        let refunds = self.refunds.lock().await;
+
+        Ok(refunds
+            .iter()
+            .filter(|refund| {
+                refund.merchant_id == merchant_id && payment_ids.contains(&refund.payment_id)
+            })
+            .cloned()
+            .collect())
+    }
+
     async fn find_refund_by_payment_id_merchant_id(
         &self,
         payment_id: &str,
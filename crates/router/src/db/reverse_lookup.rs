@@ -48,14 +48,44 @@ impl ReverseLookupInterface for Store {
 impl ReverseLookupInterface for MockDb {
     async fn insert_reverse_lookup(
         &self,
-        _new: ReverseLookupNew,
+        new: ReverseLookupNew,
     ) -> CustomResult<ReverseLookup, errors::StorageError> {
-        Err(errors::StorageError::MockDbError.into())
+        let mut reverse_lookups = self.reverse_lookups.lock().await;
+        if reverse_lookups
+            .iter()
+            .any(|lookup| lookup.lookup_id == new.lookup_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "reverse_lookup",
+                key: Some(new.lookup_id.clone()),
+            })?
+        }
+
+        let reverse_lookup = ReverseLookup {
+            lookup_id: new.lookup_id,
+            sk_id: new.sk_id,
+            pk_id: new.pk_id,
+            source: new.source,
+        };
+        reverse_lookups.push(reverse_lookup.clone());
+        Ok(reverse_lookup)
     }
+
     async fn get_lookup_by_lookup_id(
         &self,
-        _id: &str,
+        id: &str,
     ) -> CustomResult<ReverseLookup, errors::StorageError> {
-        Err(errors::StorageError::MockDbError.into())
+        self.reverse_lookups
+            .lock()
+            .await
+            .iter()
+            .find(|lookup| lookup.lookup_id == id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find reverse lookup for lookup_id = {id}"
+                ))
+                .into()
+            })
     }
 }
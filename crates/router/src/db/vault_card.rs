@@ -0,0 +1,274 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait VaultCardInterface {
+    async fn insert_vault_card(
+        &self,
+        vault_card: storage::VaultCardNew,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError>;
+
+    async fn find_vault_card_by_merchant_id_customer_id_card_reference(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError>;
+
+    async fn find_vault_card_by_merchant_id_customer_id_card_fingerprint(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<Option<storage::VaultCard>, errors::StorageError>;
+
+    async fn find_vault_cards_not_under_key_id(
+        &self,
+        key_id: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::VaultCard>, errors::StorageError>;
+
+    async fn update_vault_card(
+        &self,
+        this: storage::VaultCard,
+        vault_card: storage::VaultCardUpdate,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError>;
+
+    async fn delete_vault_card_by_merchant_id_customer_id_card_reference(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl VaultCardInterface for Store {
+    async fn insert_vault_card(
+        &self,
+        vault_card: storage::VaultCardNew,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        vault_card
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_vault_card_by_merchant_id_customer_id_card_reference(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultCard::find_by_merchant_id_customer_id_card_reference(
+            &conn,
+            merchant_id,
+            customer_id,
+            card_reference,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_vault_card_by_merchant_id_customer_id_card_fingerprint(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<Option<storage::VaultCard>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultCard::find_optional_by_merchant_id_customer_id_card_fingerprint(
+            &conn,
+            merchant_id,
+            customer_id,
+            card_fingerprint,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_vault_cards_not_under_key_id(
+        &self,
+        key_id: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::VaultCard>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultCard::find_by_key_id_not(&conn, key_id, limit)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_vault_card(
+        &self,
+        this: storage::VaultCard,
+        vault_card: storage::VaultCardUpdate,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, vault_card)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn delete_vault_card_by_merchant_id_customer_id_card_reference(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::VaultCard::delete_by_merchant_id_customer_id_card_reference(
+            &conn,
+            merchant_id,
+            customer_id,
+            card_reference,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl VaultCardInterface for MockDb {
+    async fn insert_vault_card(
+        &self,
+        vault_card: storage::VaultCardNew,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let mut vault_cards = self.vault_cards.lock().await;
+        if vault_cards
+            .iter()
+            .any(|card| card.card_reference == vault_card.card_reference)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "vault_card",
+                key: Some(vault_card.card_reference.clone()),
+            })?
+        }
+
+        let now = common_utils::date_time::now();
+        let vault_card = storage::VaultCard {
+            #[allow(clippy::as_conversions)]
+            id: vault_cards.len() as i32,
+            card_reference: vault_card.card_reference,
+            merchant_id: vault_card.merchant_id,
+            customer_id: vault_card.customer_id,
+            card_fingerprint: vault_card.card_fingerprint,
+            encrypted_card: vault_card.encrypted_card,
+            encrypted_data_key: vault_card.encrypted_data_key,
+            key_id: vault_card.key_id,
+            created_at: now,
+            modified_at: now,
+        };
+        vault_cards.push(vault_card.clone());
+        Ok(vault_card)
+    }
+
+    async fn find_vault_card_by_merchant_id_customer_id_card_reference(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        self.vault_cards
+            .lock()
+            .await
+            .iter()
+            .find(|card| {
+                card.merchant_id == merchant_id
+                    && card.customer_id == customer_id
+                    && card.card_reference == card_reference
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find vault card for card_reference = {card_reference}"
+                ))
+                .into()
+            })
+    }
+
+    async fn find_vault_card_by_merchant_id_customer_id_card_fingerprint(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<Option<storage::VaultCard>, errors::StorageError> {
+        Ok(self
+            .vault_cards
+            .lock()
+            .await
+            .iter()
+            .find(|card| {
+                card.merchant_id == merchant_id
+                    && card.customer_id == customer_id
+                    && card.card_fingerprint == card_fingerprint
+            })
+            .cloned())
+    }
+
+    async fn find_vault_cards_not_under_key_id(
+        &self,
+        key_id: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::VaultCard>, errors::StorageError> {
+        Ok(self
+            .vault_cards
+            .lock()
+            .await
+            .iter()
+            .filter(|card| card.key_id != key_id)
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .cloned()
+            .collect())
+    }
+
+    async fn update_vault_card(
+        &self,
+        this: storage::VaultCard,
+        vault_card: storage::VaultCardUpdate,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let mut vault_cards = self.vault_cards.lock().await;
+        let card = vault_cards
+            .iter_mut()
+            .find(|card| card.card_reference == this.card_reference)
+            .ok_or(errors::StorageError::MockDbError)?;
+        *card = storage::VaultCardUpdateInternal::from(vault_card).apply_changeset(this);
+        Ok(card.clone())
+    }
+
+    async fn delete_vault_card_by_merchant_id_customer_id_card_reference(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut vault_cards = self.vault_cards.lock().await;
+        let position = vault_cards
+            .iter()
+            .position(|card| {
+                card.merchant_id == merchant_id
+                    && card.customer_id == customer_id
+                    && card.card_reference == card_reference
+            })
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find vault card for card_reference = {card_reference}"
+                ))
+            })?;
+        vault_cards.remove(position);
+        Ok(true)
+    }
+}
@@ -0,0 +1,157 @@
+use error_stack::IntoReport;
+use time::PrimitiveDateTime;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait VaultTokenInterface {
+    async fn insert_vault_token(
+        &self,
+        vault_token: storage::VaultTokenNew,
+    ) -> CustomResult<storage::VaultToken, errors::StorageError>;
+
+    async fn find_vault_token_by_token_id(
+        &self,
+        token_id: &str,
+    ) -> CustomResult<storage::VaultToken, errors::StorageError>;
+
+    async fn delete_vault_token_by_token_id(
+        &self,
+        token_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn delete_expired_vault_tokens(
+        &self,
+        expired_before: PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl VaultTokenInterface for Store {
+    async fn insert_vault_token(
+        &self,
+        vault_token: storage::VaultTokenNew,
+    ) -> CustomResult<storage::VaultToken, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        vault_token
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_vault_token_by_token_id(
+        &self,
+        token_id: &str,
+    ) -> CustomResult<storage::VaultToken, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultToken::find_by_token_id(&conn, token_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn delete_vault_token_by_token_id(
+        &self,
+        token_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::VaultToken::delete_by_token_id(&conn, token_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn delete_expired_vault_tokens(
+        &self,
+        expired_before: PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::VaultToken::delete_expired(&conn, expired_before)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl VaultTokenInterface for MockDb {
+    async fn insert_vault_token(
+        &self,
+        vault_token: storage::VaultTokenNew,
+    ) -> CustomResult<storage::VaultToken, errors::StorageError> {
+        let mut vault_tokens = self.vault_tokens.lock().await;
+        if vault_tokens
+            .iter()
+            .any(|token| token.token_id == vault_token.token_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "vault_token",
+                key: Some(vault_token.token_id.clone()),
+            })?
+        }
+
+        let vault_token = storage::VaultToken {
+            #[allow(clippy::as_conversions)]
+            id: vault_tokens.len() as i32,
+            token_id: vault_token.token_id,
+            encrypted_value: vault_token.encrypted_value,
+            encrypted_data_key: vault_token.encrypted_data_key,
+            key_id: vault_token.key_id,
+            created_at: common_utils::date_time::now(),
+            expires_at: vault_token.expires_at,
+        };
+        vault_tokens.push(vault_token.clone());
+        Ok(vault_token)
+    }
+
+    async fn find_vault_token_by_token_id(
+        &self,
+        token_id: &str,
+    ) -> CustomResult<storage::VaultToken, errors::StorageError> {
+        self.vault_tokens
+            .lock()
+            .await
+            .iter()
+            .find(|token| token.token_id == token_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find vault token for token_id = {token_id}"
+                ))
+                .into()
+            })
+    }
+
+    async fn delete_vault_token_by_token_id(
+        &self,
+        token_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut vault_tokens = self.vault_tokens.lock().await;
+        let position = vault_tokens
+            .iter()
+            .position(|token| token.token_id == token_id)
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find vault token for token_id = {token_id}"
+                ))
+            })?;
+        vault_tokens.remove(position);
+        Ok(true)
+    }
+
+    async fn delete_expired_vault_tokens(
+        &self,
+        expired_before: PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut vault_tokens = self.vault_tokens.lock().await;
+        let token_count = vault_tokens.len();
+        vault_tokens.retain(|token| token.expires_at >= expired_before);
+        Ok(vault_tokens.len() < token_count)
+    }
+}
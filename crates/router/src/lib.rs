@@ -130,7 +130,10 @@ pub fn mk_app(
             .service(routes::MerchantAccount::server(state.clone()))
             .service(routes::ApiKeys::server(state.clone()))
             .service(routes::Files::server(state.clone()))
-            .service(routes::Disputes::server(state.clone()));
+            .service(routes::Disputes::server(state.clone()))
+            .service(routes::ProcessTracker::server(state.clone()))
+            .service(routes::RetrySchedules::server(state.clone()))
+            .service(routes::Reconciliation::server(state.clone()));
     }
 
     #[cfg(feature = "stripe")]
@@ -58,6 +58,7 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Customers", description = "Create and manage customers"),
         (name = "Payment Methods", description = "Create and manage payment methods of customers"),
         (name = "Disputes", description = "Manage disputes"),
+        (name = "Reconciliation", description = "Reconcile connector reports with payments and refunds"),
         // (name = "API Key", description = "Create and manage API Keys"),
     ),
     paths(
@@ -90,20 +91,35 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::payment_methods::create_payment_method_api,
         crate::routes::payment_methods::list_payment_method_api,
         crate::routes::payment_methods::list_customer_payment_method_api,
+        crate::routes::payment_methods::set_default_payment_method_api,
         crate::routes::payment_methods::payment_method_retrieve_api,
         crate::routes::payment_methods::payment_method_update_api,
         crate::routes::payment_methods::payment_method_delete_api,
+        crate::routes::payment_methods::payment_method_network_token_update_api,
         crate::routes::customers::customers_create,
         crate::routes::customers::customers_retrieve,
         crate::routes::customers::customers_update,
         crate::routes::customers::customers_delete,
+        crate::routes::customers::customers_list,
+        crate::routes::customers::customers_export,
+        crate::routes::customers::customers_erase,
+        crate::routes::customers::customers_erasure_retrieve,
+        crate::routes::customers::customer_addresses_create,
+        crate::routes::customers::customer_addresses_list,
+        crate::routes::customers::customer_addresses_retrieve,
+        crate::routes::customers::customer_addresses_update,
+        crate::routes::customers::customer_addresses_delete,
         // crate::routes::api_keys::api_key_create,
         // crate::routes::api_keys::api_key_retrieve,
         // crate::routes::api_keys::api_key_update,
         // crate::routes::api_keys::api_key_revoke,
         // crate::routes::api_keys::api_key_list,
+        // crate::routes::api_keys::api_key_list_unused,
         crate::routes::disputes::retrieve_disputes_list,
         crate::routes::disputes::retrieve_dispute,
+        crate::routes::reconciliation::reconciliation_run_create,
+        crate::routes::reconciliation::reconciliation_run_retrieve,
+        crate::routes::reconciliation::reconciliation_run_list,
     ),
     components(schemas(
         crate::types::api::refunds::RefundRequest,
@@ -125,11 +141,24 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::types::api::payment_methods::PaymentMethodListResponse,
         crate::types::api::payment_methods::CustomerPaymentMethodsListResponse,
         crate::types::api::payment_methods::PaymentMethodDeleteResponse,
+        crate::types::api::payment_methods::NetworkTokenUpdateRequest,
+        crate::types::api::payment_methods::NetworkTokenResponse,
         crate::types::api::payment_methods::PaymentMethodUpdate,
         crate::types::api::payment_methods::CardDetailFromLocker,
         crate::types::api::payment_methods::CardDetail,
         api_models::customers::CustomerResponse,
+        api_models::customers::CustomerDefaultPaymentMethodResponse,
+        api_models::customers::CustomerListResponse,
+        api_models::customers::CustomerDataExport,
+        api_models::customers::CustomerDataExportConstraints,
+        api_models::customers::CustomerErasureResponse,
+        api_models::customers::CustomerAddressRequest,
+        api_models::customers::CustomerAddressResponse,
+        api_models::customers::CustomerAddressListResponse,
+        api_models::customers::CustomerAddressDeleteResponse,
         api_models::admin::AcceptedCountries,
+        api_models::admin::CaptureSchedule,
+        api_models::admin::CaptureScheduleConfig,
         api_models::admin::AcceptedCurrencies,
         api_models::enums::RoutingAlgorithm,
         api_models::enums::PaymentMethod,
@@ -161,6 +190,16 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorDetails,
         api_models::disputes::DisputeResponse,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
+        api_models::reconciliation::ReconciliationRunRequest,
+        api_models::reconciliation::ReconciliationRunResponse,
+        api_models::reconciliation::ReconciliationMismatch,
+        api_models::reconciliation::ReconciliationMismatchType,
+        api_models::reconciliation::ReconciliationTransactionType,
+        api_models::enums::ReconciliationReportType,
+        api_models::enums::ReconciliationRunStatus,
+        api_models::enums::NetworkTokenStatus,
+        api_models::enums::CardAccountStatus,
+        api_models::enums::CustomerErasureStatus,
         api_models::payments::AddressDetails,
         api_models::payments::BankDebitData,
         api_models::payments::AliPayRedirection,
@@ -258,7 +297,8 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::types::api::api_keys::CreateApiKeyResponse,
         crate::types::api::api_keys::RetrieveApiKeyResponse,
         crate::types::api::api_keys::RevokeApiKeyResponse,
-        crate::types::api::api_keys::UpdateApiKeyRequest
+        crate::types::api::api_keys::UpdateApiKeyRequest,
+        crate::types::api::api_keys::UnusedApiKeysResponse
     )),
     modifiers(&SecurityAddon)
 )]
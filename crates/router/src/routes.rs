@@ -16,7 +16,10 @@ pub mod metrics;
 pub mod payment_methods;
 pub mod payments;
 pub mod payouts;
+pub mod process_tracker;
+pub mod reconciliation;
 pub mod refunds;
+pub mod retry_schedules;
 pub mod webhooks;
 
 #[cfg(feature = "dummy_connector")]
@@ -24,7 +27,7 @@ pub use self::app::DummyConnector;
 pub use self::app::{
     ApiKeys, AppState, Cache, Cards, Configs, Customers, Disputes, EphemeralKey, Files, Health,
     Mandates, MerchantAccount, MerchantConnectorAccount, PaymentMethods, Payments, Payouts,
-    Refunds, Webhooks,
+    ProcessTracker, Reconciliation, Refunds, RetrySchedules, Webhooks,
 };
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
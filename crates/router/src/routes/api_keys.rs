@@ -133,7 +133,13 @@ pub async fn api_key_update(
         &req,
         (&merchant_id, &key_id, payload),
         |state, _, (merchant_id, key_id, payload)| {
-            api_keys::update_api_key(&*state.store, merchant_id, key_id, payload)
+            api_keys::update_api_key(
+                &*state.store,
+                &state.conf.api_keys,
+                merchant_id,
+                key_id,
+                payload,
+            )
         },
         &auth::AdminApiAuth,
     )
@@ -224,3 +230,51 @@ pub async fn api_key_list(
     )
     .await
 }
+
+/// API Key - List Unused
+///
+/// List the API Keys associated with your merchant account that have not been used within the
+/// specified number of days.
+#[utoipa::path(
+    get,
+    path = "/api_keys/{merchant_id}/unused",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("unused_for_days" = Option<i64>, Query, description = "The number of days for which an API Key must not have been used to be included in the response"),
+    ),
+    responses(
+        (status = 200, description = "List of unused API Keys retrieved successfully", body = UnusedApiKeysResponse),
+    ),
+    tag = "API Key",
+    operation_id = "List unused API Keys associated with a merchant account",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ApiKeyListUnused))]
+pub async fn api_key_list_unused(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<api_types::UnusedApiKeyConstraints>,
+) -> impl Responder {
+    let flow = Flow::ApiKeyListUnused;
+    let unused_for_days = query.into_inner().unused_for_days;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (unused_for_days, merchant_id),
+        |state, _, (unused_for_days, merchant_id)| async move {
+            api_keys::list_unused_api_keys(
+                &*state.store,
+                &state.conf.api_keys,
+                merchant_id,
+                unused_for_days,
+            )
+            .await
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
@@ -1,19 +1,22 @@
 use actix_web::{web, Scope};
 #[cfg(feature = "email")]
-use external_services::email::{AwsSes, EmailClient};
+use external_services::email::{get_email_client, EmailClient};
 use tokio::sync::oneshot;
 
 #[cfg(feature = "dummy_connector")]
 use super::dummy_connector::*;
 #[cfg(feature = "olap")]
-use super::{admin::*, api_keys::*, disputes::*, files::*};
+use super::{
+    admin::*, api_keys::*, disputes::*, files::*, process_tracker::*, reconciliation::*,
+    retry_schedules::*,
+};
 use super::{cache::*, health::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers::*, mandates::*, payments::*, payouts::*, refunds::*};
 #[cfg(feature = "oltp")]
 use super::{ephemeral_key::*, payment_methods::*, webhooks::*};
 #[cfg(feature = "kms")]
-use crate::configs::kms;
+use crate::{configs::kms, core::payment_methods::builtin_vault};
 use crate::{
     configs::settings,
     db::{MockDb, StorageImpl, StorageInterface},
@@ -63,11 +66,16 @@ impl AppState {
         shut_down_signal: oneshot::Sender<()>,
     ) -> Self {
         let testable = storage_impl == StorageImpl::PostgresqlTest;
+        #[allow(clippy::expect_used)]
         let store: Box<dyn StorageInterface> = match storage_impl {
             StorageImpl::Postgresql | StorageImpl::PostgresqlTest => {
                 Box::new(Store::new(&conf, testable, shut_down_signal).await)
             }
-            StorageImpl::Mock => Box::new(MockDb::new(&conf).await),
+            StorageImpl::Mock => Box::new(
+                MockDb::new(&conf)
+                    .await
+                    .expect("Failed to create mock database"),
+            ),
         };
 
         #[cfg(feature = "kms")]
@@ -75,6 +83,8 @@ impl AppState {
         let kms_secrets = kms::KmsDecrypt::decrypt_inner(
             settings::ActiveKmsSecrets {
                 jwekey: conf.jwekey.clone().into(),
+                builtin_vault: builtin_vault::is_enabled(&conf.locker)
+                    .then(|| conf.builtin_vault.clone().into()),
             },
             &conf.kms,
         )
@@ -83,7 +93,9 @@ impl AppState {
 
         #[cfg(feature = "email")]
         #[allow(clippy::expect_used)]
-        let email_client = Box::new(AwsSes::new(&conf.email).await);
+        let email_client = get_email_client(&conf.email)
+            .await
+            .expect("Failed while creating email client");
         Self {
             flow_name: String::from("default"),
             store,
@@ -107,6 +119,7 @@ impl Health {
         web::scope("")
             .app_data(web::Data::new(state))
             .service(web::resource("/health").route(web::get().to(health)))
+            .service(web::resource("/health/ready").route(web::get().to(deep_health_check)))
     }
 }
 
@@ -209,10 +222,12 @@ impl Customers {
 
         #[cfg(feature = "olap")]
         {
-            route = route.service(
-                web::resource("/{customer_id}/mandates")
-                    .route(web::get().to(get_customer_mandates)),
-            );
+            route = route
+                .service(web::resource("/list").route(web::get().to(customers_list)))
+                .service(
+                    web::resource("/{customer_id}/mandates")
+                        .route(web::get().to(get_customer_mandates)),
+                );
         }
 
         #[cfg(feature = "oltp")]
@@ -228,6 +243,31 @@ impl Customers {
                 .service(
                     web::resource("/{customer_id}/payment_methods")
                         .route(web::get().to(list_customer_payment_method_api)),
+                )
+                .service(
+                    web::resource("/{customer_id}/payment_methods/{payment_method_id}/default")
+                        .route(web::post().to(set_default_payment_method_api)),
+                )
+                .service(
+                    web::resource("/{customer_id}/addresses")
+                        .route(web::get().to(customer_addresses_list))
+                        .route(web::post().to(customer_addresses_create)),
+                )
+                .service(
+                    web::resource("/{customer_id}/addresses/{address_id}")
+                        .route(web::get().to(customer_addresses_retrieve))
+                        .route(web::post().to(customer_addresses_update))
+                        .route(web::delete().to(customer_addresses_delete)),
+                )
+                .service(
+                    web::resource("/{customer_id}/export").route(web::get().to(customers_export)),
+                )
+                .service(
+                    web::resource("/{customer_id}/erasures").route(web::post().to(customers_erase)),
+                )
+                .service(
+                    web::resource("/{customer_id}/erasures/{erasure_id}")
+                        .route(web::get().to(customers_erasure_retrieve)),
                 );
         }
         route
@@ -303,6 +343,10 @@ impl PaymentMethods {
                     .route(web::post().to(payment_method_update_api))
                     .route(web::delete().to(payment_method_delete_api)),
             )
+            .service(
+                web::resource("/{payment_method_id}/network_token")
+                    .route(web::post().to(payment_method_network_token_update_api)),
+            )
     }
 }
 
@@ -442,6 +486,7 @@ impl ApiKeys {
             .app_data(web::Data::new(state))
             .service(web::resource("").route(web::post().to(api_key_create)))
             .service(web::resource("/list").route(web::get().to(api_key_list)))
+            .service(web::resource("/unused").route(web::get().to(api_key_list_unused)))
             .service(
                 web::resource("/{key_id}")
                     .route(web::get().to(api_key_retrieve))
@@ -495,6 +540,73 @@ impl Files {
     }
 }
 
+pub struct ProcessTracker;
+
+#[cfg(feature = "olap")]
+impl ProcessTracker {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/process_tracker")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/list").route(web::get().to(process_tracker_list)))
+            .service(web::resource("/{process_id}").route(web::get().to(process_tracker_retrieve)))
+            .service(
+                web::resource("/{process_id}/retry").route(web::post().to(process_tracker_retry)),
+            )
+            .service(
+                web::resource("/{process_id}/reschedule")
+                    .route(web::post().to(process_tracker_reschedule)),
+            )
+            .service(
+                web::resource("/{process_id}/cancel").route(web::post().to(process_tracker_cancel)),
+            )
+    }
+}
+
+pub struct RetrySchedules;
+
+#[cfg(feature = "olap")]
+impl RetrySchedules {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/retry_schedules")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/connectors/{connector}/{workflow}")
+                    .route(web::get().to(connector_retry_schedule_retrieve))
+                    .route(web::post().to(connector_retry_schedule_update))
+                    .route(web::delete().to(connector_retry_schedule_delete)),
+            )
+            .service(
+                web::resource("/connectors/{connector}/{workflow}/preview")
+                    .route(web::get().to(connector_retry_timeline_preview)),
+            )
+            .service(
+                web::resource("/payment_methods/{payment_method}")
+                    .route(web::get().to(payment_method_retry_schedule_retrieve))
+                    .route(web::post().to(payment_method_retry_schedule_update))
+                    .route(web::delete().to(payment_method_retry_schedule_delete)),
+            )
+            .service(
+                web::resource("/payment_methods/{payment_method}/preview")
+                    .route(web::get().to(payment_method_retry_timeline_preview)),
+            )
+    }
+}
+
+pub struct Reconciliation;
+
+#[cfg(feature = "olap")]
+impl Reconciliation {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/reconciliation")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/runs").route(web::post().to(reconciliation_run_create)))
+            .service(web::resource("/runs/list").route(web::get().to(reconciliation_run_list)))
+            .service(
+                web::resource("/runs/{run_id}").route(web::get().to(reconciliation_run_retrieve)),
+            )
+    }
+}
+
 pub struct Cache;
 
 impl Cache {
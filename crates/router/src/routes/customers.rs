@@ -165,6 +165,53 @@ pub async fn customers_delete(
     .await
 }
 
+/// Customers - List
+///
+/// To list the customers of a merchant, optionally searching them by email or phone number
+#[utoipa::path(
+    get,
+    path = "/customers/list",
+    params(
+        ("email" = String, Query, description = "Exact match on the customer's email address"),
+        ("phone" = String, Query, description = "Exact match on the customer's phone number"),
+        ("starting_after" = String, Query, description = "A cursor for use in pagination, fetch the next list after some object"),
+        ("ending_before" = String, Query, description = "A cursor for use in pagination, fetch the previous list before some object"),
+        ("limit" = i64, Query, description = "Limit on the number of objects to return"),
+        ("created" = PrimitiveDateTime, Query, description = "The time at which customer is created"),
+        ("created_lt" = PrimitiveDateTime, Query, description = "Time less than the customer created time"),
+        ("created_gt" = PrimitiveDateTime, Query, description = "Time greater than the customer created time"),
+        ("created_lte" = PrimitiveDateTime, Query, description = "Time less than or equals to the customer created time"),
+        ("created_gte" = PrimitiveDateTime, Query, description = "Time greater than or equals to the customer created time")
+    ),
+    responses(
+        (status = 200, description = "List of customers", body = CustomerListResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Customers",
+    operation_id = "List all Customers",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersList))]
+#[cfg(feature = "olap")]
+pub async fn customers_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<customers::CustomerListConstraints>,
+) -> impl Responder {
+    let flow = Flow::CustomersList;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload.into_inner(),
+        |state, auth, req| {
+            list_customers(&*state.store, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::CustomersGetMandates))]
 pub async fn get_customer_mandates(
     state: web::Data<AppState>,
@@ -188,3 +235,356 @@ pub async fn get_customer_mandates(
     )
     .await
 }
+
+/// Customers - Export
+///
+/// To export all the data held for a customer, with the personal details decrypted, including the addresses, saved payment methods, payments, refunds, mandates and disputes of the customer
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/export",
+    params (
+        ("customer_id" = String, Path, description = "The unique identifier for the Customer"),
+        ("limit" = Option<i64>, Query, description = "The number of payments to export, most recent first"),
+        ("offset" = Option<i64>, Query, description = "The number of most recent payments to skip")
+    ),
+    responses(
+        (status = 200, description = "Customer data exported", body = CustomerDataExport),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Export the data of a Customer",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersExport))]
+pub async fn customers_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<customers::CustomerDataExportConstraints>,
+) -> impl Responder {
+    let flow = Flow::CustomersExport;
+    let payload = (
+        customers::CustomerId {
+            customer_id: path.into_inner(),
+        },
+        query.into_inner(),
+    );
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, auth, (customer_id, constraints)| {
+            export_customer_data(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                customer_id,
+                constraints,
+            )
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Customers - Erase
+///
+/// To erase the personal data of a customer across the customer, address, payment method, mandate, payment, refund and dispute records. The erasure is run asynchronously, and its status can be tracked with the erasure ID returned.
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/erasures",
+    params (("customer_id" = String, Path, description = "The unique identifier for the Customer")),
+    responses(
+        (status = 200, description = "Customer erasure scheduled", body = CustomerErasureResponse),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Erase the data of a Customer",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersErase))]
+pub async fn customers_erase(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::CustomersErase;
+    let payload = customers::CustomerId {
+        customer_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, auth, req| {
+            request_customer_erasure(&*state.store, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Customers - Retrieve Erasure
+///
+/// To retrieve the status of an erasure of the data of a customer
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/erasures/{erasure_id}",
+    params (
+        ("customer_id" = String, Path, description = "The unique identifier for the Customer"),
+        ("erasure_id" = String, Path, description = "The unique identifier for the erasure")
+    ),
+    responses(
+        (status = 200, description = "Customer erasure retrieved", body = CustomerErasureResponse),
+        (status = 404, description = "Customer erasure was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Retrieve an erasure of the data of a Customer",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersErasureRetrieve))]
+pub async fn customers_erasure_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::CustomersErasureRetrieve;
+    let (customer_id, erasure_id) = path.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (customer_id, erasure_id),
+        |state, auth, (customer_id, erasure_id)| {
+            retrieve_customer_erasure(
+                &*state.store,
+                auth.merchant_account,
+                customer_id,
+                erasure_id,
+            )
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Customer Addresses - Create
+///
+/// To save an address in the address book of a customer, optionally making it the default billing or shipping address of the customer
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/addresses",
+    request_body = CustomerAddressRequest,
+    params (("customer_id" = String, Path, description = "The unique identifier for the Customer")),
+    responses(
+        (status = 200, description = "Customer address saved", body = CustomerAddressResponse),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Save an address of a Customer",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerAddressesCreate))]
+pub async fn customer_addresses_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<customers::CustomerAddressRequest>,
+) -> impl Responder {
+    let flow = Flow::CustomerAddressesCreate;
+    let customer_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (customer_id, json_payload.into_inner()),
+        |state, auth, (customer_id, req)| {
+            create_customer_address(
+                &*state.store,
+                auth.merchant_account,
+                auth.key_store,
+                customer_id,
+                req,
+            )
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Customer Addresses - List
+///
+/// To list the addresses saved in the address book of a customer
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/addresses",
+    params (("customer_id" = String, Path, description = "The unique identifier for the Customer")),
+    responses(
+        (status = 200, description = "List of saved addresses", body = CustomerAddressListResponse),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "List all addresses of a Customer",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerAddressesList))]
+pub async fn customer_addresses_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::CustomerAddressesList;
+    let payload = customers::CustomerId {
+        customer_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, auth, req| {
+            list_customer_addresses(&*state.store, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Customer Addresses - Retrieve
+///
+/// To retrieve an address saved in the address book of a customer
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/addresses/{address_id}",
+    params (
+        ("customer_id" = String, Path, description = "The unique identifier for the Customer"),
+        ("address_id" = String, Path, description = "The unique identifier for the saved address")
+    ),
+    responses(
+        (status = 200, description = "Customer address retrieved", body = CustomerAddressResponse),
+        (status = 404, description = "Customer address was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Retrieve an address of a Customer",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerAddressesRetrieve))]
+pub async fn customer_addresses_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::CustomerAddressesRetrieve;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        |state, auth, (customer_id, address_id)| {
+            retrieve_customer_address(
+                &*state.store,
+                auth.merchant_account,
+                auth.key_store,
+                customer_id,
+                address_id,
+            )
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Customer Addresses - Update
+///
+/// To update an address saved in the address book of a customer. The address details and the phone details are each replaced as a whole when passed.
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/addresses/{address_id}",
+    request_body = CustomerAddressRequest,
+    params (
+        ("customer_id" = String, Path, description = "The unique identifier for the Customer"),
+        ("address_id" = String, Path, description = "The unique identifier for the saved address")
+    ),
+    responses(
+        (status = 200, description = "Customer address updated", body = CustomerAddressResponse),
+        (status = 404, description = "Customer address was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Update an address of a Customer",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerAddressesUpdate))]
+pub async fn customer_addresses_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<customers::CustomerAddressRequest>,
+) -> impl Responder {
+    let flow = Flow::CustomerAddressesUpdate;
+    let (customer_id, address_id) = path.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (customer_id, address_id, json_payload.into_inner()),
+        |state, auth, (customer_id, address_id, req)| {
+            update_customer_address(
+                &*state.store,
+                auth.merchant_account,
+                auth.key_store,
+                customer_id,
+                address_id,
+                req,
+            )
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Customer Addresses - Delete
+///
+/// To delete an address saved in the address book of a customer. The addresses of the payments made with it are not affected.
+#[utoipa::path(
+    delete,
+    path = "/customers/{customer_id}/addresses/{address_id}",
+    params (
+        ("customer_id" = String, Path, description = "The unique identifier for the Customer"),
+        ("address_id" = String, Path, description = "The unique identifier for the saved address")
+    ),
+    responses(
+        (status = 200, description = "Customer address deleted", body = CustomerAddressDeleteResponse),
+        (status = 404, description = "Customer address was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Delete an address of a Customer",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerAddressesDelete))]
+pub async fn customer_addresses_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::CustomerAddressesDelete;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        |state, auth, (customer_id, address_id)| {
+            delete_customer_address(
+                &*state.store,
+                auth.merchant_account,
+                auth.key_store,
+                customer_id,
+                address_id,
+            )
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
@@ -5,6 +5,8 @@ global_meter!(GLOBAL_METER, "ROUTER_API");
 
 counter_metric!(HEALTH_METRIC, GLOBAL_METER); // No. of health API hits
 counter_metric!(KV_MISS, GLOBAL_METER); // No. of KV misses
+counter_metric!(IN_MEMORY_CACHE_HIT, GLOBAL_METER); // No. of in-memory cache hits
+counter_metric!(IN_MEMORY_CACHE_MISS, GLOBAL_METER); // No. of in-memory cache misses
 #[cfg(feature = "kms")]
 counter_metric!(AWS_KMS_FAILURES, GLOBAL_METER); // No. of AWS KMS API failures
 
@@ -38,11 +40,26 @@ counter_metric!(CREATED_TOKENIZED_CARD, GLOBAL_METER);
 counter_metric!(DELETED_TOKENIZED_CARD, GLOBAL_METER);
 counter_metric!(GET_TOKENIZED_CARD, GLOBAL_METER);
 
+counter_metric!(NETWORK_TOKENS_PROVISIONED, GLOBAL_METER);
+counter_metric!(NETWORK_TOKEN_PAYMENTS, GLOBAL_METER);
+counter_metric!(NETWORK_TOKEN_FAILURES, GLOBAL_METER);
+
+counter_metric!(CARD_ACCOUNT_UPDATES, GLOBAL_METER);
+counter_metric!(CARD_ACCOUNT_UPDATE_FAILURES, GLOBAL_METER);
+
 counter_metric!(CUSTOMER_CREATED, GLOBAL_METER);
 counter_metric!(CUSTOMER_REDACTED, GLOBAL_METER);
 
+counter_metric!(PAYMENT_INTENT_EXPIRY_TASKS_CREATED, GLOBAL_METER);
+counter_metric!(PAYMENT_INTENTS_EXPIRED, GLOBAL_METER);
+counter_metric!(CAPTURE_SCHEDULE_TASKS_CREATED, GLOBAL_METER);
+counter_metric!(AUTO_CAPTURES_TRIGGERED, GLOBAL_METER);
+counter_metric!(AUTO_VOIDS_TRIGGERED, GLOBAL_METER);
+
 counter_metric!(API_KEY_CREATED, GLOBAL_METER);
 counter_metric!(API_KEY_REVOKED, GLOBAL_METER);
+counter_metric!(API_KEY_EXPIRY_TASKS_CREATED, GLOBAL_METER);
+counter_metric!(API_KEY_EXPIRY_REMINDERS_SENT, GLOBAL_METER);
 
 counter_metric!(MCA_CREATE, GLOBAL_METER);
 
@@ -82,5 +99,16 @@ histogram_metric!(CARD_DELETE_TIME, GLOBAL_METER);
 histogram_metric!(ENCRYPTION_TIME, GLOBAL_METER);
 histogram_metric!(DECRYPTION_TIME, GLOBAL_METER);
 
+// Reconciliation metrics
+counter_metric!(RECONCILIATION_RUNS_COMPLETED, GLOBAL_METER);
+counter_metric!(RECONCILIATION_RUNS_FAILED, GLOBAL_METER);
+counter_metric!(RECONCILIATION_MISMATCHES_FOUND, GLOBAL_METER);
+
+// Stale object sync metrics
+counter_metric!(STALE_OBJECTS_SYNCED, GLOBAL_METER);
+counter_metric!(STALE_OBJECT_SYNC_FAILED, GLOBAL_METER);
+counter_metric!(STALE_OBJECT_SYNC_RATE_LIMITED, GLOBAL_METER);
+counter_metric!(STALE_DISPUTES_FOUND, GLOBAL_METER);
+
 pub mod request;
 pub mod utils;
@@ -3,7 +3,7 @@ use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::payment_methods::cards,
+    core::payment_methods::{cards, network_tokenization},
     services::{api, authentication as auth},
     types::api::payment_methods::{self, PaymentMethodId},
 };
@@ -36,7 +36,7 @@ pub async fn create_payment_method_api(
         &req,
         json_payload.into_inner(),
         |state, auth, req| async move {
-            cards::add_payment_method(state, req, &auth.merchant_account).await
+            cards::add_payment_method(state, req, &auth.merchant_account, &auth.key_store).await
         },
         &auth::ApiKeyAuth,
     )
@@ -151,6 +151,53 @@ pub async fn list_customer_payment_method_api(
     .await
 }
 
+/// Payment Method - Set Default Payment Method for Customer
+///
+/// Set the payment method which is used for off-session payments of the customer, when no payment method is provided
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/payment_methods/{payment_method_id}/default",
+    params (
+        ("customer_id" = String, Path, description = "The unique identifier for the Customer"),
+        ("payment_method_id" = String, Path, description = "The unique identifier for the Payment Method"),
+    ),
+    responses(
+        (status = 200, description = "Default Payment Method set for the Customer", body = CustomerDefaultPaymentMethodResponse),
+        (status = 400, description = "Payment Method cannot be used as the default"),
+        (status = 404, description = "Customer or Payment Method does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Set the Default Payment Method for a Customer",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPaymentMethodsSetDefault))]
+pub async fn set_default_payment_method_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::CustomerPaymentMethodsSetDefault;
+    let (customer_id, payment_method_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payment_method_id,
+        |state, auth, payment_method_id| {
+            cards::set_default_payment_method(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                &customer_id,
+                &payment_method_id,
+            )
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
 /// Payment Method - Retrieve
 ///
 /// To retrieve a payment method
@@ -228,6 +275,7 @@ pub async fn payment_method_update_api(
             cards::update_customer_payment_method(
                 state,
                 auth.merchant_account,
+                auth.key_store,
                 payload,
                 &payment_method_id,
             )
@@ -269,7 +317,55 @@ pub async fn payment_method_delete_api(
         state.get_ref(),
         &req,
         pm,
-        |state, auth, req| cards::delete_payment_method(state, auth.merchant_account, req),
+        |state, auth, req| {
+            cards::delete_payment_method(state, auth.merchant_account, req, auth.key_store)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Payment Method - Update Network Token
+///
+/// To suspend, resume or delete the network token provisioned for a saved card
+#[utoipa::path(
+    post,
+    path = "/payment_methods/{method_id}/network_token",
+    params (
+        ("method_id" = String, Path, description = "The unique identifier for the Payment Method"),
+    ),
+    request_body = NetworkTokenUpdateRequest,
+    responses(
+        (status = 200, description = "Network token updated", body = NetworkTokenResponse),
+        (status = 404, description = "Payment Method does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Update the network token of a Payment method",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsNetworkTokenUpdate))]
+pub async fn payment_method_network_token_update_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<payment_methods::NetworkTokenUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsNetworkTokenUpdate;
+    let payment_method_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, auth, payload| {
+            network_tokenization::update_network_token(
+                state,
+                auth.merchant_account,
+                &payment_method_id,
+                payload,
+            )
+        },
         &auth::ApiKeyAuth,
     )
     .await
@@ -0,0 +1,121 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::process_tracker as process_tracker_api;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::process_tracker,
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerList))]
+pub async fn process_tracker_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<process_tracker_api::ProcessTrackerListConstraints>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerList;
+    let payload = query.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, _, constraints| process_tracker::list_processes(&*state.store, constraints),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerRetrieve))]
+pub async fn process_tracker_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerRetrieve;
+    let process_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        &process_id,
+        |state, _, process_id| process_tracker::retrieve_process(&*state.store, process_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerRetry))]
+pub async fn process_tracker_retry(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: Option<web::Json<process_tracker_api::ProcessTrackerActionRequest>>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerRetry;
+    let process_id = path.into_inner();
+    let payload = json_payload.map(web::Json::into_inner).unwrap_or_default();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (&process_id, payload),
+        |state, _, (process_id, payload)| {
+            process_tracker::retry_process(&*state.store, process_id, payload)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerReschedule))]
+pub async fn process_tracker_reschedule(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<process_tracker_api::ProcessTrackerRescheduleRequest>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerReschedule;
+    let process_id = path.into_inner();
+    let payload = json_payload.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (&process_id, payload),
+        |state, _, (process_id, payload)| {
+            process_tracker::reschedule_process(&*state.store, process_id, payload)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerCancel))]
+pub async fn process_tracker_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: Option<web::Json<process_tracker_api::ProcessTrackerActionRequest>>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerCancel;
+    let process_id = path.into_inner();
+    let payload = json_payload.map(web::Json::into_inner).unwrap_or_default();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (&process_id, payload),
+        |state, _, (process_id, payload)| {
+            process_tracker::cancel_process(&*state.store, process_id, payload)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
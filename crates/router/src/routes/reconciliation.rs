@@ -0,0 +1,125 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::reconciliation as reconciliation_api;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::reconciliation,
+    services::{api, authentication as auth},
+};
+
+/// Reconciliation - Create Run
+///
+/// Reconcile a settlement or payout report of a connector, uploaded through the files API, with
+/// the payments and refunds of the merchant
+#[utoipa::path(
+    post,
+    path = "/reconciliation/runs",
+    request_body = ReconciliationRunRequest,
+    responses(
+        (status = 200, description = "The report was reconciled", body = ReconciliationRunResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "File not found")
+    ),
+    tag = "Reconciliation",
+    operation_id = "Create a Reconciliation Run",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReconciliationRunCreate))]
+pub async fn reconciliation_run_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<reconciliation_api::ReconciliationRunRequest>,
+) -> HttpResponse {
+    let flow = Flow::ReconciliationRunCreate;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req| {
+            reconciliation::create_reconciliation_run(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+    )
+    .await
+}
+
+/// Reconciliation - Retrieve Run
+#[utoipa::path(
+    get,
+    path = "/reconciliation/runs/{run_id}",
+    params(
+        ("run_id" = String, Path, description = "The identifier for the reconciliation run")
+    ),
+    responses(
+        (status = 200, description = "The reconciliation run was retrieved successfully", body = ReconciliationRunResponse),
+        (status = 404, description = "Reconciliation run does not exist in our records")
+    ),
+    tag = "Reconciliation",
+    operation_id = "Retrieve a Reconciliation Run",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReconciliationRunRetrieve))]
+pub async fn reconciliation_run_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ReconciliationRunRetrieve;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        |state, auth, run_id| {
+            reconciliation::retrieve_reconciliation_run(state, auth.merchant_account, run_id)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+    )
+    .await
+}
+
+/// Reconciliation - List Runs
+#[utoipa::path(
+    get,
+    path = "/reconciliation/runs/list",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of runs to include in the response"),
+        ("connector" = Option<Connector>, Query, description = "The connector which generated the reconciled reports"),
+        ("status" = Option<ReconciliationRunStatus>, Query, description = "The status of the run"),
+        ("created.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the creation time of the run"),
+        ("created.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the creation time of the run"),
+    ),
+    responses(
+        (status = 200, description = "The reconciliation runs were retrieved successfully", body = Vec<ReconciliationRunResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Reconciliation",
+    operation_id = "List Reconciliation Runs",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReconciliationRunList))]
+pub async fn reconciliation_run_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<reconciliation_api::ReconciliationRunListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::ReconciliationRunList;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload.into_inner(),
+        |state, auth, req| {
+            reconciliation::list_reconciliation_runs(state, auth.merchant_account, req)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+    )
+    .await
+}
@@ -0,0 +1,206 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::retry_schedules as retry_schedules_api;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::retry_schedules,
+    services::{api, authentication as auth},
+    types::api::enums as api_enums,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorRetryScheduleRetrieve))]
+pub async fn connector_retry_schedule_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, retry_schedules_api::ConnectorRetryWorkflow)>,
+) -> impl Responder {
+    let flow = Flow::ConnectorRetryScheduleRetrieve;
+    let (connector, workflow) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (&connector, workflow),
+        |state, _, (connector, workflow)| {
+            retry_schedules::retrieve_connector_retry_schedule(&*state.store, connector, workflow)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorRetryScheduleUpdate))]
+pub async fn connector_retry_schedule_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, retry_schedules_api::ConnectorRetryWorkflow)>,
+    json_payload: web::Json<retry_schedules_api::ConnectorRetrySchedule>,
+) -> impl Responder {
+    let flow = Flow::ConnectorRetryScheduleUpdate;
+    let (connector, workflow) = path.into_inner();
+    let payload = json_payload.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (&connector, workflow, payload),
+        |state, _, (connector, workflow, payload)| {
+            retry_schedules::update_connector_retry_schedule(
+                &*state.store,
+                connector,
+                workflow,
+                payload,
+            )
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorRetryScheduleDelete))]
+pub async fn connector_retry_schedule_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, retry_schedules_api::ConnectorRetryWorkflow)>,
+) -> impl Responder {
+    let flow = Flow::ConnectorRetryScheduleDelete;
+    let (connector, workflow) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (&connector, workflow),
+        |state, _, (connector, workflow)| {
+            retry_schedules::delete_connector_retry_schedule(&*state.store, connector, workflow)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorRetryTimelinePreview))]
+pub async fn connector_retry_timeline_preview(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, retry_schedules_api::ConnectorRetryWorkflow)>,
+    query: web::Query<retry_schedules_api::ConnectorRetryTimelineRequest>,
+) -> impl Responder {
+    let flow = Flow::ConnectorRetryTimelinePreview;
+    let (connector, workflow) = path.into_inner();
+    let payload = query.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (&connector, workflow, payload),
+        |state, _, (connector, workflow, payload)| {
+            retry_schedules::preview_connector_retry_timeline(
+                &*state.store,
+                connector,
+                workflow,
+                payload,
+            )
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodRetryScheduleRetrieve))]
+pub async fn payment_method_retry_schedule_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<api_enums::PaymentMethod>,
+) -> impl Responder {
+    let flow = Flow::PaymentMethodRetryScheduleRetrieve;
+    let payment_method = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payment_method,
+        |state, _, payment_method| {
+            retry_schedules::retrieve_payment_method_retry_schedule(&*state.store, payment_method)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodRetryScheduleUpdate))]
+pub async fn payment_method_retry_schedule_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<api_enums::PaymentMethod>,
+    json_payload: web::Json<retry_schedules_api::PaymentMethodRetrySchedule>,
+) -> impl Responder {
+    let flow = Flow::PaymentMethodRetryScheduleUpdate;
+    let payment_method = path.into_inner();
+    let payload = json_payload.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (payment_method, payload),
+        |state, _, (payment_method, payload)| {
+            retry_schedules::update_payment_method_retry_schedule(
+                &*state.store,
+                payment_method,
+                payload,
+            )
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodRetryScheduleDelete))]
+pub async fn payment_method_retry_schedule_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<api_enums::PaymentMethod>,
+) -> impl Responder {
+    let flow = Flow::PaymentMethodRetryScheduleDelete;
+    let payment_method = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payment_method,
+        |state, _, payment_method| {
+            retry_schedules::delete_payment_method_retry_schedule(&*state.store, payment_method)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodRetryTimelinePreview))]
+pub async fn payment_method_retry_timeline_preview(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<api_enums::PaymentMethod>,
+) -> impl Responder {
+    let flow = Flow::PaymentMethodRetryTimelinePreview;
+    let payment_method = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payment_method,
+        |state, _, payment_method| {
+            retry_schedules::preview_payment_method_retry_timeline(&*state.store, payment_method)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
@@ -1,6 +1,7 @@
 // TODO: Figure out what to log
 
 use std::{
+    collections::HashSet,
     fmt,
     sync::{self, atomic},
 };
@@ -9,7 +10,7 @@ use common_utils::signals::get_allowed_signals;
 use error_stack::{IntoReport, ResultExt};
 use futures::future;
 use redis_interface::{RedisConnectionPool, RedisEntryId};
-use router_env::{instrument, tracing};
+use router_env::{instrument, opentelemetry, tracing};
 use time::PrimitiveDateTime;
 use tokio::sync::mpsc;
 use uuid::Uuid;
@@ -24,7 +25,7 @@ use crate::{
     db::StorageInterface,
     logger,
     routes::AppState,
-    scheduler::utils as pt_utils,
+    scheduler::{self, utils as pt_utils},
     types::storage::{self, enums, ProcessTrackerExt},
 };
 
@@ -40,7 +41,7 @@ pub async fn start_consumer(
     workflow_selector: workflows::WorkflowSelectorFn,
     (tx, mut rx): (mpsc::Sender<()>, mpsc::Receiver<()>),
 ) -> CustomResult<(), errors::ProcessTrackerError> {
-    use std::time::Duration;
+    use std::time::{Duration, Instant};
 
     use rand::Rng;
 
@@ -52,7 +53,13 @@ pub async fn start_consumer(
     let mut shutdown_interval =
         tokio::time::interval(Duration::from_millis(settings.graceful_shutdown_interval));
 
+    let reclaim_interval = Duration::from_millis(settings.consumer.reclaim_interval);
+    let mut last_reclaimed_at = Instant::now();
+
     let consumer_operation_counter = sync::Arc::new(atomic::AtomicU64::new(0));
+    let mut stream_selector = pt_utils::StreamSelector::new(&settings);
+    let concurrency_limits =
+        sync::Arc::new(pt_utils::RunnerConcurrencyLimits::new(&settings.consumer));
     let signal = get_allowed_signals()
         .map_err(|error| {
             logger::error!("Signal Handler Error: {:?}", error);
@@ -81,7 +88,23 @@ pub async fn start_consumer(
                     },
                     sync::Arc::clone(&consumer_operation_counter),
                     workflow_selector,
+                    stream_selector.next_stream().to_owned(),
+                    sync::Arc::clone(&concurrency_limits),
                 ));
+
+                if last_reclaimed_at.elapsed() >= reclaim_interval {
+                    last_reclaimed_at = Instant::now();
+                    tokio::task::spawn(pt_utils::reclaim_operation_handler(
+                        state.clone(),
+                        settings.clone(),
+                        |err| {
+                            logger::error!(%err);
+                        },
+                        sync::Arc::clone(&consumer_operation_counter),
+                        workflow_selector,
+                        sync::Arc::clone(&concurrency_limits),
+                    ));
+                }
             }
             Ok(()) | Err(mpsc::error::TryRecvError::Disconnected) => {
                 logger::debug!("Awaiting shutdown!");
@@ -112,57 +135,285 @@ pub async fn consumer_operations(
     state: &AppState,
     settings: &settings::SchedulerSettings,
     workflow_selector: workflows::WorkflowSelectorFn,
+    stream_name: &str,
+    concurrency_limits: sync::Arc<pt_utils::RunnerConcurrencyLimits>,
 ) -> CustomResult<(), errors::ProcessTrackerError> {
-    let stream_name = settings.stream.clone();
     let group_name = settings.consumer.consumer_group.clone();
     let consumer_name = format!("consumer_{}", Uuid::new_v4());
 
     let group_created = &mut state
         .store
-        .consumer_group_create(&stream_name, &group_name, &RedisEntryId::AfterLastID)
+        .consumer_group_create(stream_name, &group_name, &RedisEntryId::AfterLastID)
         .await;
     if group_created.is_err() {
         logger::info!("Consumer group already exists");
     }
 
-    let mut tasks = state
+    let (tasks, entry_ids) = state
+        .store
+        .fetch_consumer_tasks(stream_name, &group_name, &consumer_name)
+        .await?;
+
+    logger::info!(
+        "{} picked {} tasks from {}",
+        consumer_name,
+        tasks.len(),
+        stream_name
+    );
+
+    execute_tasks(
+        state,
+        settings,
+        tasks,
+        stream_name,
+        &consumer_name,
+        workflow_selector,
+        concurrency_limits,
+    )
+    .await?;
+
+    state
         .store
-        .fetch_consumer_tasks(&stream_name, &group_name, &consumer_name)
+        .acknowledge_consumer_tasks(stream_name, &group_name, entry_ids)
+        .await
+}
+
+/// Claims the batches which have not been acknowledged within the configured idle time, since the
+/// consumers which read them are presumed dead, and executes the tasks in them whose lease has
+/// expired. Batches are acknowledged only once all their pending tasks have been executed again.
+#[instrument(skip_all)]
+pub async fn reclaim_operations(
+    state: &AppState,
+    settings: &settings::SchedulerSettings,
+    workflow_selector: workflows::WorkflowSelectorFn,
+    concurrency_limits: sync::Arc<pt_utils::RunnerConcurrencyLimits>,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let group_name = settings.consumer.consumer_group.clone();
+    let consumer_name = format!("consumer_{}", Uuid::new_v4());
+
+    for stream_name in pt_utils::get_consumer_stream_names(settings) {
+        let claimed = state
+            .store
+            .claim_stalled_consumer_tasks(
+                stream_name,
+                &group_name,
+                &consumer_name,
+                settings.consumer.reclaim_idle_time,
+            )
+            .await;
+        let batches = match claimed {
+            Ok(batches) => batches,
+            Err(error) => {
+                logger::warn!(%stream_name, ?error, "Failed to claim stalled batches");
+                continue;
+            }
+        };
+        if batches.is_empty() {
+            continue;
+        }
+
+        let (entries, tasks) = batches.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut entries, mut tasks), batch| {
+                let task_ids = batch
+                    .tasks
+                    .iter()
+                    .map(|task| task.id.clone())
+                    .collect::<Vec<_>>();
+                entries.push((batch.entry_id, batch.has_leased_tasks, task_ids));
+                tasks.extend(batch.tasks);
+                (entries, tasks)
+            },
+        );
+
+        logger::info!(
+            "{} reclaimed {} tasks from {}",
+            consumer_name,
+            tasks.len(),
+            stream_name
+        );
+
+        let skipped_task_ids = execute_tasks(
+            state,
+            settings,
+            tasks,
+            stream_name,
+            &consumer_name,
+            workflow_selector,
+            sync::Arc::clone(&concurrency_limits),
+        )
         .await?;
 
-    logger::info!("{} picked {} tasks", consumer_name, tasks.len());
+        // Batches holding tasks which are still being executed by another consumer are left to be
+        // reclaimed again, so that the tasks are not dropped if that consumer dies
+        let entry_ids = entries
+            .into_iter()
+            .filter(|(_, has_leased_tasks, task_ids)| {
+                !has_leased_tasks
+                    && task_ids
+                        .iter()
+                        .all(|task_id| !skipped_task_ids.contains(task_id))
+            })
+            .map(|(entry_id, _, _)| entry_id)
+            .collect();
+
+        state
+            .store
+            .acknowledge_consumer_tasks(stream_name, &group_name, entry_ids)
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Executes the tasks which the consumer is able to lease, returning the IDs of the tasks skipped
+/// since they are leased by another consumer. Tasks whose runner is unknown can never be executed,
+/// they are finished instead of failing the batch, so that the batch is acknowledged. Fails only
+/// if the leases could not be acquired, leaving the batch to be reclaimed.
+async fn execute_tasks(
+    state: &AppState,
+    settings: &settings::SchedulerSettings,
+    tasks: Vec<storage::ProcessTracker>,
+    stream_name: &str,
+    consumer_name: &str,
+    workflow_selector: workflows::WorkflowSelectorFn,
+    concurrency_limits: sync::Arc<pt_utils::RunnerConcurrencyLimits>,
+) -> CustomResult<HashSet<String>, errors::ProcessTrackerError> {
+    let db: &dyn StorageInterface = &*state.store;
+    let lease_duration = settings.consumer.lease_duration;
     let mut handler = vec![];
+    let mut lease_error = None;
+    let mut skipped_task_ids = HashSet::new();
 
-    for task in tasks.iter_mut() {
+    for task in tasks {
         let pickup_time = common_utils::date_time::now();
 
-        pt_utils::add_histogram_metrics(&pickup_time, task, &stream_name);
+        pt_utils::add_histogram_metrics(&pickup_time, &task, stream_name);
 
         metrics::TASK_CONSUMED.add(&metrics::CONTEXT, 1, &[]);
-        let runner = workflow_selector(task)?.ok_or(errors::ProcessTrackerError::UnexpectedFlow)?;
-        handler.push(tokio::task::spawn(start_workflow(
-            state.clone(),
-            task.clone(),
-            pickup_time,
-            runner,
-        )))
+        let runner = match workflow_selector(&task) {
+            Ok(Some(runner)) => runner,
+            Ok(None) | Err(_) => {
+                logger::error!(pt.id = %task.id, pt.runner = ?task.runner, "Unknown runner, finishing the task");
+                if let Err(error) = task
+                    .finish_with_status(db, "UNKNOWN_RUNNER".to_string())
+                    .await
+                {
+                    logger::error!(%error, "Failed while performing database operation: UNKNOWN_RUNNER");
+                }
+                continue;
+            }
+        };
+
+        // Held until the workflow completes, to limit the number of tasks of the runner executing
+        // concurrently. The permit is acquired before the task is leased and marked as started, so
+        // that tasks waiting for a permit are not held by this consumer and its lease does not
+        // lapse while waiting.
+        let permit = concurrency_limits.acquire(task.runner.as_deref()).await;
+
+        // The task may be executing on another consumer, if the batch was reclaimed from a consumer
+        // which is slow rather than dead
+        let task = match db
+            .acquire_process_lease(&task.id, consumer_name, get_lease_expiry(lease_duration))
+            .await
+        {
+            Ok(Some(task)) => task,
+            Ok(None) => {
+                logger::info!(pt.id = %task.id, "Task is leased by another consumer or completed, skipping");
+                skipped_task_ids.insert(task.id);
+                continue;
+            }
+            Err(error) => {
+                logger::error!(pt.id = %task.id, ?error, "Failed to acquire the lease of the task");
+                lease_error = Some(error);
+                continue;
+            }
+        };
+
+        let state = state.clone();
+        let consumer_name = consumer_name.to_owned();
+        handler.push(tokio::task::spawn(async move {
+            let _permit = permit;
+            run_with_lease(
+                state,
+                task,
+                pickup_time,
+                runner,
+                consumer_name,
+                lease_duration,
+            )
+            .await
+        }))
     }
     future::join_all(handler).await;
 
-    Ok(())
+    match lease_error {
+        Some(error) => {
+            Err(error.change_context(errors::ProcessTrackerError::ProcessFetchingFailed))
+        }
+        None => Ok(skipped_task_ids),
+    }
 }
 
-#[instrument(skip(db, redis_conn))]
+fn get_lease_expiry(lease_duration: u64) -> PrimitiveDateTime {
+    common_utils::date_time::now().saturating_add(time::Duration::milliseconds(
+        i64::try_from(lease_duration).unwrap_or(i64::MAX),
+    ))
+}
+
+/// Executes the workflow of the task while renewing the lease of the task, and releases the lease
+/// once the workflow completes
+async fn run_with_lease(
+    state: AppState,
+    task: storage::ProcessTracker,
+    pickup_time: PrimitiveDateTime,
+    runner: Box<dyn ProcessTrackerWorkflow>,
+    consumer_name: String,
+    lease_duration: u64,
+) {
+    let db: &dyn StorageInterface = &*state.store;
+    let task_id = task.id.clone();
+    let mut heartbeat = tokio::time::interval(std::time::Duration::from_millis(
+        (lease_duration / 3).max(1),
+    ));
+    // The first tick completes immediately, the lease was just acquired
+    heartbeat.tick().await;
+
+    let workflow = start_workflow(state.clone(), task, pickup_time, runner);
+    tokio::pin!(workflow);
+    loop {
+        tokio::select! {
+            _ = &mut workflow => break,
+            _ = heartbeat.tick() => {
+                match db
+                    .renew_process_lease(&task_id, &consumer_name, get_lease_expiry(lease_duration))
+                    .await
+                {
+                    Ok(true) => (),
+                    Ok(false) => logger::warn!(pt.id = %task_id, "Lease of the task is held by another consumer"),
+                    Err(error) => logger::error!(pt.id = %task_id, ?error, "Failed to renew the lease of the task"),
+                }
+            }
+        }
+    }
+
+    if let Err(error) = db.release_process_lease(&task_id, &consumer_name).await {
+        logger::error!(pt.id = %task_id, ?error, "Failed to release the lease of the task");
+    }
+}
+
+#[instrument(skip(redis_conn))]
 pub async fn fetch_consumer_tasks(
-    db: &dyn StorageInterface,
     redis_conn: &RedisConnectionPool,
     stream_name: &str,
     group_name: &str,
     consumer_name: &str,
-) -> CustomResult<Vec<storage::ProcessTracker>, errors::ProcessTrackerError> {
-    let batches = pt_utils::get_batches(redis_conn, stream_name, group_name, consumer_name).await?;
+) -> CustomResult<(Vec<storage::ProcessTracker>, Vec<String>), errors::ProcessTrackerError> {
+    let (batches, entry_ids) =
+        pt_utils::get_batches(redis_conn, stream_name, group_name, consumer_name).await?;
 
-    let mut tasks = batches.into_iter().fold(Vec::new(), |mut acc, batch| {
+    // The tasks are marked as started when their lease is acquired, before they are executed
+    let tasks = batches.into_iter().fold(Vec::new(), |mut acc, batch| {
         acc.extend_from_slice(
             batch
                 .trackers
@@ -173,24 +424,99 @@ pub async fn fetch_consumer_tasks(
         );
         acc
     });
-    let task_ids = tasks
-        .iter()
-        .map(|task| task.id.to_owned())
-        .collect::<Vec<_>>();
+    Ok((tasks, entry_ids))
+}
 
-    db.process_tracker_update_process_status_by_ids(
-        task_ids,
-        storage::ProcessTrackerUpdate::StatusUpdate {
-            status: enums::ProcessTrackerStatus::ProcessStarted,
-            business_status: None,
-        },
+/// Claims the stalled batches of the stream and obtains the tasks in them which were not completed
+/// by the consumer that read them and whose lease has expired. The retry count of these tasks is
+/// incremented, and they are executed again only once their lease is acquired.
+#[instrument(skip(db, redis_conn))]
+pub async fn claim_stalled_tasks(
+    db: &dyn StorageInterface,
+    redis_conn: &RedisConnectionPool,
+    stream_name: &str,
+    group_name: &str,
+    consumer_name: &str,
+    min_idle_time: u64,
+) -> CustomResult<Vec<scheduler::StalledBatch>, errors::ProcessTrackerError> {
+    let (batches, entry_ids) = pt_utils::claim_stalled_batches(
+        redis_conn,
+        stream_name,
+        group_name,
+        consumer_name,
+        min_idle_time,
     )
-    .await
-    .change_context(errors::ProcessTrackerError::ProcessFetchingFailed)?;
-    tasks
-        .iter_mut()
-        .for_each(|x| x.status = enums::ProcessTrackerStatus::ProcessStarted);
-    Ok(tasks)
+    .await?;
+
+    let now = common_utils::date_time::now();
+    let mut stalled_batches = Vec::with_capacity(batches.len());
+    for (batch, entry_id) in batches.into_iter().zip(entry_ids) {
+        let mut tasks = Vec::new();
+        let mut has_leased_tasks = false;
+        for task in batch.trackers {
+            // The batch holds the tasks as they were when added to the stream, the tasks which
+            // have since been completed or rescheduled, or are still being executed by a live
+            // consumer, must not be executed again
+            let task = match db
+                .find_process_by_id(&task.id)
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessFetchingFailed)?
+            {
+                Some(task)
+                    if matches!(
+                        task.status,
+                        enums::ProcessTrackerStatus::Processing
+                            | enums::ProcessTrackerStatus::ProcessStarted
+                    ) =>
+                {
+                    task
+                }
+                _ => continue,
+            };
+            if task
+                .lease_expires_at
+                .map_or(false, |expires_at| expires_at >= now)
+            {
+                has_leased_tasks = true;
+                continue;
+            }
+
+            let retry_count = task.retry_count + 1;
+            let task = db
+                .update_process(
+                    task,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: Some(retry_count),
+                        schedule_time: None,
+                        tracking_data: None,
+                        business_status: None,
+                        status: None,
+                        updated_at: Some(now),
+                    },
+                )
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+
+            metrics::TASKS_RECLAIMED.add(
+                &metrics::CONTEXT,
+                1,
+                &[opentelemetry::KeyValue::new(
+                    "runner",
+                    task.runner.clone().unwrap_or_default(),
+                )],
+            );
+            tasks.push(task);
+        }
+
+        stalled_batches.push(scheduler::StalledBatch {
+            entry_id,
+            tasks,
+            has_leased_tasks,
+        });
+    }
+
+    Ok(stalled_batches)
 }
 
 // Accept flow_options if required
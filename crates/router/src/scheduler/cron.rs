@@ -0,0 +1,282 @@
+//! Parsing and evaluation of the cron expressions used to declare recurring scheduler jobs.
+//!
+//! Expressions consist of the five standard fields `minute hour day-of-month month day-of-week`,
+//! each accepting `*`, single values, ranges (`1-5`), steps (`*/15`, `0-30/10`) and
+//! comma-separated lists of these. Days of the week range from `0` (Sunday) to `7` (Sunday).
+//! Occurrences are evaluated in UTC.
+
+use std::str::FromStr;
+
+use error_stack::{report, IntoReport, ResultExt};
+use time::{Date, Duration, PrimitiveDateTime, Time};
+
+use crate::core::errors::{self, CustomResult};
+
+/// Upper bound on the number of days searched for the next occurrence, accounting for schedules
+/// that only match on the 29th of February.
+const MAX_DAYS_SEARCHED: u16 = 366 * 8;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u32,
+    days_of_month: u32,
+    months: u16,
+    days_of_week: u8,
+    /// Whether the day of month field is restricted, as opposed to `*`
+    days_of_month_restricted: bool,
+    /// Whether the day of week field is restricted, as opposed to `*`
+    days_of_week_restricted: bool,
+}
+
+impl FromStr for CronSchedule {
+    type Err = error_stack::Report<errors::ProcessTrackerError>;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let fields = expression.split_whitespace().collect::<Vec<_>>();
+        let [minutes, hours, days_of_month, months, days_of_week] = fields.as_slice() else {
+            return Err(report!(errors::ProcessTrackerError::ConfigurationError))
+                .attach_printable_lazy(|| {
+                    format!("Cron expression `{expression}` must consist of exactly five fields")
+                });
+        };
+
+        let days_of_week_mask = parse_field(days_of_week, 0, 7)
+            .attach_printable_lazy(|| format!("Invalid day of week in `{expression}`"))?;
+        // Both `0` and `7` denote Sunday
+        let days_of_week_mask = (days_of_week_mask | (days_of_week_mask >> 7)) & 0x7f;
+
+        Ok(Self {
+            minutes: parse_field(minutes, 0, 59)
+                .attach_printable_lazy(|| format!("Invalid minute in `{expression}`"))?,
+            hours: narrow(parse_field(hours, 0, 23))
+                .attach_printable_lazy(|| format!("Invalid hour in `{expression}`"))?,
+            days_of_month: narrow(parse_field(days_of_month, 1, 31))
+                .attach_printable_lazy(|| format!("Invalid day of month in `{expression}`"))?,
+            months: narrow(parse_field(months, 1, 12))
+                .attach_printable_lazy(|| format!("Invalid month in `{expression}`"))?,
+            days_of_week: narrow(Ok(days_of_week_mask))?,
+            days_of_month_restricted: *days_of_month != "*",
+            days_of_week_restricted: *days_of_week != "*",
+        })
+    }
+}
+
+impl CronSchedule {
+    /// Returns the first occurrence of the schedule strictly after the specified time.
+    pub fn next_after(&self, after: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        // Occurrences are on whole minutes, start from the minute following `after`
+        let start = after
+            .replace_time(Time::from_hms(after.hour(), after.minute(), 0).ok()?)
+            .checked_add(Duration::minutes(1))?;
+
+        let mut date = start.date();
+        let mut earliest_time = Some(start.time());
+        for _ in 0..MAX_DAYS_SEARCHED {
+            if self.matches_date(date) {
+                if let Some(time) = self.first_time_from(earliest_time.unwrap_or(Time::MIDNIGHT)) {
+                    return Some(PrimitiveDateTime::new(date, time));
+                }
+            }
+            date = date.next_day()?;
+            earliest_time = None;
+        }
+        None
+    }
+
+    fn matches_date(&self, date: Date) -> bool {
+        let month_matches = is_set(self.months.into(), u8::from(date.month()));
+        let day_of_month_matches = is_set(self.days_of_month.into(), date.day());
+        let day_of_week_matches = is_set(
+            self.days_of_week.into(),
+            date.weekday().number_days_from_sunday(),
+        );
+
+        // As with the standard cron, a day matches either of the day fields when both of them are
+        // restricted
+        let day_matches = match (self.days_of_month_restricted, self.days_of_week_restricted) {
+            (true, true) => day_of_month_matches || day_of_week_matches,
+            _ => day_of_month_matches && day_of_week_matches,
+        };
+
+        month_matches && day_matches
+    }
+
+    fn first_time_from(&self, earliest: Time) -> Option<Time> {
+        (earliest.hour()..24)
+            .filter(|hour| is_set(self.hours.into(), *hour))
+            .find_map(|hour| {
+                let first_minute = if hour == earliest.hour() {
+                    earliest.minute()
+                } else {
+                    0
+                };
+                (first_minute..60)
+                    .find(|minute| is_set(self.minutes, *minute))
+                    .and_then(|minute| Time::from_hms(hour, minute, 0).ok())
+            })
+    }
+}
+
+fn is_set(mask: u64, value: u8) -> bool {
+    mask & (1_u64 << value) != 0
+}
+
+fn narrow<T: TryFrom<u64>>(
+    mask: CustomResult<u64, errors::ProcessTrackerError>,
+) -> CustomResult<T, errors::ProcessTrackerError> {
+    T::try_from(mask?)
+        .map_err(|_| report!(errors::ProcessTrackerError::ConfigurationError))
+        .attach_printable("Cron field value out of range")
+}
+
+/// Parses a single field of a cron expression into a bit mask of the matching values.
+fn parse_field(field: &str, min: u8, max: u8) -> CustomResult<u64, errors::ProcessTrackerError> {
+    field.split(',').try_fold(0, |mask, part| {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, parse_value(step, 1, max)?),
+            None => (part, 1),
+        };
+
+        let (start, end) = match range {
+            "*" => (min, max),
+            range => match range.split_once('-') {
+                Some((start, end)) => (parse_value(start, min, max)?, parse_value(end, min, max)?),
+                // A single value with a step, such as `5/15`, applies until the maximum value
+                None if part.contains('/') => (parse_value(range, min, max)?, max),
+                None => {
+                    let value = parse_value(range, min, max)?;
+                    (value, value)
+                }
+            },
+        };
+
+        if start > end {
+            return Err(report!(errors::ProcessTrackerError::ConfigurationError))
+                .attach_printable_lazy(|| format!("Invalid range `{range}` in cron field"));
+        }
+
+        Ok((start..=end)
+            .step_by(step.into())
+            .fold(mask, |mask, value| mask | (1_u64 << value)))
+    })
+}
+
+fn parse_value(value: &str, min: u8, max: u8) -> CustomResult<u8, errors::ProcessTrackerError> {
+    let parsed = value
+        .parse::<u8>()
+        .into_report()
+        .change_context(errors::ProcessTrackerError::ConfigurationError)
+        .attach_printable_lazy(|| format!("Invalid value `{value}` in cron field"))?;
+
+    if (min..=max).contains(&parsed) {
+        Ok(parsed)
+    } else {
+        Err(report!(errors::ProcessTrackerError::ConfigurationError)).attach_printable_lazy(|| {
+            format!("Value `{value}` in cron field must be between {min} and {max}")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn next(expression: &str, after: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        CronSchedule::from_str(expression)
+            .unwrap()
+            .next_after(after)
+    }
+
+    #[test]
+    fn test_every_minute() {
+        assert_eq!(
+            next("* * * * *", datetime!(2023-06-20 10:15:30)),
+            Some(datetime!(2023-06-20 10:16:00))
+        );
+    }
+
+    #[test]
+    fn test_daily_at_fixed_time() {
+        assert_eq!(
+            next("30 2 * * *", datetime!(2023-06-20 10:15:00)),
+            Some(datetime!(2023-06-21 02:30:00))
+        );
+        assert_eq!(
+            next("30 2 * * *", datetime!(2023-06-20 02:29:59)),
+            Some(datetime!(2023-06-20 02:30:00))
+        );
+    }
+
+    #[test]
+    fn test_steps_ranges_and_lists() {
+        assert_eq!(
+            next("*/15 * * * *", datetime!(2023-06-20 10:15:00)),
+            Some(datetime!(2023-06-20 10:30:00))
+        );
+        assert_eq!(
+            next("0 9-17/4 * * *", datetime!(2023-06-20 13:00:00)),
+            Some(datetime!(2023-06-20 17:00:00))
+        );
+        assert_eq!(
+            next("5,45 * * * *", datetime!(2023-06-20 10:46:00)),
+            Some(datetime!(2023-06-20 11:05:00))
+        );
+    }
+
+    #[test]
+    fn test_days_of_week_and_month() {
+        // 2023-06-20 is a Tuesday
+        assert_eq!(
+            next("0 0 * * 0", datetime!(2023-06-20 10:00:00)),
+            Some(datetime!(2023-06-25 00:00:00))
+        );
+        assert_eq!(
+            next("0 0 * * 7", datetime!(2023-06-20 10:00:00)),
+            Some(datetime!(2023-06-25 00:00:00))
+        );
+        assert_eq!(
+            next("0 0 1 * *", datetime!(2023-06-20 10:00:00)),
+            Some(datetime!(2023-07-01 00:00:00))
+        );
+        // Either of the day fields matches when both are restricted
+        assert_eq!(
+            next("0 0 1 * 3", datetime!(2023-06-20 10:00:00)),
+            Some(datetime!(2023-06-21 00:00:00))
+        );
+    }
+
+    #[test]
+    fn test_rare_schedules() {
+        assert_eq!(
+            next("0 0 29 2 *", datetime!(2023-03-01 00:00:00)),
+            Some(datetime!(2024-02-29 00:00:00))
+        );
+        assert_eq!(next("0 0 31 2 *", datetime!(2023-03-01 00:00:00)), None);
+    }
+
+    #[test]
+    fn test_invalid_expressions() {
+        for expression in [
+            "* * * *",
+            "* * * * * *",
+            "60 * * * *",
+            "* 24 * * *",
+            "* * 0 * *",
+            "* * * 13 *",
+            "* * * * 8",
+            "10-5 * * * *",
+            "*/0 * * * *",
+            "a * * * *",
+        ] {
+            assert!(
+                CronSchedule::from_str(expression).is_err(),
+                "{expression} should be invalid"
+            );
+        }
+    }
+}
@@ -4,12 +4,16 @@ metrics_context!(CONTEXT);
 global_meter!(PT_METER, "PROCESS_TRACKER");
 
 histogram_metric!(CONSUMER_STATS, PT_METER, "CONSUMER_OPS");
+histogram_metric!(TASK_PICKUP_LAG, PT_METER); // Time between schedule time and pickup of a task
 
 counter_metric!(PAYMENT_COUNT, PT_METER); // No. of payments created
 counter_metric!(TASKS_ADDED_COUNT, PT_METER); // Tasks added to process tracker
 counter_metric!(TASKS_PICKED_COUNT, PT_METER); // Tasks picked by
+counter_metric!(RECURRING_JOB_TASKS_CREATED, PT_METER); // Tasks created for recurring jobs
 counter_metric!(BATCHES_CREATED, PT_METER); // Batches added to stream
 counter_metric!(BATCHES_CONSUMED, PT_METER); // Batches consumed by consumer
+counter_metric!(BATCHES_RECLAIMED, PT_METER); // Stalled batches claimed from dead consumers
+counter_metric!(TASKS_RECLAIMED, PT_METER); // Tasks executed again from stalled batches
 counter_metric!(TASK_CONSUMED, PT_METER); // Tasks consumed by consumer
 counter_metric!(TASK_PROCESSED, PT_METER); // Tasks completed processing
 counter_metric!(TASK_FINISHED, PT_METER); // Tasks finished
@@ -1,21 +1,24 @@
-use std::sync::Arc;
+use std::{str::FromStr, sync::Arc};
 
 use error_stack::{report, IntoReport, ResultExt};
-use router_env::{instrument, tracing};
+use router_env::{instrument, opentelemetry, tracing};
 use time::Duration;
 use tokio::sync::mpsc;
 
 use super::metrics;
 use crate::{
-    configs::settings::SchedulerSettings,
+    configs::settings::{RecurringJob, SchedulerSettings},
     core::errors::{self, CustomResult},
     db::StorageInterface,
     logger::{self, debug, error, warn},
     routes::AppState,
-    scheduler::{utils::*, SchedulerFlow},
-    types::storage::{self, enums::ProcessTrackerStatus},
+    scheduler::{cron::CronSchedule, utils::*, SchedulerFlow},
+    types::storage::{self, enums::ProcessTrackerStatus, ProcessTrackerExt},
 };
 
+/// Tag of the tasks created for recurring jobs.
+const RECURRING_JOB_TAG: &str = "RECURRING_JOB";
+
 #[instrument(skip_all)]
 pub async fn start_producer(
     state: &AppState,
@@ -82,11 +85,11 @@ pub async fn run_producer_flow(
     settings: &SchedulerSettings,
 ) -> CustomResult<(), errors::ProcessTrackerError> {
     lock_acquire_release::<_, _>(state, settings, move || async {
+        schedule_recurring_jobs(&*state.store, settings).await;
+
         let tasks = fetch_producer_tasks(&*state.store, settings).await?;
         debug!("Producer count of tasks {}", tasks.len());
 
-        // [#268]: Allow task based segregation of tasks
-
         divide_and_append_tasks(state, SchedulerFlow::Producer, tasks, settings).await?;
 
         Ok(())
@@ -96,6 +99,68 @@ pub async fn run_producer_flow(
     Ok(())
 }
 
+/// Creates a task for the next occurrence of each recurring job, unless it has already been
+/// created. This must only be run while holding the producer lock, so that a single task is
+/// created for each occurrence.
+#[instrument(skip_all)]
+pub async fn schedule_recurring_jobs(db: &dyn StorageInterface, conf: &SchedulerSettings) {
+    let now = common_utils::date_time::now();
+    for (job_name, job) in conf.recurring_jobs.iter().filter(|(_, job)| !job.disabled) {
+        if let Err(error) = schedule_recurring_job(db, job_name, job, now).await {
+            error!(%job_name, ?error, "Failed to schedule recurring job");
+        }
+    }
+}
+
+async fn schedule_recurring_job(
+    db: &dyn StorageInterface,
+    job_name: &str,
+    job: &RecurringJob,
+    now: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let Some(schedule_time) = CronSchedule::from_str(&job.schedule)?.next_after(now) else {
+        warn!(%job_name, "Recurring job does not have any upcoming occurrence");
+        return Ok(());
+    };
+
+    let process_tracker_id =
+        get_recurring_job_process_tracker_id(&job.runner, job_name, schedule_time);
+    if db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ProcessTrackerError::ProcessFetchingFailed)?
+        .is_some()
+    {
+        return Ok(());
+    }
+
+    let tracking_data = storage::RecurringJobTrackingData {
+        job_name: job_name.to_owned(),
+        scheduled_at: schedule_time,
+    };
+    let mut process_tracker_entry = storage::ProcessTracker::make_process_tracker_new(
+        process_tracker_id,
+        job_name,
+        &job.runner,
+        tracking_data,
+        schedule_time,
+    )?;
+    process_tracker_entry.tag = vec![RECURRING_JOB_TAG.to_string()];
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)
+        .attach_printable("Failed to insert task for recurring job")?;
+    debug!(%job_name, %schedule_time, "Scheduled next occurrence of recurring job");
+    metrics::RECURRING_JOB_TASKS_CREATED.add(
+        &metrics::CONTEXT,
+        1,
+        &[opentelemetry::KeyValue::new("job", job_name.to_owned())],
+    );
+
+    Ok(())
+}
+
 #[instrument(skip_all)]
 pub async fn fetch_producer_tasks(
     db: &dyn StorageInterface,
@@ -5,7 +5,7 @@ pub mod process_data;
 pub mod state;
 
 pub use self::{
-    batch::ProcessTrackerBatch,
+    batch::{ProcessTrackerBatch, StalledBatch},
     config::SchedulerConfig,
     flow::SchedulerFlow,
     process_data::ProcessData,
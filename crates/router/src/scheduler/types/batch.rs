@@ -20,6 +20,18 @@ pub struct ProcessTrackerBatch {
     pub trackers: Vec<ProcessTracker>, /* FIXME: Add sized also here,  list */
 }
 
+/// A batch claimed from a consumer which failed to acknowledge it, along with the tasks in it which
+/// are to be executed again.
+#[derive(Debug, Clone)]
+pub struct StalledBatch {
+    /// Redis entry ID of the batch
+    pub entry_id: String,
+    pub tasks: Vec<ProcessTracker>,
+    /// Whether any task in the batch is still leased by a live consumer, in which case the batch
+    /// must not be acknowledged until the task completes
+    pub has_leased_tasks: bool,
+}
+
 impl ProcessTrackerBatch {
     pub fn to_redis_field_value_pairs(
         &self,
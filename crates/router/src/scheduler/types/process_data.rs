@@ -10,14 +10,14 @@ pub struct ProcessData {
     process_tracker: ProcessTracker,
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct RetryMapping {
     pub start_after: i32,
     pub frequency: Vec<i32>,
     pub count: Vec<i32>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ConnectorPTMapping {
     pub default_mapping: RetryMapping,
@@ -39,7 +39,7 @@ impl Default for ConnectorPTMapping {
     }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentMethodsPTMapping {
     pub default_mapping: RetryMapping,
@@ -60,3 +60,18 @@ impl Default for PaymentMethodsPTMapping {
         }
     }
 }
+
+/// Key of the config holding the retry schedule for syncing payments with the connector
+pub fn get_payment_sync_mapping_key(connector: &str) -> String {
+    format!("pt_mapping_{connector}")
+}
+
+/// Key of the config holding the retry schedule for syncing refunds with the connector
+pub fn get_refund_sync_mapping_key(connector: &str) -> String {
+    format!("pt_mapping_refund_sync_{connector}")
+}
+
+/// Key of the config holding the retry schedule for deleting tokenized data of the payment method
+pub fn get_delete_tokenize_data_mapping_key(payment_method: &enums::PaymentMethod) -> String {
+    format!("pt_mapping_delete_{payment_method}_tokenize_data")
+}
@@ -1,20 +1,23 @@
 use std::{
+    collections::HashMap,
     sync::{self, atomic},
     time as std_time,
 };
 
+use common_utils::ext_traits::StringExt;
 use error_stack::{report, ResultExt};
 #[cfg(not(target_os = "windows"))]
 use futures::StreamExt;
 use redis_interface::{RedisConnectionPool, RedisEntryId};
 use router_env::opentelemetry;
-use tokio::sync::oneshot;
+use tokio::sync::{oneshot, OwnedSemaphorePermit, Semaphore};
 use uuid::Uuid;
 
 use super::{consumer, metrics, process_data, workflows};
 use crate::{
-    configs::settings::SchedulerSettings,
+    configs::settings::{ConsumerSettings, SchedulerSettings},
     core::errors::{self, CustomResult},
+    db::StorageInterface,
     logger,
     routes::AppState,
     scheduler::{ProcessTrackerBatch, SchedulerFlow},
@@ -24,6 +27,9 @@ use crate::{
     },
 };
 
+/// Maximum number of stalled batches claimed from a stream at once
+const RECLAIM_BATCH_COUNT: u64 = 10;
+
 pub async fn divide_and_append_tasks(
     state: &AppState,
     flow: SchedulerFlow,
@@ -143,7 +149,21 @@ pub fn divide(
 ) -> Vec<ProcessTrackerBatch> {
     let now = common_utils::date_time::now();
     let batch_size = conf.producer.batch_size;
-    divide_into_batches(batch_size, tasks, now, conf)
+
+    let mut tasks_by_stream: HashMap<&str, Vec<storage::ProcessTracker>> = HashMap::new();
+    for task in tasks {
+        tasks_by_stream
+            .entry(get_stream_name(conf, task.runner.as_deref()))
+            .or_default()
+            .push(task);
+    }
+
+    tasks_by_stream
+        .into_iter()
+        .flat_map(|(stream_name, tasks)| {
+            divide_into_batches(batch_size, tasks, now, conf, stream_name)
+        })
+        .collect()
 }
 
 pub fn divide_into_batches(
@@ -151,6 +171,7 @@ pub fn divide_into_batches(
     tasks: Vec<storage::ProcessTracker>,
     batch_creation_time: time::PrimitiveDateTime,
     conf: &SchedulerSettings,
+    stream_name: &str,
 ) -> Vec<ProcessTrackerBatch> {
     let batch_id = Uuid::new_v4().to_string();
 
@@ -160,7 +181,7 @@ pub fn divide_into_batches(
             let batch = ProcessTrackerBatch {
                 id: batch_id.clone(),
                 group_name: conf.consumer.consumer_group.clone(),
-                stream_name: conf.stream.clone(),
+                stream_name: stream_name.to_owned(),
                 connection_name: String::new(),
                 created_time: batch_creation_time,
                 rule: String::new(), // is it required?
@@ -172,12 +193,122 @@ pub fn divide_into_batches(
         })
 }
 
+/// Obtains the stream to which tasks of the specified runner are added.
+pub fn get_stream_name<'a>(conf: &'a SchedulerSettings, runner: Option<&str>) -> &'a str {
+    runner
+        .and_then(|runner| conf.runner_streams.get(runner))
+        .unwrap_or(&conf.stream)
+}
+
+/// Obtains all the streams to which the producer may add tasks.
+pub fn get_all_stream_names(conf: &SchedulerSettings) -> Vec<&str> {
+    let mut streams = conf
+        .runner_streams
+        .values()
+        .map(String::as_str)
+        .chain(std::iter::once(conf.stream.as_str()))
+        .collect::<Vec<_>>();
+    streams.sort_unstable();
+    streams.dedup();
+    streams
+}
+
+/// Obtains the streams read by the consumer.
+pub fn get_consumer_stream_names(conf: &SchedulerSettings) -> Vec<&str> {
+    if conf.consumer.streams.is_empty() {
+        vec![conf.stream.as_str()]
+    } else {
+        conf.consumer.streams.keys().map(String::as_str).collect()
+    }
+}
+
+/// Picks the stream to be read by a consumer in each iteration, such that every stream is picked
+/// in proportion to its configured weight (smooth weighted round robin).
+#[derive(Debug)]
+pub struct StreamSelector {
+    streams: Vec<WeightedStream>,
+    total_weight: i64,
+}
+
+#[derive(Debug)]
+struct WeightedStream {
+    name: String,
+    weight: i64,
+    current_weight: i64,
+}
+
+impl StreamSelector {
+    pub fn new(conf: &SchedulerSettings) -> Self {
+        let mut streams = if conf.consumer.streams.is_empty() {
+            vec![WeightedStream {
+                name: conf.stream.clone(),
+                weight: 1,
+                current_weight: 0,
+            }]
+        } else {
+            conf.consumer
+                .streams
+                .iter()
+                .map(|(name, weight)| WeightedStream {
+                    name: name.clone(),
+                    weight: i64::from(*weight),
+                    current_weight: 0,
+                })
+                .collect()
+        };
+        // Ensure a deterministic order, since the streams are read from a map
+        streams.sort_by(|a, b| a.name.cmp(&b.name));
+        let total_weight = streams.iter().map(|stream| stream.weight).sum();
+
+        Self {
+            streams,
+            total_weight,
+        }
+    }
+
+    pub fn next_stream(&mut self) -> &str {
+        let mut selected = 0;
+        for index in 0..self.streams.len() {
+            self.streams[index].current_weight += self.streams[index].weight;
+            if self.streams[index].current_weight > self.streams[selected].current_weight {
+                selected = index;
+            }
+        }
+        self.streams[selected].current_weight -= self.total_weight;
+        &self.streams[selected].name
+    }
+}
+
+/// Limits the number of tasks of a runner being executed concurrently by a consumer.
+#[derive(Debug, Default)]
+pub struct RunnerConcurrencyLimits(HashMap<String, sync::Arc<Semaphore>>);
+
+impl RunnerConcurrencyLimits {
+    pub fn new(conf: &ConsumerSettings) -> Self {
+        Self(
+            conf.runner_concurrency
+                .iter()
+                .map(|(runner, limit)| (runner.clone(), sync::Arc::new(Semaphore::new(*limit))))
+                .collect(),
+        )
+    }
+
+    /// Waits until a task of the runner can be executed. The returned permit must be held for as
+    /// long as the task is being executed. Returns `None` if the runner has no limit configured.
+    pub async fn acquire(&self, runner: Option<&str>) -> Option<OwnedSemaphorePermit> {
+        let semaphore = runner.and_then(|runner| self.0.get(runner))?.clone();
+        semaphore.acquire_owned().await.ok()
+    }
+}
+
+/// Reads a batch from the stream, along with the ID of the stream entry it was read from. The
+/// entry is pending in the consumer group until it is acknowledged with [`acknowledge_batches`].
 pub async fn get_batches(
     conn: &RedisConnectionPool,
     stream_name: &str,
     group_name: &str,
     consumer_name: &str,
-) -> CustomResult<Vec<ProcessTrackerBatch>, errors::ProcessTrackerError> {
+) -> CustomResult<(Vec<ProcessTrackerBatch>, Vec<String>), errors::ProcessTrackerError> {
     let response = conn
         .stream_read_with_options(
             stream_name,
@@ -194,40 +325,116 @@ pub async fn get_batches(
         })?;
     metrics::BATCHES_CONSUMED.add(&metrics::CONTEXT, 1, &[]);
 
-    let (batches, entry_ids): (Vec<Vec<ProcessTrackerBatch>>, Vec<Vec<String>>) = response.into_values().map(|entries| {
-        entries.into_iter().try_fold(
-            (Vec::new(), Vec::new()),
-            |(mut batches, mut entry_ids), entry| {
-                // Redis entry ID
-                entry_ids.push(entry.0);
-                // Value HashMap
-                batches.push(ProcessTrackerBatch::from_redis_stream_entry(entry.1)?);
-
-                Ok((batches, entry_ids))
-            },
-        )
-    }).collect::<CustomResult<Vec<(Vec<ProcessTrackerBatch>, Vec<String>)>, errors::ProcessTrackerError>>()?
-    .into_iter()
-    .unzip();
+    let (batches, entry_ids): (Vec<Vec<ProcessTrackerBatch>>, Vec<Vec<String>>) = response
+        .into_values()
+        .map(parse_stream_entries)
+        .collect::<CustomResult<Vec<(Vec<ProcessTrackerBatch>, Vec<String>)>, errors::ProcessTrackerError>>()?
+        .into_iter()
+        .unzip();
     // Flattening the Vec's since the count provided above is 1. This needs to be updated if a
     // count greater than 1 is provided.
     let batches = batches.into_iter().flatten().collect::<Vec<_>>();
     let entry_ids = entry_ids.into_iter().flatten().collect::<Vec<_>>();
 
+    Ok((batches, entry_ids))
+}
+
+/// Claims the batches which have not been acknowledged for at least `min_idle_time`
+/// milliseconds, along with the IDs of the stream entries they were read from.
+pub async fn claim_stalled_batches(
+    conn: &RedisConnectionPool,
+    stream_name: &str,
+    group_name: &str,
+    consumer_name: &str,
+    min_idle_time: u64,
+) -> CustomResult<(Vec<ProcessTrackerBatch>, Vec<String>), errors::ProcessTrackerError> {
+    let pending_entry_ids = conn
+        .consumer_group_get_pending_entries(
+            stream_name,
+            group_name,
+            min_idle_time,
+            RECLAIM_BATCH_COUNT,
+        )
+        .await
+        .change_context(errors::ProcessTrackerError::BatchNotFound)?
+        .into_iter()
+        .map(|entry| {
+            logger::info!(
+                entry_id = %entry.id,
+                consumer = %entry.consumer,
+                idle_time = entry.idle_time,
+                delivery_count = entry.delivery_count,
+                "Claiming stalled batch"
+            );
+            entry.id
+        })
+        .collect::<Vec<_>>();
+
+    if pending_entry_ids.is_empty() {
+        return Ok((Vec::new(), Vec::new()));
+    }
+
+    // Entries which were claimed by another consumer in the meantime are not returned
+    let entries = conn
+        .consumer_group_set_message_owner::<_, Vec<(String, HashMap<String, Option<String>>)>>(
+            stream_name,
+            group_name,
+            consumer_name,
+            min_idle_time,
+            pending_entry_ids,
+        )
+        .await
+        .change_context(errors::ProcessTrackerError::BatchUpdateFailed)?;
+
+    let (batches, entry_ids) = parse_stream_entries(entries)?;
+    // Safety: Assuming we won't deal with more than `u64::MAX` batches at once
+    #[allow(clippy::as_conversions)]
+    metrics::BATCHES_RECLAIMED.add(&metrics::CONTEXT, batches.len() as u64, &[]);
+
+    Ok((batches, entry_ids))
+}
+
+fn parse_stream_entries(
+    entries: Vec<(String, HashMap<String, Option<String>>)>,
+) -> CustomResult<(Vec<ProcessTrackerBatch>, Vec<String>), errors::ProcessTrackerError> {
+    entries.into_iter().try_fold(
+        (Vec::new(), Vec::new()),
+        |(mut batches, mut entry_ids), entry| {
+            // Redis entry ID
+            entry_ids.push(entry.0);
+            // Value HashMap
+            batches.push(ProcessTrackerBatch::from_redis_stream_entry(entry.1)?);
+
+            Ok((batches, entry_ids))
+        },
+    )
+}
+
+/// Acknowledges and deletes the stream entries of batches whose tasks have been processed.
+pub async fn acknowledge_batches(
+    conn: &RedisConnectionPool,
+    stream_name: &str,
+    group_name: &str,
+    entry_ids: Vec<String>,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    if entry_ids.is_empty() {
+        return Ok(());
+    }
+
     conn.stream_acknowledge_entries(stream_name, group_name, entry_ids.clone())
         .await
         .map_err(|error| {
             logger::error!(%error, "Error acknowledging batch in stream");
             error.change_context(errors::ProcessTrackerError::BatchUpdateFailed)
         })?;
-    conn.stream_delete_entries(stream_name, entry_ids.clone())
+    conn.stream_delete_entries(stream_name, entry_ids)
         .await
         .map_err(|error| {
             logger::error!(%error, "Error deleting batch from stream");
             error.change_context(errors::ProcessTrackerError::BatchDeleteFailed)
         })?;
 
-    Ok(batches)
+    Ok(())
 }
 
 pub fn get_process_tracker_id<'a>(
@@ -239,6 +446,17 @@ pub fn get_process_tracker_id<'a>(
     format!("{runner}_{task_name}_{txn_id}_{merchant_id}")
 }
 
+pub fn get_recurring_job_process_tracker_id(
+    runner: &str,
+    job_name: &str,
+    scheduled_at: time::PrimitiveDateTime,
+) -> String {
+    format!(
+        "{runner}_{job_name}_{}",
+        scheduled_at.assume_utc().unix_timestamp()
+    )
+}
+
 pub fn get_time_from_delta(delta: Option<i32>) -> Option<time::PrimitiveDateTime> {
     delta.map(|t| common_utils::date_time::now().saturating_add(time::Duration::seconds(t.into())))
 }
@@ -249,6 +467,8 @@ pub async fn consumer_operation_handler<E>(
     error_handler_fun: E,
     consumer_operation_counter: sync::Arc<atomic::AtomicU64>,
     workflow_selector: workflows::WorkflowSelectorFn,
+    stream_name: String,
+    concurrency_limits: sync::Arc<RunnerConcurrencyLimits>,
 ) where
     // Error handler function
     E: FnOnce(error_stack::Report<errors::ProcessTrackerError>),
@@ -256,7 +476,15 @@ pub async fn consumer_operation_handler<E>(
     consumer_operation_counter.fetch_add(1, atomic::Ordering::Release);
     let start_time = std_time::Instant::now();
 
-    match consumer::consumer_operations(&state, &settings, workflow_selector).await {
+    match consumer::consumer_operations(
+        &state,
+        &settings,
+        workflow_selector,
+        &stream_name,
+        concurrency_limits,
+    )
+    .await
+    {
         Ok(_) => (),
         Err(err) => error_handler_fun(err),
     }
@@ -268,9 +496,37 @@ pub async fn consumer_operation_handler<E>(
     logger::info!("Current tasks being executed: {}", current_count);
 }
 
+pub async fn reclaim_operation_handler<E>(
+    state: AppState,
+    settings: sync::Arc<SchedulerSettings>,
+    error_handler_fun: E,
+    consumer_operation_counter: sync::Arc<atomic::AtomicU64>,
+    workflow_selector: workflows::WorkflowSelectorFn,
+    concurrency_limits: sync::Arc<RunnerConcurrencyLimits>,
+) where
+    // Error handler function
+    E: FnOnce(error_stack::Report<errors::ProcessTrackerError>),
+{
+    consumer_operation_counter.fetch_add(1, atomic::Ordering::Release);
+    let start_time = std_time::Instant::now();
+
+    match consumer::reclaim_operations(&state, &settings, workflow_selector, concurrency_limits)
+        .await
+    {
+        Ok(_) => (),
+        Err(err) => error_handler_fun(err),
+    }
+    let end_time = std_time::Instant::now();
+    let duration = end_time.saturating_duration_since(start_time).as_secs_f64();
+    logger::debug!("Time taken to execute reclaim_operation: {}s", duration);
+
+    let current_count = consumer_operation_counter.fetch_sub(1, atomic::Ordering::Release);
+    logger::info!("Current tasks being executed: {}", current_count);
+}
+
 pub fn add_histogram_metrics(
     pickup_time: &time::PrimitiveDateTime,
-    task: &mut storage::ProcessTracker,
+    task: &storage::ProcessTracker,
     stream_name: &str,
 ) {
     #[warn(clippy::option_map_unit_fn)]
@@ -283,10 +539,72 @@ pub fn add_histogram_metrics(
             pickup_schedule_delta,
             &[opentelemetry::KeyValue::new(
                 stream_name.to_owned(),
-                runner_name,
+                runner_name.clone(),
             )],
         );
+        metrics::TASK_PICKUP_LAG.record(
+            &metrics::CONTEXT,
+            pickup_schedule_delta,
+            &[
+                opentelemetry::KeyValue::new("runner", runner_name),
+                opentelemetry::KeyValue::new("stream", stream_name.to_owned()),
+            ],
+        );
+    };
+}
+
+/// Obtains the retry schedule stored in the config with the specified key, falling back to the
+/// schedule stored in redis and then to the default retry schedule if it has not been configured
+/// or cannot be read.
+pub async fn get_pt_mapping<T>(db: &dyn StorageInterface, key: &str, type_name: &'static str) -> T
+where
+    T: serde::de::DeserializeOwned + Default,
+{
+    let config = match db.find_config_by_key_cached(key).await {
+        Ok(config) => config,
+        Err(error)
+            if error.current_context().is_db_not_found()
+                || matches!(
+                    error.current_context(),
+                    errors::StorageError::ValueNotFound(_)
+                ) =>
+        {
+            return get_redis_pt_mapping(db, key, type_name)
+                .await
+                .unwrap_or_else(|| {
+                    logger::debug!(%key, "Retry schedule not configured, using the default schedule");
+                    T::default()
+                });
+        }
+        Err(error) => {
+            logger::error!(?error, %key, "Failed to fetch retry schedule, using the default schedule");
+            return T::default();
+        }
     };
+
+    config
+        .config
+        .parse_struct(type_name)
+        .map_err(|error| {
+            logger::error!(?error, %key, "Failed to parse retry schedule, using the default schedule");
+        })
+        .unwrap_or_default()
+}
+
+/// Obtains the retry schedule stored in redis with the specified key. Retry schedules used to be
+/// stored only in redis, and these are still used until they are replaced by a config.
+pub async fn get_redis_pt_mapping<T>(
+    db: &dyn StorageInterface,
+    key: &str,
+    type_name: &'static str,
+) -> Option<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    crate::db::get_and_deserialize_key(db, key, type_name)
+        .await
+        .map_err(|error| logger::debug!(?error, %key, "Retry schedule not found in redis"))
+        .ok()
 }
 
 pub fn get_schedule_time(
@@ -412,3 +730,114 @@ pub(crate) async fn signal_handler(
     _sender: oneshot::Sender<()>,
 ) {
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn make_task(id: &str, runner: &str) -> storage::ProcessTracker {
+        let now = common_utils::date_time::now();
+        storage::ProcessTracker {
+            id: id.to_string(),
+            name: None,
+            tag: vec![],
+            runner: Some(runner.to_string()),
+            retry_count: 0,
+            schedule_time: Some(now),
+            rule: String::new(),
+            tracking_data: serde_json::Value::Null,
+            business_status: "Pending".to_string(),
+            status: ProcessTrackerStatus::New,
+            event: vec![],
+            created_at: now,
+            updated_at: now,
+            lease_owner: None,
+            lease_expires_at: None,
+            admin_audit_trail: vec![],
+        }
+    }
+
+    #[test]
+    fn tasks_are_divided_by_runner_stream() {
+        let mut conf = SchedulerSettings::default();
+        conf.producer.batch_size = 2;
+        conf.runner_streams.insert(
+            "PAYMENTS_SYNC_WORKFLOW".to_string(),
+            "SYNC_STREAM".to_string(),
+        );
+
+        let tasks = vec![
+            make_task("1", "PAYMENTS_SYNC_WORKFLOW"),
+            make_task("2", "REFUND_WORKFLOW_ROUTER"),
+            make_task("3", "PAYMENTS_SYNC_WORKFLOW"),
+            make_task("4", "PAYMENTS_SYNC_WORKFLOW"),
+        ];
+        let batches = divide(tasks, &conf);
+
+        let sync_batches = batches
+            .iter()
+            .filter(|batch| batch.stream_name == "SYNC_STREAM")
+            .collect::<Vec<_>>();
+        assert_eq!(sync_batches.len(), 2);
+        assert!(sync_batches
+            .iter()
+            .flat_map(|batch| batch.trackers.iter())
+            .all(|task| task.runner.as_deref() == Some("PAYMENTS_SYNC_WORKFLOW")));
+
+        let default_batches = batches
+            .iter()
+            .filter(|batch| batch.stream_name == conf.stream)
+            .collect::<Vec<_>>();
+        assert_eq!(default_batches.len(), 1);
+        assert_eq!(default_batches[0].trackers[0].id, "2");
+    }
+
+    #[test]
+    fn streams_are_selected_in_proportion_to_weights() {
+        let mut conf = SchedulerSettings::default();
+        conf.consumer.streams.insert("HIGH".to_string(), 3);
+        conf.consumer.streams.insert("LOW".to_string(), 1);
+
+        let mut selector = StreamSelector::new(&conf);
+        let selected = (0..8)
+            .map(|_| selector.next_stream().to_owned())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            selected.iter().filter(|stream| *stream == "HIGH").count(),
+            6
+        );
+        assert_eq!(selected.iter().filter(|stream| *stream == "LOW").count(), 2);
+        // The lower weighted stream must not be starved within a cycle
+        assert!(selected[..4].contains(&"LOW".to_string()));
+    }
+
+    #[test]
+    fn default_stream_is_selected_without_configured_streams() {
+        let conf = SchedulerSettings::default();
+        let mut selector = StreamSelector::new(&conf);
+
+        assert_eq!(selector.next_stream(), conf.stream);
+        assert_eq!(selector.next_stream(), conf.stream);
+    }
+
+    #[tokio::test]
+    async fn runner_concurrency_is_limited() {
+        let mut conf = ConsumerSettings::default();
+        conf.runner_concurrency
+            .insert("PAYMENTS_SYNC_WORKFLOW".to_string(), 1);
+        let limits = RunnerConcurrencyLimits::new(&conf);
+
+        let permit = limits.acquire(Some("PAYMENTS_SYNC_WORKFLOW")).await;
+        assert!(permit.is_some());
+        assert_eq!(limits.0["PAYMENTS_SYNC_WORKFLOW"].available_permits(), 0);
+        assert!(limits
+            .acquire(Some("REFUND_WORKFLOW_ROUTER"))
+            .await
+            .is_none());
+
+        drop(permit);
+        assert_eq!(limits.0["PAYMENTS_SYNC_WORKFLOW"].available_permits(), 1);
+    }
+}
@@ -8,8 +8,17 @@ use crate::{
     types::storage,
     utils::{OptionExt, StringExt},
 };
+pub mod api_key_expiry;
+pub mod builtin_vault_maintenance;
+pub mod capture_schedule;
+pub mod card_account_updater;
+pub mod customer_erasure;
+pub mod dispute_lifecycle;
+pub mod payment_intent_expiry;
 pub mod payment_sync;
 pub mod refund_router;
+pub mod settlement_reconciliation;
+pub mod stale_object_sync;
 pub mod tokenized_data;
 
 macro_rules! runners {
@@ -52,7 +61,17 @@ macro_rules! as_item {
 runners! {
     PaymentsSyncWorkflow,
     RefundWorkflowRouter,
-    DeleteTokenizeDataWorkflow
+    DeleteTokenizeDataWorkflow,
+    ApiKeyExpiryWorkflow,
+    PaymentIntentExpiryWorkflow,
+    AutoCaptureWorkflow,
+    AutoVoidWorkflow,
+    SettlementReconciliationWorkflow,
+    StaleObjectSyncWorkflow,
+    DisputeLifecycleWorkflow,
+    CardAccountUpdaterWorkflow,
+    CustomerErasureWorkflow,
+    BuiltinVaultMaintenanceWorkflow
 }
 
 pub type WorkflowSelectorFn =
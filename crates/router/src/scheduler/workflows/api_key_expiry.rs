@@ -0,0 +1,115 @@
+use router_env::logger;
+
+use super::{ApiKeyExpiryWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::{api_keys, webhooks},
+    db::StorageInterface,
+    errors,
+    routes::{metrics, AppState},
+    scheduler::consumer,
+    types::{
+        api,
+        storage::{self, enums, ProcessTrackerExt},
+        transformers::ForeignInto,
+    },
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for ApiKeyExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::ApiKeyExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ApiKeyExpiryTrackingData")?;
+
+        let api_key = db
+            .find_api_key_by_merchant_id_key_id_optional(
+                &tracking_data.merchant_id,
+                &tracking_data.key_id,
+            )
+            .await?;
+
+        // The API key may have been revoked, or its expiry removed, after the task was scheduled
+        let Some((api_key, api_key_expiry)) =
+            api_key.and_then(|api_key| api_key.expires_at.map(|expiry| (api_key, expiry)))
+        else {
+            return process
+                .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+                .await;
+        };
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let api_key_response: api::RetrieveApiKeyResponse = api_key.foreign_into();
+        webhooks::create_event_and_trigger_outgoing_webhook::<api::OutgoingWebhook>(
+            state.clone(),
+            merchant_account,
+            enums::EventType::ApiKeyExpiring,
+            enums::EventClass::ApiKeys,
+            None,
+            tracking_data.key_id.clone(),
+            enums::EventObjectType::ApiKeyDetails,
+            api::OutgoingWebhookContent::ApiKeyDetails(Box::new(api_key_response)),
+        )
+        .await?;
+        metrics::API_KEY_EXPIRY_REMINDERS_SENT.add(&metrics::CONTEXT, 1, &[]);
+
+        match api_keys::get_next_expiry_reminder(
+            api_key_expiry,
+            &tracking_data.expiry_reminder_days,
+            tracking_data.reminder_index + 1,
+        ) {
+            Some((reminder_index, schedule_time)) => {
+                let tracking_data = serde_json::to_value(storage::ApiKeyExpiryTrackingData {
+                    reminder_index,
+                    ..tracking_data
+                })
+                .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+                db.update_process_tracker(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: None,
+                        schedule_time: Some(schedule_time),
+                        tracking_data: Some(tracking_data),
+                        business_status: None,
+                        status: Some(enums::ProcessTrackerStatus::Pending),
+                        updated_at: Some(common_utils::date_time::now()),
+                    },
+                )
+                .await?;
+                Ok(())
+            }
+            None => {
+                process
+                    .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+                    .await
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while sending API key expiry reminder");
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
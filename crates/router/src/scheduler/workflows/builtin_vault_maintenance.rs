@@ -0,0 +1,61 @@
+use router_env::logger;
+
+use super::{BuiltinVaultMaintenanceWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::payment_methods::builtin_vault,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, ProcessTrackerExt},
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for BuiltinVaultMaintenanceWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        if !builtin_vault::is_enabled(&state.conf.locker) {
+            logger::info!("Built-in vault is not the configured locker, skipping its maintenance");
+            return process
+                .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+                .await;
+        }
+
+        let rotated_cards = builtin_vault::rotate_card_data_keys(state)
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to rotate data keys of vault cards");
+                errors::ProcessTrackerError::FlowExecutionError {
+                    flow: "builtin_vault_key_rotation",
+                }
+            })?;
+        logger::info!(rotated_cards, "Rotated data keys of vault cards");
+
+        let purged_tokens = builtin_vault::purge_expired_tokens(state)
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to purge expired vault tokens");
+                errors::ProcessTrackerError::FlowExecutionError {
+                    flow: "builtin_vault_token_purge",
+                }
+            })?;
+        logger::info!(purged_tokens, "Purged expired vault tokens");
+
+        process
+            .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while maintaining the built-in vault");
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
@@ -0,0 +1,201 @@
+use router_env::logger;
+
+use super::{AutoCaptureWorkflow, AutoVoidWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::{
+        payments::{self as payment_flows, operations, PaymentData},
+        webhooks,
+    },
+    db::StorageInterface,
+    errors,
+    routes::{metrics, AppState},
+    scheduler::consumer,
+    types::{
+        api, domain,
+        storage::{self, enums, ProcessTrackerExt},
+        transformers::{ForeignFrom, ForeignInto, ForeignTryInto},
+    },
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for AutoCaptureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let Some((tracking_data, merchant_account, key_store)) =
+            get_payment_requiring_capture(db, &process).await?
+        else {
+            return process
+                .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+                .await;
+        };
+
+        let (payment_data, _, _) = payment_flows::payments_operation_core::<api::Capture, _, _, _>(
+            state,
+            merchant_account.clone(),
+            key_store,
+            operations::PaymentCapture,
+            api::PaymentsCaptureRequest {
+                payment_id: Some(tracking_data.payment_id),
+                merchant_id: Some(tracking_data.merchant_id),
+                ..Default::default()
+            },
+            payment_flows::CallConnectorAction::Trigger,
+        )
+        .await?;
+        metrics::AUTO_CAPTURES_TRIGGERED.add(&metrics::CONTEXT, 1, &[]);
+
+        trigger_payment_outcome_webhook(state, merchant_account, payment_data).await?;
+
+        process
+            .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while capturing payment automatically");
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for AutoVoidWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let Some((tracking_data, merchant_account, key_store)) =
+            get_payment_requiring_capture(db, &process).await?
+        else {
+            return process
+                .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+                .await;
+        };
+
+        let (payment_data, _, _) = payment_flows::payments_operation_core::<api::Void, _, _, _>(
+            state,
+            merchant_account.clone(),
+            key_store,
+            operations::PaymentCancel,
+            api::PaymentsCancelRequest {
+                payment_id: tracking_data.payment_id,
+                cancellation_reason: Some("Authorization was not captured in time".to_string()),
+                merchant_connector_details: None,
+            },
+            payment_flows::CallConnectorAction::Trigger,
+        )
+        .await?;
+        metrics::AUTO_VOIDS_TRIGGERED.add(&metrics::CONTEXT, 1, &[]);
+
+        trigger_payment_outcome_webhook(state, merchant_account, payment_data).await?;
+
+        process
+            .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while voiding payment automatically");
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+/// Returns the tracking data along with the merchant account and key store, if the payment being
+/// tracked still requires capture.
+async fn get_payment_requiring_capture(
+    db: &dyn StorageInterface,
+    process: &storage::ProcessTracker,
+) -> Result<
+    Option<(
+        storage::CaptureScheduleTrackingData,
+        domain::MerchantAccount,
+        domain::MerchantKeyStore,
+    )>,
+    errors::ProcessTrackerError,
+> {
+    let tracking_data: storage::CaptureScheduleTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("CaptureScheduleTrackingData")?;
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+        .await?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &tracking_data.payment_id,
+            &tracking_data.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+    // The payment may have been captured, voided or retried after the task was scheduled
+    if payment_intent.status != enums::IntentStatus::RequiresCapture
+        || payment_intent.active_attempt_id != tracking_data.attempt_id
+    {
+        return Ok(None);
+    }
+
+    Ok(Some((tracking_data, merchant_account, key_store)))
+}
+
+async fn trigger_payment_outcome_webhook<F: Clone>(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    payment_data: PaymentData<F>,
+) -> Result<(), errors::ProcessTrackerError> {
+    let payment_id = payment_data.payment_intent.payment_id.clone();
+    let intent_status: api::enums::IntentStatus = payment_data.payment_intent.status.foreign_into();
+    let event_type: enums::EventType = match intent_status.foreign_try_into() {
+        Ok(event_type) => event_type,
+        Err(error) => {
+            logger::warn!(
+                ?error,
+                %payment_id,
+                "Payment outcome does not correspond to any event, skipping webhook"
+            );
+            return Ok(());
+        }
+    };
+
+    let payments_response = api::PaymentsResponse::foreign_from((
+        payment_data.payment_intent,
+        payment_data.payment_attempt,
+    ));
+    webhooks::create_event_and_trigger_outgoing_webhook::<api::OutgoingWebhook>(
+        state.clone(),
+        merchant_account,
+        event_type,
+        enums::EventClass::Payments,
+        None,
+        payment_id,
+        enums::EventObjectType::PaymentDetails,
+        api::OutgoingWebhookContent::PaymentDetails(payments_response),
+    )
+    .await?;
+    Ok(())
+}
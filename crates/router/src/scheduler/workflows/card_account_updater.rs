@@ -0,0 +1,118 @@
+use router_env::logger;
+
+use super::{CardAccountUpdaterWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::payment_methods::account_updater,
+    errors,
+    routes::{metrics, AppState},
+    scheduler::consumer,
+    types::storage::{self, ProcessTrackerExt},
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for CardAccountUpdaterWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let config = &state.conf.card_account_updater;
+        // Saved cards are only updated with updates reported by an actual account updater
+        if config.provider.is_none() {
+            logger::warn!(
+                "No account updater has been configured, skipping the check of saved cards"
+            );
+            return process
+                .finish_with_status(db, "ACCOUNT_UPDATER_NOT_CONFIGURED".to_string())
+                .await;
+        }
+        let now = common_utils::date_time::now();
+        let expiring_cards = db
+            .find_cards_expiring_before(
+                now + time::Duration::days(config.expiry_window_days),
+                now - time::Duration::days(config.recheck_interval_days),
+                config.batch_size,
+            )
+            .await?;
+
+        for payment_method in expiring_cards {
+            let payment_method_id = payment_method.payment_method_id.clone();
+            match update_saved_card(state, payment_method.clone()).await {
+                Ok(card_update) => {
+                    logger::info!(
+                        %payment_method_id,
+                        update_type = card_update.get_update_type(),
+                        "Checked saved card with the account updater"
+                    );
+                    metrics::CARD_ACCOUNT_UPDATES.add(
+                        &metrics::CONTEXT,
+                        1,
+                        &[metrics::request::add_attributes(
+                            "update_type",
+                            card_update.get_update_type(),
+                        )],
+                    );
+                }
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        %payment_method_id,
+                        "Failed to update saved card with the account updater"
+                    );
+                    metrics::CARD_ACCOUNT_UPDATE_FAILURES.add(&metrics::CONTEXT, 1, &[]);
+
+                    // The card is checked again after the recheck interval, so that cards which
+                    // keep failing do not hold up the remaining cards
+                    db.update_payment_method(
+                        payment_method,
+                        storage::PaymentMethodUpdate::AccountUpdaterUpdate {
+                            card_account_status: None,
+                            account_updater_checked_at: now,
+                        },
+                    )
+                    .await
+                    .map_err(|error| {
+                        logger::error!(?error, %payment_method_id, "Failed to update saved card")
+                    })
+                    .ok();
+                }
+            }
+        }
+
+        process
+            .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while updating expiring saved cards");
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+async fn update_saved_card(
+    state: &AppState,
+    payment_method: storage::PaymentMethod,
+) -> Result<account_updater::CardAccountUpdate, errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &payment_method.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&payment_method.merchant_id, &key_store)
+        .await?;
+
+    Ok(
+        account_updater::update_saved_card(state, payment_method, &merchant_account, &key_store)
+            .await?,
+    )
+}
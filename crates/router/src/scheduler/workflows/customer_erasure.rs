@@ -0,0 +1,109 @@
+use error_stack::{IntoReport, ResultExt};
+use router_env::logger;
+
+use super::{CustomerErasureWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::customers,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for CustomerErasureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::CustomerErasureTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CustomerErasureTrackingData")?;
+
+        let customer_erasure = db
+            .find_customer_erasure_by_merchant_id_erasure_id(
+                &tracking_data.merchant_id,
+                &tracking_data.erasure_id,
+            )
+            .await?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let erased_records = customers::erase_customer_data(
+            state,
+            &merchant_account,
+            &key_store,
+            &tracking_data.customer_id,
+        )
+        .await?;
+        let erased_records = serde_json::to_value(erased_records)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while serializing the erased records")?;
+
+        db.update_customer_erasure(
+            customer_erasure,
+            storage::CustomerErasureUpdate::Completed { erased_records },
+        )
+        .await?;
+        logger::info!(
+            erasure_id = %tracking_data.erasure_id,
+            "Completed the erasure of the customer data"
+        );
+
+        process
+            .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while erasing the customer data");
+
+        // The erasure is marked as failed, so that the merchant can request it again
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: Result<storage::CustomerErasureTrackingData, _> = process
+            .tracking_data
+            .clone()
+            .parse_value("CustomerErasureTrackingData");
+        if let Ok(tracking_data) = tracking_data {
+            if let Ok(customer_erasure) = db
+                .find_customer_erasure_by_merchant_id_erasure_id(
+                    &tracking_data.merchant_id,
+                    &tracking_data.erasure_id,
+                )
+                .await
+            {
+                db.update_customer_erasure(
+                    customer_erasure,
+                    storage::CustomerErasureUpdate::Failed {
+                        error_message: error.to_string(),
+                    },
+                )
+                .await
+                .map_err(|error| logger::error!(customer_erasure_update_error=?error))
+                .ok();
+            }
+        }
+
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
@@ -0,0 +1,230 @@
+use error_stack::IntoReport;
+use router_env::logger;
+
+use super::{DisputeLifecycleWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::{disputes, metrics, webhooks},
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::{consumer, process_data, utils as pt_utils},
+    services,
+    types::{
+        api,
+        storage::{self, enums, ProcessTrackerExt},
+        transformers::{ForeignFrom, ForeignTryFrom},
+    },
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for DisputeLifecycleWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::DisputeLifecycleTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DisputeLifecycleTrackingData")?;
+
+        let dispute = db
+            .find_dispute_by_merchant_id_dispute_id(
+                &tracking_data.merchant_id,
+                &tracking_data.dispute_id,
+            )
+            .await?;
+
+        // The dispute may have been accepted, challenged or closed by the connector after the task
+        // was scheduled
+        let Some(challenge_required_by) = dispute
+            .challenge_required_by
+            .filter(|_| dispute.dispute_status == enums::DisputeStatus::DisputeOpened)
+        else {
+            return process
+                .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+                .await;
+        };
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        // The evaluation of the auto accept rules is followed by the evidence reminders and the
+        // expiry of the dispute
+        let next_step_index = if let Some(step_index) = tracking_data.step_index {
+            if step_index < tracking_data.evidence_reminder_hours.len() {
+                webhooks::create_event_and_trigger_outgoing_webhook::<api::OutgoingWebhook>(
+                    state.clone(),
+                    merchant_account,
+                    enums::EventType::DisputeEvidenceDue,
+                    enums::EventClass::Disputes,
+                    None,
+                    dispute.dispute_id.clone(),
+                    enums::EventObjectType::DisputeDetails,
+                    api::OutgoingWebhookContent::DisputeDetails(Box::new(
+                        api_models::disputes::DisputeResponse::foreign_from(dispute),
+                    )),
+                )
+                .await?;
+                metrics::DISPUTE_EVIDENCE_REMINDERS_SENT.add(&metrics::CONTEXT, 1, &[]);
+            } else {
+                // Connectors do not always notify about disputes expiring, the dispute is synced
+                // with the connector and is expired only if the connector has not recorded a
+                // response to it by the evidence deadline
+                let Some(dispute) =
+                    disputes::sync_dispute(state, &merchant_account, &key_store, dispute).await?
+                else {
+                    logger::info!(
+                        dispute_id = %tracking_data.dispute_id,
+                        "Not expiring dispute as its status cannot be fetched from the connector"
+                    );
+                    return process
+                        .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+                        .await;
+                };
+
+                let dispute = match dispute.dispute_status {
+                    enums::DisputeStatus::DisputeOpened => {
+                        db.update_dispute(
+                            dispute,
+                            storage::DisputeUpdate::StatusUpdate {
+                                dispute_status: enums::DisputeStatus::DisputeExpired,
+                                connector_status: None,
+                            },
+                        )
+                        .await?
+                    }
+                    _ => dispute,
+                };
+                let event_type =
+                    enums::EventType::foreign_try_from(dispute.dispute_status).into_report()?;
+                webhooks::create_event_and_trigger_outgoing_webhook::<api::OutgoingWebhook>(
+                    state.clone(),
+                    merchant_account,
+                    event_type,
+                    enums::EventClass::Disputes,
+                    None,
+                    dispute.dispute_id.clone(),
+                    enums::EventObjectType::DisputeDetails,
+                    api::OutgoingWebhookContent::DisputeDetails(Box::new(
+                        api_models::disputes::DisputeResponse::foreign_from(dispute),
+                    )),
+                )
+                .await?;
+                if event_type == enums::EventType::DisputeExpired {
+                    metrics::DISPUTES_EXPIRED.add(&metrics::CONTEXT, 1, &[]);
+                }
+
+                return process
+                    .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+                    .await;
+            }
+
+            step_index + 1
+        } else {
+            let auto_accept_rules =
+                disputes::find_dispute_auto_accept_rules(db, &tracking_data.merchant_id).await?;
+            if auto_accept_rules.map_or(false, |rules| rules.should_accept(&dispute)) {
+                let response = disputes::accept_dispute(
+                    state,
+                    merchant_account.clone(),
+                    key_store,
+                    api::DisputeId {
+                        dispute_id: dispute.dispute_id.clone(),
+                    },
+                )
+                .await?;
+
+                if let services::ApplicationResponse::Json(dispute_response) = response {
+                    webhooks::create_event_and_trigger_outgoing_webhook::<api::OutgoingWebhook>(
+                        state.clone(),
+                        merchant_account,
+                        enums::EventType::DisputeAccepted,
+                        enums::EventClass::Disputes,
+                        None,
+                        dispute.dispute_id.clone(),
+                        enums::EventObjectType::DisputeDetails,
+                        api::OutgoingWebhookContent::DisputeDetails(Box::new(dispute_response)),
+                    )
+                    .await?;
+                }
+                metrics::DISPUTES_AUTO_ACCEPTED.add(&metrics::CONTEXT, 1, &[]);
+
+                return process
+                    .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+                    .await;
+            }
+
+            0
+        };
+
+        let (step_index, schedule_time) = disputes::get_next_dispute_lifecycle_step(
+            challenge_required_by,
+            &tracking_data.evidence_reminder_hours,
+            state.conf.disputes.expiry_grace_period,
+            next_step_index,
+        );
+        let tracking_data = serde_json::to_value(storage::DisputeLifecycleTrackingData {
+            step_index: Some(step_index),
+            ..tracking_data
+        })
+        .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+        // The retry count is reset, it tracks the retries of the step after transient failures
+        db.update_process_tracker(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(schedule_time),
+                tracking_data: Some(tracking_data),
+                business_status: None,
+                status: Some(enums::ProcessTrackerStatus::Pending),
+                updated_at: Some(common_utils::date_time::now()),
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, ?error, "Failed while executing dispute lifecycle step");
+
+        // Failures such as the connector or the database being unavailable are transient, the step
+        // is retried on the merchant's retry schedule before the task is given up on
+        let tracking_data: storage::DisputeLifecycleTrackingData = match process
+            .tracking_data
+            .clone()
+            .parse_value("DisputeLifecycleTrackingData")
+        {
+            Ok(tracking_data) => tracking_data,
+            Err(parsing_error) => {
+                logger::error!(%process.id, ?parsing_error, "Failed to parse tracking data");
+                return consumer::consumer_error_handler(state, process, error).await;
+            }
+        };
+        let schedule_time = pt_utils::get_time_from_delta(pt_utils::get_schedule_time(
+            process_data::ConnectorPTMapping::default(),
+            &tracking_data.merchant_id,
+            process.retry_count + 1,
+        ));
+        match schedule_time {
+            Some(schedule_time) => Ok(process.retry(&*state.store, schedule_time).await?),
+            None => consumer::consumer_error_handler(state, process, error).await,
+        }
+    }
+}
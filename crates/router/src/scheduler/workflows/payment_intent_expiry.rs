@@ -0,0 +1,145 @@
+use router_env::logger;
+
+use super::{PaymentIntentExpiryWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::{payment_methods::vault, payments::helpers, webhooks},
+    db::StorageInterface,
+    errors,
+    routes::{metrics, AppState},
+    scheduler::consumer,
+    types::{
+        api,
+        storage::{self, enums, ProcessTrackerExt},
+        transformers::ForeignFrom,
+    },
+    utils::ValueExt,
+};
+
+/// Statuses of payment intents which have not been confirmed, and can be expired
+const EXPIRABLE_INTENT_STATUSES: [enums::IntentStatus; 2] = [
+    enums::IntentStatus::RequiresPaymentMethod,
+    enums::IntentStatus::RequiresConfirmation,
+];
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for PaymentIntentExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::PaymentIntentExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentIntentExpiryTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+        let storage_scheme = merchant_account.storage_scheme;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                storage_scheme,
+            )
+            .await?;
+
+        // The payment intent may have been confirmed or cancelled after the task was scheduled
+        if !EXPIRABLE_INTENT_STATUSES.contains(&payment_intent.status) {
+            return process
+                .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+                .await;
+        }
+
+        // The fulfillment time of the merchant may have been extended after the task was scheduled
+        let expires_at = helpers::get_intent_fulfillment_deadline(
+            &payment_intent,
+            merchant_account.intent_fulfillment_time,
+        );
+        if expires_at > common_utils::date_time::now() {
+            let retry_count = process.retry_count;
+            db.update_process_tracker(
+                process,
+                storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                    status: enums::ProcessTrackerStatus::Pending,
+                    retry_count,
+                    schedule_time: expires_at,
+                },
+            )
+            .await?;
+            return Ok(());
+        }
+
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                &payment_intent.payment_id,
+                &payment_intent.merchant_id,
+                &payment_intent.active_attempt_id,
+                storage_scheme,
+            )
+            .await?;
+
+        // The payment intent is only expired if it has not been confirmed in the meantime
+        let Some(payment_intent) = db
+            .update_payment_intent_if_status_in(
+                payment_intent,
+                storage::PaymentIntentUpdate::PGStatusUpdate {
+                    status: enums::IntentStatus::Expired,
+                },
+                EXPIRABLE_INTENT_STATUSES.to_vec(),
+                storage_scheme,
+            )
+            .await?
+        else {
+            return process
+                .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+                .await;
+        };
+        metrics::PAYMENT_INTENTS_EXPIRED.add(&metrics::CONTEXT, 1, &[]);
+
+        // Payment method data vaulted temporarily for the payment is no longer required
+        vault::Vault::delete_locker_payment_method_by_lookup_key(
+            state,
+            &payment_attempt.payment_token,
+        )
+        .await;
+
+        let payments_response =
+            api::PaymentsResponse::foreign_from((payment_intent, payment_attempt));
+        webhooks::create_event_and_trigger_outgoing_webhook::<api::OutgoingWebhook>(
+            state.clone(),
+            merchant_account,
+            enums::EventType::PaymentExpired,
+            enums::EventClass::Payments,
+            None,
+            tracking_data.payment_id.clone(),
+            enums::EventObjectType::PaymentDetails,
+            api::OutgoingWebhookContent::PaymentDetails(payments_response),
+        )
+        .await?;
+
+        process
+            .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while expiring payment intent");
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
@@ -1,9 +1,7 @@
-use router_env::logger;
-
 use super::{PaymentsSyncWorkflow, ProcessTrackerWorkflow};
 use crate::{
     core::payments::{self as payment_flows, operations},
-    db::{get_and_deserialize_key, StorageInterface},
+    db::StorageInterface,
     errors,
     routes::AppState,
     scheduler::{consumer, process_data, utils},
@@ -107,15 +105,12 @@ pub async fn get_sync_process_schedule_time(
     merchant_id: &str,
     retry_count: i32,
 ) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
-    let redis_mapping: errors::CustomResult<process_data::ConnectorPTMapping, errors::RedisError> =
-        get_and_deserialize_key(db, &format!("pt_mapping_{connector}"), "ConnectorPTMapping").await;
-    let mapping = match redis_mapping {
-        Ok(x) => x,
-        Err(err) => {
-            logger::info!("Redis Mapping Error: {}", err);
-            process_data::ConnectorPTMapping::default()
-        }
-    };
+    let mapping: process_data::ConnectorPTMapping = utils::get_pt_mapping(
+        db,
+        &process_data::get_payment_sync_mapping_key(connector),
+        "ConnectorPTMapping",
+    )
+    .await;
     let time_delta = utils::get_schedule_time(mapping, merchant_id, retry_count + 1);
 
     Ok(utils::get_time_from_delta(time_delta))
@@ -0,0 +1,50 @@
+use std::path::Path;
+
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::{ProcessTrackerWorkflow, SettlementReconciliationWorkflow};
+use crate::{
+    core::reconciliation,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, ProcessTrackerExt},
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for SettlementReconciliationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        match state.conf.reconciliation.report_directory.as_deref() {
+            Some(report_directory) => {
+                reconciliation::import_reports_from_directory(db, Path::new(report_directory))
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to import connector reports")?;
+            }
+            None => logger::warn!(
+                %process.id,
+                "Reconciliation report directory is not configured, skipping report import"
+            ),
+        }
+
+        process
+            .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while importing connector reports");
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
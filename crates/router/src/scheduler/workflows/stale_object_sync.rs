@@ -0,0 +1,369 @@
+use std::collections::HashMap;
+
+use router_env::logger;
+
+use super::{ProcessTrackerWorkflow, StaleObjectSyncWorkflow};
+use crate::{
+    configs::settings,
+    core::{
+        disputes,
+        payments::{self as payment_flows, operations},
+        refunds,
+    },
+    db::StorageInterface,
+    errors,
+    routes::{metrics, AppState},
+    scheduler::consumer,
+    types::{
+        api, domain,
+        storage::{self, enums, ProcessTrackerExt},
+    },
+};
+
+/// Payment attempt statuses which are expected to be updated by the connector.
+const STALE_PAYMENT_ATTEMPT_STATUSES: [enums::AttemptStatus; 5] = [
+    enums::AttemptStatus::AuthenticationPending,
+    enums::AttemptStatus::Authorizing,
+    enums::AttemptStatus::Pending,
+    enums::AttemptStatus::CaptureInitiated,
+    enums::AttemptStatus::VoidInitiated,
+];
+
+/// Dispute statuses which are expected to be updated by the connector.
+const STALE_DISPUTE_STATUSES: [enums::DisputeStatus; 2] = [
+    enums::DisputeStatus::DisputeOpened,
+    enums::DisputeStatus::DisputeChallenged,
+];
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for StaleObjectSyncWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let mut rate_limiter = ConnectorRateLimiter::new(&state.conf.stale_object_sync);
+
+        sync_stale_payment_attempts(state, &mut rate_limiter).await?;
+        sync_stale_refunds(state, &mut rate_limiter).await?;
+        sync_stale_disputes(state, &mut rate_limiter).await?;
+
+        process
+            .finish_with_status(&*state.store, "COMPLETED_BY_PT".to_string())
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while syncing stale objects");
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+/// Limits the number of sync calls made to each connector in a single sweep.
+struct ConnectorRateLimiter<'a> {
+    config: &'a settings::StaleObjectSync,
+    calls: HashMap<String, usize>,
+}
+
+impl<'a> ConnectorRateLimiter<'a> {
+    fn new(config: &'a settings::StaleObjectSync) -> Self {
+        Self {
+            config,
+            calls: HashMap::new(),
+        }
+    }
+
+    /// Records a call to the connector, unless its limit has been reached.
+    fn try_acquire(&mut self, connector: &str) -> bool {
+        let limit = self
+            .config
+            .connector_rate_limits
+            .get(connector)
+            .copied()
+            .unwrap_or(self.config.connector_rate_limit);
+        let calls = self.calls.entry(connector.to_string()).or_default();
+        if *calls >= limit {
+            return false;
+        }
+        *calls += 1;
+        true
+    }
+}
+
+fn get_threshold_time(threshold: i64) -> time::PrimitiveDateTime {
+    common_utils::date_time::now() - time::Duration::seconds(threshold)
+}
+
+async fn get_merchant_account_and_key_store(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> Result<(domain::MerchantAccount, domain::MerchantKeyStore), errors::ProcessTrackerError> {
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await?;
+    Ok((merchant_account, key_store))
+}
+
+async fn sync_stale_payment_attempts(
+    state: &AppState,
+    rate_limiter: &mut ConnectorRateLimiter<'_>,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let config = &state.conf.stale_object_sync;
+    let payment_attempts = db
+        .find_payment_attempts_by_statuses_modified_between(
+            STALE_PAYMENT_ATTEMPT_STATUSES.to_vec(),
+            get_threshold_time(config.max_age),
+            get_threshold_time(config.payment_threshold),
+            config.batch_size,
+        )
+        .await?;
+
+    for payment_attempt in payment_attempts {
+        // Attempts which were never routed to a connector have nothing to be synced
+        let Some(connector) = payment_attempt.connector.clone() else {
+            continue;
+        };
+        if !rate_limiter.try_acquire(&connector) {
+            metrics::STALE_OBJECT_SYNC_RATE_LIMITED.add(
+                &metrics::CONTEXT,
+                1,
+                &[
+                    metrics::request::add_attributes("object", "payment"),
+                    metrics::request::add_attributes("connector", connector),
+                ],
+            );
+            continue;
+        }
+
+        let result = sync_payment_attempt(state, &payment_attempt).await;
+        record_sync_result("payment", connector, &payment_attempt.attempt_id, result);
+    }
+
+    Ok(())
+}
+
+async fn sync_payment_attempt(
+    state: &AppState,
+    payment_attempt: &storage::PaymentAttempt,
+) -> Result<(), errors::ProcessTrackerError> {
+    let (merchant_account, key_store) =
+        get_merchant_account_and_key_store(&*state.store, &payment_attempt.merchant_id).await?;
+
+    let (payment_data, _, _) = payment_flows::payments_operation_core::<api::PSync, _, _, _>(
+        state,
+        merchant_account,
+        key_store,
+        operations::PaymentStatus,
+        api::PaymentsRetrieveRequest {
+            force_sync: true,
+            merchant_id: Some(payment_attempt.merchant_id.clone()),
+            resource_id: api::PaymentIdType::PaymentAttemptId(payment_attempt.attempt_id.clone()),
+            ..Default::default()
+        },
+        payment_flows::CallConnectorAction::Trigger,
+    )
+    .await?;
+
+    logger::info!(
+        attempt_id = %payment_attempt.attempt_id,
+        previous_status = %payment_attempt.status,
+        status = %payment_data.payment_attempt.status,
+        "Synced stale payment attempt"
+    );
+    Ok(())
+}
+
+async fn sync_stale_refunds(
+    state: &AppState,
+    rate_limiter: &mut ConnectorRateLimiter<'_>,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let config = &state.conf.stale_object_sync;
+    let stale_refunds = db
+        .find_refunds_by_statuses_modified_between(
+            vec![enums::RefundStatus::Pending],
+            get_threshold_time(config.max_age),
+            get_threshold_time(config.refund_threshold),
+            config.batch_size,
+        )
+        .await?;
+
+    // Refunds not sent to the connector yet are picked up by the refund execute workflow
+    for refund in stale_refunds
+        .into_iter()
+        .filter(|refund| refund.sent_to_gateway)
+    {
+        if !rate_limiter.try_acquire(&refund.connector) {
+            metrics::STALE_OBJECT_SYNC_RATE_LIMITED.add(
+                &metrics::CONTEXT,
+                1,
+                &[
+                    metrics::request::add_attributes("object", "refund"),
+                    metrics::request::add_attributes("connector", refund.connector.clone()),
+                ],
+            );
+            continue;
+        }
+
+        let result = sync_refund(state, &refund).await;
+        record_sync_result("refund", refund.connector, &refund.refund_id, result);
+    }
+
+    Ok(())
+}
+
+async fn sync_refund(
+    state: &AppState,
+    refund: &storage::Refund,
+) -> Result<(), errors::ProcessTrackerError> {
+    let (merchant_account, key_store) =
+        get_merchant_account_and_key_store(&*state.store, &refund.merchant_id).await?;
+
+    let synced_refund = refunds::refund_retrieve_core(
+        state,
+        merchant_account,
+        key_store,
+        api_models::refunds::RefundsRetrieveRequest {
+            refund_id: refund.refund_id.clone(),
+            force_sync: Some(true),
+            merchant_connector_details: None,
+        },
+    )
+    .await?;
+
+    logger::info!(
+        refund_id = %refund.refund_id,
+        previous_status = %refund.refund_status,
+        status = %synced_refund.refund_status,
+        "Synced stale refund"
+    );
+    Ok(())
+}
+
+fn record_sync_result(
+    object: &'static str,
+    connector: String,
+    object_id: &str,
+    result: Result<(), errors::ProcessTrackerError>,
+) {
+    let attributes = [
+        metrics::request::add_attributes("object", object),
+        metrics::request::add_attributes("connector", connector),
+    ];
+    match result {
+        Ok(()) => metrics::STALE_OBJECTS_SYNCED.add(&metrics::CONTEXT, 1, &attributes),
+        Err(error) => {
+            logger::error!(?error, %object, %object_id, "Failed to sync stale object");
+            metrics::STALE_OBJECT_SYNC_FAILED.add(&metrics::CONTEXT, 1, &attributes);
+        }
+    }
+}
+
+async fn sync_stale_disputes(
+    state: &AppState,
+    rate_limiter: &mut ConnectorRateLimiter<'_>,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let config = &state.conf.stale_object_sync;
+    let stale_disputes = db
+        .find_disputes_by_statuses_modified_between(
+            STALE_DISPUTE_STATUSES.to_vec(),
+            get_threshold_time(config.max_age),
+            get_threshold_time(config.dispute_threshold),
+            config.batch_size,
+        )
+        .await?;
+
+    for dispute in stale_disputes {
+        if !rate_limiter.try_acquire(&dispute.connector) {
+            metrics::STALE_OBJECT_SYNC_RATE_LIMITED.add(
+                &metrics::CONTEXT,
+                1,
+                &[
+                    metrics::request::add_attributes("object", "dispute"),
+                    metrics::request::add_attributes("connector", dispute.connector.clone()),
+                ],
+            );
+            continue;
+        }
+
+        let connector = dispute.connector.clone();
+        let dispute_id = dispute.dispute_id.clone();
+        match sync_dispute(state, dispute).await {
+            Ok(true) => record_sync_result("dispute", connector, &dispute_id, Ok(())),
+            Ok(false) => metrics::STALE_DISPUTES_FOUND.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes("connector", connector)],
+            ),
+            Err(error) => record_sync_result("dispute", connector, &dispute_id, Err(error)),
+        }
+    }
+
+    Ok(())
+}
+
+/// Syncs the dispute with the connector, returning whether the connector supports retrieving
+/// disputes. Disputes of other connectors are only updated through webhooks, and are reported
+/// instead, so that they can be followed up on the connector dashboard.
+async fn sync_dispute(
+    state: &AppState,
+    dispute: storage::Dispute,
+) -> Result<bool, errors::ProcessTrackerError> {
+    let (merchant_account, key_store) =
+        get_merchant_account_and_key_store(&*state.store, &dispute.merchant_id).await?;
+    let dispute_id = dispute.dispute_id.clone();
+    let previous_status = dispute.dispute_status;
+
+    let Some(synced_dispute) =
+        disputes::sync_dispute(state, &merchant_account, &key_store, dispute.clone()).await?
+    else {
+        logger::warn!(
+            dispute_id = %dispute.dispute_id,
+            merchant_id = %dispute.merchant_id,
+            connector = %dispute.connector,
+            dispute_status = %dispute.dispute_status,
+            "Dispute has not been updated by the connector"
+        );
+        return Ok(false);
+    };
+
+    logger::info!(
+        %dispute_id,
+        %previous_status,
+        status = %synced_dispute.dispute_status,
+        "Synced stale dispute"
+    );
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connector_rate_limiter() {
+        let config = settings::StaleObjectSync {
+            connector_rate_limit: 2,
+            connector_rate_limits: HashMap::from([("adyen".to_string(), 1)]),
+            ..Default::default()
+        };
+        let mut rate_limiter = ConnectorRateLimiter::new(&config);
+
+        assert!(rate_limiter.try_acquire("stripe"));
+        assert!(rate_limiter.try_acquire("stripe"));
+        assert!(!rate_limiter.try_acquire("stripe"));
+
+        assert!(rate_limiter.try_acquire("adyen"));
+        assert!(!rate_limiter.try_acquire("adyen"));
+    }
+}
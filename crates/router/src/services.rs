@@ -1,5 +1,7 @@
 pub mod api;
 pub mod authentication;
+#[cfg(feature = "email")]
+pub mod email;
 pub mod encryption;
 pub mod logger;
 
@@ -14,7 +16,7 @@ use tokio::sync::oneshot;
 pub use self::{api::*, encryption::*};
 use crate::{
     async_spawn,
-    cache::{CacheKind, ACCOUNTS_CACHE, CONFIG_CACHE},
+    cache::CacheKind,
     configs::settings,
     connection::{diesel_make_pg_pool, PgPool},
     consts,
@@ -78,23 +80,12 @@ impl PubSubInterface for redis_interface::RedisConnectionPool {
                 }
             };
 
-            let key = match key {
-                CacheKind::Config(key) => {
-                    CONFIG_CACHE.invalidate(key.as_ref()).await;
-                    key
-                }
-                CacheKind::Accounts(key) => {
-                    ACCOUNTS_CACHE.invalidate(key.as_ref()).await;
-                    key
-                }
-                CacheKind::All(key) => {
-                    CONFIG_CACHE.invalidate(key.as_ref()).await;
-                    ACCOUNTS_CACHE.invalidate(key.as_ref()).await;
-                    key
-                }
-            };
+            for cache in key.caches() {
+                cache.invalidate(key.key()).await;
+            }
 
-            self.delete_key(key.as_ref())
+            let key = key.key();
+            self.delete_key(key)
                 .await
                 .map_err(|err| logger::error!("Error while deleting redis key: {err:?}"))
                 .ok();
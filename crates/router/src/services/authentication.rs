@@ -9,12 +9,14 @@ use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
 use masking::{PeekInterface, StrongSecret};
 
 use crate::{
+    async_spawn,
     configs::settings,
     core::{
         api_keys,
         errors::{self, utils::StorageErrorExt, RouterResult},
     },
     db::StorageInterface,
+    logger,
     routes::app::AppStateInfo,
     services::api,
     types::domain,
@@ -94,15 +96,13 @@ where
         }
 
         let api_key = api_keys::PlaintextApiKey::from(api_key);
-        let hash_key = {
-            let config = state.conf();
-            api_keys::get_hash_key(
-                &config.api_keys,
-                #[cfg(feature = "kms")]
-                &config.kms,
-            )
-            .await?
-        };
+        let config = state.conf();
+        let hash_key = api_keys::get_hash_key(
+            &config.api_keys,
+            #[cfg(feature = "kms")]
+            &config.kms,
+        )
+        .await?;
         let hashed_api_key = api_key.keyed_hash(hash_key.peek());
 
         let stored_api_key = state
@@ -139,6 +139,15 @@ where
             .await
             .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
 
+        let store = state.store();
+        let last_used_update_interval = config.api_keys.last_used_update_interval;
+        async_spawn!({
+            api_keys::update_last_used(&*store, &stored_api_key, last_used_update_interval)
+                .await
+                .map_err(|error| logger::error!(api_key_last_used_update_error=?error))
+                .ok();
+        });
+
         Ok(AuthenticationData {
             merchant_account: merchant,
             key_store,
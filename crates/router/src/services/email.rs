@@ -0,0 +1,443 @@
+//! Notification emails sent to merchants and customers.
+
+use common_utils::pii;
+use masking::PeekInterface;
+
+use crate::{
+    async_spawn, logger,
+    routes::AppState,
+    types::{api, domain, storage::enums},
+    utils::ValueExt,
+};
+
+/// The subject and body of an email to be sent.
+#[derive(Debug, Clone)]
+pub struct EmailContents {
+    pub subject: String,
+    pub body: String,
+}
+
+/// The templates available for notification emails.
+#[derive(Debug, Clone)]
+pub enum EmailTemplate {
+    /// Receipt sent to the customer once their payment succeeds.
+    PaymentReceipt {
+        merchant_name: String,
+        payment_id: String,
+        amount: i64,
+        currency: String,
+    },
+    /// Notification sent to the merchant once a refund has been processed.
+    RefundProcessed {
+        refund_id: String,
+        payment_id: String,
+        amount: i64,
+        currency: String,
+    },
+    /// Alert sent to the merchant when a dispute is opened against one of their payments.
+    DisputeOpened {
+        dispute_id: String,
+        payment_id: String,
+        amount: String,
+        currency: String,
+        connector: String,
+        challenge_required_by: Option<time::PrimitiveDateTime>,
+    },
+    /// Reminder sent to the merchant when the evidence deadline of an open dispute is approaching.
+    DisputeEvidenceDue {
+        dispute_id: String,
+        payment_id: String,
+        amount: String,
+        currency: String,
+        connector: String,
+        challenge_required_by: time::PrimitiveDateTime,
+    },
+    /// Reminder sent to the merchant when one of their API keys is about to expire.
+    ApiKeyExpiring {
+        key_id: String,
+        key_name: String,
+        prefix: String,
+        expires_at: time::PrimitiveDateTime,
+    },
+}
+
+impl EmailTemplate {
+    pub fn get_email_contents(&self, base_url: &str) -> EmailContents {
+        match self {
+            Self::PaymentReceipt {
+                merchant_name,
+                payment_id,
+                amount,
+                currency,
+            } => EmailContents {
+                subject: format!("Your payment to {merchant_name} was successful"),
+                body: format!(
+                    "Hello,\n\n\
+                     Your payment to {merchant_name} has been completed successfully.\n\n\
+                     Payment ID: {payment_id}\n\
+                     Amount: {amount} {currency} (in minor units)\n\n\
+                     Thank you."
+                ),
+            },
+            Self::RefundProcessed {
+                refund_id,
+                payment_id,
+                amount,
+                currency,
+            } => EmailContents {
+                subject: format!("Refund {refund_id} has been processed"),
+                body: format!(
+                    "Hello,\n\n\
+                     The refund {refund_id} for payment {payment_id} has been processed.\n\n\
+                     Amount: {amount} {currency} (in minor units)\n\n\
+                     You can view the refund at {base_url}/refunds/{refund_id}"
+                ),
+            },
+            Self::DisputeOpened {
+                dispute_id,
+                payment_id,
+                amount,
+                currency,
+                connector,
+                challenge_required_by,
+            } => EmailContents {
+                subject: format!("A dispute has been opened for payment {payment_id}"),
+                body: format!(
+                    "Hello,\n\n\
+                     A dispute has been opened through {connector} for payment {payment_id}.\n\n\
+                     Dispute ID: {dispute_id}\n\
+                     Amount: {amount} {currency}\n\
+                     Evidence due by: {}\n\n\
+                     You can view the dispute at {base_url}/disputes/{dispute_id}",
+                    challenge_required_by
+                        .map(|due_date| due_date.to_string())
+                        .unwrap_or_else(|| "not specified".to_string())
+                ),
+            },
+            Self::DisputeEvidenceDue {
+                dispute_id,
+                payment_id,
+                amount,
+                currency,
+                connector,
+                challenge_required_by,
+            } => EmailContents {
+                subject: format!("Evidence for dispute {dispute_id} is due soon"),
+                body: format!(
+                    "Hello,\n\n\
+                     The evidence for the dispute opened through {connector} for payment \
+                     {payment_id} is due by {challenge_required_by} UTC.\n\
+                     The dispute will be lost if it is not challenged before then.\n\n\
+                     Dispute ID: {dispute_id}\n\
+                     Amount: {amount} {currency}\n\n\
+                     You can view the dispute at {base_url}/disputes/{dispute_id}"
+                ),
+            },
+            Self::ApiKeyExpiring {
+                key_id,
+                key_name,
+                prefix,
+                expires_at,
+            } => EmailContents {
+                subject: format!("Your API key {key_name} is about to expire"),
+                body: format!(
+                    "Hello,\n\n\
+                     Your API key {key_name} ({prefix}...) will expire on {expires_at} UTC.\n\
+                     Requests made using this key will fail once it expires.\n\n\
+                     Key ID: {key_id}\n\n\
+                     Please create a new API key and update your integration before then."
+                ),
+            },
+        }
+    }
+}
+
+/// Obtains the primary contact email of the merchant, if one was provided in the merchant
+/// details.
+pub fn get_merchant_email(merchant_account: &domain::MerchantAccount) -> Option<pii::Email> {
+    merchant_account
+        .merchant_details
+        .as_ref()
+        .and_then(|merchant_details| {
+            merchant_details
+                .peek()
+                .clone()
+                .parse_value::<api::MerchantDetails>("MerchantDetails")
+                .map_err(|error| logger::warn!(merchant_details_parsing_error=?error))
+                .ok()
+        })
+        .and_then(|merchant_details| merchant_details.primary_email)
+}
+
+/// Determines the recipient and the email template to be used for notifying about an event, if
+/// the event warrants an email.
+pub fn get_event_notification(
+    merchant_account: &domain::MerchantAccount,
+    event_type: enums::EventType,
+    content: &api::OutgoingWebhookContent,
+) -> Option<(pii::Email, EmailTemplate)> {
+    match (event_type, content) {
+        (
+            enums::EventType::PaymentSucceeded,
+            api::OutgoingWebhookContent::PaymentDetails(payment),
+        ) => {
+            let recipient = payment.email.clone().map(pii::Email::from)?;
+            let merchant_name = merchant_account
+                .merchant_name
+                .as_ref()
+                .map(|merchant_name| merchant_name.peek().to_owned())
+                .unwrap_or_else(|| merchant_account.merchant_id.clone());
+            Some((
+                recipient,
+                EmailTemplate::PaymentReceipt {
+                    merchant_name,
+                    payment_id: payment.payment_id.clone()?,
+                    amount: payment.amount,
+                    currency: payment.currency.clone(),
+                },
+            ))
+        }
+        (enums::EventType::RefundSucceeded, api::OutgoingWebhookContent::RefundDetails(refund)) => {
+            Some((
+                get_merchant_email(merchant_account)?,
+                EmailTemplate::RefundProcessed {
+                    refund_id: refund.refund_id.clone(),
+                    payment_id: refund.payment_id.clone(),
+                    amount: refund.amount,
+                    currency: refund.currency.clone(),
+                },
+            ))
+        }
+        (enums::EventType::DisputeOpened, api::OutgoingWebhookContent::DisputeDetails(dispute)) => {
+            Some((
+                get_merchant_email(merchant_account)?,
+                EmailTemplate::DisputeOpened {
+                    dispute_id: dispute.dispute_id.clone(),
+                    payment_id: dispute.payment_id.clone(),
+                    amount: dispute.amount.clone(),
+                    currency: dispute.currency.clone(),
+                    connector: dispute.connector.clone(),
+                    challenge_required_by: dispute.challenge_required_by,
+                },
+            ))
+        }
+        (
+            enums::EventType::DisputeEvidenceDue,
+            api::OutgoingWebhookContent::DisputeDetails(dispute),
+        ) => Some((
+            get_merchant_email(merchant_account)?,
+            EmailTemplate::DisputeEvidenceDue {
+                dispute_id: dispute.dispute_id.clone(),
+                payment_id: dispute.payment_id.clone(),
+                amount: dispute.amount.clone(),
+                currency: dispute.currency.clone(),
+                connector: dispute.connector.clone(),
+                challenge_required_by: dispute.challenge_required_by?,
+            },
+        )),
+        (enums::EventType::ApiKeyExpiring, api::OutgoingWebhookContent::ApiKeyDetails(api_key)) => {
+            let expires_at = match api_key.expiration {
+                api::ApiKeyExpiration::Never => return None,
+                api::ApiKeyExpiration::DateTime(expires_at) => expires_at,
+            };
+            Some((
+                get_merchant_email(merchant_account)?,
+                EmailTemplate::ApiKeyExpiring {
+                    key_id: api_key.key_id.clone(),
+                    key_name: api_key.name.clone(),
+                    prefix: api_key.prefix.peek().to_owned(),
+                    expires_at,
+                },
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Sends the email in the background, logging any failure. Does nothing if notification emails
+/// are disabled.
+pub fn trigger_email_notification(
+    state: &AppState,
+    recipient: pii::Email,
+    template: EmailTemplate,
+) {
+    if !state.conf.email.notifications_enabled {
+        return;
+    }
+
+    let email_client = state.email_client.clone();
+    let EmailContents { subject, body } = template.get_email_contents(&state.conf.email.base_url);
+
+    async_spawn!({
+        if let Err(error) = email_client.send_email(recipient, subject, body).await {
+            logger::error!(email_sending_error=?error);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_utils::crypto::Encryptable;
+    use masking::{Secret, StrongSecret};
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::utils::test_utils;
+
+    fn merchant_account(primary_email: Option<&str>) -> domain::MerchantAccount {
+        domain::MerchantAccount {
+            merchant_details: primary_email.map(|primary_email| {
+                Encryptable::new(
+                    Secret::new(serde_json::json!({ "primary_email": primary_email })),
+                    Secret::new(Vec::new()),
+                )
+            }),
+            ..test_utils::merchant_account()
+        }
+    }
+
+    fn refund_content() -> api::OutgoingWebhookContent {
+        api::OutgoingWebhookContent::RefundDetails(api::RefundResponse {
+            refund_id: "refund_1".into(),
+            payment_id: "pay_1".into(),
+            amount: 1000,
+            currency: "USD".into(),
+            reason: None,
+            status: api::RefundStatus::Succeeded,
+            metadata: None,
+            error_message: None,
+            error_code: None,
+            created_at: None,
+            updated_at: None,
+            connector: "stripe".into(),
+        })
+    }
+
+    fn api_key_content(expiration: api::ApiKeyExpiration) -> api::OutgoingWebhookContent {
+        api::OutgoingWebhookContent::ApiKeyDetails(Box::new(api::RetrieveApiKeyResponse {
+            key_id: "key_1".into(),
+            merchant_id: "merchant_1".into(),
+            name: "Production key".into(),
+            description: None,
+            prefix: StrongSecret::new("snd_abc".into()),
+            created: datetime!(2023-07-01 0:00),
+            expiration,
+            last_used: None,
+        }))
+    }
+
+    #[test]
+    fn test_refund_processed_template_links_to_refund() {
+        let contents = EmailTemplate::RefundProcessed {
+            refund_id: "refund_1".into(),
+            payment_id: "pay_1".into(),
+            amount: 1000,
+            currency: "USD".into(),
+        }
+        .get_email_contents("https://dashboard.example.com");
+
+        assert_eq!(contents.subject, "Refund refund_1 has been processed");
+        assert!(contents.body.contains("for payment pay_1"));
+        assert!(contents.body.contains("1000 USD"));
+        assert!(contents
+            .body
+            .contains("https://dashboard.example.com/refunds/refund_1"));
+    }
+
+    #[test]
+    fn test_dispute_opened_template_without_evidence_deadline() {
+        let contents = EmailTemplate::DisputeOpened {
+            dispute_id: "dispute_1".into(),
+            payment_id: "pay_1".into(),
+            amount: "1000".into(),
+            currency: "USD".into(),
+            connector: "stripe".into(),
+            challenge_required_by: None,
+        }
+        .get_email_contents("https://dashboard.example.com");
+
+        assert_eq!(
+            contents.subject,
+            "A dispute has been opened for payment pay_1"
+        );
+        assert!(contents.body.contains("through stripe"));
+        assert!(contents.body.contains("Evidence due by: not specified"));
+    }
+
+    #[test]
+    fn test_api_key_expiring_template_includes_expiry() {
+        let contents = EmailTemplate::ApiKeyExpiring {
+            key_id: "key_1".into(),
+            key_name: "Production key".into(),
+            prefix: "snd_abc".into(),
+            expires_at: datetime!(2023-08-01 0:00),
+        }
+        .get_email_contents("https://dashboard.example.com");
+
+        assert_eq!(
+            contents.subject,
+            "Your API key Production key is about to expire"
+        );
+        assert!(contents.body.contains("(snd_abc...)"));
+        assert!(contents.body.contains("2023-08-01 0:00:00.0 UTC"));
+    }
+
+    #[test]
+    fn test_refund_notification_is_sent_to_merchant() {
+        let (recipient, template) = get_event_notification(
+            &merchant_account(Some("merchant@example.com")),
+            enums::EventType::RefundSucceeded,
+            &refund_content(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            recipient,
+            pii::Email::try_from("merchant@example.com".to_string()).unwrap()
+        );
+        assert!(matches!(
+            template,
+            EmailTemplate::RefundProcessed { refund_id, .. } if refund_id == "refund_1"
+        ));
+    }
+
+    #[test]
+    fn test_no_notification_without_merchant_email() {
+        assert!(get_event_notification(
+            &merchant_account(None),
+            enums::EventType::RefundSucceeded,
+            &refund_content(),
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_no_notification_for_api_key_without_expiry() {
+        let merchant_account = merchant_account(Some("merchant@example.com"));
+
+        assert!(get_event_notification(
+            &merchant_account,
+            enums::EventType::ApiKeyExpiring,
+            &api_key_content(api::ApiKeyExpiration::Never),
+        )
+        .is_none());
+        assert!(get_event_notification(
+            &merchant_account,
+            enums::EventType::ApiKeyExpiring,
+            &api_key_content(api::ApiKeyExpiration::DateTime(datetime!(2023-08-01 0:00))),
+        )
+        .is_some());
+    }
+
+    #[test]
+    fn test_no_notification_for_mismatched_event_content() {
+        assert!(get_event_notification(
+            &merchant_account(Some("merchant@example.com")),
+            enums::EventType::PaymentSucceeded,
+            &refund_content(),
+        )
+        .is_none());
+    }
+}
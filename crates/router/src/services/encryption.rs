@@ -121,7 +121,14 @@ pub async fn encrypt_jwe(
     payload: &[u8],
     public_key: String,
 ) -> CustomResult<String, errors::EncryptionError> {
-    let alg = jwe::RSA_OAEP_256;
+    encrypt_jwe_with_algorithm(payload, public_key, jwe::RSA_OAEP_256).await
+}
+
+pub async fn encrypt_jwe_with_algorithm(
+    payload: &[u8],
+    public_key: String,
+    alg: jwe::alg::rsaes::RsaesJweAlgorithm,
+) -> CustomResult<String, errors::EncryptionError> {
     let enc = "A256GCM";
     let mut src_header = jwe::JweHeader::new();
     src_header.set_content_encryption(enc);
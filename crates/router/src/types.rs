@@ -156,6 +156,15 @@ pub type DefendDisputeType = dyn services::ConnectorIntegration<
     DefendDisputeResponse,
 >;
 
+pub type FetchDisputeType =
+    dyn services::ConnectorIntegration<api::Fetch, FetchDisputeRequestData, FetchDisputeResponse>;
+
+pub type MandateRevokeType = dyn services::ConnectorIntegration<
+    api::mandates::MandateRevoke,
+    MandateRevokeRequestData,
+    MandateRevokeResponseData,
+>;
+
 pub type VerifyRouterData = RouterData<api::Verify, VerifyRequestData, PaymentsResponseData>;
 
 pub type AcceptDisputeRouterData =
@@ -172,6 +181,12 @@ pub type RetrieveFileRouterData =
 pub type DefendDisputeRouterData =
     RouterData<api::Defend, DefendDisputeRequestData, DefendDisputeResponse>;
 
+pub type FetchDisputeRouterData =
+    RouterData<api::Fetch, FetchDisputeRequestData, FetchDisputeResponse>;
+
+pub type MandateRevokeRouterData =
+    RouterData<api::mandates::MandateRevoke, MandateRevokeRequestData, MandateRevokeResponseData>;
+
 #[derive(Debug, Clone)]
 pub struct RouterData<Flow, Request, Response> {
     pub flow: PhantomData<Flow>,
@@ -195,6 +210,7 @@ pub struct RouterData<Flow, Request, Response> {
     pub reference_id: Option<String>,
     pub payment_method_token: Option<String>,
     pub preprocessing_id: Option<String>,
+    pub network_token: Option<NetworkTokenData>,
 
     /// Contains flow-specific data required to construct a request and send it to the connector.
     pub request: Request,
@@ -233,6 +249,7 @@ pub struct PaymentsAuthorizeData {
     pub payment_experience: Option<storage_enums::PaymentExperience>,
     pub payment_method_type: Option<storage_enums::PaymentMethodType>,
     pub customer_id: Option<String>,
+    pub installment_count: Option<u8>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -388,6 +405,17 @@ pub struct AccessToken {
     pub expires: i64,
 }
 
+/// Network token of a saved card along with the cryptogram for a single payment, sent to the
+/// connector in place of the card number
+#[derive(Debug, Clone)]
+pub struct NetworkTokenData {
+    pub token_number: cards::CardNumber,
+    pub token_exp_month: Secret<String>,
+    pub token_exp_year: Secret<String>,
+    pub token_cryptogram: Secret<String>,
+    pub eci: Option<String>,
+}
+
 #[derive(serde::Serialize, Debug, Clone)]
 pub struct MandateReference {
     pub connector_mandate_id: Option<String>,
@@ -578,6 +606,30 @@ pub struct DefendDisputeResponse {
     pub connector_status: Option<String>,
 }
 
+#[derive(Default, Debug, Clone)]
+pub struct FetchDisputeRequestData {
+    pub dispute_id: String,
+    pub connector_dispute_id: String,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct FetchDisputeResponse {
+    pub dispute_status: api_models::enums::DisputeStatus,
+    pub connector_status: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MandateRevokeRequestData {
+    pub mandate_id: String,
+    pub connector_mandate_id: Option<String>,
+    pub connector_payment_method_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MandateRevokeResponseData {
+    pub mandate_status: storage_enums::MandateStatus,
+}
+
 #[derive(Clone, Debug)]
 pub struct UploadFileRequestData {
     pub file_key: String,
@@ -774,6 +826,7 @@ impl From<&VerifyRouterData> for PaymentsAuthorizeData {
             payment_experience: None,
             payment_method_type: None,
             customer_id: None,
+            installment_count: None,
         }
     }
 }
@@ -808,6 +861,7 @@ impl<F1, F2, T1, T2> From<(&RouterData<F1, T1, PaymentsResponseData>, T2)>
             customer_id: data.customer_id.clone(),
             payment_method_token: None,
             preprocessing_id: None,
+            network_token: None,
             connector_customer: data.connector_customer.clone(),
         }
     }
@@ -36,6 +36,22 @@ pub struct DisputeEvidence {
     pub uncategorized_file: Option<String>,
 }
 
+impl DisputeEvidence {
+    /// Removes the evidence files which may hold personal data of the customer, such as their
+    /// signature or their communication with the merchant, and returns the IDs of the files
+    pub fn take_customer_file_ids(&mut self) -> Vec<String> {
+        [
+            self.customer_communication.take(),
+            self.customer_signature.take(),
+            self.receipt.take(),
+            self.shipping_documentation.take(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AttachEvidenceRequest {
     pub create_file_request: types::api::CreateFileRequest,
@@ -94,4 +110,19 @@ pub trait DefendDispute:
 {
 }
 
-pub trait Dispute: super::ConnectorCommon + AcceptDispute + SubmitEvidence + DefendDispute {}
+#[derive(Debug, Clone)]
+pub struct Fetch;
+
+pub trait FetchDispute:
+    services::ConnectorIntegration<
+    Fetch,
+    types::FetchDisputeRequestData,
+    types::FetchDisputeResponse,
+>
+{
+}
+
+pub trait Dispute:
+    super::ConnectorCommon + AcceptDispute + SubmitEvidence + DefendDispute + FetchDispute
+{
+}
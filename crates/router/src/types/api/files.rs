@@ -62,6 +62,7 @@ pub struct CreateFileRequest {
 #[strum(serialize_all = "snake_case")]
 pub enum FilePurpose {
     DisputeEvidence,
+    SettlementReport,
 }
 
 #[derive(Debug, Clone)]
@@ -10,13 +10,26 @@ use crate::{
     },
     newtype,
     routes::AppState,
+    services,
     types::{
-        api, domain,
+        self, api, domain,
         storage::{self, enums as storage_enums},
         transformers::ForeignInto,
     },
 };
 
+#[derive(Debug, Clone)]
+pub struct MandateRevoke;
+
+pub trait ConnectorMandateRevoke:
+    services::ConnectorIntegration<
+    MandateRevoke,
+    types::MandateRevokeRequestData,
+    types::MandateRevokeResponseData,
+>
+{
+}
+
 newtype!(
     pub MandateCardDetails = mandates::MandateCardDetails,
     derives = (Default, Debug, Deserialize, Serialize)
@@ -49,7 +62,7 @@ impl MandateResponseExt for MandateResponse {
                 state,
                 &payment_method.customer_id,
                 &payment_method.merchant_id,
-                &payment_method.payment_method_id,
+                payment_method.get_card_reference(),
                 merchant_account.locker_id.clone(),
             )
             .await?;
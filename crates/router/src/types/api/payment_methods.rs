@@ -3,8 +3,9 @@ use std::collections::HashMap;
 pub use api_models::payment_methods::{
     CardDetail, CardDetailFromLocker, CustomerPaymentMethod, CustomerPaymentMethodsListResponse,
     DeleteTokenizeByDateRequest, DeleteTokenizeByTokenRequest, GetTokenizePayloadRequest,
-    GetTokenizePayloadResponse, PaymentMethodCreate, PaymentMethodDeleteResponse, PaymentMethodId,
-    PaymentMethodList, PaymentMethodListRequest, PaymentMethodListResponse, PaymentMethodResponse,
+    GetTokenizePayloadResponse, NetworkTokenResponse, NetworkTokenUpdateRequest,
+    PaymentMethodCreate, PaymentMethodDeleteResponse, PaymentMethodId, PaymentMethodList,
+    PaymentMethodListRequest, PaymentMethodListResponse, PaymentMethodResponse,
     PaymentMethodUpdate, TokenizePayloadEncrypted, TokenizePayloadRequest, TokenizedCardValue1,
     TokenizedCardValue2, TokenizedWalletValue1, TokenizedWalletValue2,
 };
@@ -93,6 +94,36 @@ impl PaymentMethodCreateExt for PaymentMethodCreate {
             .attach_printable("Invalid payment method issuer code"));
         }
 
+        let details = [
+            (self.card.is_some(), api_enums::PaymentMethod::Card, "card"),
+            (
+                self.wallet.is_some(),
+                api_enums::PaymentMethod::Wallet,
+                "wallet",
+            ),
+            (
+                self.bank_transfer.is_some(),
+                api_enums::PaymentMethod::BankTransfer,
+                "bank_transfer",
+            ),
+            (
+                self.bank_debit.is_some(),
+                api_enums::PaymentMethod::BankDebit,
+                "bank_debit",
+            ),
+        ];
+        if let Some((_, _, field_name)) = details.iter().find(|(is_present, payment_method, _)| {
+            *is_present && *payment_method != self.payment_method
+        }) {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "'{field_name}' cannot be provided for the payment method '{}'",
+                    self.payment_method
+                )
+            })
+            .attach_printable("Payment method details do not match the payment method"));
+        }
+
         Ok(())
     }
 
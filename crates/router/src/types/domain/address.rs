@@ -38,6 +38,8 @@ pub struct Address {
     pub modified_at: PrimitiveDateTime,
     pub customer_id: String,
     pub merchant_id: String,
+    #[serde(skip_serializing)]
+    pub is_saved: bool,
 }
 
 #[async_trait]
@@ -66,6 +68,7 @@ impl behaviour::Conversion for Address {
             modified_at: self.modified_at,
             customer_id: self.customer_id,
             merchant_id: self.merchant_id,
+            is_saved: self.is_saved,
         })
     }
 
@@ -93,6 +96,7 @@ impl behaviour::Conversion for Address {
                 modified_at: other.modified_at,
                 customer_id: other.customer_id,
                 merchant_id: other.merchant_id,
+                is_saved: other.is_saved,
             })
         }
         .await
@@ -125,6 +129,7 @@ impl behaviour::Conversion for Address {
             merchant_id: self.merchant_id,
             created_at: now,
             modified_at: now,
+            is_saved: self.is_saved,
         })
     }
 }
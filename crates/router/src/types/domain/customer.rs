@@ -1,4 +1,4 @@
-use common_utils::{crypto, date_time, pii};
+use common_utils::{crypto, date_time, errors::CryptoError, pii};
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
 use storage_models::{customers::CustomerUpdateInternal, encryption::Encryption};
@@ -21,6 +21,11 @@ pub struct Customer {
     pub metadata: Option<pii::SecretSerdeValue>,
     pub modified_at: PrimitiveDateTime,
     pub connector_customer: Option<serde_json::Value>,
+    pub default_payment_method_id: Option<String>,
+    pub email_blind_index: Option<String>,
+    pub phone_blind_index: Option<String>,
+    pub default_billing_address_id: Option<String>,
+    pub default_shipping_address_id: Option<String>,
 }
 
 #[async_trait::async_trait]
@@ -43,6 +48,11 @@ impl super::behaviour::Conversion for Customer {
             metadata: self.metadata,
             modified_at: self.modified_at,
             connector_customer: self.connector_customer,
+            default_payment_method_id: self.default_payment_method_id,
+            email_blind_index: self.email_blind_index,
+            phone_blind_index: self.phone_blind_index,
+            default_billing_address_id: self.default_billing_address_id,
+            default_shipping_address_id: self.default_shipping_address_id,
         })
     }
 
@@ -69,6 +79,11 @@ impl super::behaviour::Conversion for Customer {
                 metadata: item.metadata,
                 modified_at: item.modified_at,
                 connector_customer: item.connector_customer,
+                default_payment_method_id: item.default_payment_method_id,
+                email_blind_index: item.email_blind_index,
+                phone_blind_index: item.phone_blind_index,
+                default_billing_address_id: item.default_billing_address_id,
+                default_shipping_address_id: item.default_shipping_address_id,
             })
         }
         .await
@@ -91,6 +106,11 @@ impl super::behaviour::Conversion for Customer {
             created_at: now,
             modified_at: now,
             connector_customer: self.connector_customer,
+            default_payment_method_id: self.default_payment_method_id,
+            email_blind_index: self.email_blind_index,
+            phone_blind_index: self.phone_blind_index,
+            default_billing_address_id: self.default_billing_address_id,
+            default_shipping_address_id: self.default_shipping_address_id,
         })
     }
 }
@@ -105,10 +125,19 @@ pub enum CustomerUpdate {
         phone_country_code: Option<String>,
         metadata: Option<pii::SecretSerdeValue>,
         connector_customer: Option<serde_json::Value>,
+        email_blind_index: Option<String>,
+        phone_blind_index: Option<String>,
     },
     ConnectorCustomer {
         connector_customer: Option<serde_json::Value>,
     },
+    DefaultPaymentMethod {
+        default_payment_method_id: Option<String>,
+    },
+    DefaultAddresses {
+        default_billing_address_id: Option<Option<String>>,
+        default_shipping_address_id: Option<Option<String>>,
+    },
 }
 
 impl From<CustomerUpdate> for CustomerUpdateInternal {
@@ -122,6 +151,8 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 phone_country_code,
                 metadata,
                 connector_customer,
+                email_blind_index,
+                phone_blind_index,
             } => Self {
                 name: name.map(Encryption::from),
                 email: email.map(Encryption::from),
@@ -131,12 +162,49 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 metadata,
                 connector_customer,
                 modified_at: Some(date_time::now()),
+                default_payment_method_id: None,
+                email_blind_index,
+                phone_blind_index,
+                default_billing_address_id: None,
+                default_shipping_address_id: None,
             },
             CustomerUpdate::ConnectorCustomer { connector_customer } => Self {
                 connector_customer,
                 modified_at: Some(common_utils::date_time::now()),
                 ..Default::default()
             },
+            CustomerUpdate::DefaultPaymentMethod {
+                default_payment_method_id,
+            } => Self {
+                default_payment_method_id: Some(default_payment_method_id),
+                modified_at: Some(date_time::now()),
+                ..Default::default()
+            },
+            CustomerUpdate::DefaultAddresses {
+                default_billing_address_id,
+                default_shipping_address_id,
+            } => Self {
+                default_billing_address_id,
+                default_shipping_address_id,
+                modified_at: Some(date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
+
+/// Generates the blind index of the email of a customer, which is used for searching customers
+/// by their email
+pub fn get_email_blind_index(email: &str, key: &[u8]) -> CustomResult<String, CryptoError> {
+    types::generate_blind_index(&email.trim().to_lowercase(), key)
+}
+
+/// Generates the blind index of the phone number of a customer, which is used for searching
+/// customers by their phone number. Only the digits of the phone number are considered.
+pub fn get_phone_blind_index(phone: &str, key: &[u8]) -> CustomResult<String, CryptoError> {
+    let digits = phone
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>();
+    types::generate_blind_index(&digits, key)
+}
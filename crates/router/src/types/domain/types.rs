@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use common_utils::{
-    crypto,
+    crypto::{self, SignMessage},
     errors::{self, CustomResult},
     ext_traits::AsyncExt,
 };
@@ -224,3 +224,16 @@ where
     .await
     .transpose()
 }
+
+/// Context used for deriving the blind index key from the merchant key, so that the merchant key
+/// is only ever used for encryption
+const BLIND_INDEX_KEY_CONTEXT: &[u8] = b"blind_index";
+
+/// Generates a blind index of the value, which is a keyed hash allowing exact match lookups of
+/// the value without decrypting the stored data
+pub fn generate_blind_index(value: &str, key: &[u8]) -> CustomResult<String, errors::CryptoError> {
+    let blind_index_key = crypto::HmacSha256.sign_message(key, BLIND_INDEX_KEY_CONTEXT)?;
+    crypto::HmacSha256
+        .sign_message(&blind_index_key, value.as_bytes())
+        .map(hex::encode)
+}
@@ -3,6 +3,7 @@ pub mod api_keys;
 pub mod cards_info;
 pub mod configs;
 pub mod connector_response;
+pub mod customer_erasure;
 pub mod customers;
 pub mod dispute;
 pub mod enums;
@@ -17,7 +18,10 @@ pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
 pub mod process_tracker;
+pub mod reconciliation;
 pub mod reverse_lookup;
+pub mod vault_card;
+pub mod vault_token;
 
 mod query;
 pub mod refund;
@@ -26,8 +30,9 @@ pub mod refund;
 pub mod kv;
 
 pub use self::{
-    address::*, api_keys::*, cards_info::*, configs::*, connector_response::*, customers::*,
-    dispute::*, ephemeral_key::*, events::*, file::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, payment_attempt::*, payment_intent::*,
-    payment_method::*, process_tracker::*, refund::*, reverse_lookup::*,
+    address::*, api_keys::*, cards_info::*, configs::*, connector_response::*,
+    customer_erasure::*, customers::*, dispute::*, ephemeral_key::*, events::*, file::*,
+    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
+    payment_attempt::*, payment_intent::*, payment_method::*, process_tracker::*,
+    reconciliation::*, refund::*, reverse_lookup::*, vault_card::*, vault_token::*,
 };
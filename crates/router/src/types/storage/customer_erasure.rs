@@ -0,0 +1,4 @@
+pub use storage_models::customer_erasure::{
+    CustomerErasure, CustomerErasureNew, CustomerErasureTrackingData, CustomerErasureUpdate,
+    CustomerErasureUpdateInternal,
+};
@@ -1,3 +1,126 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing};
 pub use storage_models::customers::{Customer, CustomerNew, CustomerUpdateInternal};
+use storage_models::{errors, schema::customers::dsl};
+use time::PrimitiveDateTime;
 
 pub use crate::types::domain::CustomerUpdate;
+use crate::{connection::PgPooledConn, core::customers::REDACTED, logger};
+
+/// Constraints for listing the customers of a merchant. Since the customer details are stored
+/// encrypted, the email and phone number are matched by their blind index, which is not set for
+/// customers whose email or phone number has not been saved since the blind indexes were added.
+#[derive(Clone, Debug, Default)]
+pub struct CustomerListConstraints {
+    pub email_blind_index: Option<String>,
+    pub phone_blind_index: Option<String>,
+    pub starting_after: Option<String>,
+    pub ending_before: Option<String>,
+    pub limit: i64,
+    pub created: Option<PrimitiveDateTime>,
+    pub created_lt: Option<PrimitiveDateTime>,
+    pub created_gt: Option<PrimitiveDateTime>,
+    pub created_lte: Option<PrimitiveDateTime>,
+    pub created_gte: Option<PrimitiveDateTime>,
+}
+
+#[async_trait::async_trait]
+pub trait CustomerDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &CustomerListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl CustomerDbExt for Customer {
+    #[instrument(skip(conn))]
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &CustomerListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        // Customers which have been deleted are redacted rather than removed, and are excluded in
+        // the query so that they do not take up any of the page
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(
+                dsl::description
+                    .is_null()
+                    .or(dsl::description.ne(REDACTED))
+                    .or(dsl::phone_country_code.is_null())
+                    .or(dsl::phone_country_code.ne(REDACTED)),
+            )
+            .into_boxed();
+
+        if let Some(email_blind_index) = &constraints.email_blind_index {
+            filter = filter.filter(dsl::email_blind_index.eq(email_blind_index.to_owned()));
+        }
+        if let Some(phone_blind_index) = &constraints.phone_blind_index {
+            filter = filter.filter(dsl::phone_blind_index.eq(phone_blind_index.to_owned()));
+        }
+        if let Some(created) = constraints.created {
+            filter = filter.filter(dsl::created_at.eq(created));
+        }
+        if let Some(created_lt) = constraints.created_lt {
+            filter = filter.filter(dsl::created_at.lt(created_lt));
+        }
+        if let Some(created_gt) = constraints.created_gt {
+            filter = filter.filter(dsl::created_at.gt(created_gt));
+        }
+        if let Some(created_lte) = constraints.created_lte {
+            filter = filter.filter(dsl::created_at.le(created_lte));
+        }
+        if let Some(created_gte) = constraints.created_gte {
+            filter = filter.filter(dsl::created_at.ge(created_gte));
+        }
+        if let Some(starting_after) = &constraints.starting_after {
+            let id = Self::find_by_customer_id_merchant_id(conn, starting_after, merchant_id)
+                .await?
+                .id;
+            filter = filter.filter(dsl::id.lt(id));
+        }
+
+        // Customers are listed from the most recently created one. When paginating backwards,
+        // the customers closest to the cursor are fetched first and the page is reversed after.
+        let customers = match &constraints.ending_before {
+            Some(ending_before) => {
+                let id = Self::find_by_customer_id_merchant_id(conn, ending_before, merchant_id)
+                    .await?
+                    .id;
+                filter = filter
+                    .filter(dsl::id.gt(id))
+                    .order(dsl::id.asc())
+                    .limit(constraints.limit);
+
+                logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+                filter
+                    .get_results_async::<Self>(conn)
+                    .await
+                    .map(|mut customers| {
+                        customers.reverse();
+                        customers
+                    })
+            }
+            None => {
+                filter = filter.order(dsl::id.desc()).limit(constraints.limit);
+
+                logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+                filter.get_results_async(conn).await
+            }
+        };
+
+        customers
+            .into_report()
+            // The query built here returns an empty Vec when no records are found, and if any error does occur,
+            // it would be an internal database error, due to which we are raising a DatabaseError::Others error
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering customers by specified constraints")
+    }
+}
@@ -2,7 +2,9 @@ use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::errors::CustomResult;
 use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
 use error_stack::{IntoReport, ResultExt};
-pub use storage_models::dispute::{Dispute, DisputeNew, DisputeUpdate};
+pub use storage_models::dispute::{
+    Dispute, DisputeLifecycleTrackingData, DisputeNew, DisputeUpdate,
+};
 use storage_models::{errors, schema::dispute::dsl};
 
 use crate::{connection::PgPooledConn, logger, types::transformers::ForeignInto};
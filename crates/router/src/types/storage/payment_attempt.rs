@@ -1,5 +1,6 @@
 pub use storage_models::payment_attempt::{
-    PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate, PaymentAttemptUpdateInternal,
+    CaptureScheduleTrackingData, PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate,
+    PaymentAttemptUpdateInternal,
 };
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
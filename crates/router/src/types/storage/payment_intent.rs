@@ -5,7 +5,8 @@ use router_env::{instrument, tracing};
 pub use storage_models::{
     errors,
     payment_intent::{
-        PaymentIntent, PaymentIntentNew, PaymentIntentUpdate, PaymentIntentUpdateInternal,
+        PaymentIntent, PaymentIntentExpiryTrackingData, PaymentIntentNew, PaymentIntentUpdate,
+        PaymentIntentUpdateInternal,
     },
     schema::payment_intent::dsl,
 };
@@ -1,13 +1,21 @@
-use error_stack::ResultExt;
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use error_stack::{IntoReport, ResultExt};
 use serde::Serialize;
 pub use storage_models::process_tracker::{
     ProcessData, ProcessTracker, ProcessTrackerNew, ProcessTrackerUpdate,
-    ProcessTrackerUpdateInternal, SchedulerOptions,
+    ProcessTrackerUpdateInternal, RecurringJobTrackingData, SchedulerOptions,
 };
+use storage_models::{errors as storage_errors, schema::process_tracker::dsl};
 use time::PrimitiveDateTime;
 
 use crate::{
-    core::errors, db::StorageInterface, scheduler::metrics, types::storage::enums as storage_enums,
+    connection::PgPooledConn,
+    core::errors::{self, CustomResult},
+    db::StorageInterface,
+    logger,
+    scheduler::metrics,
+    types::{storage::enums as storage_enums, transformers::ForeignInto},
 };
 
 #[async_trait::async_trait]
@@ -108,3 +116,57 @@ impl ProcessTrackerExt for ProcessTracker {
         Ok(())
     }
 }
+
+#[async_trait::async_trait]
+pub trait ProcessTrackerDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        constraints: api_models::process_tracker::ProcessTrackerListConstraints,
+    ) -> CustomResult<Vec<Self>, storage_errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl ProcessTrackerDbExt for ProcessTracker {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        constraints: api_models::process_tracker::ProcessTrackerListConstraints,
+    ) -> CustomResult<Vec<Self>, storage_errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .order(dsl::schedule_time.desc())
+            .into_boxed();
+
+        if let Some(runner) = constraints.runner {
+            filter = filter.filter(dsl::runner.eq(runner));
+        }
+        if let Some(status) = constraints.status {
+            let storage_status: storage_enums::ProcessTrackerStatus = status.foreign_into();
+            filter = filter.filter(dsl::status.eq(storage_status));
+        }
+        if let Some(business_status) = constraints.business_status {
+            filter = filter.filter(dsl::business_status.eq(business_status));
+        }
+        if let Some(merchant_id) = constraints.merchant_id {
+            // The merchant is only recorded in the tracking data of the task
+            filter = filter.filter(
+                diesel::dsl::sql::<diesel::sql_types::Bool>("tracking_data ->> 'merchant_id' = ")
+                    .bind::<diesel::sql_types::Text, _>(merchant_id),
+            );
+        }
+        if let Some(schedule_time_lte) = constraints.schedule_time_lte {
+            filter = filter.filter(dsl::schedule_time.le(schedule_time_lte));
+        }
+        if let Some(schedule_time_gte) = constraints.schedule_time_gte {
+            filter = filter.filter(dsl::schedule_time.ge(schedule_time_gte));
+        }
+        filter = filter.limit(constraints.limit);
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        filter
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(storage_errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error filtering records by predicate")
+    }
+}
@@ -0,0 +1,58 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use error_stack::{IntoReport, ResultExt};
+pub use storage_models::reconciliation::{
+    ReconciliationRun, ReconciliationRunNew, ReconciliationRunUpdate,
+};
+use storage_models::{errors, schema::reconciliation_run::dsl};
+
+use crate::{connection::PgPooledConn, logger};
+
+#[async_trait::async_trait]
+pub trait ReconciliationRunDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: api_models::reconciliation::ReconciliationRunListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl ReconciliationRunDbExt for ReconciliationRun {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: api_models::reconciliation::ReconciliationRunListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(connector) = constraints.connector {
+            filter = filter.filter(dsl::connector.eq(connector.to_string()));
+        }
+        if let Some(status) = constraints.status {
+            filter = filter.filter(dsl::status.eq(status));
+        }
+        if let Some(created_lte) = constraints.created_lte {
+            filter = filter.filter(dsl::created_at.le(created_lte));
+        }
+        if let Some(created_gte) = constraints.created_gte {
+            filter = filter.filter(dsl::created_at.ge(created_gte));
+        }
+        if let Some(limit) = constraints.limit {
+            filter = filter.limit(limit);
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        filter
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error filtering records by predicate")
+    }
+}
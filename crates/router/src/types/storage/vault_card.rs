@@ -0,0 +1,3 @@
+pub use storage_models::vault_card::{
+    VaultCard, VaultCardNew, VaultCardUpdate, VaultCardUpdateInternal,
+};
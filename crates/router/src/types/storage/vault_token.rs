@@ -0,0 +1 @@
+pub use storage_models::vault_token::{VaultToken, VaultTokenNew};
@@ -7,6 +7,7 @@ use storage_models::enums as storage_enums;
 use super::domain;
 use crate::{
     core::errors,
+    scheduler::process_data,
     types::{api as api_types, storage},
 };
 
@@ -219,6 +220,8 @@ impl ForeignTryFrom<api_enums::IntentStatus> for storage_enums::EventType {
             api_enums::IntentStatus::Succeeded => Ok(Self::PaymentSucceeded),
             api_enums::IntentStatus::Failed => Ok(Self::PaymentFailed),
             api_enums::IntentStatus::Processing => Ok(Self::PaymentProcessing),
+            api_enums::IntentStatus::Expired => Ok(Self::PaymentExpired),
+            api_enums::IntentStatus::Cancelled => Ok(Self::PaymentCancelled),
             api_enums::IntentStatus::RequiresMerchantAction
             | api_enums::IntentStatus::RequiresCustomerAction => Ok(Self::ActionRequired),
             _ => Err(errors::ValidationError::IncorrectValueProvided {
@@ -431,6 +434,7 @@ impl ForeignFrom<storage_models::api_keys::ApiKey>
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            last_used: api_key.last_used,
         }
     }
 }
@@ -478,6 +482,18 @@ impl ForeignFrom<storage_enums::DisputeStatus> for api_enums::DisputeStatus {
     }
 }
 
+impl ForeignFrom<api_enums::ProcessTrackerStatus> for storage_enums::ProcessTrackerStatus {
+    fn foreign_from(status: api_enums::ProcessTrackerStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<storage_enums::ProcessTrackerStatus> for api_enums::ProcessTrackerStatus {
+    fn foreign_from(status: storage_enums::ProcessTrackerStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
 impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::DisputeStatus {
     type Error = errors::ValidationError;
 
@@ -528,6 +544,20 @@ impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponse {
     }
 }
 
+impl ForeignFrom<storage::CustomerErasure> for api_models::customers::CustomerErasureResponse {
+    fn foreign_from(customer_erasure: storage::CustomerErasure) -> Self {
+        Self {
+            erasure_id: customer_erasure.erasure_id,
+            customer_id: customer_erasure.customer_id,
+            status: customer_erasure.status,
+            erased_records: customer_erasure.erased_records,
+            error_message: customer_erasure.error_message,
+            created_at: customer_erasure.created_at,
+            completed_at: customer_erasure.completed_at,
+        }
+    }
+}
+
 impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponsePaymentsRetrieve {
     fn foreign_from(dispute: storage::Dispute) -> Self {
         Self {
@@ -546,6 +576,138 @@ impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponsePaym
     }
 }
 
+impl ForeignFrom<storage::ProcessTracker> for api_models::process_tracker::ProcessTrackerResponse {
+    fn foreign_from(process: storage::ProcessTracker) -> Self {
+        Self {
+            id: process.id,
+            name: process.name,
+            tag: process.tag,
+            runner: process.runner,
+            retry_count: process.retry_count,
+            schedule_time: process.schedule_time,
+            tracking_data: process.tracking_data,
+            business_status: process.business_status,
+            status: process.status.foreign_into(),
+            event: process.event,
+            admin_audit_trail: process.admin_audit_trail,
+            created_at: process.created_at,
+            updated_at: process.updated_at,
+        }
+    }
+}
+
+impl ForeignTryFrom<storage::ReconciliationRun>
+    for api_models::reconciliation::ReconciliationRunResponse
+{
+    type Error = error_stack::Report<errors::ParsingError>;
+
+    fn foreign_try_from(run: storage::ReconciliationRun) -> Result<Self, Self::Error> {
+        Ok(Self {
+            run_id: run.run_id,
+            connector: run.connector,
+            report_type: run.report_type,
+            report_source: run.report_source,
+            status: run.status,
+            period_start: run.period_start,
+            period_end: run.period_end,
+            total_records: run.total_records,
+            matched_records: run.matched_records,
+            mismatches: run.mismatches.parse_value("ReconciliationMismatch")?,
+            error_message: run.error_message,
+            created_at: run.created_at,
+            modified_at: run.modified_at,
+        })
+    }
+}
+
+impl ForeignFrom<api_models::retry_schedules::RetryMapping> for process_data::RetryMapping {
+    fn foreign_from(mapping: api_models::retry_schedules::RetryMapping) -> Self {
+        Self {
+            start_after: mapping.start_after,
+            frequency: mapping.frequency,
+            count: mapping.count,
+        }
+    }
+}
+
+impl ForeignFrom<process_data::RetryMapping> for api_models::retry_schedules::RetryMapping {
+    fn foreign_from(mapping: process_data::RetryMapping) -> Self {
+        Self {
+            start_after: mapping.start_after,
+            frequency: mapping.frequency,
+            count: mapping.count,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::retry_schedules::ConnectorRetrySchedule>
+    for process_data::ConnectorPTMapping
+{
+    fn foreign_from(schedule: api_models::retry_schedules::ConnectorRetrySchedule) -> Self {
+        Self {
+            default_mapping: schedule.default_mapping.foreign_into(),
+            custom_merchant_mapping: schedule
+                .custom_merchant_mapping
+                .into_iter()
+                .map(|(merchant_id, mapping)| (merchant_id, mapping.foreign_into()))
+                .collect(),
+            max_retries_count: schedule.max_retries_count,
+        }
+    }
+}
+
+impl ForeignFrom<process_data::ConnectorPTMapping>
+    for api_models::retry_schedules::ConnectorRetrySchedule
+{
+    fn foreign_from(mapping: process_data::ConnectorPTMapping) -> Self {
+        Self {
+            default_mapping: mapping.default_mapping.foreign_into(),
+            custom_merchant_mapping: mapping
+                .custom_merchant_mapping
+                .into_iter()
+                .map(|(merchant_id, mapping)| (merchant_id, mapping.foreign_into()))
+                .collect(),
+            max_retries_count: mapping.max_retries_count,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::retry_schedules::PaymentMethodRetrySchedule>
+    for process_data::PaymentMethodsPTMapping
+{
+    fn foreign_from(schedule: api_models::retry_schedules::PaymentMethodRetrySchedule) -> Self {
+        Self {
+            default_mapping: schedule.default_mapping.foreign_into(),
+            custom_pm_mapping: schedule
+                .custom_pm_mapping
+                .into_iter()
+                .map(|(payment_method, mapping)| {
+                    (payment_method.foreign_into(), mapping.foreign_into())
+                })
+                .collect(),
+            max_retries_count: schedule.max_retries_count,
+        }
+    }
+}
+
+impl ForeignFrom<process_data::PaymentMethodsPTMapping>
+    for api_models::retry_schedules::PaymentMethodRetrySchedule
+{
+    fn foreign_from(mapping: process_data::PaymentMethodsPTMapping) -> Self {
+        Self {
+            default_mapping: mapping.default_mapping.foreign_into(),
+            custom_pm_mapping: mapping
+                .custom_pm_mapping
+                .into_iter()
+                .map(|(payment_method, mapping)| {
+                    (payment_method.foreign_into(), mapping.foreign_into())
+                })
+                .collect(),
+            max_retries_count: mapping.max_retries_count,
+        }
+    }
+}
+
 impl ForeignFrom<storage::FileMetadata> for api_models::files::FileMetadataResponse {
     fn foreign_from(file_metadata: storage::FileMetadata) -> Self {
         Self {
@@ -4,6 +4,8 @@ pub mod ext_traits;
 
 #[cfg(feature = "kv_store")]
 pub mod storage_partitioning;
+#[cfg(test)]
+pub mod test_utils;
 
 pub use common_utils::{
     crypto,
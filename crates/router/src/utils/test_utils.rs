@@ -0,0 +1,47 @@
+//! Fixtures shared by the unit tests of the crate.
+
+use common_utils::crypto::Encryptable;
+use masking::Secret;
+use time::macros::datetime;
+
+use crate::types::{domain, storage::enums};
+
+/// Key of the merchant, used for encrypting the merchant's data in tests
+pub const MERCHANT_KEY: [u8; 32] = [1; 32];
+
+/// Merchant account `merchant_1` with none of the optional details set
+pub fn merchant_account() -> domain::MerchantAccount {
+    domain::MerchantAccount {
+        id: None,
+        merchant_id: "merchant_1".into(),
+        return_url: None,
+        enable_payment_response_hash: false,
+        payment_response_hash_key: None,
+        redirect_to_merchant_with_http_post: false,
+        merchant_name: None,
+        merchant_details: None,
+        webhook_details: None,
+        sub_merchants_enabled: None,
+        parent_merchant_id: None,
+        publishable_key: None,
+        storage_scheme: enums::MerchantStorageScheme::PostgresOnly,
+        locker_id: None,
+        metadata: None,
+        routing_algorithm: None,
+        primary_business_details: serde_json::json!([]),
+        frm_routing_algorithm: None,
+        created_at: datetime!(2023-07-01 0:00),
+        modified_at: datetime!(2023-07-01 0:00),
+        intent_fulfillment_time: None,
+        capture_schedule: None,
+    }
+}
+
+/// Key store of merchant `merchant_1`, holding the specified key
+pub fn merchant_key_store(key: [u8; 32]) -> domain::MerchantKeyStore {
+    domain::MerchantKeyStore {
+        merchant_id: "merchant_1".into(),
+        key: Encryptable::new(Secret::new(key.to_vec()), Vec::new().into()),
+        created_at: datetime!(2023-07-01 0:00),
+    }
+}
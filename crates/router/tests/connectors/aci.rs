@@ -64,6 +64,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             webhook_url: None,
             complete_authorize_url: None,
             customer_id: None,
+            installment_count: None,
         },
         response: Err(types::ErrorResponse::default()),
         payment_method_id: None,
@@ -76,6 +77,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         payment_method_token: None,
         connector_customer: None,
         preprocessing_id: None,
+        network_token: None,
     }
 }
 
@@ -120,6 +122,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         payment_method_token: None,
         connector_customer: None,
         preprocessing_id: None,
+        network_token: None,
     }
 }
 
@@ -92,6 +92,7 @@ impl AdyenTest {
             webhook_url: None,
             complete_authorize_url: None,
             customer_id: None,
+            installment_count: None,
         })
     }
 }
@@ -64,6 +64,7 @@ impl CashtocodeTest {
             webhook_url: None,
             complete_authorize_url: None,
             customer_id: Some("John Doe".to_owned()),
+            installment_count: None,
         })
     }
 
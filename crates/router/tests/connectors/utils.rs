@@ -403,6 +403,7 @@ pub trait ConnectorActions: Connector {
             payment_method_token: None,
             connector_customer: None,
             preprocessing_id: None,
+            network_token: None,
         }
     }
 
@@ -520,6 +521,7 @@ impl Default for PaymentAuthorizeType {
             complete_authorize_url: None,
             webhook_url: None,
             customer_id: None,
+            installment_count: None,
         };
         Self(data)
     }
@@ -95,6 +95,7 @@ impl WorldlineTest {
             webhook_url: None,
             complete_authorize_url: None,
             customer_id: None,
+            installment_count: None,
         })
     }
 }
@@ -86,8 +86,26 @@ pub enum Flow {
     CustomersUpdate,
     /// Customers delete flow.
     CustomersDelete,
+    /// Customers list flow.
+    CustomersList,
     /// Customers get mandates flow.
     CustomersGetMandates,
+    /// Customers export flow.
+    CustomersExport,
+    /// Customers erase flow.
+    CustomersErase,
+    /// Customers erasure retrieve flow.
+    CustomersErasureRetrieve,
+    /// Customer addresses create flow.
+    CustomerAddressesCreate,
+    /// Customer addresses list flow.
+    CustomerAddressesList,
+    /// Customer addresses retrieve flow.
+    CustomerAddressesRetrieve,
+    /// Customer addresses update flow.
+    CustomerAddressesUpdate,
+    /// Customer addresses delete flow.
+    CustomerAddressesDelete,
     /// Create an Ephemeral Key.
     EphemeralKeyCreate,
     /// Delete an Ephemeral Key.
@@ -104,12 +122,16 @@ pub enum Flow {
     PaymentMethodsList,
     /// Customer payment methods list flow.
     CustomerPaymentMethodsList,
+    /// Customer default payment method set flow.
+    CustomerPaymentMethodsSetDefault,
     /// Payment methods retrieve flow.
     PaymentMethodsRetrieve,
     /// Payment methods update flow.
     PaymentMethodsUpdate,
     /// Payment methods delete flow.
     PaymentMethodsDelete,
+    /// Payment methods network token update flow.
+    PaymentMethodsNetworkTokenUpdate,
     /// Payments create flow.
     PaymentsCreate,
     /// Payments Retrieve flow.
@@ -164,6 +186,8 @@ pub enum Flow {
     ApiKeyRevoke,
     /// API Key list flow
     ApiKeyList,
+    /// API Key list unused flow
+    ApiKeyListUnused,
     /// Dispute Retrieve flow
     DisputesRetrieve,
     /// Dispute List flow
@@ -186,6 +210,38 @@ pub enum Flow {
     RetrieveDisputeEvidence,
     /// Invalidate cache flow
     CacheInvalidate,
+    /// Process tracker task list flow
+    ProcessTrackerList,
+    /// Process tracker task retrieve flow
+    ProcessTrackerRetrieve,
+    /// Process tracker task retry flow
+    ProcessTrackerRetry,
+    /// Process tracker task reschedule flow
+    ProcessTrackerReschedule,
+    /// Process tracker task cancel flow
+    ProcessTrackerCancel,
+    /// Connector retry schedule retrieve flow
+    ConnectorRetryScheduleRetrieve,
+    /// Connector retry schedule update flow
+    ConnectorRetryScheduleUpdate,
+    /// Connector retry schedule delete flow
+    ConnectorRetryScheduleDelete,
+    /// Connector retry timeline preview flow
+    ConnectorRetryTimelinePreview,
+    /// Payment method retry schedule retrieve flow
+    PaymentMethodRetryScheduleRetrieve,
+    /// Payment method retry schedule update flow
+    PaymentMethodRetryScheduleUpdate,
+    /// Payment method retry schedule delete flow
+    PaymentMethodRetryScheduleDelete,
+    /// Payment method retry timeline preview flow
+    PaymentMethodRetryTimelinePreview,
+    /// Reconciliation run create flow
+    ReconciliationRunCreate,
+    /// Reconciliation run retrieve flow
+    ReconciliationRunRetrieve,
+    /// Reconciliation run list flow
+    ReconciliationRunList,
 }
 
 ///
@@ -81,6 +81,21 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
 #[diesel(sql_type = diesel::sql_types::Text)]
 pub struct HashedApiKey(String);
 
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ApiKeyExpiryTrackingData {
+    pub key_id: String,
+    pub merchant_id: String,
+    pub api_key_name: String,
+    pub prefix: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub api_key_expiry: PrimitiveDateTime,
+    /// The number of days before expiry at which reminders are sent, in descending order
+    pub expiry_reminder_days: Vec<u8>,
+    /// Index in `expiry_reminder_days` of the reminder to be sent next
+    #[serde(default)]
+    pub reminder_index: usize,
+}
+
 impl HashedApiKey {
     pub fn into_inner(self) -> String {
         self.0
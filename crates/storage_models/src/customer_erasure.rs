@@ -0,0 +1,94 @@
+use common_utils::custom_serde;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::customer_erasure};
+
+#[derive(Clone, Debug, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = customer_erasure)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerErasureNew {
+    pub erasure_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub status: storage_enums::CustomerErasureStatus,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = customer_erasure)]
+pub struct CustomerErasure {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub erasure_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub status: storage_enums::CustomerErasureStatus,
+    pub erased_records: serde_json::Value,
+    pub error_message: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CustomerErasureTrackingData {
+    pub erasure_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+}
+
+#[derive(Debug)]
+pub enum CustomerErasureUpdate {
+    Completed { erased_records: serde_json::Value },
+    Failed { error_message: String },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = customer_erasure)]
+pub struct CustomerErasureUpdateInternal {
+    status: Option<storage_enums::CustomerErasureStatus>,
+    erased_records: Option<serde_json::Value>,
+    error_message: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+    completed_at: Option<PrimitiveDateTime>,
+}
+
+impl CustomerErasureUpdateInternal {
+    pub fn apply_changeset(self, source: CustomerErasure) -> CustomerErasure {
+        CustomerErasure {
+            status: self.status.unwrap_or(source.status),
+            erased_records: self.erased_records.unwrap_or(source.erased_records),
+            error_message: self.error_message.or(source.error_message),
+            modified_at: self
+                .modified_at
+                .unwrap_or_else(common_utils::date_time::now),
+            completed_at: self.completed_at.or(source.completed_at),
+            ..source
+        }
+    }
+}
+
+impl From<CustomerErasureUpdate> for CustomerErasureUpdateInternal {
+    fn from(customer_erasure_update: CustomerErasureUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match customer_erasure_update {
+            CustomerErasureUpdate::Completed { erased_records } => Self {
+                status: Some(storage_enums::CustomerErasureStatus::Completed),
+                erased_records: Some(erased_records),
+                modified_at: Some(now),
+                completed_at: Some(now),
+                ..Default::default()
+            },
+            CustomerErasureUpdate::Failed { error_message } => Self {
+                status: Some(storage_enums::CustomerErasureStatus::Failed),
+                error_message: Some(error_message),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+        }
+    }
+}
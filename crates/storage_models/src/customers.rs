@@ -18,6 +18,11 @@ pub struct CustomerNew {
     pub connector_customer: Option<serde_json::Value>,
     pub created_at: PrimitiveDateTime,
     pub modified_at: PrimitiveDateTime,
+    pub default_payment_method_id: Option<String>,
+    pub email_blind_index: Option<String>,
+    pub phone_blind_index: Option<String>,
+    pub default_billing_address_id: Option<String>,
+    pub default_shipping_address_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Identifiable, Queryable)]
@@ -35,6 +40,11 @@ pub struct Customer {
     pub metadata: Option<pii::SecretSerdeValue>,
     pub connector_customer: Option<serde_json::Value>,
     pub modified_at: PrimitiveDateTime,
+    pub default_payment_method_id: Option<String>,
+    pub email_blind_index: Option<String>,
+    pub phone_blind_index: Option<String>,
+    pub default_billing_address_id: Option<String>,
+    pub default_shipping_address_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -48,4 +58,38 @@ pub struct CustomerUpdateInternal {
     pub metadata: Option<pii::SecretSerdeValue>,
     pub modified_at: Option<PrimitiveDateTime>,
     pub connector_customer: Option<serde_json::Value>,
+    pub default_payment_method_id: Option<Option<String>>,
+    pub email_blind_index: Option<String>,
+    pub phone_blind_index: Option<String>,
+    pub default_billing_address_id: Option<Option<String>>,
+    pub default_shipping_address_id: Option<Option<String>>,
+}
+
+impl CustomerUpdateInternal {
+    pub fn apply_changeset(self, source: Customer) -> Customer {
+        Customer {
+            name: self.name.or(source.name),
+            email: self.email.or(source.email),
+            phone: self.phone.or(source.phone),
+            description: self.description.or(source.description),
+            phone_country_code: self.phone_country_code.or(source.phone_country_code),
+            metadata: self.metadata.or(source.metadata),
+            connector_customer: self.connector_customer.or(source.connector_customer),
+            default_payment_method_id: self
+                .default_payment_method_id
+                .unwrap_or(source.default_payment_method_id),
+            email_blind_index: self.email_blind_index.or(source.email_blind_index),
+            phone_blind_index: self.phone_blind_index.or(source.phone_blind_index),
+            default_billing_address_id: self
+                .default_billing_address_id
+                .unwrap_or(source.default_billing_address_id),
+            default_shipping_address_id: self
+                .default_shipping_address_id
+                .unwrap_or(source.default_shipping_address_id),
+            modified_at: self
+                .modified_at
+                .unwrap_or_else(common_utils::date_time::now),
+            ..source
+        }
+    }
 }
@@ -57,6 +57,19 @@ pub struct Dispute {
     pub evidence: Secret<serde_json::Value>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DisputeLifecycleTrackingData {
+    pub dispute_id: String,
+    pub merchant_id: String,
+    /// The number of hours before the evidence deadline at which reminders are sent, in
+    /// descending order
+    pub evidence_reminder_hours: Vec<u16>,
+    /// Index of the lifecycle step to be executed next, `None` until the auto accept rules of the
+    /// merchant have been evaluated
+    #[serde(default)]
+    pub step_index: Option<usize>,
+}
+
 #[derive(Debug)]
 pub enum DisputeUpdate {
     Update {
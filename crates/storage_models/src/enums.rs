@@ -285,6 +285,8 @@ pub enum EventClass {
     Payments,
     Refunds,
     Disputes,
+    ApiKeys,
+    PaymentMethods,
 }
 
 #[derive(
@@ -305,6 +307,8 @@ pub enum EventObjectType {
     PaymentDetails,
     RefundDetails,
     DisputeDetails,
+    ApiKeyDetails,
+    PaymentMethodDetails,
 }
 
 #[derive(
@@ -326,6 +330,8 @@ pub enum EventType {
     PaymentSucceeded,
     PaymentFailed,
     PaymentProcessing,
+    PaymentExpired,
+    PaymentCancelled,
     ActionRequired,
     RefundSucceeded,
     RefundFailed,
@@ -336,6 +342,9 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    DisputeEvidenceDue,
+    ApiKeyExpiring,
+    PaymentMethodUpdated,
 }
 
 #[derive(
@@ -365,6 +374,7 @@ pub enum IntentStatus {
     #[default]
     RequiresConfirmation,
     RequiresCapture,
+    Expired,
 }
 
 #[derive(
@@ -497,6 +507,7 @@ pub enum WalletIssuer {
     serde::Serialize,
     strum::Display,
     strum::EnumString,
+    frunk::LabelledGeneric,
 )]
 #[router_derive::diesel_enum(storage_type = "pg_enum")]
 #[serde(rename_all = "snake_case")]
@@ -3,6 +3,7 @@ pub mod api_keys;
 pub mod cards_info;
 pub mod configs;
 pub mod connector_response;
+pub mod customer_erasure;
 pub mod customers;
 pub mod dispute;
 pub mod encryption;
@@ -23,10 +24,13 @@ pub mod payment_intent;
 pub mod payment_method;
 pub mod process_tracker;
 pub mod query;
+pub mod reconciliation;
 pub mod refund;
 pub mod reverse_lookup;
 #[allow(unused_qualifications)]
 pub mod schema;
+pub mod vault_card;
+pub mod vault_token;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 
@@ -71,6 +71,8 @@ pub enum MandateUpdate {
     ConnectorReferenceUpdate {
         connector_mandate_ids: Option<pii::SecretSerdeValue>,
     },
+    /// Redacts the personal data of the customer captured when the mandate was accepted
+    RedactionUpdate,
 }
 
 #[derive(Clone, Eq, PartialEq, Copy, Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -85,6 +87,9 @@ pub struct MandateUpdateInternal {
     mandate_status: Option<storage_enums::MandateStatus>,
     amount_captured: Option<i64>,
     connector_mandate_ids: Option<pii::SecretSerdeValue>,
+    customer_ip_address: Option<Secret<String, pii::IpAddress>>,
+    customer_user_agent: Option<String>,
+    metadata: Option<pii::SecretSerdeValue>,
 }
 
 impl From<MandateUpdate> for MandateUpdateInternal {
@@ -94,11 +99,17 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 mandate_status: Some(mandate_status),
                 connector_mandate_ids: None,
                 amount_captured: None,
+                customer_ip_address: None,
+                customer_user_agent: None,
+                metadata: None,
             },
             MandateUpdate::CaptureAmountUpdate { amount_captured } => Self {
                 mandate_status: None,
                 amount_captured,
                 connector_mandate_ids: None,
+                customer_ip_address: None,
+                customer_user_agent: None,
+                metadata: None,
             },
             MandateUpdate::ConnectorReferenceUpdate {
                 connector_mandate_ids: connector_mandate_id,
@@ -106,6 +117,12 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 connector_mandate_ids: connector_mandate_id,
                 ..Default::default()
             },
+            MandateUpdate::RedactionUpdate => Self {
+                customer_ip_address: Some(Secret::new(pii::REDACTED.to_string())),
+                customer_user_agent: Some(pii::REDACTED.to_string()),
+                metadata: Some(serde_json::json!({}).into()),
+                ..Default::default()
+            },
         }
     }
 }
@@ -35,6 +35,7 @@ pub struct MerchantAccount {
     pub created_at: time::PrimitiveDateTime,
     pub modified_at: time::PrimitiveDateTime,
     pub frm_routing_algorithm: Option<serde_json::Value>,
+    pub capture_schedule: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -59,6 +60,7 @@ pub struct MerchantAccountNew {
     pub created_at: time::PrimitiveDateTime,
     pub modified_at: time::PrimitiveDateTime,
     pub frm_routing_algorithm: Option<serde_json::Value>,
+    pub capture_schedule: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -82,4 +84,44 @@ pub struct MerchantAccountUpdateInternal {
     pub modified_at: Option<time::PrimitiveDateTime>,
     pub intent_fulfillment_time: Option<i64>,
     pub frm_routing_algorithm: Option<serde_json::Value>,
+    pub capture_schedule: Option<serde_json::Value>,
+}
+
+impl MerchantAccountUpdateInternal {
+    pub fn apply_changeset(self, source: MerchantAccount) -> MerchantAccount {
+        MerchantAccount {
+            merchant_name: self.merchant_name.or(source.merchant_name),
+            merchant_details: self.merchant_details.or(source.merchant_details),
+            return_url: self.return_url.or(source.return_url),
+            webhook_details: self.webhook_details.or(source.webhook_details),
+            sub_merchants_enabled: self.sub_merchants_enabled.or(source.sub_merchants_enabled),
+            parent_merchant_id: self.parent_merchant_id.or(source.parent_merchant_id),
+            enable_payment_response_hash: self
+                .enable_payment_response_hash
+                .unwrap_or(source.enable_payment_response_hash),
+            payment_response_hash_key: self
+                .payment_response_hash_key
+                .or(source.payment_response_hash_key),
+            redirect_to_merchant_with_http_post: self
+                .redirect_to_merchant_with_http_post
+                .unwrap_or(source.redirect_to_merchant_with_http_post),
+            publishable_key: self.publishable_key.or(source.publishable_key),
+            storage_scheme: self.storage_scheme.unwrap_or(source.storage_scheme),
+            locker_id: self.locker_id.or(source.locker_id),
+            metadata: self.metadata.or(source.metadata),
+            routing_algorithm: self.routing_algorithm.or(source.routing_algorithm),
+            primary_business_details: self
+                .primary_business_details
+                .unwrap_or(source.primary_business_details),
+            modified_at: self
+                .modified_at
+                .unwrap_or_else(common_utils::date_time::now),
+            intent_fulfillment_time: self
+                .intent_fulfillment_time
+                .or(source.intent_fulfillment_time),
+            frm_routing_algorithm: self.frm_routing_algorithm.or(source.frm_routing_algorithm),
+            capture_schedule: self.capture_schedule.or(source.capture_schedule),
+            ..source
+        }
+    }
 }
@@ -50,6 +50,7 @@ pub struct PaymentAttempt {
     // providing a location to store mandate details intermediately for transaction
     pub mandate_details: Option<storage_enums::MandateDataType>,
     pub error_reason: Option<String>,
+    pub installment_count: Option<i16>,
 }
 
 #[derive(
@@ -98,6 +99,7 @@ pub struct PaymentAttemptNew {
     pub preprocessing_step_id: Option<String>,
     pub mandate_details: Option<storage_enums::MandateDataType>,
     pub error_reason: Option<String>,
+    pub installment_count: Option<i16>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -115,6 +117,7 @@ pub enum PaymentAttemptUpdate {
         business_sub_label: Option<String>,
         amount_to_capture: Option<i64>,
         capture_method: Option<storage_enums::CaptureMethod>,
+        installment_count: Option<i16>,
     },
     UpdateTrackers {
         payment_token: Option<String>,
@@ -138,6 +141,7 @@ pub enum PaymentAttemptUpdate {
         payment_experience: Option<storage_enums::PaymentExperience>,
         business_sub_label: Option<String>,
         straight_through_algorithm: Option<serde_json::Value>,
+        installment_count: Option<i16>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -182,6 +186,15 @@ pub enum PaymentAttemptUpdate {
         preprocessing_step_id: Option<String>,
         connector_transaction_id: Option<String>,
     },
+    /// Redacts the browser and payment method details of the customer
+    RedactionUpdate,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CaptureScheduleTrackingData {
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub attempt_id: String,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -212,6 +225,7 @@ pub struct PaymentAttemptUpdateInternal {
     preprocessing_step_id: Option<String>,
     error_reason: Option<Option<String>>,
     capture_method: Option<storage_enums::CaptureMethod>,
+    installment_count: Option<i16>,
 }
 
 impl PaymentAttemptUpdate {
@@ -232,12 +246,14 @@ impl PaymentAttemptUpdate {
                 .payment_method_id
                 .unwrap_or(source.payment_method_id),
             browser_info: pa_update.browser_info.or(source.browser_info),
+            payment_method_data: pa_update.payment_method_data.or(source.payment_method_data),
             modified_at: common_utils::date_time::now(),
             payment_token: pa_update.payment_token.or(source.payment_token),
             connector_metadata: pa_update.connector_metadata.or(source.connector_metadata),
             preprocessing_step_id: pa_update
                 .preprocessing_step_id
                 .or(source.preprocessing_step_id),
+            installment_count: pa_update.installment_count.or(source.installment_count),
             ..source
         }
     }
@@ -260,6 +276,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 business_sub_label,
                 amount_to_capture,
                 capture_method,
+                installment_count,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -275,6 +292,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 business_sub_label,
                 amount_to_capture,
                 capture_method,
+                installment_count,
                 ..Default::default()
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
@@ -298,6 +316,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_experience,
                 business_sub_label,
                 straight_through_algorithm,
+                installment_count,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -313,6 +332,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_experience,
                 business_sub_label,
                 straight_through_algorithm,
+                installment_count,
                 ..Default::default()
             },
             PaymentAttemptUpdate::VoidUpdate {
@@ -413,6 +433,12 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 connector_transaction_id,
                 ..Default::default()
             },
+            PaymentAttemptUpdate::RedactionUpdate => Self {
+                browser_info: Some(serde_json::json!({})),
+                payment_method_data: Some(serde_json::json!({})),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
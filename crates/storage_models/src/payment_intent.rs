@@ -136,6 +136,14 @@ pub enum PaymentIntentUpdate {
         status: storage_enums::IntentStatus,
         active_attempt_id: String,
     },
+    /// Redacts the personal data of the customer provided with the payment
+    RedactionUpdate,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PaymentIntentExpiryTrackingData {
+    pub payment_id: String,
+    pub merchant_id: String,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -192,6 +200,8 @@ impl PaymentIntentUpdate {
                 .or(source.shipping_address_id),
             modified_at: common_utils::date_time::now(),
             order_details: internal_update.order_details.or(source.order_details),
+            description: internal_update.description.or(source.description),
+            udf: internal_update.udf.or(source.udf),
             ..source
         }
     }
@@ -307,6 +317,14 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 active_attempt_id: Some(active_attempt_id),
                 ..Default::default()
             },
+            PaymentIntentUpdate::RedactionUpdate => Self {
+                description: Some(pii::REDACTED.to_string()),
+                metadata: Some(serde_json::json!({}).into()),
+                order_details: Some(Vec::new()),
+                udf: Some(serde_json::json!({}).into()),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
@@ -315,9 +333,9 @@ fn make_client_secret_null_based_on_status(
     status: storage_enums::IntentStatus,
 ) -> Option<Option<String>> {
     match status {
-        storage_enums::IntentStatus::Succeeded | storage_enums::IntentStatus::Cancelled => {
-            Some(None)
-        }
+        storage_enums::IntentStatus::Succeeded
+        | storage_enums::IntentStatus::Cancelled
+        | storage_enums::IntentStatus::Expired => Some(None),
         storage_enums::IntentStatus::Processing
         | storage_enums::IntentStatus::RequiresCustomerAction
         | storage_enums::IntentStatus::RequiresMerchantAction
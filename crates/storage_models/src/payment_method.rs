@@ -4,7 +4,7 @@ use masking::Secret;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
-use crate::{enums as storage_enums, schema::payment_methods};
+use crate::{encryption::Encryption, enums as storage_enums, schema::payment_methods};
 
 #[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable)]
 #[diesel(table_name = payment_methods)]
@@ -32,6 +32,26 @@ pub struct PaymentMethod {
     pub payment_method_issuer: Option<String>,
     pub payment_method_issuer_code: Option<storage_enums::PaymentMethodIssuerCode>,
     pub metadata: Option<pii::SecretSerdeValue>,
+    pub payment_method_data: Option<Encryption>,
+    pub card_fingerprint: Option<String>,
+    pub network_token_reference: Option<String>,
+    pub network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    pub network_token_data: Option<Encryption>,
+    pub card_expires_at: Option<PrimitiveDateTime>,
+    pub card_account_status: Option<storage_enums::CardAccountStatus>,
+    pub account_updater_checked_at: Option<PrimitiveDateTime>,
+    pub last_used_at: Option<PrimitiveDateTime>,
+    pub card_reference: Option<String>,
+}
+
+impl PaymentMethod {
+    /// Returns the reference of the card in the locker, which is the payment method ID unless the
+    /// card has been replaced in the locker since the payment method was created
+    pub fn get_card_reference(&self) -> &str {
+        self.card_reference
+            .as_deref()
+            .unwrap_or(&self.payment_method_id)
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Insertable, Queryable, router_derive::DebugAsDisplay)]
@@ -57,6 +77,16 @@ pub struct PaymentMethodNew {
     pub created_at: PrimitiveDateTime,
     pub last_modified: PrimitiveDateTime,
     pub metadata: Option<pii::SecretSerdeValue>,
+    pub payment_method_data: Option<Encryption>,
+    pub card_fingerprint: Option<String>,
+    pub network_token_reference: Option<String>,
+    pub network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    pub network_token_data: Option<Encryption>,
+    pub card_expires_at: Option<PrimitiveDateTime>,
+    pub card_account_status: Option<storage_enums::CardAccountStatus>,
+    pub account_updater_checked_at: Option<PrimitiveDateTime>,
+    pub last_used_at: Option<PrimitiveDateTime>,
+    pub card_reference: Option<String>,
 }
 
 impl Default for PaymentMethodNew {
@@ -84,6 +114,16 @@ impl Default for PaymentMethodNew {
             created_at: now,
             last_modified: now,
             metadata: Option::default(),
+            payment_method_data: Option::default(),
+            card_fingerprint: Option::default(),
+            network_token_reference: Option::default(),
+            network_token_status: Option::default(),
+            network_token_data: Option::default(),
+            card_expires_at: Option::default(),
+            card_account_status: Option::default(),
+            account_updater_checked_at: Option::default(),
+            last_used_at: Option::default(),
+            card_reference: Option::default(),
         }
     }
 }
@@ -96,27 +136,104 @@ pub struct TokenizeCoreWorkflow {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub enum PaymentMethodUpdate {
-    MetadataUpdate { metadata: Option<serde_json::Value> },
+    MetadataUpdate {
+        metadata: Option<serde_json::Value>,
+    },
+    CardUpdate {
+        card_reference: Option<String>,
+        card_fingerprint: Option<String>,
+        card_expires_at: Option<PrimitiveDateTime>,
+    },
+    NetworkTokenUpdate {
+        network_token_reference: Option<String>,
+        network_token_status: storage_enums::NetworkTokenStatus,
+        network_token_data: Option<Encryption>,
+    },
+    AccountUpdaterUpdate {
+        card_account_status: Option<storage_enums::CardAccountStatus>,
+        account_updater_checked_at: PrimitiveDateTime,
+    },
+    LastUsedUpdate {
+        last_used_at: PrimitiveDateTime,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
 #[diesel(table_name = payment_methods)]
 pub struct PaymentMethodUpdateInternal {
     metadata: Option<serde_json::Value>,
+    card_reference: Option<String>,
+    card_fingerprint: Option<String>,
+    network_token_reference: Option<String>,
+    network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    network_token_data: Option<Encryption>,
+    card_expires_at: Option<PrimitiveDateTime>,
+    card_account_status: Option<storage_enums::CardAccountStatus>,
+    account_updater_checked_at: Option<PrimitiveDateTime>,
+    last_used_at: Option<PrimitiveDateTime>,
 }
 
 impl PaymentMethodUpdateInternal {
     pub fn create_payment_method(self, source: PaymentMethod) -> PaymentMethod {
-        let metadata = self.metadata.map(Secret::new);
-
-        PaymentMethod { metadata, ..source }
+        PaymentMethod {
+            metadata: self.metadata.map(Secret::new).or(source.metadata),
+            card_reference: self.card_reference.or(source.card_reference),
+            card_fingerprint: self.card_fingerprint.or(source.card_fingerprint),
+            network_token_reference: self
+                .network_token_reference
+                .or(source.network_token_reference),
+            network_token_status: self.network_token_status.or(source.network_token_status),
+            network_token_data: self.network_token_data.or(source.network_token_data),
+            card_expires_at: self.card_expires_at.or(source.card_expires_at),
+            card_account_status: self.card_account_status.or(source.card_account_status),
+            account_updater_checked_at: self
+                .account_updater_checked_at
+                .or(source.account_updater_checked_at),
+            last_used_at: self.last_used_at.or(source.last_used_at),
+            ..source
+        }
     }
 }
 
 impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
     fn from(payment_method_update: PaymentMethodUpdate) -> Self {
         match payment_method_update {
-            PaymentMethodUpdate::MetadataUpdate { metadata } => Self { metadata },
+            PaymentMethodUpdate::MetadataUpdate { metadata } => Self {
+                metadata,
+                ..Default::default()
+            },
+            PaymentMethodUpdate::CardUpdate {
+                card_reference,
+                card_fingerprint,
+                card_expires_at,
+            } => Self {
+                card_reference,
+                card_fingerprint,
+                card_expires_at,
+                ..Default::default()
+            },
+            PaymentMethodUpdate::NetworkTokenUpdate {
+                network_token_reference,
+                network_token_status,
+                network_token_data,
+            } => Self {
+                network_token_reference,
+                network_token_status: Some(network_token_status),
+                network_token_data,
+                ..Default::default()
+            },
+            PaymentMethodUpdate::AccountUpdaterUpdate {
+                card_account_status,
+                account_updater_checked_at,
+            } => Self {
+                card_account_status,
+                account_updater_checked_at: Some(account_updater_checked_at),
+                ..Default::default()
+            },
+            PaymentMethodUpdate::LastUsedUpdate { last_used_at } => Self {
+                last_used_at: Some(last_used_at),
+                ..Default::default()
+            },
         }
     }
 }
@@ -35,6 +35,22 @@ pub struct ProcessTracker {
     pub created_at: PrimitiveDateTime,
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub updated_at: PrimitiveDateTime,
+    /// Consumer executing the task, which holds it until the lease expires
+    pub lease_owner: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub lease_expires_at: Option<PrimitiveDateTime>,
+    /// Actions performed on the task through the admin APIs
+    #[serde(default)]
+    #[diesel(deserialize_as = super::DieselArray<serde_json::Value>)]
+    pub admin_audit_trail: Vec<serde_json::Value>,
+}
+
+/// Tracking data of the tasks created for the occurrences of recurring scheduler jobs.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RecurringJobTrackingData {
+    pub job_name: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub scheduled_at: PrimitiveDateTime,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -75,6 +91,13 @@ pub enum ProcessTrackerUpdate {
         retry_count: i32,
         schedule_time: PrimitiveDateTime,
     },
+    AdminUpdate {
+        status: storage_enums::ProcessTrackerStatus,
+        business_status: Option<String>,
+        retry_count: Option<i32>,
+        schedule_time: Option<PrimitiveDateTime>,
+        admin_audit_trail: Vec<serde_json::Value>,
+    },
 }
 
 #[derive(Debug, Clone, AsChangeset, router_derive::DebugAsDisplay)]
@@ -86,6 +109,7 @@ pub struct ProcessTrackerUpdateInternal {
     tracking_data: Option<serde_json::Value>,
     business_status: Option<String>,
     status: Option<storage_enums::ProcessTrackerStatus>,
+    admin_audit_trail: Option<Vec<serde_json::Value>>,
     updated_at: Option<PrimitiveDateTime>,
 }
 
@@ -98,6 +122,7 @@ impl Default for ProcessTrackerUpdateInternal {
             tracking_data: Option::default(),
             business_status: Option::default(),
             status: Option::default(),
+            admin_audit_trail: Option::default(),
             updated_at: Some(common_utils::date_time::now()),
         }
     }
@@ -122,6 +147,7 @@ impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
                 business_status,
                 status,
                 updated_at,
+                ..Default::default()
             },
             ProcessTrackerUpdate::StatusUpdate {
                 status,
@@ -141,6 +167,36 @@ impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
                 schedule_time: Some(schedule_time),
                 ..Default::default()
             },
+            ProcessTrackerUpdate::AdminUpdate {
+                status,
+                business_status,
+                retry_count,
+                schedule_time,
+                admin_audit_trail,
+            } => Self {
+                status: Some(status),
+                business_status,
+                retry_count,
+                schedule_time,
+                admin_audit_trail: Some(admin_audit_trail),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl ProcessTrackerUpdateInternal {
+    pub fn apply_changeset(self, source: ProcessTracker) -> ProcessTracker {
+        ProcessTracker {
+            name: self.name.or(source.name),
+            retry_count: self.retry_count.unwrap_or(source.retry_count),
+            schedule_time: self.schedule_time.or(source.schedule_time),
+            tracking_data: self.tracking_data.unwrap_or(source.tracking_data),
+            business_status: self.business_status.unwrap_or(source.business_status),
+            status: self.status.unwrap_or(source.status),
+            admin_audit_trail: self.admin_audit_trail.unwrap_or(source.admin_audit_trail),
+            updated_at: self.updated_at.unwrap_or_else(common_utils::date_time::now),
+            ..source
         }
     }
 }
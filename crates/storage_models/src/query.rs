@@ -3,6 +3,7 @@ pub mod api_keys;
 pub mod cards_info;
 pub mod configs;
 pub mod connector_response;
+pub mod customer_erasure;
 pub mod customers;
 pub mod dispute;
 pub mod events;
@@ -17,5 +18,8 @@ pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
 pub mod process_tracker;
+pub mod reconciliation;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod vault_card;
+pub mod vault_token;
@@ -1,5 +1,6 @@
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
@@ -115,4 +116,24 @@ impl ApiKey {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_unused_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        unused_since: PrimitiveDateTime,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()).and(
+                dsl::last_used.lt(unused_since).or(dsl::last_used
+                    .is_null()
+                    .and(dsl::created_at.lt(unused_since))),
+            ),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
 }
@@ -1,10 +1,11 @@
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
     dispute::{Dispute, DisputeNew, DisputeUpdate, DisputeUpdateInternal},
-    errors,
+    enums, errors,
     schema::dispute::dsl,
     PgPooledConn, StorageResult,
 };
@@ -70,6 +71,29 @@ impl Dispute {
         .await
     }
 
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_payment_ids(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq_any(payment_ids)),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn update(self, conn: &PgPooledConn, dispute: DisputeUpdate) -> StorageResult<Self> {
         match generics::generic_update_with_unique_predicate_get_result::<
@@ -91,4 +115,27 @@ impl Dispute {
             result => result,
         }
     }
+
+    /// Lists the disputes which were last updated within the time range, least recently updated
+    /// first
+    #[instrument(skip(conn))]
+    pub async fn find_by_statuses_modified_between(
+        conn: &PgPooledConn,
+        statuses: Vec<enums::DisputeStatus>,
+        modified_after: PrimitiveDateTime,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::dispute_status
+                .eq_any(statuses)
+                .and(dsl::modified_at.gt(modified_after))
+                .and(dsl::modified_at.lt(modified_before)),
+            Some(limit),
+            None,
+            Some(dsl::modified_at.asc()),
+        )
+        .await
+    }
 }
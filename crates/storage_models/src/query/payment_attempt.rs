@@ -1,6 +1,7 @@
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
 use error_stack::IntoReport;
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
@@ -63,6 +64,29 @@ impl PaymentAttempt {
         .await
     }
 
+    #[instrument(skip(conn))]
+    pub async fn find_by_payment_id_merchant_id(
+        conn: &PgPooledConn,
+        payment_id: &str,
+        merchant_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned())),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_by_connector_transaction_id_payment_id_merchant_id(
         conn: &PgPooledConn,
@@ -142,6 +166,29 @@ impl PaymentAttempt {
         .await
     }
 
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_attempt_ids(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        attempt_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::attempt_id.eq_any(attempt_ids)),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_by_merchant_id_preprocessing_id(
         conn: &PgPooledConn,
@@ -174,4 +221,81 @@ impl PaymentAttempt {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_connector_status_created_between(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector: &str,
+        status: enums::AttemptStatus,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector.eq(connector.to_owned()))
+                .and(dsl::status.eq(status))
+                .and(dsl::created_at.between(start_time, end_time)),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Lists the payment attempts routed to a connector which were last updated within the time
+    /// range, least recently updated first
+    #[instrument(skip(conn))]
+    pub async fn find_by_statuses_modified_between(
+        conn: &PgPooledConn,
+        statuses: Vec<enums::AttemptStatus>,
+        modified_after: PrimitiveDateTime,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::status
+                .eq_any(statuses)
+                .and(dsl::connector.is_not_null())
+                .and(dsl::modified_at.gt(modified_after))
+                .and(dsl::modified_at.lt(modified_before)),
+            Some(limit),
+            None,
+            Some(dsl::modified_at.asc()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_connector_connector_txn_ids(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector: &str,
+        connector_txn_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector.eq(connector.to_owned()))
+                .and(dsl::connector_transaction_id.eq_any(connector_txn_ids)),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
 }
@@ -1,9 +1,10 @@
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
-    errors,
+    enums, errors,
     payment_method::{self, PaymentMethod, PaymentMethodNew},
     schema::payment_methods::dsl,
     PgPooledConn, StorageResult,
@@ -99,6 +100,53 @@ impl PaymentMethod {
         .await
     }
 
+    #[instrument(skip(conn))]
+    pub async fn find_by_customer_id_merchant_id_card_fingerprint(
+        conn: &PgPooledConn,
+        customer_id: &str,
+        merchant_id: &str,
+        card_fingerprint: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::customer_id
+                .eq(customer_id.to_owned())
+                .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+                .and(dsl::card_fingerprint.eq(card_fingerprint.to_owned())),
+        )
+        .await
+    }
+
+    /// Finds the saved cards expiring before the given time, which have not been checked with the
+    /// account updater since the given time and have not been reported as closed
+    #[instrument(skip(conn))]
+    pub async fn find_cards_expiring_before(
+        conn: &PgPooledConn,
+        expires_before: PrimitiveDateTime,
+        checked_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::card_expires_at
+                .lt(expires_before)
+                .and(
+                    dsl::card_account_status
+                        .is_null()
+                        .or(dsl::card_account_status.ne(enums::CardAccountStatus::Closed)),
+                )
+                .and(
+                    dsl::account_updater_checked_at
+                        .is_null()
+                        .or(dsl::account_updater_checked_at.lt(checked_before)),
+                ),
+            Some(limit),
+            None,
+            Some(dsl::card_expires_at.asc()),
+        )
+        .await
+    }
+
     pub async fn update_with_payment_method_id(
         self,
         conn: &PgPooledConn,
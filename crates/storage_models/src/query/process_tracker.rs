@@ -64,6 +64,81 @@ impl ProcessTracker {
         .await
     }
 
+    /// Acquires the lease of the task for the consumer, marking the task as started, if the task is
+    /// yet to be completed and is not leased by another consumer. Returns `None` if the lease could
+    /// not be acquired.
+    #[instrument(skip(conn))]
+    pub async fn acquire_lease(
+        conn: &PgPooledConn,
+        id: &str,
+        lease_owner: &str,
+        lease_expires_at: PrimitiveDateTime,
+        now: PrimitiveDateTime,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::id
+                .eq(id.to_owned())
+                .and(dsl::status.eq_any(vec![
+                    enums::ProcessTrackerStatus::Processing,
+                    enums::ProcessTrackerStatus::ProcessStarted,
+                ]))
+                .and(
+                    dsl::lease_expires_at
+                        .is_null()
+                        .or(dsl::lease_expires_at.lt(now)),
+                ),
+            (
+                dsl::status.eq(enums::ProcessTrackerStatus::ProcessStarted),
+                dsl::lease_owner.eq(lease_owner.to_owned()),
+                dsl::lease_expires_at.eq(lease_expires_at),
+                dsl::updated_at.eq(now),
+            ),
+        )
+        .await
+        .map(|mut processes: Vec<Self>| processes.pop())
+    }
+
+    /// Extends the lease of the task if it is still held by the consumer
+    #[instrument(skip(conn))]
+    pub async fn renew_lease(
+        conn: &PgPooledConn,
+        id: &str,
+        lease_owner: &str,
+        lease_expires_at: PrimitiveDateTime,
+    ) -> StorageResult<bool> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::id
+                .eq(id.to_owned())
+                .and(dsl::lease_owner.eq(lease_owner.to_owned())),
+            dsl::lease_expires_at.eq(lease_expires_at),
+        )
+        .await
+        .map(|count| count > 0)
+    }
+
+    /// Releases the lease of the task if it is still held by the consumer
+    #[instrument(skip(conn))]
+    pub async fn release_lease(
+        conn: &PgPooledConn,
+        id: &str,
+        lease_owner: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::id
+                .eq(id.to_owned())
+                .and(dsl::lease_owner.eq(lease_owner.to_owned())),
+            (
+                dsl::lease_owner.eq(None::<String>),
+                dsl::lease_expires_at.eq(None::<PrimitiveDateTime>),
+            ),
+        )
+        .await
+        .map(|count| count > 0)
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_processes_by_time_status(
         conn: &PgPooledConn,
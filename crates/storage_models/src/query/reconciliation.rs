@@ -0,0 +1,65 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    reconciliation::{
+        ReconciliationRun, ReconciliationRunNew, ReconciliationRunUpdate,
+        ReconciliationRunUpdateInternal,
+    },
+    schema::reconciliation_run::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ReconciliationRunNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ReconciliationRun> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ReconciliationRun {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_run_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        run_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::run_id.eq(run_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        reconciliation_run: ReconciliationRunUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::run_id.eq(self.run_id.to_owned())),
+            ReconciliationRunUpdateInternal::from(reconciliation_run),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
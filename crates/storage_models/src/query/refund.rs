@@ -1,9 +1,10 @@
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
-    errors,
+    enums, errors,
     refund::{Refund, RefundNew, RefundUpdate, RefundUpdateInternal},
     schema::refund::dsl,
     PgPooledConn, StorageResult,
@@ -134,4 +135,104 @@ impl Refund {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_payment_ids(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq_any(payment_ids)),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_connector_status_created_between(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector: &str,
+        refund_status: enums::RefundStatus,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector.eq(connector.to_owned()))
+                .and(dsl::refund_status.eq(refund_status))
+                .and(dsl::created_at.between(start_time, end_time)),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Lists the refunds sent to the connector which were last updated within the time range,
+    /// least recently updated first
+    #[instrument(skip(conn))]
+    pub async fn find_by_statuses_modified_between(
+        conn: &PgPooledConn,
+        statuses: Vec<enums::RefundStatus>,
+        modified_after: PrimitiveDateTime,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::refund_status
+                .eq_any(statuses)
+                .and(dsl::sent_to_gateway.eq(true))
+                .and(dsl::modified_at.gt(modified_after))
+                .and(dsl::modified_at.lt(modified_before)),
+            Some(limit),
+            None,
+            Some(dsl::modified_at.asc()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_connector_refund_ids_connector(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector_refund_ids: Vec<String>,
+        connector: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector_refund_id.eq_any(connector_refund_ids))
+                .and(dsl::connector.eq(connector.to_owned())),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
 }
@@ -0,0 +1,57 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use super::generics;
+use crate::{
+    errors,
+    schema::vault_token::dsl,
+    vault_token::{VaultToken, VaultTokenNew},
+    PgPooledConn, StorageResult,
+};
+
+impl VaultTokenNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<VaultToken> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl VaultToken {
+    #[instrument(skip(conn))]
+    pub async fn find_by_token_id(conn: &PgPooledConn, token_id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::token_id.eq(token_id.to_owned()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_token_id(conn: &PgPooledConn, token_id: &str) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::token_id.eq(token_id.to_owned()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_expired(
+        conn: &PgPooledConn,
+        expired_before: PrimitiveDateTime,
+    ) -> StorageResult<bool> {
+        match generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::expires_at.lt(expired_before),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NotFound => Ok(false),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
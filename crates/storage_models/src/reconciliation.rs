@@ -0,0 +1,91 @@
+use common_utils::custom_serde;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::Serialize;
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::reconciliation_run};
+
+#[derive(Clone, Debug, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = reconciliation_run)]
+#[serde(deny_unknown_fields)]
+pub struct ReconciliationRunNew {
+    pub run_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub report_type: storage_enums::ReconciliationReportType,
+    pub report_source: String,
+    pub status: storage_enums::ReconciliationRunStatus,
+    pub period_start: Option<PrimitiveDateTime>,
+    pub period_end: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = reconciliation_run)]
+pub struct ReconciliationRun {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub run_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub report_type: storage_enums::ReconciliationReportType,
+    pub report_source: String,
+    pub status: storage_enums::ReconciliationRunStatus,
+    pub period_start: Option<PrimitiveDateTime>,
+    pub period_end: Option<PrimitiveDateTime>,
+    pub total_records: i32,
+    pub matched_records: i32,
+    pub mismatches: serde_json::Value,
+    pub error_message: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum ReconciliationRunUpdate {
+    Completed {
+        total_records: i32,
+        matched_records: i32,
+        mismatches: serde_json::Value,
+    },
+    Failed {
+        error_message: String,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = reconciliation_run)]
+pub struct ReconciliationRunUpdateInternal {
+    status: Option<storage_enums::ReconciliationRunStatus>,
+    total_records: Option<i32>,
+    matched_records: Option<i32>,
+    mismatches: Option<serde_json::Value>,
+    error_message: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<ReconciliationRunUpdate> for ReconciliationRunUpdateInternal {
+    fn from(reconciliation_run_update: ReconciliationRunUpdate) -> Self {
+        match reconciliation_run_update {
+            ReconciliationRunUpdate::Completed {
+                total_records,
+                matched_records,
+                mismatches,
+            } => Self {
+                status: Some(storage_enums::ReconciliationRunStatus::Completed),
+                total_records: Some(total_records),
+                matched_records: Some(matched_records),
+                mismatches: Some(mismatches),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            ReconciliationRunUpdate::Failed { error_message } => Self {
+                status: Some(storage_enums::ReconciliationRunStatus::Failed),
+                error_message: Some(error_message),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        }
+    }
+}
@@ -100,6 +100,8 @@ pub enum RefundUpdate {
         refund_error_message: Option<String>,
         refund_error_code: Option<String>,
     },
+    /// Redacts the personal data of the customer provided with the refund
+    RedactionUpdate,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -113,6 +115,7 @@ pub struct RefundUpdateInternal {
     metadata: Option<pii::SecretSerdeValue>,
     refund_reason: Option<String>,
     refund_error_code: Option<String>,
+    description: Option<String>,
 }
 
 impl RefundUpdateInternal {
@@ -126,6 +129,7 @@ impl RefundUpdateInternal {
             metadata: self.metadata,
             refund_reason: self.refund_reason,
             refund_error_code: self.refund_error_code,
+            description: self.description.or(source.description),
             ..source
         }
     }
@@ -173,6 +177,12 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_error_code,
                 ..Default::default()
             },
+            RefundUpdate::RedactionUpdate => Self {
+                metadata: Some(serde_json::json!({}).into()),
+                refund_reason: Some(pii::REDACTED.to_string()),
+                description: Some(pii::REDACTED.to_string()),
+                ..Default::default()
+            },
         }
     }
 }
@@ -190,6 +200,8 @@ impl RefundUpdate {
             refund_error_code: pa_update.refund_error_code.or(source.refund_error_code),
             refund_arn: pa_update.refund_arn.or(source.refund_arn),
             metadata: pa_update.metadata.or(source.metadata),
+            refund_reason: pa_update.refund_reason.or(source.refund_reason),
+            description: pa_update.description.or(source.description),
             ..source
         }
     }
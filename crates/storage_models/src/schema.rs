@@ -27,6 +27,7 @@ diesel::table! {
         customer_id -> Varchar,
         #[max_length = 64]
         merchant_id -> Varchar,
+        is_saved -> Bool,
     }
 }
 
@@ -112,6 +113,28 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    customer_erasure (id) {
+        id -> Int4,
+        #[max_length = 64]
+        erasure_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        erased_records -> Jsonb,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        completed_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -133,6 +156,16 @@ diesel::table! {
         metadata -> Nullable<Json>,
         connector_customer -> Nullable<Jsonb>,
         modified_at -> Timestamp,
+        #[max_length = 64]
+        default_payment_method_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        email_blind_index -> Nullable<Varchar>,
+        #[max_length = 64]
+        phone_blind_index -> Nullable<Varchar>,
+        #[max_length = 64]
+        default_billing_address_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        default_shipping_address_id -> Nullable<Varchar>,
     }
 }
 
@@ -328,6 +361,7 @@ diesel::table! {
         created_at -> Timestamp,
         modified_at -> Timestamp,
         frm_routing_algorithm -> Nullable<Jsonb>,
+        capture_schedule -> Nullable<Jsonb>,
     }
 }
 
@@ -430,6 +464,7 @@ diesel::table! {
         preprocessing_step_id -> Nullable<Varchar>,
         mandate_details -> Nullable<Jsonb>,
         error_reason -> Nullable<Text>,
+        installment_count -> Nullable<Int2>,
     }
 }
 
@@ -518,6 +553,21 @@ diesel::table! {
         payment_method_issuer -> Nullable<Varchar>,
         payment_method_issuer_code -> Nullable<PaymentMethodIssuerCode>,
         metadata -> Nullable<Json>,
+        payment_method_data -> Nullable<Bytea>,
+        #[max_length = 64]
+        card_fingerprint -> Nullable<Varchar>,
+        #[max_length = 128]
+        network_token_reference -> Nullable<Varchar>,
+        #[max_length = 32]
+        network_token_status -> Nullable<Varchar>,
+        network_token_data -> Nullable<Bytea>,
+        card_expires_at -> Nullable<Timestamp>,
+        #[max_length = 32]
+        card_account_status -> Nullable<Varchar>,
+        account_updater_checked_at -> Nullable<Timestamp>,
+        last_used_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        card_reference -> Nullable<Varchar>,
     }
 }
 
@@ -544,6 +594,38 @@ diesel::table! {
         event -> Array<Nullable<Text>>,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        #[max_length = 64]
+        lease_owner -> Nullable<Varchar>,
+        lease_expires_at -> Nullable<Timestamp>,
+        admin_audit_trail -> Array<Nullable<Jsonb>>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    reconciliation_run (id) {
+        id -> Int4,
+        #[max_length = 64]
+        run_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 32]
+        report_type -> Varchar,
+        report_source -> Text,
+        #[max_length = 32]
+        status -> Varchar,
+        period_start -> Nullable<Timestamp>,
+        period_end -> Nullable<Timestamp>,
+        total_records -> Int4,
+        matched_records -> Int4,
+        mismatches -> Jsonb,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -607,12 +689,53 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    vault_card (id) {
+        id -> Int4,
+        #[max_length = 64]
+        card_reference -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        card_fingerprint -> Varchar,
+        encrypted_card -> Bytea,
+        encrypted_data_key -> Bytea,
+        #[max_length = 64]
+        key_id -> Varchar,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    vault_token (id) {
+        id -> Int4,
+        #[max_length = 255]
+        token_id -> Varchar,
+        encrypted_value -> Bytea,
+        encrypted_data_key -> Bytea,
+        #[max_length = 64]
+        key_id -> Varchar,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
     cards_info,
     configs,
     connector_response,
+    customer_erasure,
     customers,
     dispute,
     events,
@@ -626,6 +749,9 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_intent,
     payment_methods,
     process_tracker,
+    reconciliation_run,
     refund,
     reverse_lookup,
+    vault_card,
+    vault_token,
 );
@@ -0,0 +1,93 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::vault_card;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = vault_card)]
+pub struct VaultCardNew {
+    pub card_reference: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub card_fingerprint: String,
+    pub encrypted_card: Vec<u8>,
+    pub encrypted_data_key: Vec<u8>,
+    pub key_id: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable)]
+#[diesel(table_name = vault_card)]
+pub struct VaultCard {
+    pub id: i32,
+    pub card_reference: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub card_fingerprint: String,
+    pub encrypted_card: Vec<u8>,
+    pub encrypted_data_key: Vec<u8>,
+    pub key_id: String,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum VaultCardUpdate {
+    CardUpdate {
+        encrypted_card: Vec<u8>,
+        encrypted_data_key: Vec<u8>,
+        key_id: String,
+    },
+    KeyRotationUpdate {
+        encrypted_data_key: Vec<u8>,
+        key_id: String,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = vault_card)]
+pub struct VaultCardUpdateInternal {
+    encrypted_card: Option<Vec<u8>>,
+    encrypted_data_key: Option<Vec<u8>>,
+    key_id: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl VaultCardUpdateInternal {
+    pub fn apply_changeset(self, source: VaultCard) -> VaultCard {
+        VaultCard {
+            encrypted_card: self.encrypted_card.unwrap_or(source.encrypted_card),
+            encrypted_data_key: self.encrypted_data_key.unwrap_or(source.encrypted_data_key),
+            key_id: self.key_id.unwrap_or(source.key_id),
+            modified_at: self
+                .modified_at
+                .unwrap_or_else(common_utils::date_time::now),
+            ..source
+        }
+    }
+}
+
+impl From<VaultCardUpdate> for VaultCardUpdateInternal {
+    fn from(vault_card_update: VaultCardUpdate) -> Self {
+        match vault_card_update {
+            VaultCardUpdate::CardUpdate {
+                encrypted_card,
+                encrypted_data_key,
+                key_id,
+            } => Self {
+                encrypted_card: Some(encrypted_card),
+                encrypted_data_key: Some(encrypted_data_key),
+                key_id: Some(key_id),
+                modified_at: Some(common_utils::date_time::now()),
+            },
+            VaultCardUpdate::KeyRotationUpdate {
+                encrypted_data_key,
+                key_id,
+            } => Self {
+                encrypted_card: None,
+                encrypted_data_key: Some(encrypted_data_key),
+                key_id: Some(key_id),
+                modified_at: Some(common_utils::date_time::now()),
+            },
+        }
+    }
+}
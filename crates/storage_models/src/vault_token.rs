@@ -0,0 +1,26 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::vault_token;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = vault_token)]
+pub struct VaultTokenNew {
+    pub token_id: String,
+    pub encrypted_value: Vec<u8>,
+    pub encrypted_data_key: Vec<u8>,
+    pub key_id: String,
+    pub expires_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable)]
+#[diesel(table_name = vault_token)]
+pub struct VaultToken {
+    pub id: i32,
+    pub token_id: String,
+    pub encrypted_value: Vec<u8>,
+    pub encrypted_data_key: Vec<u8>,
+    pub key_id: String,
+    pub created_at: PrimitiveDateTime,
+    pub expires_at: PrimitiveDateTime,
+}
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'api_keys';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'api_key_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'api_key_expiring';
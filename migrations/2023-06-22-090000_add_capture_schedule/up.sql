@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE merchant_account
+ADD COLUMN capture_schedule JSONB NULL;
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_cancelled';
@@ -0,0 +1,21 @@
+CREATE TABLE reconciliation_run (
+    id SERIAL PRIMARY KEY,
+    run_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    report_type VARCHAR(32) NOT NULL,
+    report_source VARCHAR(255) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    period_start TIMESTAMP,
+    period_end TIMESTAMP,
+    total_records INTEGER NOT NULL DEFAULT 0,
+    matched_records INTEGER NOT NULL DEFAULT 0,
+    mismatches JSONB NOT NULL DEFAULT '[]'::JSONB,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX reconciliation_run_merchant_id_run_id_index ON reconciliation_run (merchant_id, run_id);
+
+CREATE INDEX reconciliation_run_merchant_id_created_at_index ON reconciliation_run (merchant_id, created_at);
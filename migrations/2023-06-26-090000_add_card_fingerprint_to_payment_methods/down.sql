@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX payment_methods_merchant_id_customer_id_card_fingerprint_index;
+
+ALTER TABLE payment_methods DROP COLUMN card_fingerprint;
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_methods
+DROP COLUMN network_token_reference,
+DROP COLUMN network_token_status,
+DROP COLUMN network_token_data;
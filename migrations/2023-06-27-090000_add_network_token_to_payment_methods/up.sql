@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE payment_methods
+ADD COLUMN network_token_reference VARCHAR(128) DEFAULT NULL,
+ADD COLUMN network_token_status VARCHAR(32) DEFAULT NULL,
+ADD COLUMN network_token_data BYTEA DEFAULT NULL;
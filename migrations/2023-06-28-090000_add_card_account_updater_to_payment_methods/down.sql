@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX payment_methods_card_expires_at_index;
+
+ALTER TABLE payment_methods
+DROP COLUMN card_expires_at,
+DROP COLUMN card_account_status,
+DROP COLUMN account_updater_checked_at;
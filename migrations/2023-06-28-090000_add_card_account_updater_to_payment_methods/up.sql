@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TABLE payment_methods
+ADD COLUMN card_expires_at TIMESTAMP DEFAULT NULL,
+ADD COLUMN card_account_status VARCHAR(32) DEFAULT NULL,
+ADD COLUMN account_updater_checked_at TIMESTAMP DEFAULT NULL;
+
+CREATE INDEX payment_methods_card_expires_at_index ON payment_methods (card_expires_at);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE customers DROP COLUMN default_payment_method_id;
+
+ALTER TABLE payment_methods DROP COLUMN last_used_at;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE customers
+ADD COLUMN default_payment_method_id VARCHAR(64) DEFAULT NULL;
+
+ALTER TABLE payment_methods
+ADD COLUMN last_used_at TIMESTAMP DEFAULT NULL;
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX customers_merchant_id_email_blind_index_index;
+
+DROP INDEX customers_merchant_id_phone_blind_index_index;
+
+ALTER TABLE customers
+DROP COLUMN email_blind_index,
+DROP COLUMN phone_blind_index;
@@ -0,0 +1,11 @@
+-- Your SQL goes here
+-- The blind indexes are computed from the decrypted email and phone number with the merchant key,
+-- so existing customers cannot be backfilled here. They are set when a customer is created or its
+-- email or phone number is updated, and customers without them are not matched by the list filters.
+ALTER TABLE customers
+ADD COLUMN email_blind_index VARCHAR(64) DEFAULT NULL,
+ADD COLUMN phone_blind_index VARCHAR(64) DEFAULT NULL;
+
+CREATE INDEX customers_merchant_id_email_blind_index_index ON customers (merchant_id, email_blind_index);
+
+CREATE INDEX customers_merchant_id_phone_blind_index_index ON customers (merchant_id, phone_blind_index);
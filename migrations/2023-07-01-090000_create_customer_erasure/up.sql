@@ -0,0 +1,16 @@
+CREATE TABLE customer_erasure (
+    id SERIAL PRIMARY KEY,
+    erasure_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    erased_records JSONB NOT NULL DEFAULT '{}'::JSONB,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    completed_at TIMESTAMP
+);
+
+CREATE UNIQUE INDEX customer_erasure_merchant_id_erasure_id_index ON customer_erasure (merchant_id, erasure_id);
+
+CREATE INDEX customer_erasure_merchant_id_customer_id_index ON customer_erasure (merchant_id, customer_id);
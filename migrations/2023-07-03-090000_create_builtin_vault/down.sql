@@ -0,0 +1,3 @@
+DROP TABLE vault_token;
+
+DROP TABLE vault_card;
@@ -0,0 +1,32 @@
+CREATE TABLE vault_card (
+    id SERIAL PRIMARY KEY,
+    card_reference VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    card_fingerprint VARCHAR(64) NOT NULL,
+    encrypted_card BYTEA NOT NULL,
+    encrypted_data_key BYTEA NOT NULL,
+    key_id VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX vault_card_card_reference_index ON vault_card (card_reference);
+
+CREATE INDEX vault_card_merchant_id_customer_id_card_fingerprint_index ON vault_card (merchant_id, customer_id, card_fingerprint);
+
+CREATE INDEX vault_card_key_id_index ON vault_card (key_id);
+
+CREATE TABLE vault_token (
+    id SERIAL PRIMARY KEY,
+    token_id VARCHAR(255) NOT NULL,
+    encrypted_value BYTEA NOT NULL,
+    encrypted_data_key BYTEA NOT NULL,
+    key_id VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    expires_at TIMESTAMP NOT NULL
+);
+
+CREATE UNIQUE INDEX vault_token_token_id_index ON vault_token (token_id);
+
+CREATE INDEX vault_token_expires_at_index ON vault_token (expires_at);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE customers
+DROP COLUMN default_billing_address_id,
+DROP COLUMN default_shipping_address_id;
+
+ALTER TABLE address DROP COLUMN is_saved;
@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TABLE address
+ADD COLUMN is_saved BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE customers
+ADD COLUMN default_billing_address_id VARCHAR(64) DEFAULT NULL,
+ADD COLUMN default_shipping_address_id VARCHAR(64) DEFAULT NULL;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE process_tracker
+DROP COLUMN lease_owner,
+DROP COLUMN lease_expires_at;
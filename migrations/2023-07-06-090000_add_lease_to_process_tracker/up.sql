@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE process_tracker
+ADD COLUMN lease_owner VARCHAR(64),
+ADD COLUMN lease_expires_at TIMESTAMP;
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE process_tracker
+DROP COLUMN admin_audit_trail;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE process_tracker
+ADD COLUMN admin_audit_trail JSONB[] NOT NULL DEFAULT ARRAY[]::JSONB[];
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX payment_attempt_status_modified_at_index;
+
+DROP INDEX refund_refund_status_modified_at_index;
+
+DROP INDEX dispute_dispute_status_modified_at_index;
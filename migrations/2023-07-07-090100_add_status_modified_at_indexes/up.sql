@@ -0,0 +1,6 @@
+-- Your SQL goes here
+CREATE INDEX payment_attempt_status_modified_at_index ON payment_attempt (status, modified_at);
+
+CREATE INDEX refund_refund_status_modified_at_index ON refund (refund_status, modified_at);
+
+CREATE INDEX dispute_dispute_status_modified_at_index ON dispute (dispute_status, modified_at);
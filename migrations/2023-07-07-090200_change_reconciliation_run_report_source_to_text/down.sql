@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE reconciliation_run ALTER COLUMN report_source TYPE VARCHAR(255) USING LEFT(report_source, 255);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE reconciliation_run ALTER COLUMN report_source TYPE TEXT;